@@ -18,7 +18,7 @@ fn main() {
 
     let mut map = phf_codegen::Map::<u32>::new();
 
-    const CSR_ARG_NAMES: [(u32, &'static str); 288] = [
+    const CSR_ARG_NAMES: [(u32, &str); 321] = [
         (0x0280, "\"bsatp\""),
         (0x0242, "\"bscause\""),
         (0x0241, "\"bsepc\""),
@@ -32,7 +32,8 @@ fn main() {
         (0x0c80, "\"cycleh\""),
         (0x07b0, "\"dcsr\""),
         (0x07b1, "\"dpc\""),
-        (0x07b2, "\"dscratch\""),
+        (0x07b2, "\"dscratch0\""),
+        (0x07b3, "\"dscratch1\""),
         (0x0003, "\"fcsr\""),
         (0x0001, "\"fflags\""),
         (0x0002, "\"frm\""),
@@ -217,6 +218,35 @@ fn main() {
         (0x0327, "\"mhpmevent7\""),
         (0x0328, "\"mhpmevent8\""),
         (0x0329, "\"mhpmevent9\""),
+        (0x072a, "\"mhpmevent10h\""),
+        (0x072b, "\"mhpmevent11h\""),
+        (0x072c, "\"mhpmevent12h\""),
+        (0x072d, "\"mhpmevent13h\""),
+        (0x072e, "\"mhpmevent14h\""),
+        (0x072f, "\"mhpmevent15h\""),
+        (0x0730, "\"mhpmevent16h\""),
+        (0x0731, "\"mhpmevent17h\""),
+        (0x0732, "\"mhpmevent18h\""),
+        (0x0733, "\"mhpmevent19h\""),
+        (0x0734, "\"mhpmevent20h\""),
+        (0x0735, "\"mhpmevent21h\""),
+        (0x0736, "\"mhpmevent22h\""),
+        (0x0737, "\"mhpmevent23h\""),
+        (0x0738, "\"mhpmevent24h\""),
+        (0x0739, "\"mhpmevent25h\""),
+        (0x073a, "\"mhpmevent26h\""),
+        (0x073b, "\"mhpmevent27h\""),
+        (0x073c, "\"mhpmevent28h\""),
+        (0x073d, "\"mhpmevent29h\""),
+        (0x073e, "\"mhpmevent30h\""),
+        (0x073f, "\"mhpmevent31h\""),
+        (0x0723, "\"mhpmevent3h\""),
+        (0x0724, "\"mhpmevent4h\""),
+        (0x0725, "\"mhpmevent5h\""),
+        (0x0726, "\"mhpmevent6h\""),
+        (0x0727, "\"mhpmevent7h\""),
+        (0x0728, "\"mhpmevent8h\""),
+        (0x0729, "\"mhpmevent9h\""),
         (0x0382, "\"mibase\""),
         (0x0383, "\"mibound\""),
         (0x0303, "\"mideleg\""),
@@ -271,6 +301,9 @@ fn main() {
         (0x05a8, "\"scontext\""),
         (0x0106, "\"scounteren\""),
         (0x0102, "\"sedeleg\""),
+        // zkr entropy source, only readable through read-write instructions (csrrw etc) since the
+        // read itself consumes entropy, a read-only access raises an illegal instruction exception
+        (0x0015, "\"seed\""),
         (0x010a, "\"senvcfg\""),
         (0x0141, "\"sepc\""),
         (0x0103, "\"sideleg\""),
@@ -285,9 +318,11 @@ fn main() {
         (0x0143, "\"stval\""),
         (0x0105, "\"stvec\""),
         (0x0107, "\"stvt\""),
+        (0x07a5, "\"tcontrol\""),
         (0x07a1, "\"tdata1\""),
         (0x07a2, "\"tdata2\""),
         (0x07a3, "\"tdata3\""),
+        (0x07a4, "\"tinfo\""),
         (0x0c01, "\"time\""),
         (0x0c81, "\"timeh\""),
         (0x07a0, "\"tselect\""),
@@ -326,7 +361,7 @@ fn main() {
 
     writeln!(
         &mut file,
-        "static CSRS: phf::Map<u32, &'static str> = {};",
+        "pub(crate) static CSRS: phf::Map<u32, &'static str> = {};",
         map.build()
     )
     .unwrap();
@@ -0,0 +1,218 @@
+use std::io::{self, BufRead, Write};
+
+use riscv_decoder::stream::Decoded;
+
+const PAGE: usize = 20;
+
+const HELP: &str = "commands:
+  <enter>, j      next page
+  k               previous page
+  g <addr|name>   go to an address or a symbol
+  f               follow the branch/jump on the current line
+  b               go back to where the last `f` or `g` came from
+  /<mnemonic>     find the next instruction with that mnemonic
+  q               quit";
+
+struct Viewer<'a> {
+    lines: Vec<Decoded>,
+    // function names and labels out of an ELF file, sorted by address
+    symbols: Vec<(u32, &'a str)>,
+    // index of the line at the top of the screen
+    cursor: usize,
+    history: Vec<usize>,
+}
+
+impl Viewer<'_> {
+    fn index_of(&self, addr: u32) -> Option<usize> {
+        self.lines.iter().position(|line| line.addr == addr)
+    }
+
+    // a symbol name first since plenty of names (`add`, `face`) are valid hex too
+    fn resolve(&self, target: &str) -> Option<u32> {
+        match self.symbols.iter().find(|(_, name)| *name == target) {
+            Some((addr, _)) => Some(*addr),
+            None => u32::from_str_radix(target.trim_start_matches("0x"), 16).ok(),
+        }
+    }
+
+    fn jump(&mut self, index: usize) {
+        self.history.push(self.cursor);
+        self.cursor = index;
+    }
+
+    fn render<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        for (i, line) in self.lines.iter().enumerate().skip(self.cursor).take(PAGE) {
+            for (_, name) in self.symbols.iter().filter(|(addr, _)| *addr == line.addr) {
+                writeln!(out, "{name}:")?;
+            }
+            let marker = if i == self.cursor { '>' } else { ' ' };
+            match &line.inst {
+                Ok(inst) => writeln!(out, "{marker} {:08x}: {inst}", line.addr)?,
+                Err(_) => writeln!(out, "{marker} {:08x}: .word {:#x}", line.addr, line.raw)?,
+            }
+        }
+        Ok(())
+    }
+
+    // runs one command, false once the viewer should close
+    fn command<W: Write>(&mut self, command: &str, out: &mut W) -> io::Result<bool> {
+        let last = self.lines.len().saturating_sub(1);
+        match command.trim() {
+            "" | "j" => self.cursor = (self.cursor + PAGE).min(last),
+            "k" => self.cursor = self.cursor.saturating_sub(PAGE),
+            "q" => return Ok(false),
+            "b" => match self.history.pop() {
+                Some(index) => self.cursor = index,
+                None => writeln!(out, "nothing to go back to")?,
+            },
+            "f" => {
+                let line = &self.lines[self.cursor];
+                let target = line
+                    .inst
+                    .as_ref()
+                    .ok()
+                    .and_then(|inst| inst.branch_target(line.addr));
+                match target.and_then(|target| self.index_of(target)) {
+                    Some(index) => self.jump(index),
+                    None => writeln!(out, "no branch target to follow")?,
+                }
+            }
+            command if command.starts_with("g ") => {
+                let addr = self.resolve(command[2..].trim());
+                match addr.and_then(|addr| self.index_of(addr)) {
+                    Some(index) => self.jump(index),
+                    None => writeln!(out, "no instruction at that address")?,
+                }
+            }
+            command if command.starts_with('/') => {
+                let mnemonic = &command[1..];
+                let found = self
+                    .lines
+                    .get(self.cursor + 1..)
+                    .unwrap_or_default()
+                    .iter()
+                    .position(|line| {
+                        line.inst
+                            .as_ref()
+                            .is_ok_and(|inst| inst.mnemonic() == mnemonic)
+                    })
+                    .map(|offset| self.cursor + 1 + offset);
+                match found {
+                    Some(index) => self.jump(index),
+                    None => writeln!(out, "{mnemonic} not found")?,
+                }
+            }
+            _ => writeln!(out, "{HELP}")?,
+        }
+        Ok(true)
+    }
+}
+
+// a line based viewer rather than a TUI, every command redraws a page of the listing. `symbols` are printed as
+// labels above the line they point at and can be used with `g`
+pub fn run<R: BufRead, W: Write>(
+    lines: Vec<Decoded>,
+    mut symbols: Vec<(u32, &str)>,
+    input: R,
+    out: &mut W,
+) -> io::Result<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    symbols.sort_by_key(|(addr, _)| *addr);
+    let mut viewer = Viewer {
+        lines,
+        symbols,
+        cursor: 0,
+        history: Vec::new(),
+    };
+    viewer.render(out)?;
+    out.flush()?;
+    for command in input.lines() {
+        if !viewer.command(&command?, out)? {
+            break;
+        }
+        viewer.render(out)?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+#[test]
+fn navigation() {
+    use riscv_decoder::stream::InstructionStream;
+    let bytes = [
+        0x6f, 0x00, 0x80, 0x00, /* jal x0 8 */
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+        0x73, 0x00, 0x00, 0x00, /* ecall */
+    ];
+    let lines = InstructionStream::new(&bytes, 0x1000)
+        .unwrap()
+        .collect::<Vec<_>>();
+    let mut out = Vec::new();
+    run(
+        lines,
+        Vec::new(),
+        "f\nb\n/addi\ng 1008\nq\n".as_bytes(),
+        &mut out,
+    )
+    .unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let cursors = out
+        .lines()
+        .filter(|line| line.starts_with('>'))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        cursors,
+        vec![
+            "> 00001000: jal 8(zero)",
+            "> 00001008: ecall",
+            "> 00001000: jal 8(zero)",
+            "> 00001004: addi a2, a3, 100",
+            "> 00001008: ecall",
+        ]
+    );
+}
+
+#[test]
+fn symbols() {
+    use riscv_decoder::stream::InstructionStream;
+    let bytes = [
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+        0x73, 0x00, 0x00, 0x00, /* ecall */
+        0x73, 0x00, 0x00, 0x00, /* ecall */
+    ];
+    let lines = InstructionStream::new(&bytes, 0x1000)
+        .unwrap()
+        .collect::<Vec<_>>();
+    let symbols = vec![(0x1008, "add"), (0x1000, "main")];
+    let mut out = Vec::new();
+    run(lines, symbols, "g add\ng 1004\nq\n".as_bytes(), &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("main:\n> 00001000: addi a2, a3, 100\n"));
+    let cursors = out
+        .lines()
+        .filter(|line| line.starts_with('>'))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        cursors,
+        vec![
+            "> 00001000: addi a2, a3, 100",
+            "> 00001008: ecall",
+            "> 00001004: ecall",
+        ]
+    );
+    assert!(out.contains("add:\n> 00001008: ecall"));
+}
+
+#[test]
+fn search_past_the_end() {
+    use riscv_decoder::stream::InstructionStream;
+    let bytes = [0x73, 0x00, 0x00, 0x00 /* ecall */];
+    let lines = InstructionStream::new(&bytes, 0x1000)
+        .unwrap()
+        .collect::<Vec<_>>();
+    let mut out = Vec::new();
+    run(lines, Vec::new(), "/ecall\nq\n".as_bytes(), &mut out).unwrap();
+    assert!(String::from_utf8(out).unwrap().contains("ecall not found"));
+}
@@ -0,0 +1,204 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Result;
+
+use crate::decoded_inst::InstructionDecoded;
+use crate::decoder::Decoder;
+use crate::instructions::InstructionSize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Eviction {
+    // drop whatever was used the longest time ago
+    #[default]
+    LeastRecentlyUsed,
+    // drop whatever was decoded first, hits don't keep an entry around any longer
+    FirstInFirstOut,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    // when this is false every call goes straight to the decoder (hits and misses aren't counted)
+    pub enabled: bool,
+    // how many decoded instructions are kept, 0 keeps nothing
+    pub capacity: usize,
+    pub eviction: Eviction,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            capacity: 4096,
+            eviction: Eviction::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    // failed decodes aren't cached so they always count as a miss
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl CacheStats {
+    // between 0 and 1, 0 before anything was looked up
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+// memoizes a `Decoder` for code that decodes the same words over and over (an interpreter running
+// a hot loop), plain decoding is already cheap so this only pays off when lookups mostly hit
+#[derive(Debug, Clone)]
+pub struct CachedDecoder {
+    pub decoder: Decoder,
+    policy: CachePolicy,
+    // the inst along with the tick it was last used (lru) or inserted (fifo) at
+    entries: HashMap<InstructionSize, (InstructionDecoded, u64)>,
+    // tick -> inst, the first entry is the next one to go
+    order: BTreeMap<u64, InstructionSize>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+impl CachedDecoder {
+    pub fn new(decoder: Decoder, policy: CachePolicy) -> Self {
+        Self {
+            decoder,
+            policy,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            stats: CacheStats::default(),
+        }
+    }
+
+    pub fn policy(&self) -> CachePolicy {
+        self.policy
+    }
+
+    // a smaller capacity evicts right away, everything is dropped when the cache gets disabled
+    pub fn set_policy(&mut self, policy: CachePolicy) {
+        self.policy = policy;
+        if !policy.enabled {
+            self.clear();
+        }
+        while self.entries.len() > policy.capacity {
+            self.evict();
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    pub fn decode(&mut self, inst: InstructionSize) -> Result<InstructionDecoded> {
+        if !self.policy.enabled {
+            return self.decoder.decode(inst);
+        }
+
+        self.tick += 1;
+        if let Some((decoded, used)) = self.entries.get_mut(&inst) {
+            self.stats.hits += 1;
+            if self.policy.eviction == Eviction::LeastRecentlyUsed {
+                self.order.remove(used);
+                self.order.insert(self.tick, inst);
+                *used = self.tick;
+            }
+            return Ok(decoded.clone());
+        }
+
+        self.stats.misses += 1;
+        let decoded = self.decoder.decode(inst)?;
+        if self.policy.capacity > 0 {
+            if self.entries.len() >= self.policy.capacity {
+                self.evict();
+            }
+            self.entries.insert(inst, (decoded.clone(), self.tick));
+            self.order.insert(self.tick, inst);
+        }
+        Ok(decoded)
+    }
+
+    fn evict(&mut self) {
+        if let Some((_, inst)) = self.order.pop_first() {
+            self.entries.remove(&inst);
+            self.stats.evictions += 1;
+        }
+    }
+}
+
+#[test]
+fn policies() {
+    const ADDI: InstructionSize = 0x06468613; /* addi x12 x13 100 */
+    const ECALL: InstructionSize = 0x00000073;
+    const EBREAK: InstructionSize = 0x00100073;
+
+    let policy = CachePolicy {
+        capacity: 2,
+        ..Default::default()
+    };
+    let mut cache = CachedDecoder::new(Decoder::new(), policy);
+    for inst in [ADDI, ECALL, ADDI, EBREAK, ADDI, ECALL] {
+        cache.decode(inst).unwrap();
+    }
+    // lru keeps addi around since it keeps getting used, ecall was pushed out by ebreak
+    assert_eq!(
+        cache.stats(),
+        CacheStats {
+            hits: 2,
+            misses: 4,
+            evictions: 2,
+        }
+    );
+    assert_eq!(
+        cache.decode(ADDI).unwrap(),
+        Decoder::new().decode(ADDI).unwrap()
+    );
+
+    let mut cache = CachedDecoder::new(
+        Decoder::new(),
+        CachePolicy {
+            eviction: Eviction::FirstInFirstOut,
+            ..policy
+        },
+    );
+    for inst in [ADDI, ECALL, ADDI, EBREAK, ADDI] {
+        cache.decode(inst).unwrap();
+    }
+    // fifo drops addi first even though it was just used
+    assert_eq!(cache.stats().hits, 1);
+    assert_eq!(cache.stats().misses, 4);
+
+    cache.reset_stats();
+    cache.set_policy(CachePolicy {
+        enabled: false,
+        ..policy
+    });
+    assert!(cache.is_empty());
+    cache.decode(ADDI).unwrap();
+    assert_eq!(cache.stats(), CacheStats::default());
+    assert_eq!(CacheStats::default().hit_rate(), 0.0);
+}
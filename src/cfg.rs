@@ -0,0 +1,321 @@
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+use crate::decoded_inst::InstructionDecoded;
+use crate::instructions::InstructionSize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    Taken,
+    FallThrough,
+}
+
+impl EdgeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EdgeKind::Taken => "taken",
+            EdgeKind::FallThrough => "fall-through",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge {
+    pub to: InstructionSize,
+    pub kind: EdgeKind,
+}
+
+// a run of instructions with a single entry and a single exit, `start..end` indexes into the
+// slice the blocks were built from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicBlock {
+    pub addr: InstructionSize,
+    pub start: usize,
+    pub end: usize,
+    pub successors: Vec<Edge>,
+}
+
+// does this instruction end a basic block and can execution continue to the next one
+fn terminator(inst: &InstructionDecoded) -> Option<bool> {
+    match inst {
+        InstructionDecoded::Beq { .. }
+        | InstructionDecoded::Bne { .. }
+        | InstructionDecoded::Blt { .. }
+        | InstructionDecoded::Bge { .. }
+        | InstructionDecoded::Bltu { .. }
+        | InstructionDecoded::Bgeu { .. }
+        | InstructionDecoded::CBeqz { .. }
+        | InstructionDecoded::CBnez { .. } => Some(true),
+        // a jal that links somewhere is a call and comes back, only plain jumps end the block
+        InstructionDecoded::Jal { rd: 0, .. } | InstructionDecoded::CJ { .. } => Some(false),
+        // c.jal always links to ra
+        InstructionDecoded::CJal { .. } => None,
+        // an indirect call still comes back, but where it goes isn't known so the block ends here
+        InstructionDecoded::Jalr { rd: 0, .. }
+        | InstructionDecoded::CJr { .. }
+        | InstructionDecoded::CmJt { .. } => Some(false),
+        InstructionDecoded::Jalr { .. }
+        | InstructionDecoded::CJalr { .. }
+        | InstructionDecoded::CmJalt { .. } => Some(true),
+        // returns, the Zcmp pops that return included
+        InstructionDecoded::MRet
+        | InstructionDecoded::SRet
+        | InstructionDecoded::CmPopret { .. }
+        | InstructionDecoded::CmPopretz { .. } => Some(false),
+        _ => None,
+    }
+}
+
+// splits the (address, instruction) listing of a function into basic blocks, only edges that
+// land inside the listing are kept
+pub fn basic_blocks(insts: &[(InstructionSize, InstructionDecoded)]) -> Vec<BasicBlock> {
+    let index_of = |addr: InstructionSize| insts.iter().position(|(a, _)| *a == addr);
+
+    let mut leaders = BTreeSet::new();
+    if !insts.is_empty() {
+        leaders.insert(0);
+    }
+    for (i, (addr, inst)) in insts.iter().enumerate() {
+        if terminator(inst).is_none() {
+            continue;
+        }
+        if let Some(target) = inst.branch_target(*addr).and_then(index_of) {
+            leaders.insert(target);
+        }
+        if i + 1 < insts.len() {
+            leaders.insert(i + 1);
+        }
+    }
+
+    let leaders = leaders.into_iter().collect::<Vec<_>>();
+    let mut blocks = Vec::with_capacity(leaders.len());
+    for (n, &start) in leaders.iter().enumerate() {
+        let end = leaders.get(n + 1).copied().unwrap_or(insts.len());
+        let (last_addr, last) = &insts[end - 1];
+
+        let mut successors = Vec::new();
+        if terminator(last).is_some() {
+            if let Some(target) = last.branch_target(*last_addr) {
+                if index_of(target).is_some() {
+                    successors.push(Edge {
+                        to: target,
+                        kind: EdgeKind::Taken,
+                    });
+                }
+            }
+        }
+        if terminator(last).unwrap_or(true) && end < insts.len() {
+            successors.push(Edge {
+                to: insts[end].0,
+                kind: EdgeKind::FallThrough,
+            });
+        }
+
+        blocks.push(BasicBlock {
+            addr: insts[start].0,
+            start,
+            end,
+            successors,
+        });
+    }
+
+    blocks
+}
+
+fn node_name(addr: InstructionSize) -> String {
+    format!("bb_{addr:08x}")
+}
+
+fn escape_label(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// writes the control flow graph of a function as a graphviz digraph, each node lists the
+// instructions of its block
+pub fn write_dot<W: Write>(
+    out: &mut W,
+    name: &str,
+    insts: &[(InstructionSize, InstructionDecoded)],
+) -> io::Result<()> {
+    writeln!(out, "digraph \"{}\" {{", escape_label(name))?;
+    writeln!(out, "    node [shape=box, fontname=\"monospace\"];")?;
+
+    let blocks = basic_blocks(insts);
+    for block in &blocks {
+        let mut label = String::new();
+        for (addr, inst) in &insts[block.start..block.end] {
+            label.push_str(&escape_label(&format!("{addr:08x}: {inst}")));
+            label.push_str("\\l");
+        }
+        writeln!(out, "    {} [label=\"{label}\"];", node_name(block.addr))?;
+    }
+    for block in &blocks {
+        for edge in &block.successors {
+            writeln!(
+                out,
+                "    {} -> {} [label=\"{}\"];",
+                node_name(block.addr),
+                node_name(edge.to),
+                edge.kind.label()
+            )?;
+        }
+    }
+
+    writeln!(out, "}}")
+}
+
+#[cfg(test)]
+fn sample() -> Vec<(InstructionSize, InstructionDecoded)> {
+    use crate::decoder::try_decode;
+    [
+        (0x0, 0x06468613 /* addi x12 x13 100 */),
+        (0x4, 0x00409663 /* bne x1 x4 12 */),
+        (0x8, 0x06468613 /* addi x12 x13 100 */),
+        (0xc, 0x0080006f /* jal x0 8 */),
+        (0x10, 0x06468613 /* addi x12 x13 100 */),
+        (0x14, 0x00008067 /* jalr x0 0(ra) */),
+    ]
+    .into_iter()
+    .map(|(addr, inst)| (addr, try_decode(inst).unwrap()))
+    .collect()
+}
+
+#[test]
+fn blocks() {
+    let blocks = basic_blocks(&sample());
+    let starts = blocks.iter().map(|b| b.addr).collect::<Vec<_>>();
+    assert_eq!(starts, vec![0x0, 0x8, 0x10, 0x14]);
+    assert_eq!(
+        blocks[0].successors,
+        vec![
+            Edge {
+                to: 0x10,
+                kind: EdgeKind::Taken
+            },
+            Edge {
+                to: 0x8,
+                kind: EdgeKind::FallThrough
+            },
+        ]
+    );
+    assert_eq!(
+        blocks[1].successors,
+        vec![Edge {
+            to: 0x14,
+            kind: EdgeKind::Taken
+        }]
+    );
+    assert_eq!(
+        blocks[2].successors,
+        vec![Edge {
+            to: 0x14,
+            kind: EdgeKind::FallThrough
+        }]
+    );
+    assert!(blocks[3].successors.is_empty());
+}
+
+#[test]
+fn dot() {
+    let mut out = Vec::new();
+    write_dot(&mut out, "main", &sample()).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("digraph \"main\" {\n"));
+    assert!(out.contains(
+        "bb_00000000 [label=\"00000000: addi a2, a3, 100\\l00000004: bne ra, tp, 12\\l\"];"
+    ));
+    assert!(out.contains("bb_00000000 -> bb_00000010 [label=\"taken\"];"));
+    assert!(out.contains("bb_00000000 -> bb_00000008 [label=\"fall-through\"];"));
+    assert!(out.ends_with("}\n"));
+}
+
+#[test]
+fn indirect_calls() {
+    use crate::decoder::try_decode;
+    let insts = [
+        (0x0, 0x000500e7 /* jalr ra, 0(a0) */),
+        (0x4, 0x00000513 /* addi a0, zero, 0 */),
+        (0x8, 0x00008067 /* ret */),
+    ]
+    .into_iter()
+    .map(|(addr, inst)| (addr, try_decode(inst).unwrap()))
+    .collect::<Vec<_>>();
+    let blocks = basic_blocks(&insts);
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(
+        blocks[0].successors,
+        vec![Edge {
+            to: 0x4,
+            kind: EdgeKind::FallThrough
+        }]
+    );
+    assert!(blocks[1].successors.is_empty());
+}
+
+#[test]
+fn compressed_branches() {
+    use crate::decoder::try_decode;
+    let insts = [
+        (0x0, 0xc111 /* c.beqz a0, 4 */),
+        (0x2, 0xa011 /* c.j 4 */),
+        (0x4, 0x0505 /* c.addi a0, 1 */),
+        (0x6, 0x0001 /* c.nop */),
+    ]
+    .into_iter()
+    .map(|(addr, inst)| (addr, try_decode(inst).unwrap()))
+    .collect::<Vec<_>>();
+    let blocks = basic_blocks(&insts);
+    let starts = blocks.iter().map(|b| b.addr).collect::<Vec<_>>();
+    assert_eq!(starts, vec![0x0, 0x2, 0x4, 0x6]);
+    assert_eq!(
+        blocks[0].successors,
+        vec![
+            Edge {
+                to: 0x4,
+                kind: EdgeKind::Taken
+            },
+            Edge {
+                to: 0x2,
+                kind: EdgeKind::FallThrough
+            },
+        ]
+    );
+    // c.j doesn't fall through
+    assert_eq!(
+        blocks[1].successors,
+        vec![Edge {
+            to: 0x6,
+            kind: EdgeKind::Taken
+        }]
+    );
+}
+
+#[test]
+fn compressed_indirect_jumps() {
+    use crate::decoder::Decoder;
+    let decoder = Decoder {
+        compressed_double: crate::decoder::CompressedDouble::Zcmp,
+        ..Decoder::new()
+    };
+    let insts = [
+        (0x0, 0x9502u16 /* c.jalr a0 */),
+        (0x2, 0x8082 /* c.jr ra */),
+        (0x4, 0xa002 /* cm.jt 0 */),
+        (0x6, 0xbe42 /* cm.popret {ra}, 16 */),
+        (0x8, 0x0001 /* c.nop */),
+    ]
+    .into_iter()
+    .map(|(addr, inst)| (addr, decoder.decode(inst).unwrap()))
+    .collect::<Vec<_>>();
+    let blocks = basic_blocks(&insts);
+    let starts = blocks.iter().map(|b| b.addr).collect::<Vec<_>>();
+    assert_eq!(starts, vec![0x0, 0x2, 0x4, 0x6, 0x8]);
+    assert_eq!(
+        blocks[0].successors,
+        vec![Edge {
+            to: 0x2,
+            kind: EdgeKind::FallThrough
+        }]
+    );
+    assert!(blocks[1..4].iter().all(|b| b.successors.is_empty()));
+}
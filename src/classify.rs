@@ -0,0 +1,166 @@
+use crate::effects::{RegisterAccess, RegisterFile};
+use crate::instructions::InstructionSize;
+use crate::stream::InstructionStream;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    Code,
+    Data,
+}
+
+// how code-like one window of bytes looks, every part is between 0 and 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowScore {
+    pub addr: InstructionSize,
+    // how much of the window decodes at all
+    pub decode_rate: f32,
+    // how many branches/jumps land inside the region being classified
+    pub control_flow: f32,
+    // how many instructions only read registers that were written before or that the abi sets up
+    // (random data decodes into reads of registers nothing ever wrote)
+    pub registers: f32,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub start: InstructionSize,
+    pub end: InstructionSize,
+    pub kind: Classification,
+}
+
+// zero, ra, sp, gp, tp, s0 and a0-a7 are live on entry to pretty much any function
+const ABI_LIVE: u32 = 0b0000_0000_0000_0011_1111_1101_0001_1111;
+
+const CODE_THRESHOLD: f32 = 0.7;
+
+// scores `bytes` (at `addr`) as a linear sweep would decode it, branch targets are checked against
+// `region` which is everything being classified
+pub fn score_window(
+    bytes: &[u8],
+    addr: InstructionSize,
+    region: std::ops::Range<InstructionSize>,
+) -> WindowScore {
+    let Ok(stream) = InstructionStream::new(bytes, addr) else {
+        return WindowScore {
+            addr,
+            decode_rate: 0.0,
+            control_flow: 0.0,
+            registers: 0.0,
+            score: 0.0,
+        };
+    };
+
+    let (mut total, mut decoded, mut branches, mut good_branches, mut good_regs) = (0, 0, 0, 0, 0);
+    let mut written = ABI_LIVE;
+    for item in stream {
+        total += 1;
+        let Ok(inst) = item.inst else {
+            continue;
+        };
+        decoded += 1;
+
+        if let Some(target) = inst.branch_target(item.addr) {
+            branches += 1;
+            if region.contains(&target) && target % 2 == 0 {
+                good_branches += 1;
+            }
+        }
+
+        let effects = inst.effects();
+        let int = |reg: &&RegisterAccess| reg.file == RegisterFile::Int;
+        if effects
+            .reads
+            .iter()
+            .filter(int)
+            .all(|reg| written & (1 << reg.num) != 0)
+        {
+            good_regs += 1;
+        }
+        for reg in effects.writes.iter().filter(int) {
+            written |= 1 << reg.num;
+        }
+    }
+
+    let ratio = |part: u32, whole: u32, empty: f32| match whole {
+        0 => empty,
+        _ => part as f32 / whole as f32,
+    };
+    let decode_rate = ratio(decoded, total, 0.0);
+    // no branches isn't evidence either way
+    let control_flow = ratio(good_branches, branches, 1.0);
+    let registers = ratio(good_regs, decoded, 0.0);
+    WindowScore {
+        addr,
+        decode_rate,
+        control_flow,
+        registers,
+        score: 0.6 * decode_rate + 0.2 * control_flow + 0.2 * registers,
+    }
+}
+
+// splits `bytes` into `window` sized chunks, scores each one and merges neighbours that got the
+// same classification
+pub fn classify(bytes: &[u8], base: InstructionSize, window: usize) -> Vec<Region> {
+    let region = base..base.wrapping_add(bytes.len() as InstructionSize);
+    let mut regions: Vec<Region> = Vec::new();
+    for (i, chunk) in bytes.chunks(window.max(4)).enumerate() {
+        let start = base.wrapping_add((i * window.max(4)) as InstructionSize);
+        let score = score_window(chunk, start, region.clone());
+        let kind = if score.score >= CODE_THRESHOLD && score.decode_rate > 0.5 {
+            Classification::Code
+        } else {
+            Classification::Data
+        };
+        let end = start.wrapping_add(chunk.len() as InstructionSize);
+        match regions.last_mut() {
+            Some(last) if last.kind == kind => last.end = end,
+            _ => regions.push(Region { start, end, kind }),
+        }
+    }
+    regions
+}
+
+#[test]
+fn code_and_data() {
+    let code = [
+        0x13, 0x01, 0x01, 0xff, /* addi sp, sp, -16 */
+        0x23, 0x26, 0x11, 0x00, /* sw ra, 12(sp) */
+        0x13, 0x05, 0x15, 0x00, /* addi a0, a0, 1 */
+        0xe3, 0x0e, 0x05, 0xfe, /* beq a0, zero, -4 */
+        0x83, 0x20, 0xc1, 0x00, /* lw ra, 12(sp) */
+        0x13, 0x01, 0x01, 0x01, /* addi sp, sp, 16 */
+        0x67, 0x80, 0x00, 0x00, /* jalr zero, 0(ra) */
+        0x13, 0x00, 0x00, 0x00, /* nop */
+    ];
+    let mut bytes = code.to_vec();
+    // zero padding, ascii text mostly decodes as compressed loads, c.lui and c.jal
+    bytes.extend_from_slice(&[0; 32]);
+    bytes.extend_from_slice(&code);
+
+    let regions = classify(&bytes, 0x1000, 32);
+    assert_eq!(
+        regions,
+        vec![
+            Region {
+                start: 0x1000,
+                end: 0x1020,
+                kind: Classification::Code
+            },
+            Region {
+                start: 0x1020,
+                end: 0x1040,
+                kind: Classification::Data
+            },
+            Region {
+                start: 0x1040,
+                end: 0x1060,
+                kind: Classification::Code
+            },
+        ]
+    );
+
+    let score = score_window(&code, 0x1000, 0x1000..0x1020);
+    assert_eq!(score.decode_rate, 1.0);
+    assert_eq!(score.control_flow, 1.0);
+}
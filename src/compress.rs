@@ -0,0 +1,609 @@
+use crate::decoded_inst::InstructionDecoded;
+use crate::decoder::Xlen;
+use crate::instructions::compressed::CompressedSize;
+use crate::instructions::{InstructionSize, SignedInstructionSize};
+use crate::opcode::Extension;
+
+// the registers the compressed forms use implicitly
+const ZERO: InstructionSize = 0;
+const RA: InstructionSize = 1;
+const SP: InstructionSize = 2;
+
+impl InstructionDecoded {
+    // the 32 bit instruction a compressed one expands to, None for anything that isn't compressed
+    // and for the Zcmp/Zcmt sequences, which have no single base equivalent
+    pub fn decompress(&self) -> Option<InstructionDecoded> {
+        use InstructionDecoded as I;
+        let inst = match *self {
+            I::CNop => I::Addi {
+                rd: ZERO,
+                rs1: ZERO,
+                imm: 0,
+            },
+            I::CAddi4Spn { rd, nzuimm } => I::Addi {
+                rd,
+                rs1: SP,
+                imm: nzuimm,
+            },
+            I::CAddi { rd, imm } => I::Addi { rd, rs1: rd, imm },
+            I::CAddi16Sp { imm } => I::Addi {
+                rd: SP,
+                rs1: SP,
+                imm,
+            },
+            I::CLi { rd, imm } => I::Addi { rd, rs1: ZERO, imm },
+            I::CLui { rd, imm } => I::Lui { rd, imm },
+            I::CAddiw { rd, imm } => I::Addiw { rd, rs1: rd, imm },
+            I::CSlli { rd, rs1, shamt } => I::Slli {
+                rd,
+                rs1,
+                imm: shamt,
+            },
+            I::CSrli { rd, shamt } => I::Srli {
+                rd,
+                rs1: rd,
+                imm: shamt,
+            },
+            I::CSrai { rd, shamt } => I::Srai {
+                rd,
+                rs1: rd,
+                imm: shamt,
+            },
+            I::CAndi { rd, imm } => I::Andi { rd, rs1: rd, imm },
+            I::CSub { rd, rs2 } => I::Sub { rd, rs1: rd, rs2 },
+            I::CXor { rd, rs2 } => I::Xor { rd, rs1: rd, rs2 },
+            I::COr { rd, rs2 } => I::Or { rd, rs1: rd, rs2 },
+            I::CAnd { rd, rs2 } => I::And { rd, rs1: rd, rs2 },
+            I::CSubw { rd, rs2 } => I::Subw { rd, rs1: rd, rs2 },
+            I::CAddw { rd, rs2 } => I::Addw { rd, rs1: rd, rs2 },
+            I::CMv { rd, rs2 } => I::Add { rd, rs1: ZERO, rs2 },
+            I::CAdd { rd, rs2 } => I::Add { rd, rs1: rd, rs2 },
+            I::CLw { rd, rs1, imm } => I::Lw { rd, rs1, imm },
+            I::CSw { rs1, rs2, imm } => I::Sw { rs1, rs2, imm },
+            I::CLd { rd, rs1, imm } => I::Ld { rd, rs1, imm },
+            I::CSd { rs1, rs2, imm } => I::Sd { rs1, rs2, imm },
+            // the width field of flw is funct3 = 010
+            I::CFlw { rd, rs1, imm } => I::Flw {
+                rd,
+                width: 2,
+                rs1,
+                imm,
+            },
+            I::CFsw { rs1, rs2, imm } => I::Fsw { rs1, rs2, imm },
+            I::CLwsp { rd, imm } => I::Lw { rd, rs1: SP, imm },
+            I::CSwsp { rs2, imm } => I::Sw { rs1: SP, rs2, imm },
+            I::CLdsp { rd, imm } => I::Ld { rd, rs1: SP, imm },
+            I::CSdsp { rs2, imm } => I::Sd { rs1: SP, rs2, imm },
+            I::CFlwsp { rd, imm } => I::Flw {
+                rd,
+                width: 2,
+                rs1: SP,
+                imm,
+            },
+            I::CFswsp { rs2, imm } => I::Fsw { rs1: SP, rs2, imm },
+            I::CFld { rd, rs1, imm } => I::Fld { rd, rs1, imm },
+            I::CFsd { rs1, rs2, imm } => I::Fsd { rs1, rs2, imm },
+            I::CFldsp { rd, imm } => I::Fld { rd, rs1: SP, imm },
+            I::CFsdsp { rs2, imm } => I::Fsd { rs1: SP, rs2, imm },
+            I::CJ { imm } => I::Jal { rd: ZERO, imm },
+            I::CJal { imm } => I::Jal { rd: RA, imm },
+            I::CJr { rs1 } => I::Jalr {
+                rd: ZERO,
+                rs1,
+                imm: 0,
+            },
+            I::CJalr { rs1 } => I::Jalr {
+                rd: RA,
+                rs1,
+                imm: 0,
+            },
+            I::CBeqz { rs1, imm } => I::Beq {
+                rs1,
+                rs2: ZERO,
+                imm,
+            },
+            I::CBnez { rs1, imm } => I::Bne {
+                rs1,
+                rs2: ZERO,
+                imm,
+            },
+            I::CEbreak => I::EBreak,
+            I::CLbu { rd, rs1, imm } => I::Lbu { rd, rs1, imm },
+            I::CLhu { rd, rs1, imm } => I::Lhu { rd, rs1, imm },
+            I::CLh { rd, rs1, imm } => I::Lh { rd, rs1, imm },
+            I::CSb { rs1, rs2, imm } => I::Sb { rs1, rs2, imm },
+            I::CSh { rs1, rs2, imm } => I::Sh { rs1, rs2, imm },
+            I::CZextB { rd } => I::Andi {
+                rd,
+                rs1: rd,
+                imm: 0xff,
+            },
+            I::CSextB { rd } => I::SextB { rd, rs1: rd },
+            I::CZextH { rd } => I::ZextH { rd, rs1: rd },
+            I::CSextH { rd } => I::SextH { rd, rs1: rd },
+            // zext.w is add.uw with rs2 = zero
+            I::CZextW { rd } => I::AddUw {
+                rd,
+                rs1: rd,
+                rs2: ZERO,
+            },
+            I::CNot { rd } => I::Xori {
+                rd,
+                rs1: rd,
+                imm: InstructionSize::MAX,
+            },
+            I::CMul { rd, rs2 } => I::Mul { rd, rs1: rd, rs2 },
+            _ => return None,
+        };
+        Some(inst)
+    }
+}
+
+// the compressed encoding of a 32 bit instruction when its registers and immediate fit one, the
+// inverse of `decompress` so the encodings that are hints or reserved are never picked, the Zcb
+// forms are only picked when `zcb` says the hart has them. fld/fsd are never compressed since
+// Zcmp reuses the c.fld/c.fsd encodings
+pub fn try_compress(inst: &InstructionDecoded, xlen: Xlen, zcb: bool) -> Option<CompressedSize> {
+    let compressed = compressed_form(inst, xlen)?;
+    let info = compressed.opcode_id().info();
+    if info.extension == Extension::Zcb && !zcb {
+        return None;
+    }
+    Some((info.match_value | operand_fields(&compressed)) as CompressedSize)
+}
+
+// x8-x15, the only registers the 3 bit register fields reach
+fn is_prime(reg: InstructionSize) -> bool {
+    (8..16).contains(&reg)
+}
+
+// `imm` is a sign extended immediate that fits in `width` bits
+fn fits_signed(imm: InstructionSize, width: u32) -> bool {
+    let imm = imm as SignedInstructionSize;
+    let limit = 1 << (width - 1);
+    (-limit..limit).contains(&imm)
+}
+
+// `imm` is an unsigned multiple of `scale` below 2^width
+fn fits_scaled(imm: InstructionSize, scale: InstructionSize, width: u32) -> bool {
+    imm.is_multiple_of(scale) && imm < 1 << width
+}
+
+fn compressed_form(inst: &InstructionDecoded, xlen: Xlen) -> Option<InstructionDecoded> {
+    use InstructionDecoded as I;
+    let rv32 = xlen == Xlen::Rv32;
+    let rv64 = xlen == Xlen::Rv64;
+    let compressed = match *inst {
+        I::Addi {
+            rd: ZERO,
+            rs1: ZERO,
+            imm: 0,
+        } => I::CNop,
+        I::Addi { rd, rs1, imm } if rd == rs1 && rd != ZERO && imm != 0 && fits_signed(imm, 6) => {
+            I::CAddi { rd, imm }
+        }
+        I::Addi {
+            rd: SP,
+            rs1: SP,
+            imm,
+        } if imm != 0 && imm.is_multiple_of(16) && fits_signed(imm, 10) => I::CAddi16Sp { imm },
+        I::Addi { rd, rs1: ZERO, imm } if rd != ZERO && fits_signed(imm, 6) => I::CLi { rd, imm },
+        I::Addi { rd, rs1: SP, imm } if is_prime(rd) && imm != 0 && fits_scaled(imm, 4, 10) => {
+            I::CAddi4Spn { rd, nzuimm: imm }
+        }
+        // the 20 bit field has to be the sign extension of its low 6 bits
+        I::Lui { rd, imm }
+            if rd != ZERO
+                && rd != SP
+                && imm != 0
+                && (imm < 0x20 || (0xfffe0..0x100000).contains(&imm)) =>
+        {
+            I::CLui { rd, imm }
+        }
+        I::Addiw { rd, rs1, imm } if rv64 && rd == rs1 && rd != ZERO && fits_signed(imm, 6) => {
+            I::CAddiw { rd, imm }
+        }
+        I::Slli { rd, rs1, imm } if rd == rs1 && rd != ZERO && imm != 0 && (rv64 || imm < 32) => {
+            I::CSlli {
+                rd,
+                rs1,
+                shamt: imm,
+            }
+        }
+        I::Srli { rd, rs1, imm } if rd == rs1 && is_prime(rd) && imm != 0 && (rv64 || imm < 32) => {
+            I::CSrli { rd, shamt: imm }
+        }
+        I::Srai { rd, rs1, imm } if rd == rs1 && is_prime(rd) && imm != 0 && (rv64 || imm < 32) => {
+            I::CSrai { rd, shamt: imm }
+        }
+        I::Andi { rd, rs1, imm } if rd == rs1 && is_prime(rd) && fits_signed(imm, 6) => {
+            I::CAndi { rd, imm }
+        }
+        I::Andi { rd, rs1, imm: 0xff } if rd == rs1 && is_prime(rd) => I::CZextB { rd },
+        I::Xori {
+            rd,
+            rs1,
+            imm: InstructionSize::MAX,
+        } if rd == rs1 && is_prime(rd) => I::CNot { rd },
+        I::Sub { rd, rs1, rs2 } if rd == rs1 && is_prime(rd) && is_prime(rs2) => {
+            I::CSub { rd, rs2 }
+        }
+        I::Xor { rd, rs1, rs2 } if rd == rs1 && is_prime(rd) && is_prime(rs2) => {
+            I::CXor { rd, rs2 }
+        }
+        I::Or { rd, rs1, rs2 } if rd == rs1 && is_prime(rd) && is_prime(rs2) => I::COr { rd, rs2 },
+        I::And { rd, rs1, rs2 } if rd == rs1 && is_prime(rd) && is_prime(rs2) => {
+            I::CAnd { rd, rs2 }
+        }
+        I::Subw { rd, rs1, rs2 } if rv64 && rd == rs1 && is_prime(rd) && is_prime(rs2) => {
+            I::CSubw { rd, rs2 }
+        }
+        I::Addw { rd, rs1, rs2 } if rv64 && rd == rs1 && is_prime(rd) && is_prime(rs2) => {
+            I::CAddw { rd, rs2 }
+        }
+        I::Mul { rd, rs1, rs2 } if rd == rs1 && is_prime(rd) && is_prime(rs2) => {
+            I::CMul { rd, rs2 }
+        }
+        I::Add { rd, rs1: ZERO, rs2 } if rd != ZERO && rs2 != ZERO => I::CMv { rd, rs2 },
+        I::Add { rd, rs1, rs2 } if rd == rs1 && rd != ZERO && rs2 != ZERO => I::CAdd { rd, rs2 },
+        I::AddUw { rd, rs1, rs2: ZERO } if rv64 && rd == rs1 && is_prime(rd) => I::CZextW { rd },
+        I::SextB { rd, rs1 } if rd == rs1 && is_prime(rd) => I::CSextB { rd },
+        I::SextH { rd, rs1 } if rd == rs1 && is_prime(rd) => I::CSextH { rd },
+        I::ZextH { rd, rs1 } if rd == rs1 && is_prime(rd) => I::CZextH { rd },
+        I::Lw { rd, rs1: SP, imm } if rd != ZERO && fits_scaled(imm, 4, 8) => I::CLwsp { rd, imm },
+        I::Lw { rd, rs1, imm } if is_prime(rd) && is_prime(rs1) && fits_scaled(imm, 4, 7) => {
+            I::CLw { rd, rs1, imm }
+        }
+        I::Sw { rs1: SP, rs2, imm } if fits_scaled(imm, 4, 8) => I::CSwsp { rs2, imm },
+        I::Sw { rs1, rs2, imm } if is_prime(rs1) && is_prime(rs2) && fits_scaled(imm, 4, 7) => {
+            I::CSw { rs1, rs2, imm }
+        }
+        I::Ld { rd, rs1: SP, imm } if rv64 && rd != ZERO && fits_scaled(imm, 8, 9) => {
+            I::CLdsp { rd, imm }
+        }
+        I::Ld { rd, rs1, imm }
+            if rv64 && is_prime(rd) && is_prime(rs1) && fits_scaled(imm, 8, 8) =>
+        {
+            I::CLd { rd, rs1, imm }
+        }
+        I::Sd { rs1: SP, rs2, imm } if rv64 && fits_scaled(imm, 8, 9) => I::CSdsp { rs2, imm },
+        I::Sd { rs1, rs2, imm }
+            if rv64 && is_prime(rs1) && is_prime(rs2) && fits_scaled(imm, 8, 8) =>
+        {
+            I::CSd { rs1, rs2, imm }
+        }
+        // rv64 uses the single precision encodings for the doubleword loads and stores
+        I::Flw {
+            rd,
+            width: 2,
+            rs1: SP,
+            imm,
+        } if rv32 && fits_scaled(imm, 4, 8) => I::CFlwsp { rd, imm },
+        I::Flw {
+            rd,
+            width: 2,
+            rs1,
+            imm,
+        } if rv32 && is_prime(rd) && is_prime(rs1) && fits_scaled(imm, 4, 7) => {
+            I::CFlw { rd, rs1, imm }
+        }
+        I::Fsw { rs1: SP, rs2, imm } if rv32 && fits_scaled(imm, 4, 8) => I::CFswsp { rs2, imm },
+        I::Fsw { rs1, rs2, imm }
+            if rv32 && is_prime(rs1) && is_prime(rs2) && fits_scaled(imm, 4, 7) =>
+        {
+            I::CFsw { rs1, rs2, imm }
+        }
+        I::Lbu { rd, rs1, imm } if is_prime(rd) && is_prime(rs1) && fits_scaled(imm, 1, 2) => {
+            I::CLbu { rd, rs1, imm }
+        }
+        I::Lhu { rd, rs1, imm } if is_prime(rd) && is_prime(rs1) && fits_scaled(imm, 2, 2) => {
+            I::CLhu { rd, rs1, imm }
+        }
+        I::Lh { rd, rs1, imm } if is_prime(rd) && is_prime(rs1) && fits_scaled(imm, 2, 2) => {
+            I::CLh { rd, rs1, imm }
+        }
+        I::Sb { rs1, rs2, imm } if is_prime(rs1) && is_prime(rs2) && fits_scaled(imm, 1, 2) => {
+            I::CSb { rs1, rs2, imm }
+        }
+        I::Sh { rs1, rs2, imm } if is_prime(rs1) && is_prime(rs2) && fits_scaled(imm, 2, 2) => {
+            I::CSh { rs1, rs2, imm }
+        }
+        I::Jal { rd: ZERO, imm } if imm.is_multiple_of(2) && fits_signed(imm, 12) => I::CJ { imm },
+        // rv64 took this encoding for c.addiw
+        I::Jal { rd: RA, imm } if rv32 && imm.is_multiple_of(2) && fits_signed(imm, 12) => {
+            I::CJal { imm }
+        }
+        I::Jalr {
+            rd: ZERO,
+            rs1,
+            imm: 0,
+        } if rs1 != ZERO => I::CJr { rs1 },
+        I::Jalr {
+            rd: RA,
+            rs1,
+            imm: 0,
+        } if rs1 != ZERO => I::CJalr { rs1 },
+        I::Beq {
+            rs1,
+            rs2: ZERO,
+            imm,
+        } if is_prime(rs1) && imm.is_multiple_of(2) && fits_signed(imm, 9) => I::CBeqz { rs1, imm },
+        I::Bne {
+            rs1,
+            rs2: ZERO,
+            imm,
+        } if is_prime(rs1) && imm.is_multiple_of(2) && fits_signed(imm, 9) => I::CBnez { rs1, imm },
+        I::EBreak => I::CEbreak,
+        _ => return None,
+    };
+    Some(compressed)
+}
+
+// imm[hi:lo] shifted down to bit 0
+fn bits(imm: InstructionSize, hi: u32, lo: u32) -> InstructionSize {
+    (imm >> lo) & ((1 << (hi - lo + 1)) - 1)
+}
+
+// the 3 bit field for one of x8-x15
+fn prime(reg: InstructionSize) -> InstructionSize {
+    reg - 8
+}
+
+// everything but the opcode bits the table's match value already has, the mirror image of the
+// getters on the bitfield structs in `instructions::compressed`
+fn operand_fields(inst: &InstructionDecoded) -> InstructionSize {
+    use InstructionDecoded as I;
+    // imm[5|4:0], `c.addi`/`c.li`/`c.lui` etc
+    let ci = |rd: InstructionSize, imm| bits(imm, 5, 5) << 12 | rd << 7 | bits(imm, 4, 0) << 2;
+    // uimm[5:3|2|6], `c.lw`/`c.sw`
+    let word = |imm| bits(imm, 5, 3) << 10 | bits(imm, 2, 2) << 6 | bits(imm, 6, 6) << 5;
+    // uimm[5:3|7:6], `c.ld`/`c.sd`
+    let double = |imm| bits(imm, 5, 3) << 10 | bits(imm, 7, 6) << 5;
+    // offset[11|4|9:8|10|6|7|3:1|5]
+    let jump = |imm| {
+        bits(imm, 11, 11) << 12
+            | bits(imm, 4, 4) << 11
+            | bits(imm, 9, 8) << 9
+            | bits(imm, 10, 10) << 8
+            | bits(imm, 6, 6) << 7
+            | bits(imm, 7, 7) << 6
+            | bits(imm, 3, 1) << 3
+            | bits(imm, 5, 5) << 2
+    };
+    // offset[8|4:3] and offset[7:6|2:1|5] around rs1'
+    let branch = |rs1, imm| {
+        bits(imm, 8, 8) << 12
+            | bits(imm, 4, 3) << 10
+            | prime(rs1) << 7
+            | bits(imm, 7, 6) << 5
+            | bits(imm, 2, 1) << 3
+            | bits(imm, 5, 5) << 2
+    };
+    // rd'/rs1' and rs2'
+    let ca = |rd, rs2| prime(rd) << 7 | prime(rs2) << 2;
+    match *inst {
+        I::CAddi4Spn { rd, nzuimm } => {
+            bits(nzuimm, 5, 4) << 11
+                | bits(nzuimm, 9, 6) << 7
+                | bits(nzuimm, 2, 2) << 6
+                | bits(nzuimm, 3, 3) << 5
+                | prime(rd) << 2
+        }
+        I::CLw { rd, rs1, imm } | I::CFlw { rd, rs1, imm } => {
+            word(imm) | prime(rs1) << 7 | prime(rd) << 2
+        }
+        I::CSw { rs1, rs2, imm } | I::CFsw { rs1, rs2, imm } => {
+            word(imm) | prime(rs1) << 7 | prime(rs2) << 2
+        }
+        I::CLd { rd, rs1, imm } => double(imm) | prime(rs1) << 7 | prime(rd) << 2,
+        I::CSd { rs1, rs2, imm } => double(imm) | prime(rs1) << 7 | prime(rs2) << 2,
+        // uimm[0|1] in bits 6:5, the halfword forms only have uimm[1]
+        I::CLbu { rd, rs1, imm } | I::CLhu { rd, rs1, imm } | I::CLh { rd, rs1, imm } => {
+            prime(rs1) << 7 | bits(imm, 0, 0) << 6 | bits(imm, 1, 1) << 5 | prime(rd) << 2
+        }
+        I::CSb { rs1, rs2, imm } | I::CSh { rs1, rs2, imm } => {
+            prime(rs1) << 7 | bits(imm, 0, 0) << 6 | bits(imm, 1, 1) << 5 | prime(rs2) << 2
+        }
+        I::CAddi { rd, imm } | I::CLi { rd, imm } | I::CAddiw { rd, imm } | I::CLui { rd, imm } => {
+            ci(rd, imm)
+        }
+        I::CSlli { rd, shamt, .. } => ci(rd, shamt),
+        // nzimm[9|4|6|8:7|5]
+        I::CAddi16Sp { imm } => {
+            bits(imm, 9, 9) << 12
+                | SP << 7
+                | bits(imm, 4, 4) << 6
+                | bits(imm, 6, 6) << 5
+                | bits(imm, 8, 7) << 3
+                | bits(imm, 5, 5) << 2
+        }
+        I::CSrli { rd, shamt } | I::CSrai { rd, shamt } => {
+            bits(shamt, 5, 5) << 12 | prime(rd) << 7 | bits(shamt, 4, 0) << 2
+        }
+        I::CAndi { rd, imm } => bits(imm, 5, 5) << 12 | prime(rd) << 7 | bits(imm, 4, 0) << 2,
+        I::CSub { rd, rs2 }
+        | I::CXor { rd, rs2 }
+        | I::COr { rd, rs2 }
+        | I::CAnd { rd, rs2 }
+        | I::CSubw { rd, rs2 }
+        | I::CAddw { rd, rs2 }
+        | I::CMul { rd, rs2 } => ca(rd, rs2),
+        I::CZextB { rd }
+        | I::CSextB { rd }
+        | I::CZextH { rd }
+        | I::CSextH { rd }
+        | I::CZextW { rd }
+        | I::CNot { rd } => prime(rd) << 7,
+        I::CJ { imm } | I::CJal { imm } => jump(imm),
+        I::CBeqz { rs1, imm } | I::CBnez { rs1, imm } => branch(rs1, imm),
+        // uimm[5] and uimm[4:2|7:6] or uimm[4:3|8:6]
+        I::CLwsp { rd, imm } | I::CFlwsp { rd, imm } => {
+            bits(imm, 5, 5) << 12 | rd << 7 | bits(imm, 4, 2) << 4 | bits(imm, 7, 6) << 2
+        }
+        I::CLdsp { rd, imm } => {
+            bits(imm, 5, 5) << 12 | rd << 7 | bits(imm, 4, 3) << 5 | bits(imm, 8, 6) << 2
+        }
+        // uimm[5:2|7:6] or uimm[5:3|8:6]
+        I::CSwsp { rs2, imm } | I::CFswsp { rs2, imm } => {
+            bits(imm, 5, 2) << 9 | bits(imm, 7, 6) << 7 | rs2 << 2
+        }
+        I::CSdsp { rs2, imm } => bits(imm, 5, 3) << 10 | bits(imm, 8, 6) << 7 | rs2 << 2,
+        I::CJr { rs1 } | I::CJalr { rs1 } => rs1 << 7,
+        I::CMv { rd, rs2 } | I::CAdd { rd, rs2 } => rd << 7 | rs2 << 2,
+        _ => 0,
+    }
+}
+
+#[test]
+fn decompress() {
+    use crate::decoder::{try_decode, try_decode_compressed, try_decode_compressed_rv64};
+    let expand = |inst: u16| try_decode_compressed(inst).unwrap().decompress().unwrap();
+    assert_eq!(expand(0x0001 /* c.nop */), try_decode(0x00000013).unwrap());
+    assert_eq!(
+        expand(0x0808 /* c.addi4spn a0, sp, 16 */),
+        try_decode(0x01010513 /* addi a0, sp, 16 */).unwrap()
+    );
+    assert_eq!(
+        expand(0x557d /* c.li a0, -1 */),
+        try_decode(0xfff00513 /* addi a0, zero, -1 */).unwrap()
+    );
+    assert_eq!(
+        expand(0x7541 /* c.lui a0, 0xffff0 */),
+        try_decode(0xffff0537 /* lui a0, 0xffff0 */).unwrap()
+    );
+    assert_eq!(
+        expand(0x4502 /* c.lwsp a0, 0(sp) */),
+        try_decode(0x00012503 /* lw a0, 0(sp) */).unwrap()
+    );
+    assert_eq!(
+        expand(0x852e /* c.mv a0, a1 */),
+        try_decode(0x00b00533 /* add a0, zero, a1 */).unwrap()
+    );
+    assert_eq!(
+        expand(0x8082 /* c.jr ra */),
+        try_decode(0x00008067 /* jalr zero, 0(ra) */).unwrap()
+    );
+    assert_eq!(
+        expand(0xa001 /* c.j 0 */),
+        try_decode(0x0000006f /* jal zero, 0 */).unwrap()
+    );
+    assert_eq!(
+        expand(0xc111 /* c.beqz a0, 4 */),
+        try_decode(0x00050263 /* beq a0, zero, 4 */).unwrap()
+    );
+    assert_eq!(
+        expand(0x9c75 /* c.not s0 */),
+        try_decode(0xfff44413 /* xori s0, s0, -1 */).unwrap()
+    );
+    assert_eq!(
+        try_decode_compressed_rv64(0x2505 /* c.addiw a0, 1 */)
+            .unwrap()
+            .decompress(),
+        Some(try_decode(0x0015051b /* addiw a0, a0, 1 */).unwrap())
+    );
+    assert_eq!(
+        expand(0x2000 /* c.fld fs0, 0(s0) */),
+        try_decode(0x00043407 /* fld fs0, 0(s0) */).unwrap()
+    );
+    assert_eq!(try_decode(0x00000013).unwrap().decompress(), None);
+}
+
+#[test]
+fn compress() {
+    use crate::decoder::try_decode;
+    let compress = |inst: u32| try_compress(&try_decode(inst).unwrap(), Xlen::Rv32, false);
+    assert_eq!(compress(0x00000013 /* nop */), Some(0x0001));
+    assert_eq!(compress(0x01010513 /* addi a0, sp, 16 */), Some(0x0808));
+    assert_eq!(compress(0xfff00513 /* addi a0, zero, -1 */), Some(0x557d));
+    assert_eq!(compress(0x00012503 /* lw a0, 0(sp) */), Some(0x4502));
+    assert_eq!(compress(0x00b00533 /* add a0, zero, a1 */), Some(0x852e));
+    assert_eq!(compress(0x00008067 /* ret */), Some(0x8082));
+    assert_eq!(compress(0x00050263 /* beq a0, zero, 4 */), Some(0xc111));
+    assert_eq!(compress(0x00b50533 /* add a0, a0, a1 */), Some(0x952e));
+    assert_eq!(compress(0x40b50533 /* sub a0, a0, a1 */), Some(0x8d0d));
+    // rd has to be rs1, the immediate is too wide, and lw needs a multiple of 4
+    assert_eq!(compress(0x40b60533 /* sub a0, a2, a1 */), None);
+    assert_eq!(compress(0x40050513 /* addi a0, a0, 1024 */), None);
+    assert_eq!(compress(0x00252503 /* lw a0, 2(a0) */), None);
+    // c.jal only exists on rv32, rv64 gave its encoding to c.addiw
+    let jal = try_decode(0x008000ef /* jal ra, 8 */).unwrap();
+    assert!(try_compress(&jal, Xlen::Rv32, false).is_some());
+    assert_eq!(try_compress(&jal, Xlen::Rv64, false), None);
+}
+
+// every compressed inst with a base equivalent comes back as an encoding with the same meaning,
+// the base forms of the hints and reserved encodings don't compress at all
+#[test]
+fn compress_round_trip() {
+    use crate::decoder::Decoder;
+    for xlen in [Xlen::Rv32, Xlen::Rv64] {
+        let decoder = Decoder {
+            xlen,
+            ..Decoder::new()
+        };
+        for parcel in 0..=CompressedSize::MAX {
+            let Some(base) = decoder
+                .decode(parcel)
+                .ok()
+                .and_then(|inst| inst.decompress())
+            else {
+                continue;
+            };
+            let Some(inst) = try_compress(&base, xlen, true) else {
+                // the hints write x0 or add/shift by zero
+                use InstructionDecoded as I;
+                assert!(
+                    matches!(
+                        base,
+                        I::Fld { .. }
+                            | I::Fsd { .. }
+                            | I::Addi { rd: ZERO, .. }
+                            | I::Add { rd: ZERO, .. }
+                            | I::Lui { rd: ZERO, .. }
+                            | I::Slli { rd: ZERO, .. }
+                            | I::Addi { imm: 0, .. }
+                            | I::Slli { imm: 0, .. }
+                            | I::Srli { imm: 0, .. }
+                            | I::Srai { imm: 0, .. }
+                    ),
+                    "{parcel:#06x} ({base}) doesn't compress"
+                );
+                continue;
+            };
+            let round_trip = decoder.decode(inst).unwrap().decompress();
+            assert_eq!(round_trip, Some(base), "{parcel:#06x} -> {inst:#06x}");
+        }
+    }
+}
+
+#[test]
+fn compress_zcb() {
+    use crate::decoder::{try_decode, Decoder};
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    // plain RV64GC code never gets a Zcb encoding
+    for inst in [
+        0x0ff47413u32, /* andi s0, s0, 255 */
+        0xfff44413,    /* xori s0, s0, -1 */
+        0x02940433,    /* mul s0, s0, s1 */
+        0x0004c403,    /* lbu s0, 0(s1) */
+        0x0024d403,    /* lhu s0, 2(s1) */
+        0x00249403,    /* lh s0, 2(s1) */
+        0x00848023,    /* sb s0, 0(s1) */
+        0x00849123,    /* sh s0, 2(s1) */
+        0x60441413,    /* sext.b s0, s0 */
+        0x60541413,    /* sext.h s0, s0 */
+        0x0804443b,    /* zext.h s0, s0 */
+        0x0804043b,    /* zext.w s0, s0 */
+    ] {
+        let base = rv64.decode(inst).unwrap();
+        assert_eq!(try_compress(&base, Xlen::Rv64, false), None, "{base}");
+        let compressed = try_compress(&base, Xlen::Rv64, true).unwrap_or_else(|| panic!("{base}"));
+        assert_eq!(rv64.decode(compressed).unwrap().decompress(), Some(base));
+    }
+    // c.andi covers the small masks without Zcb
+    let andi = try_decode(0x00f47413 /* andi s0, s0, 15 */).unwrap();
+    assert_eq!(try_compress(&andi, Xlen::Rv32, false), Some(0x883d));
+}
@@ -1,8 +1,15 @@
 use std::fmt::Display;
 
-use crate::instructions::InstructionSize;
+use crate::effects::{ControlFlow, MemoryKind, RegisterFile};
+use crate::instructions::{InstructionSize, SignedInstructionSize};
+use crate::rounding::RoundingMode;
+use crate::style::DisplayStyle;
+use crate::vtype::Vtype;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+// new extensions keep adding variants so matches outside this crate need a catch all arm, use
+// `mnemonic()`/`category()`/`effects()` when that's all you're after
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum InstructionDecoded {
     Lb {
         rd: InstructionSize,
@@ -34,6 +41,11 @@ pub enum InstructionDecoded {
         rs1: InstructionSize,
         imm: InstructionSize,
     },
+    Ld {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
     Addi {
         rd: InstructionSize,
         rs1: InstructionSize,
@@ -79,6 +91,26 @@ pub enum InstructionDecoded {
         rs1: InstructionSize,
         imm: InstructionSize,
     },
+    Addiw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Slliw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Srliw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Sraiw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
     AuiPc {
         rd: InstructionSize,
         imm: InstructionSize,
@@ -98,6 +130,11 @@ pub enum InstructionDecoded {
         rs2: InstructionSize,
         imm: InstructionSize,
     },
+    Sd {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
     Add {
         rd: InstructionSize,
         rs1: InstructionSize,
@@ -148,6 +185,56 @@ pub enum InstructionDecoded {
         rs1: InstructionSize,
         rs2: InstructionSize,
     },
+    Addw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Subw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sllw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Srlw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sraw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Mulw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Divw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Divuw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Remw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Remuw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
     Lui {
         rd: InstructionSize,
         imm: InstructionSize,
@@ -196,7 +283,30 @@ pub enum InstructionDecoded {
     EBreak,
     SRet,
     MRet,
-    SFenceVma,
+    MnRet,
+    DRet,
+    SFenceVma {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    // Zawrs
+    WrsNto,
+    WrsSto,
+    // Svinval
+    SinvalVma {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    SFenceWInval,
+    SFenceInvalIr,
+    HinvalVvma {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    HinvalGvma {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
 
     CsrRw {
         rd: InstructionSize,
@@ -229,6 +339,35 @@ pub enum InstructionDecoded {
         imm: InstructionSize,
     },
 
+    CboInval {
+        rs1: InstructionSize,
+    },
+    CboClean {
+        rs1: InstructionSize,
+    },
+    CboFlush {
+        rs1: InstructionSize,
+    },
+    CboZero {
+        rs1: InstructionSize,
+    },
+    PrefetchI {
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    PrefetchR {
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    PrefetchW {
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Pause,
+    NtlP1,
+    NtlPall,
+    NtlS1,
+    NtlAll,
     Fence {
         // rd: InstructionSize,
         // rs1: InstructionSize,
@@ -261,48 +400,57 @@ pub enum InstructionDecoded {
         rs1: InstructionSize,
         rs2: InstructionSize,
         rs3: InstructionSize,
+        rm: RoundingMode,
     },
     FmsubS {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
         rs3: InstructionSize,
+        rm: RoundingMode,
     },
     FnmaddS {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
         rs3: InstructionSize,
+        rm: RoundingMode,
     },
     FnmsubS {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
         rs3: InstructionSize,
+        rm: RoundingMode,
     },
     FaddS {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
     FsubS {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
     FmulS {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
     FdivS {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
     FsqrtS {
         rd: InstructionSize,
         rs1: InstructionSize,
+        rm: RoundingMode,
     },
     FsgnjS {
         rd: InstructionSize,
@@ -332,18 +480,22 @@ pub enum InstructionDecoded {
     FcvtSW {
         rd: InstructionSize,
         rs1: InstructionSize,
+        rm: RoundingMode,
     },
     FcvtSWU {
         rd: InstructionSize,
         rs1: InstructionSize,
+        rm: RoundingMode,
     },
     FcvtWS {
         rd: InstructionSize,
         rs1: InstructionSize,
+        rm: RoundingMode,
     },
     FcvtWUS {
         rd: InstructionSize,
         rs1: InstructionSize,
+        rm: RoundingMode,
     },
     FmvXW {
         rd: InstructionSize,
@@ -353,910 +505,11215 @@ pub enum InstructionDecoded {
         rd: InstructionSize,
         rs1: InstructionSize,
     },
-    FeqS {
+    FmvXD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FmvDX {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FcvtLS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtLUS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtSL {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtSLU {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtLD {
         rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtLUD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtDL {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtDLU {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    Fld {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Fsd {
         rs1: InstructionSize,
         rs2: InstructionSize,
+        imm: InstructionSize,
     },
-    FltS {
+    FmaddD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
     },
-    FleS {
+    FmsubD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
     },
-    FClassS {
+    FnmsubD {
         rd: InstructionSize,
         rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
     },
-
-    // M Extension
-    Mul {
+    FnmaddD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
     },
-    Mulh {
+    FaddD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
-    Mulsu {
+    FsubD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
-    Mulu {
+    FmulD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
-    Div {
+    FdivD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
+        rm: RoundingMode,
     },
-    Divu {
+    FsqrtD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FsgnjD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
     },
-    Rem {
+    FsgnjnD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
     },
-    Remu {
+    FsgnjxD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
     },
-
-    // A Extension
-    LrW {
+    FminD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
     },
-    ScW {
+    FmaxD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
     },
-    AmoswapW {
+    FcvtSD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtDS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FeqD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
     },
-    AmoaddW {
+    FltD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
     },
-    AmoandW {
+    FleD {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
     },
-    AmoorW {
+    FClassD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FcvtWD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtWUD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtDW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtDWU {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    // Q extension
+    FaddQ {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
+        rm: RoundingMode,
     },
-    AmoxorW {
+    FsubQ {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
+        rm: RoundingMode,
     },
-    AmomaxW {
+    FmulQ {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
+        rm: RoundingMode,
     },
-    AmominW {
+    FdivQ {
         rd: InstructionSize,
         rs1: InstructionSize,
         rs2: InstructionSize,
-        rl: bool,
-        aq: bool,
+        rm: RoundingMode,
     },
-
-    // Compressed Instructions
-    CAddi4Spn {
+    FsqrtQ {
         rd: InstructionSize,
-        nzuimm: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
     },
-    CNop,
-    CSlli {
+    FsgnjQ {
         rd: InstructionSize,
         rs1: InstructionSize,
-        shamt: InstructionSize,
+        rs2: InstructionSize,
     },
-}
-
-// generates comptime map for large amount of csr mapping their names to their values
-include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
-
-macro_rules! print_csr {
-    ($f:expr, $name:expr, $name_exp:expr, $rd:ident, $rs1:ident, $imm:ident) => {
-        if *$rd == 0 || *$rd == *$rs1 {
-            write!(
-                $f,
-                "{} {}, {}",
-                $name,
-                CSRS.get($imm)
-                    .map(|v| *v)
-                    .unwrap_or(format!("{}", $imm).as_str()),
-                REG_NAMES[*$rs1 as usize]
-            )
-        } else {
-            write!(
-                $f,
-                "{} {}, {}, {}",
-                $name_exp,
-                REG_NAMES[*$rd as usize],
-                CSRS.get($imm)
-                    .map(|v| *v)
-                    .unwrap_or(format!("{}", $imm).as_str()),
-                REG_NAMES[*$rs1 as usize]
-            )
-        }
-    };
-}
-
-impl Display for InstructionDecoded {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        const REG_NAMES: [&str; 32] = [
-            "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3",
-            "a4", "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11",
-            "t3", "t4", "t5", "t6",
-        ];
-
-        match self {
-            InstructionDecoded::Lb { rd, rs1, imm } => {
-                write!(
-                    f,
-                    "lb {}, {}({})",
-                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
-                )
-            }
-            InstructionDecoded::Lh { rd, rs1, imm } => {
-                write!(
-                    f,
-                    "lh {}, {}({})",
-                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+    FsgnjnQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FsgnjxQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FminQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FmaxQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FcvtSQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtQS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtDQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtQD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FeqQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FltQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FleQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FClassQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FcvtWQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtWUQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtLQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtLUQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtQW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtQWU {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtQL {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtQLU {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FmaddQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    FmsubQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    FnmsubQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    FnmaddQ {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    Flq {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Fsq {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    // Zfh extension
+    FaddH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rm: RoundingMode,
+    },
+    FsubH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rm: RoundingMode,
+    },
+    FmulH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rm: RoundingMode,
+    },
+    FdivH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rm: RoundingMode,
+    },
+    FsqrtH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FsgnjH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FsgnjnH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FsgnjxH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FminH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FmaxH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FcvtSH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtHS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtDH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtHD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FeqH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FltH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FleH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FClassH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FcvtWH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtWUH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtLH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtLUH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtHW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtHWU {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtHL {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtHLU {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FmvXH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FmvHX {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FmaddH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    FmsubH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    FnmsubH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    FnmaddH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rs3: InstructionSize,
+        rm: RoundingMode,
+    },
+    Flh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Fsh {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    // Zfa extension, `imm` of fli is the index into `FLI_VALUES`
+    FliS {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    FliD {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    FminmS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FmaxmS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FminmD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FmaxmD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FroundS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FroundnxS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FroundD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FroundnxD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rm: RoundingMode,
+    },
+    FcvtmodWD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    FeqS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FltS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FleS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    FClassS {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+
+    // M Extension
+    Mul {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Mulh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Mulsu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Mulu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Div {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Divu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Rem {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Remu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+
+    // A Extension
+    LrW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    ScW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoswapW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoaddW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoandW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoorW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoxorW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmomaxW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmominW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmominuW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmomaxuW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    LrD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    ScD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoswapD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoaddD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoxorD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoandD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmoorD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmominD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmomaxD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmominuD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    AmomaxuD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+
+    // Zvksed / Zvksh (vector ShangMi crypto)
+    Vsm4kVi {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        uimm: InstructionSize,
+    },
+    Vsm4rVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    Vsm4rVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    Vsm3meVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    Vsm3cVi {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        uimm: InstructionSize,
+    },
+
+    // Zvfbfwma / Zvfbfmin (vector bfloat16)
+    Vfwmaccbf16Vv {
+        vd: InstructionSize,
+        vs1: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    Vfwmaccbf16Vf {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    Vfncvtbf16FFW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    Vfwcvtbf16FFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+
+    // Zba extension
+    Sh1add {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sh2add {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sh3add {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    AddUw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sh1addUw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sh2addUw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sh3addUw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    SlliUw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+
+    // Zbb extension
+    Andn {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Orn {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Xnor {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Min {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Minu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Max {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Maxu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Rol {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Ror {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Clz {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Ctz {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Cpop {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    SextB {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    SextH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    OrcB {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Rori {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Rolw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Rorw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Roriw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Clzw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Ctzw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Cpopw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Rev8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    ZextH {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+
+    // Zbc extension
+    Clmul {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Clmulh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Clmulr {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+
+    // Zbs extension
+    Bclr {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Bext {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Binv {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Bset {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Bclri {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Bexti {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Binvi {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    Bseti {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+
+    // Zbkb extension
+    Pack {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Packh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Packw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Brev8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Zip {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Unzip {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+
+    // Zbkx extension
+    Xperm4 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Xperm8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+
+    // Zknd/Zkne extensions, the rv32 forms pick the byte of rs2 with `bs`
+    Aes32esi {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        bs: InstructionSize,
+    },
+    Aes32esmi {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        bs: InstructionSize,
+    },
+    Aes32dsi {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        bs: InstructionSize,
+    },
+    Aes32dsmi {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        bs: InstructionSize,
+    },
+    Aes64es {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Aes64esm {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Aes64ds {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Aes64dsm {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Aes64ks2 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Aes64im {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Aes64ks1i {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rnum: InstructionSize,
+    },
+
+    // Zknh extension, rv32 does sha512 on register pairs
+    Sha256sum0 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha256sum1 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha256sig0 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha256sig1 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha512sum0 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha512sum1 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha512sig0 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha512sig1 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sha512sum0r {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sha512sum1r {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sha512sig0l {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sha512sig1l {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sha512sig0h {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sha512sig1h {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+
+    // Zksed/Zksh extensions
+    Sm4ed {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        bs: InstructionSize,
+    },
+    Sm4ks {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        bs: InstructionSize,
+    },
+    Sm3p0 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Sm3p1 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+    },
+
+    // V extension configuration, `vtypei` is the raw vtype immediate
+    Vsetvli {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        vtypei: InstructionSize,
+    },
+    Vsetivli {
+        rd: InstructionSize,
+        uimm: InstructionSize,
+        vtypei: InstructionSize,
+    },
+    Vsetvl {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+
+    // V extension loads and stores, `nf` is the number of fields minus one for segment accesses
+    Vle8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vle16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vle32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vle64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vse8V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vse16V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vse32V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vse64V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vle8ffV {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vle16ffV {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vle32ffV {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vle64ffV {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+
+    // V extension strided and indexed loads and stores
+    Vlse8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vlse16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vlse32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vlse64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsse8V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsse16V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsse32V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsse64V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vluxei8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vluxei16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vluxei32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vluxei64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vloxei8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vloxei16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vloxei32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vloxei64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsuxei8V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsuxei16V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsuxei32V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsuxei64V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsoxei8V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsoxei16V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsoxei32V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+    Vsoxei64V {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+        vs2: InstructionSize,
+        nf: InstructionSize,
+        masked: bool,
+    },
+
+    // V extension OPMVV/OPMVX
+    VredsumVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VredandVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VredorVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VredxorVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VredminuVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VredminVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VredmaxuVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VredmaxVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VaadduVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VaadduVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VaaddVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VaaddVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VasubuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VasubuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VasubVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VasubVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    Vslide1upVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    Vslide1downVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VdivuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VdivuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VdivVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VdivVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VremuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VremuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VremVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VremVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmulhuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmulhuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmulVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmulVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmulhsuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmulhsuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmulhVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmulhVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmaddVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmaddVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VnmsubVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VnmsubVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmaccVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmaccVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VnmsacVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VnmsacVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwadduVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwadduVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwaddVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwaddVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwadduWv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwadduWx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwaddWv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwaddWx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubuWv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubuWx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubWv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwsubWx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwmuluVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwmuluVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwmulsuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwmulsuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwmulVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwmulVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwmaccuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwmaccuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwmaccVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwmaccVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwmaccusVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwmaccsuVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwmaccsuVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmvXS {
+        rd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VmvSX {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    VzextVf8 {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VsextVf8 {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VzextVf4 {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VsextVf4 {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VzextVf2 {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VsextVf2 {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VcompressVm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+
+    // V extension OPFVV/OPFVF, the scalar operand `rs1` is an f register
+    VfaddVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfaddVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfredusumVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfsubVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfsubVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfredosumVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfminVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfminVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfredminVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfmaxVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfmaxVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfredmaxVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfsgnjVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfsgnjVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfsgnjnVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfsgnjnVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfsgnjxVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfsgnjxVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    Vfslide1upVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    Vfslide1downVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmfeqVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmfeqVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmfleVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmfleVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmfltVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmfltVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmfneVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VmfneVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmfgtVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VmfgeVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfdivVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfdivVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfrdivVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfmulVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfmulVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfrsubVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfmaddVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfmaddVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmaddVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmaddVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfmsubVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfmsubVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmsubVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmsubVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfmaccVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfmaccVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmaccVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmaccVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfmsacVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfmsacVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmsacVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfnmsacVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwaddVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwaddVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwredusumVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwsubVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwsubVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwredosumVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwaddWv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwaddWf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwsubWv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwsubWf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwmulVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwmulVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwmaccVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwmaccVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwnmaccVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwnmaccVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwmsacVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwmsacVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfwnmsacVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VfwnmsacVf {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VfcvtXuFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfcvtXFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfcvtFXuV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfcvtFXV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfcvtRtzXuFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfcvtRtzXFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfwcvtXuFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfwcvtXFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfwcvtFXuV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfwcvtFXV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfwcvtFFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfwcvtRtzXuFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfwcvtRtzXFV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtXuFW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtXFW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtFXuW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtFXW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtFFW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtRodFFW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtRtzXuFW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfncvtRtzXFW {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfsqrtV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    Vfrsqrt7V {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    Vfrec7V {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfclassV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfmvFS {
+        rd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VfmvSF {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    VfmergeVfm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+    },
+    VfmvVF {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+
+    // V extension mask manipulation
+    VmandnMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VmandMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VmorMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VmxorMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VmornMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VmnandMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VmnorMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VmxnorMm {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VcpopM {
+        rd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VfirstM {
+        rd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VmsbfM {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VmsofM {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VmsifM {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    ViotaM {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VidV {
+        vd: InstructionSize,
+        masked: bool,
+    },
+
+    // V extension whole register loads, stores and moves
+    Vl1re8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl1re16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl1re32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl1re64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl2re8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl2re16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl2re32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl2re64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl4re8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl4re16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl4re32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl4re64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl8re8V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl8re16V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl8re32V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vl8re64V {
+        vd: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vs1rV {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vs2rV {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vs4rV {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vs8rV {
+        vs3: InstructionSize,
+        rs1: InstructionSize,
+    },
+    Vmv1rV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    Vmv2rV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    Vmv4rV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    Vmv8rV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+
+    // Zvbb / Zvkg / Zvkned / Zvknh (vector crypto), only decoded with the `zvk` feature
+    VandnVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VandnVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VrorVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VrorVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VrorVi {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        uimm: InstructionSize,
+        masked: bool,
+    },
+    VrolVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VrolVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwsllVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+        masked: bool,
+    },
+    VwsllVx {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        rs1: InstructionSize,
+        masked: bool,
+    },
+    VwsllVi {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        uimm: InstructionSize,
+        masked: bool,
+    },
+    Vbrev8V {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    Vrev8V {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VbrevV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VclzV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VctzV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VcpopV {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        masked: bool,
+    },
+    VghshVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    VgmulVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesdfVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesdfVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesdmVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesdmVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesefVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesefVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesemVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaesemVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    VaeszVs {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+    },
+    Vaeskf1Vi {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        uimm: InstructionSize,
+    },
+    Vaeskf2Vi {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        uimm: InstructionSize,
+    },
+    Vsha2msVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    Vsha2chVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+    Vsha2clVv {
+        vd: InstructionSize,
+        vs2: InstructionSize,
+        vs1: InstructionSize,
+    },
+
+    // P (packed SIMD), only decoded with the `p-ext` feature
+    Add16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Add8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sub16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Sub8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Radd16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Radd8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Rsub16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Rsub8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Kadd16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Kadd8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Ksub16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Ksub8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Ukadd16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Ukadd8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Uksub16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Uksub8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Smul16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Smul8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Umul16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Umul8 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Pkbb16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Pkbt16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Pktb16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+    Pktt16 {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+    },
+
+    // XTheadBa / XTheadCmo / XTheadMemIdx (T-Head vendor extensions), only decoded with the `thead` feature
+    ThAddsl {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThDcacheCall,
+    ThDcacheIall,
+    ThDcacheCiall,
+    ThIcacheIall,
+    ThIcacheIalls,
+    ThDcacheCval1 {
+        rs1: InstructionSize,
+    },
+    ThDcacheCva {
+        rs1: InstructionSize,
+    },
+    ThDcacheIva {
+        rs1: InstructionSize,
+    },
+    ThDcacheCiva {
+        rs1: InstructionSize,
+    },
+    ThDcacheCpa {
+        rs1: InstructionSize,
+    },
+    ThDcacheIpa {
+        rs1: InstructionSize,
+    },
+    ThDcacheCipa {
+        rs1: InstructionSize,
+    },
+    ThIcacheIva {
+        rs1: InstructionSize,
+    },
+    ThIcacheIpa {
+        rs1: InstructionSize,
+    },
+    ThLrb {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLurb {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLbib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLbia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLrh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLurh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLhib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLhia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLrw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLurw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLwib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLwia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLrd {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLurd {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLdib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLdia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLrbu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLurbu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLbuib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLbuia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLrhu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLurhu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLhuib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLhuia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLrwu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLurwu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLwuib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThLwuia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSrb {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSurb {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSbib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSbia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSrh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSurh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThShib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThShia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSrw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSurw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSwib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSwia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSrd {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSurd {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSdib {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+    ThSdia {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+        shift: InstructionSize,
+    },
+
+    // an instruction from a `Decoder::register_custom` hook, printed as its name followed by
+    // whichever registers the hook filled in
+    Custom {
+        name: &'static str,
+        bits: InstructionSize,
+        rd: Option<InstructionSize>,
+        rs1: Option<InstructionSize>,
+        rs2: Option<InstructionSize>,
+    },
+
+    // Zicfiss / Zicfilp (control flow integrity)
+    Sspush {
+        rs2: InstructionSize,
+    },
+    Sspopchk {
+        rs1: InstructionSize,
+    },
+    Ssrdp {
+        rd: InstructionSize,
+    },
+    SsamoswapW {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    SsamoswapD {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        rl: bool,
+        aq: bool,
+    },
+    Lpad {
+        label: InstructionSize,
+    },
+
+    // Compressed Instructions
+    CAddi4Spn {
+        rd: InstructionSize,
+        nzuimm: InstructionSize,
+    },
+    CNop,
+    CSlli {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        shamt: InstructionSize,
+    },
+    CLw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSw {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFlw {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFsw {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFld {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFsd {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CAddi {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CJal {
+        imm: InstructionSize,
+    },
+    CLi {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CAddi16Sp {
+        imm: InstructionSize,
+    },
+    CLui {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSrli {
+        rd: InstructionSize,
+        shamt: InstructionSize,
+    },
+    CSrai {
+        rd: InstructionSize,
+        shamt: InstructionSize,
+    },
+    CAndi {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSub {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CXor {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    COr {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CAnd {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CJ {
+        imm: InstructionSize,
+    },
+    CBeqz {
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CBnez {
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CLwsp {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSwsp {
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFlwsp {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFswsp {
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFldsp {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CFsdsp {
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CJr {
+        rs1: InstructionSize,
+    },
+    CMv {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CEbreak,
+    CJalr {
+        rs1: InstructionSize,
+    },
+    CAdd {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CAddiw {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CLd {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSd {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CLdsp {
+        rd: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSdsp {
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSubw {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CAddw {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CLbu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CLhu {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CLh {
+        rd: InstructionSize,
+        rs1: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSb {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CSh {
+        rs1: InstructionSize,
+        rs2: InstructionSize,
+        imm: InstructionSize,
+    },
+    CZextB {
+        rd: InstructionSize,
+    },
+    CSextB {
+        rd: InstructionSize,
+    },
+    CZextH {
+        rd: InstructionSize,
+    },
+    CSextH {
+        rd: InstructionSize,
+    },
+    CZextW {
+        rd: InstructionSize,
+    },
+    CNot {
+        rd: InstructionSize,
+    },
+    CMul {
+        rd: InstructionSize,
+        rs2: InstructionSize,
+    },
+    CmPush {
+        rlist: InstructionSize,
+        stack_adj: InstructionSize,
+    },
+    CmPop {
+        rlist: InstructionSize,
+        stack_adj: InstructionSize,
+    },
+    CmPopretz {
+        rlist: InstructionSize,
+        stack_adj: InstructionSize,
+    },
+    CmPopret {
+        rlist: InstructionSize,
+        stack_adj: InstructionSize,
+    },
+    CmMvsa01 {
+        r1s: InstructionSize,
+        r2s: InstructionSize,
+    },
+    CmMva01s {
+        r1s: InstructionSize,
+        r2s: InstructionSize,
+    },
+    CmJt {
+        index: InstructionSize,
+    },
+    CmJalt {
+        index: InstructionSize,
+    },
+}
+
+pub const REG_NAMES: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6",
+];
+
+// the zkr entropy source csr
+pub const SEED_CSR: InstructionSize = 0x015;
+
+// the constants `fli.s`/`fli.d` load, indexed by rs1. printed the way assemblers take them
+pub const FLI_VALUES: [&str; 32] = [
+    "-1.0",
+    "min",
+    "1.52587890625e-05",
+    "3.0517578125e-05",
+    "0.00390625",
+    "0.0078125",
+    "0.0625",
+    "0.125",
+    "0.25",
+    "0.3125",
+    "0.375",
+    "0.4375",
+    "0.5",
+    "0.625",
+    "0.75",
+    "0.875",
+    "1.0",
+    "1.25",
+    "1.5",
+    "1.75",
+    "2.0",
+    "2.5",
+    "3.0",
+    "4.0",
+    "8.0",
+    "16.0",
+    "128.0",
+    "256.0",
+    "32768.0",
+    "65536.0",
+    "inf",
+    "nan",
+];
+
+// rough grouping of instructions, what an instruction falls under doesn't change between releases
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Category {
+    Arithmetic,
+    Load,
+    Store,
+    // read-modify-write memory ops, lr/sc and amos
+    Atomic,
+    Branch,
+    Jump,
+    Fence,
+    // csr access, traps and anything else that deals with privileged state
+    System,
+    FloatingPoint,
+    Vector,
+}
+
+impl InstructionDecoded {
+    // the mnemonic as it's printed, `c.addi4spn`, `amoswap.w`, `vsm3c.vi` etc
+    pub fn mnemonic(&self) -> String {
+        let text = Latest(self).to_string();
+        text.split(' ').next().unwrap_or_default().to_string()
+    }
+
+    pub fn category(&self) -> Category {
+        match self {
+            InstructionDecoded::Fence { .. }
+            | InstructionDecoded::FenceI { .. }
+            | InstructionDecoded::Pause
+            | InstructionDecoded::CboInval { .. }
+            | InstructionDecoded::CboClean { .. }
+            | InstructionDecoded::CboFlush { .. }
+            | InstructionDecoded::ThDcacheCall
+            | InstructionDecoded::ThDcacheIall
+            | InstructionDecoded::ThDcacheCiall
+            | InstructionDecoded::ThIcacheIall
+            | InstructionDecoded::ThIcacheIalls
+            | InstructionDecoded::ThDcacheCval1 { .. }
+            | InstructionDecoded::ThDcacheCva { .. }
+            | InstructionDecoded::ThDcacheIva { .. }
+            | InstructionDecoded::ThDcacheCiva { .. }
+            | InstructionDecoded::ThDcacheCpa { .. }
+            | InstructionDecoded::ThDcacheIpa { .. }
+            | InstructionDecoded::ThDcacheCipa { .. }
+            | InstructionDecoded::ThIcacheIva { .. }
+            | InstructionDecoded::ThIcacheIpa { .. } => return Category::Fence,
+            InstructionDecoded::SFenceVma { .. }
+            | InstructionDecoded::WrsNto
+            | InstructionDecoded::WrsSto
+            | InstructionDecoded::SinvalVma { .. }
+            | InstructionDecoded::SFenceWInval
+            | InstructionDecoded::SFenceInvalIr
+            | InstructionDecoded::HinvalVvma { .. }
+            | InstructionDecoded::HinvalGvma { .. } => return Category::System,
+            InstructionDecoded::LrW { .. }
+            | InstructionDecoded::ScW { .. }
+            | InstructionDecoded::LrD { .. }
+            | InstructionDecoded::ScD { .. } => return Category::Atomic,
+            _ => {}
+        }
+
+        let effects = self.effects();
+        let uses = |file| {
+            effects
+                .reads
+                .iter()
+                .chain(&effects.writes)
+                .any(|reg| reg.file == file)
+        };
+        match (effects.memory, effects.control_flow) {
+            (Some(access), _) => match access.kind {
+                MemoryKind::Load => Category::Load,
+                MemoryKind::Store => Category::Store,
+                MemoryKind::ReadModifyWrite => Category::Atomic,
+            },
+            (_, Some(ControlFlow::Branch { .. })) => Category::Branch,
+            (_, Some(ControlFlow::Jump { .. } | ControlFlow::IndirectJump { .. })) => {
+                Category::Jump
+            }
+            (_, Some(ControlFlow::Trap | ControlFlow::TrapReturn)) => Category::System,
+            _ if effects.csr.is_some() => Category::System,
+            _ if uses(RegisterFile::Vector) => Category::Vector,
+            _ if uses(RegisterFile::Float) => Category::FloatingPoint,
+            _ => Category::Arithmetic,
+        }
+    }
+
+    // pc relative offset of a branch or jump target, None for everything else
+    // (jalr is not included since its target depends on a register)
+    pub fn branch_offset(&self) -> Option<SignedInstructionSize> {
+        match self {
+            InstructionDecoded::Beq { imm, .. }
+            | InstructionDecoded::Bne { imm, .. }
+            | InstructionDecoded::Blt { imm, .. }
+            | InstructionDecoded::Bge { imm, .. }
+            | InstructionDecoded::Bltu { imm, .. }
+            | InstructionDecoded::Bgeu { imm, .. }
+            | InstructionDecoded::Jal { imm, .. }
+            | InstructionDecoded::CJal { imm }
+            | InstructionDecoded::CJ { imm }
+            | InstructionDecoded::CBeqz { imm, .. }
+            | InstructionDecoded::CBnez { imm, .. } => Some(*imm as SignedInstructionSize),
+            _ => None,
+        }
+    }
+
+    // absolute address of a branch or jump target given the address of the instruction itself
+    pub fn branch_target(&self, pc: InstructionSize) -> Option<InstructionSize> {
+        self.branch_offset()
+            .map(|offset| pc.wrapping_add(offset as InstructionSize))
+    }
+
+    // polls the zkr entropy source, reads of `seed` only count when they go through an
+    // instruction that also writes it (see `DecodeWarning::ReadOnlySeed`)
+    pub fn is_entropy_source(&self) -> bool {
+        self.effects()
+            .csr
+            .is_some_and(|csr| csr.csr == SEED_CSR && csr.write)
+    }
+}
+
+// generates comptime map for large amount of csr mapping their names to their values
+include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
+
+macro_rules! print_csr {
+    ($f:expr, $name:expr, $name_exp:expr, $rd:ident, $rs1:ident, $imm:ident) => {
+        if *$rd == 0 || *$rd == *$rs1 {
+            write!(
+                $f,
+                "{} {}, {}",
+                $name,
+                CSRS.get($imm)
+                    .map(|v| *v)
+                    .unwrap_or(format!("{}", $imm).as_str()),
+                REG_NAMES[*$rs1 as usize]
+            )
+        } else {
+            write!(
+                $f,
+                "{} {}, {}, {}",
+                $name_exp,
+                REG_NAMES[*$rd as usize],
+                CSRS.get($imm)
+                    .map(|v| *v)
+                    .unwrap_or(format!("{}", $imm).as_str()),
+                REG_NAMES[*$rs1 as usize]
+            )
+        }
+    };
+}
+
+// masked vector instructions only run on the elements enabled in v0
+fn mask_suffix(masked: bool) -> &'static str {
+    if masked {
+        ", v0.t"
+    } else {
+        ""
+    }
+}
+
+// `e32, m1, ta, ma`, reserved settings are printed as the plain immediate like objdump does
+fn vtype_operand(vtypei: InstructionSize) -> String {
+    match Vtype::new(vtypei) {
+        Some(vtype) if vtypei >> 8 == 0 => vtype.to_string(),
+        _ => vtypei.to_string(),
+    }
+}
+
+// `vle8.v`, or `vlseg3e8.v` when nf says it's a segment access
+fn segment(prefix: &str, suffix: &str, nf: InstructionSize) -> String {
+    match nf {
+        0 => format!("{prefix}{suffix}"),
+        _ => format!("{prefix}seg{}{suffix}", nf + 1),
+    }
+}
+
+// the rounding mode goes on the end unless it's dyn, `fcvt.w.s a0, fa0, rtz`
+pub(crate) fn rm_suffix(rm: RoundingMode) -> &'static str {
+    match rm {
+        RoundingMode::Rne => ", rne",
+        RoundingMode::Rtz => ", rtz",
+        RoundingMode::Rdn => ", rdn",
+        RoundingMode::Rup => ", rup",
+        RoundingMode::Rmm => ", rmm",
+        RoundingMode::Dyn => "",
+    }
+}
+
+// the registers a Zcmp push or pop saves, `{ra, s0-s2}`
+fn rlist_operand(rlist: InstructionSize) -> String {
+    match rlist {
+        4 => "{ra}".to_string(),
+        5 => "{ra, s0}".to_string(),
+        15 => "{ra, s0-s11}".to_string(),
+        _ => format!("{{ra, s0-s{}}}", rlist - 5),
+    }
+}
+
+// ordering bits of atomics go on the end of the mnemonic, `amoadd.w.aqrl`
+fn aqrl_suffix(aq: bool, rl: bool) -> &'static str {
+    match (aq, rl) {
+        (false, false) => "",
+        (true, false) => ".aq",
+        (false, true) => ".rl",
+        (true, true) => ".aqrl",
+    }
+}
+
+// pinned to `DisplayStyle::DEFAULT_VERSION`, a new style version doesn't change what this prints
+impl Display for InstructionDecoded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_with(DisplayStyle::default()))
+    }
+}
+
+// the text in the latest style, `DisplayWith` turns it back into what older versions printed
+pub(crate) struct Latest<'a>(pub(crate) &'a InstructionDecoded);
+
+impl Display for Latest<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            InstructionDecoded::Lb { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "lb {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Lh { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "lh {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Lw { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "lw {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Lbu { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "lbu {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Lhu { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "lhu {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Lwu { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "lwu {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Ld { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "ld {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Addi { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "addi {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Slli { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "slli {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Slti { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "slti {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Sltiu { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "sltiu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Xori { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "xori {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Srli { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "srli {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Srai { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "srai {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Ori { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "ori {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Andi { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "andi {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Addiw { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "addiw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Slliw { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "slliw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Srliw { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "srliw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Sraiw { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "sraiw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::AuiPc { rd, imm } => {
+                write!(f, "auipc {}, {}", REG_NAMES[*rd as usize], *imm as i32)
+            }
+            InstructionDecoded::Sb { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "sb {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sh { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "sh {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sw { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "sw {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sd { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "sd {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Add { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "add {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sub { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sub {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sll { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sll {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Slt { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "slt {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sltu { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sltu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Xor { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "xor {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Srl { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "srl {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sra { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sra {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Or { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "or {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::And { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "and {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Addw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "addw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Subw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "subw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sllw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sllw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Srlw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "srlw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sraw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sraw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Mulw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "mulw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Divw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "divw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Divuw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "divuw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Remw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "remw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Remuw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "remuw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Lui { rd, imm } => {
+                write!(f, "lui {}, {:#X}", REG_NAMES[*rd as usize], *imm)
+            }
+            InstructionDecoded::Beq { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "beq {}, {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Bne { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "bne {}, {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Blt { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "blt {}, {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Bge { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "bge {}, {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Bltu { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "bltu {}, {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Bgeu { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "bgeu {}, {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Jalr { rd, rs1, imm } => {
+                let args = match (*imm as i32 == 0, rd == rs1) {
+                    (true, true) => REG_NAMES[*rd as usize].to_string(),
+                    (true, false) => {
+                        format!("{}, {}", REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize])
+                    }
+                    (false, true) => format!("{}({})", *imm as i32, REG_NAMES[*rd as usize]),
+                    (false, false) => format!(
+                        "{}, {}({})",
+                        REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    ),
+                };
+                write!(f, "jalr {args}")
+            }
+            InstructionDecoded::Jal { rd, imm } => {
+                write!(f, "jal {}({})", *imm as i32, REG_NAMES[*rd as usize])
+            }
+            InstructionDecoded::ECall => {
+                write!(f, "ecall")
+            }
+            InstructionDecoded::EBreak => {
+                write!(f, "ebreak")
+            }
+            InstructionDecoded::SRet => {
+                write!(f, "sret")
+            }
+            InstructionDecoded::MRet => {
+                write!(f, "mret")
+            }
+            InstructionDecoded::MnRet => {
+                write!(f, "mnret")
+            }
+            InstructionDecoded::DRet => {
+                write!(f, "dret")
+            }
+            // x0 operands are left off the end, `sfence.vma` flushes everything
+            InstructionDecoded::SFenceVma { rs1, rs2 } => match (rs1, rs2) {
+                (0, 0) => write!(f, "sfence.vma"),
+                (_, 0) => write!(f, "sfence.vma {}", REG_NAMES[*rs1 as usize]),
+                _ => write!(
+                    f,
+                    "sfence.vma {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                ),
+            },
+            InstructionDecoded::WrsNto => {
+                write!(f, "wrs.nto")
+            }
+            InstructionDecoded::WrsSto => {
+                write!(f, "wrs.sto")
+            }
+            InstructionDecoded::SinvalVma { rs1, rs2 } => {
+                write!(
+                    f,
+                    "sinval.vma {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::SFenceWInval => {
+                write!(f, "sfence.w.inval")
+            }
+            InstructionDecoded::SFenceInvalIr => {
+                write!(f, "sfence.inval.ir")
+            }
+            InstructionDecoded::HinvalVvma { rs1, rs2 } => {
+                write!(
+                    f,
+                    "hinval.vvma {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::HinvalGvma { rs1, rs2 } => {
+                write!(
+                    f,
+                    "hinval.gvma {}, {}",
+                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::CsrRw { rd, rs1, imm } => {
+                print_csr!(f, "csrw", "csrrw", rd, rs1, imm)
+            }
+            InstructionDecoded::CsrRs { rd, rs1, imm } => {
+                print_csr!(f, "csrs", "csrrs", rd, rs1, imm)
+            }
+            InstructionDecoded::CsrRc { rd, rs1, imm } => {
+                print_csr!(f, "csrc", "csrrc", rd, rs1, imm)
+            }
+            InstructionDecoded::CsrRwi { rd, rs1, imm } => {
+                print_csr!(f, "csrwi", "csrrwi", rd, rs1, imm)
+            }
+            InstructionDecoded::CsrRsi { rd, rs1, imm } => {
+                print_csr!(f, "csrsi", "csrrsi", rd, rs1, imm)
+            }
+            InstructionDecoded::CsrRci { rd, rs1, imm } => {
+                print_csr!(f, "csrci", "csrrci", rd, rs1, imm)
+            }
+            InstructionDecoded::CboInval { rs1 } => {
+                write!(f, "cbo.inval ({})", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::CboClean { rs1 } => {
+                write!(f, "cbo.clean ({})", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::CboFlush { rs1 } => {
+                write!(f, "cbo.flush ({})", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::CboZero { rs1 } => {
+                write!(f, "cbo.zero ({})", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::PrefetchI { rs1, imm } => {
+                write!(
+                    f,
+                    "prefetch.i {}({})",
+                    *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::PrefetchR { rs1, imm } => {
+                write!(
+                    f,
+                    "prefetch.r {}({})",
+                    *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::PrefetchW { rs1, imm } => {
+                write!(
+                    f,
+                    "prefetch.w {}({})",
+                    *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Pause => {
+                write!(f, "pause")
+            }
+            InstructionDecoded::NtlP1 => {
+                write!(f, "ntl.p1")
+            }
+            InstructionDecoded::NtlPall => {
+                write!(f, "ntl.pall")
+            }
+            InstructionDecoded::NtlS1 => {
+                write!(f, "ntl.s1")
+            }
+            InstructionDecoded::NtlAll => {
+                write!(f, "ntl.all")
+            }
+            InstructionDecoded::Fence { pred, succ } => {
+                write!(f, "fence {}, {}", *pred as i32, *succ as i32)
+            }
+            InstructionDecoded::FenceI { pred, succ } => {
+                write!(f, "fence.i {}, {}", *pred as i32, *succ as i32)
+            }
+            InstructionDecoded::Flw { rd, rs1, imm, .. } => {
+                write!(
+                    f,
+                    "flw {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Fsw { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "fsw {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FmaddS {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmadd.s {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmsubS {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmsub.s {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmaddS {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmadd.s {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmsubS {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmsub.s {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FaddS { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fadd.s {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsubS { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fsub.s {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmulS { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fmul.s {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FdivS { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fdiv.s {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsqrtS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fsqrt.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsgnjS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnj.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjnS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjn.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjxS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjx.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FminS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmin.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FmaxS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmax.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FcvtSW { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.s.w {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtSWU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.s.wu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtWS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.w.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtWUS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.wu.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmvXW { rd, rs1 } => {
+                write!(
+                    f,
+                    "fmv.x.w {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FmvWX { rd, rs1 } => {
+                write!(
+                    f,
+                    "fmv.w.x {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FmvXD { rd, rs1 } => {
+                write!(
+                    f,
+                    "fmv.x.d {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FmvDX { rd, rs1 } => {
+                write!(
+                    f,
+                    "fmv.d.x {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FcvtLS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.l.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtLUS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.lu.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtSL { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.s.l {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtSLU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.s.lu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtLD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.l.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtLUD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.lu.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtDL { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.d.l {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtDLU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.d.lu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::Fld { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "fld {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Fsd { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "fsd {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FmaddD {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmadd.d {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmsubD {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmsub.d {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmsubD {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmsub.d {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmaddD {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmadd.d {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FaddD { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fadd.d {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsubD { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fsub.d {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmulD { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fmul.d {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FdivD { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fdiv.d {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsqrtD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fsqrt.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsgnjD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnj.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjnD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjn.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjxD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjx.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FminD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmin.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FmaxD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmax.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FcvtSD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.s.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtDS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.d.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FeqD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "feq.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FltD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "flt.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FleD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fle.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FClassD { rd, rs1 } => {
+                write!(
+                    f,
+                    "fclass.d {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FcvtWD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.w.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtWUD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.wu.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtDW { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.d.w {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtDWU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.d.wu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FaddQ { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fadd.q {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsubQ { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fsub.q {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmulQ { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fmul.q {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FdivQ { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fdiv.q {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsqrtQ { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fsqrt.q {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsgnjQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnj.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjnQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjn.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjxQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjx.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FminQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmin.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FmaxQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmax.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FcvtSQ { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.s.q {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtQS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.q.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtDQ { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.d.q {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtQD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.q.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FeqQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "feq.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FltQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "flt.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FleQ { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fle.q {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FClassQ { rd, rs1 } => {
+                write!(
+                    f,
+                    "fclass.q {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FcvtWQ { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.w.q {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtWUQ { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.wu.q {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtLQ { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.l.q {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtLUQ { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.lu.q {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtQW { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.q.w {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtQWU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.q.wu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtQL { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.q.l {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtQLU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.q.lu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmaddQ {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmadd.q {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmsubQ {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmsub.q {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmsubQ {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmsub.q {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmaddQ {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmadd.q {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::Flq { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "flq {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Fsq { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "fsq {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FaddH { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fadd.h {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsubH { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fsub.h {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmulH { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fmul.h {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FdivH { rd, rs1, rs2, rm } => {
+                write!(
+                    f,
+                    "fdiv.h {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsqrtH { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fsqrt.h {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FsgnjH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnj.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjnH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjn.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FsgnjxH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fsgnjx.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FminH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmin.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FmaxH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmax.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FcvtSH { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.s.h {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtHS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.h.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtDH { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.d.h {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtHD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.h.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FeqH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "feq.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FltH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "flt.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FleH { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fle.h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FClassH { rd, rs1 } => {
+                write!(
+                    f,
+                    "fclass.h {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FcvtWH { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.w.h {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtWUH { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.wu.h {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtLH { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.l.h {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtLUH { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.lu.h {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtHW { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.h.w {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtHWU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.h.wu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtHL { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.h.l {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FcvtHLU { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fcvt.h.lu {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmvXH { rd, rs1 } => {
+                write!(
+                    f,
+                    "fmv.x.h {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FmvHX { rd, rs1 } => {
+                write!(
+                    f,
+                    "fmv.h.x {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FmaddH {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmadd.h {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FmsubH {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fmsub.h {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmsubH {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmsub.h {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FnmaddH {
+                rd,
+                rs1,
+                rs2,
+                rs3,
+                rm,
+            } => {
+                write!(
+                    f,
+                    "fnmadd.h {}, {}, {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs3 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::Flh { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "flh {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Fsh { rs1, rs2, imm } => {
+                write!(
+                    f,
+                    "fsh {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FliS { rd, imm } => {
+                write!(
+                    f,
+                    "fli.s {}, {}",
+                    REG_NAMES[*rd as usize], FLI_VALUES[*imm as usize]
+                )
+            }
+            InstructionDecoded::FliD { rd, imm } => {
+                write!(
+                    f,
+                    "fli.d {}, {}",
+                    REG_NAMES[*rd as usize], FLI_VALUES[*imm as usize]
+                )
+            }
+            InstructionDecoded::FminmS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fminm.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FmaxmS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmaxm.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FminmD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fminm.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FmaxmD { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fmaxm.d {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FroundS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fround.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FroundnxS { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "froundnx.s {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FroundD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "fround.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            InstructionDecoded::FroundnxD { rd, rs1, rm } => {
+                write!(
+                    f,
+                    "froundnx.d {}, {}{}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    rm_suffix(*rm)
+                )
+            }
+            // the rounding mode is always rtz, assemblers still want it written out
+            InstructionDecoded::FcvtmodWD { rd, rs1 } => {
+                write!(
+                    f,
+                    "fcvtmod.w.d {}, {}, rtz",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::FeqS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "feq.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FltS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "flt.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FleS { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "fle.s {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::FClassS { rd, rs1 } => {
+                write!(
+                    f,
+                    "fclass.s {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Mul { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "mul {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Mulh { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "mulh {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Mulsu { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "mulsu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Mulu { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "mulu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Div { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "div {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Divu { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "divu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Rem { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "rem {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Remu { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "remu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::LrW {
+                rd, rs1, rl, aq, ..
+            } => {
+                write!(
+                    f,
+                    "lr.w{} {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::ScW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "sc.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoswapW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoswap.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoaddW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoadd.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoandW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoand.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoorW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoor.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoxorW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoxor.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmomaxW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amomax.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmominW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amomin.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmominuW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amominu.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmomaxuW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amomaxu.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::LrD {
+                rd, rs1, rl, aq, ..
+            } => {
+                write!(
+                    f,
+                    "lr.d{} {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::ScD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "sc.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoswapD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoswap.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoaddD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoadd.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoxorD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoxor.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoandD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoand.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmoorD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amoor.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmominD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amomin.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmomaxD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amomax.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmominuD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amominu.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::AmomaxuD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
+                write!(
+                    f,
+                    "amomaxu.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Vsm4kVi { vd, vs2, uimm } => {
+                write!(f, "vsm4k.vi v{}, v{}, {}", vd, vs2, uimm)
+            }
+            InstructionDecoded::Vsm4rVv { vd, vs2 } => {
+                write!(f, "vsm4r.vv v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::Vsm4rVs { vd, vs2 } => {
+                write!(f, "vsm4r.vs v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::Vsm3meVv { vd, vs2, vs1 } => {
+                write!(f, "vsm3me.vv v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::Vsm3cVi { vd, vs2, uimm } => {
+                write!(f, "vsm3c.vi v{}, v{}, {}", vd, vs2, uimm)
+            }
+            InstructionDecoded::Vfwmaccbf16Vv {
+                vd,
+                vs1,
+                vs2,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmaccbf16.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vfwmaccbf16Vf {
+                vd,
+                rs1,
+                vs2,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmaccbf16.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vfncvtbf16FFW { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfncvtbf16.f.f.w v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vfwcvtbf16FFV { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfwcvtbf16.f.f.v v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Sh1add { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sh1add {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sh2add { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sh2add {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sh3add { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sh3add {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::AddUw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "add.uw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sh1addUw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sh1add.uw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sh2addUw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sh2add.uw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sh3addUw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sh3add.uw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::SlliUw { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "slli.uw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Andn { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "andn {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Orn { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "orn {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Xnor { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "xnor {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Min { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "min {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Minu { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "minu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Max { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "max {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Maxu { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "maxu {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Rol { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "rol {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Ror { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "ror {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Clz { rd, rs1 } => {
+                write!(
+                    f,
+                    "clz {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Ctz { rd, rs1 } => {
+                write!(
+                    f,
+                    "ctz {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Cpop { rd, rs1 } => {
+                write!(
+                    f,
+                    "cpop {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::SextB { rd, rs1 } => {
+                write!(
+                    f,
+                    "sext.b {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::SextH { rd, rs1 } => {
+                write!(
+                    f,
+                    "sext.h {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::OrcB { rd, rs1 } => {
+                write!(
+                    f,
+                    "orc.b {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Rori { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "rori {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Rolw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "rolw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Rorw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "rorw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Roriw { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "roriw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Clzw { rd, rs1 } => {
+                write!(
+                    f,
+                    "clzw {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Ctzw { rd, rs1 } => {
+                write!(
+                    f,
+                    "ctzw {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Cpopw { rd, rs1 } => {
+                write!(
+                    f,
+                    "cpopw {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Rev8 { rd, rs1 } => {
+                write!(
+                    f,
+                    "rev8 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::ZextH { rd, rs1 } => {
+                write!(
+                    f,
+                    "zext.h {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Clmul { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "clmul {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Clmulh { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "clmulh {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Clmulr { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "clmulr {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Bclr { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "bclr {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Bext { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "bext {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Binv { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "binv {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Bset { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "bset {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Bclri { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "bclri {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Bexti { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "bexti {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Binvi { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "binvi {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Bseti { rd, rs1, imm } => {
+                write!(
+                    f,
+                    "bseti {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                )
+            }
+            InstructionDecoded::Pack { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "pack {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Packh { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "packh {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Packw { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "packw {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Brev8 { rd, rs1 } => {
+                write!(
+                    f,
+                    "brev8 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Zip { rd, rs1 } => {
+                write!(
+                    f,
+                    "zip {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Unzip { rd, rs1 } => {
+                write!(
+                    f,
+                    "unzip {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Xperm4 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "xperm4 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Xperm8 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "xperm8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Aes32esi { rd, rs1, rs2, bs } => {
+                write!(
+                    f,
+                    "aes32esi {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    *bs as i32
+                )
+            }
+            InstructionDecoded::Aes32esmi { rd, rs1, rs2, bs } => {
+                write!(
+                    f,
+                    "aes32esmi {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    *bs as i32
+                )
+            }
+            InstructionDecoded::Aes32dsi { rd, rs1, rs2, bs } => {
+                write!(
+                    f,
+                    "aes32dsi {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    *bs as i32
+                )
+            }
+            InstructionDecoded::Aes32dsmi { rd, rs1, rs2, bs } => {
+                write!(
+                    f,
+                    "aes32dsmi {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    *bs as i32
+                )
+            }
+            InstructionDecoded::Aes64es { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "aes64es {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Aes64esm { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "aes64esm {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Aes64ds { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "aes64ds {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Aes64dsm { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "aes64dsm {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Aes64ks2 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "aes64ks2 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Aes64im { rd, rs1 } => {
+                write!(
+                    f,
+                    "aes64im {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Aes64ks1i { rd, rs1, rnum } => {
+                write!(
+                    f,
+                    "aes64ks1i {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *rnum as i32
+                )
+            }
+            InstructionDecoded::Sha256sum0 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha256sum0 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha256sum1 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha256sum1 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha256sig0 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha256sig0 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha256sig1 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha256sig1 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sum0 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha512sum0 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sum1 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha512sum1 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sig0 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha512sig0 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sig1 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sha512sig1 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sum0r { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sha512sum0r {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sum1r { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sha512sum1r {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sig0l { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sha512sig0l {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sig1l { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sha512sig1l {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sig0h { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sha512sig0h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sha512sig1h { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sha512sig1h {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sm4ed { rd, rs1, rs2, bs } => {
+                write!(
+                    f,
+                    "sm4ed {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    *bs as i32
+                )
+            }
+            InstructionDecoded::Sm4ks { rd, rs1, rs2, bs } => {
+                write!(
+                    f,
+                    "sm4ks {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    *bs as i32
+                )
+            }
+            InstructionDecoded::Sm3p0 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sm3p0 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Sm3p1 { rd, rs1 } => {
+                write!(
+                    f,
+                    "sm3p1 {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::Vsetvli { rd, rs1, vtypei } => {
+                write!(
+                    f,
+                    "vsetvli {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    vtype_operand(*vtypei)
+                )
+            }
+            InstructionDecoded::Vsetivli { rd, uimm, vtypei } => {
+                write!(
+                    f,
+                    "vsetivli {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    uimm,
+                    vtype_operand(*vtypei)
+                )
+            }
+            InstructionDecoded::Vsetvl { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "vsetvl {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Vle8V {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e8.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vle16V {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e16.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vle32V {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e32.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vle64V {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e64.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vse8V {
+                vs3,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vs", "e8.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vse16V {
+                vs3,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vs", "e16.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vse32V {
+                vs3,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vs", "e32.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vse64V {
+                vs3,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vs", "e64.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vle8ffV {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e8ff.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vle16ffV {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e16ff.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vle32ffV {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e32ff.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vle64ffV {
+                vd,
+                rs1,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}){}",
+                    segment("vl", "e64ff.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vlse8V {
+                vd,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vls", "e8.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vlse16V {
+                vd,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vls", "e16.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vlse32V {
+                vd,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vls", "e32.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vlse64V {
+                vd,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vls", "e64.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsse8V {
+                vs3,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vss", "e8.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsse16V {
+                vs3,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vss", "e16.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsse32V {
+                vs3,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vss", "e32.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsse64V {
+                vs3,
+                rs1,
+                rs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), {}{}",
+                    segment("vss", "e64.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vluxei8V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlux", "ei8.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vluxei16V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlux", "ei16.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vluxei32V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlux", "ei32.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vluxei64V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlux", "ei64.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vloxei8V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlox", "ei8.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vloxei16V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlox", "ei16.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vloxei32V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlox", "ei32.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vloxei64V {
+                vd,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vlox", "ei64.v", *nf),
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsuxei8V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsux", "ei8.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsuxei16V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsux", "ei16.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsuxei32V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsux", "ei32.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsuxei64V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsux", "ei64.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsoxei8V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsox", "ei8.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsoxei16V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsox", "ei16.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsoxei32V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsox", "ei32.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vsoxei64V {
+                vs3,
+                rs1,
+                vs2,
+                nf,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "{} v{}, ({}), v{}{}",
+                    segment("vsox", "ei64.v", *nf),
+                    vs3,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredsumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredsum.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredandVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredand.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredorVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredor.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredxorVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredxor.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredminuVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredminu.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredminVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredmin.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredmaxuVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredmaxu.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VredmaxVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vredmax.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VaadduVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vaaddu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VaadduVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vaaddu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VaaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vaadd.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VaaddVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vaadd.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VasubuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vasubu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VasubuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vasubu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VasubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vasub.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VasubVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vasub.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vslide1upVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vslide1up.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vslide1downVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vslide1down.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VdivuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vdivu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VdivuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vdivu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VdivVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vdiv.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VdivVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vdiv.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VremuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vremu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VremuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vremu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VremVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vrem.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VremVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vrem.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulhuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmulhu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulhuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmulhu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmul.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmul.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulhsuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmulhsu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulhsuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmulhsu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulhVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmulh.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmulhVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmulh.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmadd.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmaddVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmadd.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VnmsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vnmsub.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VnmsubVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vnmsub.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmacc.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmaccVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmacc.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VnmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vnmsac.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VnmsacVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vnmsac.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwadduVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwaddu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwadduVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwaddu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwadd.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwaddVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwadd.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsubu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsubu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsub.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsub.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwadduWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwaddu.wv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwadduWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwaddu.wx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwaddWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwadd.wv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwaddWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwadd.wx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubuWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsubu.wv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubuWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsubu.wx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsub.wv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsubWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsub.wx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmuluVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmulu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmuluVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmulu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmulsuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmulsu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmulsuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmulsu.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmul.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmulVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmul.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmaccuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmaccu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmaccuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmaccu.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmacc.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmaccVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmacc.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmaccusVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmaccus.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmaccsuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmaccsu.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwmaccsuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwmaccsu.vx v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmvXS { rd, vs2 } => {
+                write!(f, "vmv.x.s {}, v{}", REG_NAMES[*rd as usize], vs2)
+            }
+            InstructionDecoded::VmvSX { vd, rs1 } => {
+                write!(f, "vmv.s.x v{}, {}", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::VzextVf8 { vd, vs2, masked } => {
+                write!(f, "vzext.vf8 v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VsextVf8 { vd, vs2, masked } => {
+                write!(f, "vsext.vf8 v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VzextVf4 { vd, vs2, masked } => {
+                write!(f, "vzext.vf4 v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VsextVf4 { vd, vs2, masked } => {
+                write!(f, "vsext.vf4 v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VzextVf2 { vd, vs2, masked } => {
+                write!(f, "vzext.vf2 v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VsextVf2 { vd, vs2, masked } => {
+                write!(f, "vsext.vf2 v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VcompressVm { vd, vs2, vs1 } => {
+                write!(f, "vcompress.vm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VfaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfadd.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfadd.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfredusumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfredusum.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsub.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsub.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfredosumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfredosum.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfminVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmin.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfminVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmin.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfredminVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfredmin.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmaxVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmax.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmaxVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmax.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfredmaxVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfredmax.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsgnjVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsgnj.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsgnjVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsgnj.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsgnjnVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsgnjn.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsgnjnVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsgnjn.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsgnjxVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsgnjx.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsgnjxVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfsgnjx.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vfslide1upVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfslide1up.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vfslide1downVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfslide1down.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfeqVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfeq.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfeqVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfeq.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfleVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfle.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfleVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfle.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfltVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmflt.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfltVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmflt.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfneVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfne.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfneVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfne.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfgtVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfgt.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmfgeVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vmfge.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfdivVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfdiv.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfdivVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfdiv.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfrdivVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfrdiv.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmul.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmulVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmul.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfrsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfrsub.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmadd.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmadd.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmadd.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmadd.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmsub.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmsub.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmsub.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmsub.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmacc.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmacc.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmacc.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmacc.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmsac.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfmsac.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmsac.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfnmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfnmsac.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwadd.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwadd.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwredusumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwredusum.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwsub.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwsub.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwredosumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwredosum.vs v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwaddWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwadd.wv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwaddWf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwadd.wf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwsubWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwsub.wv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwsubWf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwsub.wf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmul.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwmulVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmul.vf v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmacc.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmacc.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwnmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwnmacc.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwnmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwnmacc.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmsac.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwmsac.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwnmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwnmsac.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs1,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwnmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vfwnmsac.vf v{}, {}, v{}{}",
+                    vd,
+                    REG_NAMES[*rs1 as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfcvtXuFV { vd, vs2, masked } => {
+                write!(f, "vfcvt.xu.f.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfcvtXFV { vd, vs2, masked } => {
+                write!(f, "vfcvt.x.f.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfcvtFXuV { vd, vs2, masked } => {
+                write!(f, "vfcvt.f.xu.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfcvtFXV { vd, vs2, masked } => {
+                write!(f, "vfcvt.f.x.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfcvtRtzXuFV { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfcvt.rtz.xu.f.v v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfcvtRtzXFV { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfcvt.rtz.x.f.v v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwcvtXuFV { vd, vs2, masked } => {
+                write!(f, "vfwcvt.xu.f.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfwcvtXFV { vd, vs2, masked } => {
+                write!(f, "vfwcvt.x.f.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfwcvtFXuV { vd, vs2, masked } => {
+                write!(f, "vfwcvt.f.xu.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfwcvtFXV { vd, vs2, masked } => {
+                write!(f, "vfwcvt.f.x.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfwcvtFFV { vd, vs2, masked } => {
+                write!(f, "vfwcvt.f.f.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfwcvtRtzXuFV { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfwcvt.rtz.xu.f.v v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfwcvtRtzXFV { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfwcvt.rtz.x.f.v v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfncvtXuFW { vd, vs2, masked } => {
+                write!(f, "vfncvt.xu.f.w v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfncvtXFW { vd, vs2, masked } => {
+                write!(f, "vfncvt.x.f.w v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfncvtFXuW { vd, vs2, masked } => {
+                write!(f, "vfncvt.f.xu.w v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfncvtFXW { vd, vs2, masked } => {
+                write!(f, "vfncvt.f.x.w v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfncvtFFW { vd, vs2, masked } => {
+                write!(f, "vfncvt.f.f.w v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfncvtRodFFW { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfncvt.rod.f.f.w v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfncvtRtzXuFW { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfncvt.rtz.xu.f.w v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfncvtRtzXFW { vd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfncvt.rtz.x.f.w v{}, v{}{}",
+                    vd,
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfsqrtV { vd, vs2, masked } => {
+                write!(f, "vfsqrt.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::Vfrsqrt7V { vd, vs2, masked } => {
+                write!(f, "vfrsqrt7.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::Vfrec7V { vd, vs2, masked } => {
+                write!(f, "vfrec7.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfclassV { vd, vs2, masked } => {
+                write!(f, "vfclass.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VfmvFS { rd, vs2 } => {
+                write!(f, "vfmv.f.s {}, v{}", REG_NAMES[*rd as usize], vs2)
+            }
+            InstructionDecoded::VfmvSF { vd, rs1 } => {
+                write!(f, "vfmv.s.f v{}, {}", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::VfmergeVfm { vd, vs2, rs1 } => {
+                write!(
+                    f,
+                    "vfmerge.vfm v{}, v{}, {}, v0",
+                    vd, vs2, REG_NAMES[*rs1 as usize]
+                )
+            }
+            InstructionDecoded::VfmvVF { vd, rs1 } => {
+                write!(f, "vfmv.v.f v{}, {}", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::VmandnMm { vd, vs2, vs1 } => {
+                write!(f, "vmandn.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VmandMm { vd, vs2, vs1 } => {
+                write!(f, "vmand.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VmorMm { vd, vs2, vs1 } => {
+                write!(f, "vmor.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VmxorMm { vd, vs2, vs1 } => {
+                write!(f, "vmxor.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VmornMm { vd, vs2, vs1 } => {
+                write!(f, "vmorn.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VmnandMm { vd, vs2, vs1 } => {
+                write!(f, "vmnand.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VmnorMm { vd, vs2, vs1 } => {
+                write!(f, "vmnor.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VmxnorMm { vd, vs2, vs1 } => {
+                write!(f, "vmxnor.mm v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VcpopM { rd, vs2, masked } => {
+                write!(
+                    f,
+                    "vcpop.m {}, v{}{}",
+                    REG_NAMES[*rd as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VfirstM { rd, vs2, masked } => {
+                write!(
+                    f,
+                    "vfirst.m {}, v{}{}",
+                    REG_NAMES[*rd as usize],
+                    vs2,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VmsbfM { vd, vs2, masked } => {
+                write!(f, "vmsbf.m v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VmsofM { vd, vs2, masked } => {
+                write!(f, "vmsof.m v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VmsifM { vd, vs2, masked } => {
+                write!(f, "vmsif.m v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::ViotaM { vd, vs2, masked } => {
+                write!(f, "viota.m v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VidV { vd, masked } => {
+                write!(f, "vid.v v{}{}", vd, mask_suffix(*masked))
+            }
+            InstructionDecoded::Vl1re8V { vd, rs1 } => {
+                write!(f, "vl1re8.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl1re16V { vd, rs1 } => {
+                write!(f, "vl1re16.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl1re32V { vd, rs1 } => {
+                write!(f, "vl1re32.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl1re64V { vd, rs1 } => {
+                write!(f, "vl1re64.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl2re8V { vd, rs1 } => {
+                write!(f, "vl2re8.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl2re16V { vd, rs1 } => {
+                write!(f, "vl2re16.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl2re32V { vd, rs1 } => {
+                write!(f, "vl2re32.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl2re64V { vd, rs1 } => {
+                write!(f, "vl2re64.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl4re8V { vd, rs1 } => {
+                write!(f, "vl4re8.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl4re16V { vd, rs1 } => {
+                write!(f, "vl4re16.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl4re32V { vd, rs1 } => {
+                write!(f, "vl4re32.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl4re64V { vd, rs1 } => {
+                write!(f, "vl4re64.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl8re8V { vd, rs1 } => {
+                write!(f, "vl8re8.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl8re16V { vd, rs1 } => {
+                write!(f, "vl8re16.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl8re32V { vd, rs1 } => {
+                write!(f, "vl8re32.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vl8re64V { vd, rs1 } => {
+                write!(f, "vl8re64.v v{}, ({})", vd, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vs1rV { vs3, rs1 } => {
+                write!(f, "vs1r.v v{}, ({})", vs3, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vs2rV { vs3, rs1 } => {
+                write!(f, "vs2r.v v{}, ({})", vs3, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vs4rV { vs3, rs1 } => {
+                write!(f, "vs4r.v v{}, ({})", vs3, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vs8rV { vs3, rs1 } => {
+                write!(f, "vs8r.v v{}, ({})", vs3, REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::Vmv1rV { vd, vs2 } => {
+                write!(f, "vmv1r.v v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::Vmv2rV { vd, vs2 } => {
+                write!(f, "vmv2r.v v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::Vmv4rV { vd, vs2 } => {
+                write!(f, "vmv4r.v v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::Vmv8rV { vd, vs2 } => {
+                write!(f, "vmv8r.v v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VandnVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vandn.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VandnVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vandn.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VrorVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vror.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VrorVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vror.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VrorVi {
+                vd,
+                vs2,
+                uimm,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vror.vi v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    uimm,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VrolVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vrol.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VrolVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vrol.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsllVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsll.vv v{}, v{}, v{}{}",
+                    vd,
+                    vs2,
+                    vs1,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsllVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsll.vx v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    REG_NAMES[*rs1 as usize],
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::VwsllVi {
+                vd,
+                vs2,
+                uimm,
+                masked,
+            } => {
+                write!(
+                    f,
+                    "vwsll.vi v{}, v{}, {}{}",
+                    vd,
+                    vs2,
+                    uimm,
+                    mask_suffix(*masked)
+                )
+            }
+            InstructionDecoded::Vbrev8V { vd, vs2, masked } => {
+                write!(f, "vbrev8.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::Vrev8V { vd, vs2, masked } => {
+                write!(f, "vrev8.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VbrevV { vd, vs2, masked } => {
+                write!(f, "vbrev.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VclzV { vd, vs2, masked } => {
+                write!(f, "vclz.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VctzV { vd, vs2, masked } => {
+                write!(f, "vctz.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VcpopV { vd, vs2, masked } => {
+                write!(f, "vcpop.v v{}, v{}{}", vd, vs2, mask_suffix(*masked))
+            }
+            InstructionDecoded::VghshVv { vd, vs2, vs1 } => {
+                write!(f, "vghsh.vv v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::VgmulVv { vd, vs2 } => {
+                write!(f, "vgmul.vv v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesdfVv { vd, vs2 } => {
+                write!(f, "vaesdf.vv v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesdfVs { vd, vs2 } => {
+                write!(f, "vaesdf.vs v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesdmVv { vd, vs2 } => {
+                write!(f, "vaesdm.vv v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesdmVs { vd, vs2 } => {
+                write!(f, "vaesdm.vs v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesefVv { vd, vs2 } => {
+                write!(f, "vaesef.vv v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesefVs { vd, vs2 } => {
+                write!(f, "vaesef.vs v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesemVv { vd, vs2 } => {
+                write!(f, "vaesem.vv v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaesemVs { vd, vs2 } => {
+                write!(f, "vaesem.vs v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::VaeszVs { vd, vs2 } => {
+                write!(f, "vaesz.vs v{}, v{}", vd, vs2)
+            }
+            InstructionDecoded::Vaeskf1Vi { vd, vs2, uimm } => {
+                write!(f, "vaeskf1.vi v{}, v{}, {}", vd, vs2, uimm)
+            }
+            InstructionDecoded::Vaeskf2Vi { vd, vs2, uimm } => {
+                write!(f, "vaeskf2.vi v{}, v{}, {}", vd, vs2, uimm)
+            }
+            InstructionDecoded::Vsha2msVv { vd, vs2, vs1 } => {
+                write!(f, "vsha2ms.vv v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::Vsha2chVv { vd, vs2, vs1 } => {
+                write!(f, "vsha2ch.vv v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::Vsha2clVv { vd, vs2, vs1 } => {
+                write!(f, "vsha2cl.vv v{}, v{}, v{}", vd, vs2, vs1)
+            }
+            InstructionDecoded::Add16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "add16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Add8 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "add8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sub16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sub16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Sub8 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "sub8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Lw { rd, rs1, imm } => {
+            InstructionDecoded::Radd16 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "lw {}, {}({})",
-                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "radd16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Lbu { rd, rs1, imm } => {
+            InstructionDecoded::Radd8 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "lbu {}, {}({})",
-                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "radd8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Lhu { rd, rs1, imm } => {
+            InstructionDecoded::Rsub16 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "lhu {}, {}({})",
-                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "rsub16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Lwu { rd, rs1, imm } => {
+            InstructionDecoded::Rsub8 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "lwu {}, {}({})",
-                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "rsub8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Addi { rd, rs1, imm } => {
+            InstructionDecoded::Kadd16 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "addi {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "kadd16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Slli { rd, rs1, imm } => {
+            InstructionDecoded::Kadd8 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "slli {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "kadd8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Slti { rd, rs1, imm } => {
+            InstructionDecoded::Ksub16 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "slti {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "ksub16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Sltiu { rd, rs1, imm } => {
+            InstructionDecoded::Ksub8 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "sltiu {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "ksub8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Xori { rd, rs1, imm } => {
+            InstructionDecoded::Ukadd16 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "xori {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "ukadd16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Srli { rd, rs1, imm } => {
+            InstructionDecoded::Ukadd8 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "srli {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "ukadd8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Srai { rd, rs1, imm } => {
+            InstructionDecoded::Uksub16 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "srai {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "uksub16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Ori { rd, rs1, imm } => {
+            InstructionDecoded::Uksub8 { rd, rs1, rs2 } => {
                 write!(
                     f,
-                    "ori {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "uksub8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Andi { rd, rs1, imm } => {
+            InstructionDecoded::Smul16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "smul16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Smul8 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "smul8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Umul16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "umul16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Umul8 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "umul8 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Pkbb16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "pkbb16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Pkbt16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "pkbt16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Pktb16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "pktb16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::Pktt16 { rd, rs1, rs2 } => {
+                write!(
+                    f,
+                    "pktt16 {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                )
+            }
+            InstructionDecoded::ThAddsl {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.addsl {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThDcacheCall => {
+                write!(f, "th.dcache.call")
+            }
+            InstructionDecoded::ThDcacheIall => {
+                write!(f, "th.dcache.iall")
+            }
+            InstructionDecoded::ThDcacheCiall => {
+                write!(f, "th.dcache.ciall")
+            }
+            InstructionDecoded::ThIcacheIall => {
+                write!(f, "th.icache.iall")
+            }
+            InstructionDecoded::ThIcacheIalls => {
+                write!(f, "th.icache.ialls")
+            }
+            InstructionDecoded::ThDcacheCval1 { rs1 } => {
+                write!(f, "th.dcache.cval1 {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThDcacheCva { rs1 } => {
+                write!(f, "th.dcache.cva {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThDcacheIva { rs1 } => {
+                write!(f, "th.dcache.iva {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThDcacheCiva { rs1 } => {
+                write!(f, "th.dcache.civa {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThDcacheCpa { rs1 } => {
+                write!(f, "th.dcache.cpa {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThDcacheIpa { rs1 } => {
+                write!(f, "th.dcache.ipa {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThDcacheCipa { rs1 } => {
+                write!(f, "th.dcache.cipa {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThIcacheIva { rs1 } => {
+                write!(f, "th.icache.iva {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThIcacheIpa { rs1 } => {
+                write!(f, "th.icache.ipa {}", REG_NAMES[*rs1 as usize])
+            }
+            InstructionDecoded::ThLrb {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lrb {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLurb {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lurb {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLbib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lbib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
+                )
+            }
+            InstructionDecoded::ThLbia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lbia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
+                )
+            }
+            InstructionDecoded::ThLrh {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lrh {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLurh {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lurh {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLhib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lhib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
+                )
+            }
+            InstructionDecoded::ThLhia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lhia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
+                )
+            }
+            InstructionDecoded::ThLrw {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lrw {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLurw {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lurw {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLwib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lwib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
+                )
+            }
+            InstructionDecoded::ThLwia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lwia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
+                )
+            }
+            InstructionDecoded::ThLrd {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lrd {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLurd {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.lurd {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
+                )
+            }
+            InstructionDecoded::ThLdib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "andi {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32
+                    "th.ldib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::AuiPc { rd, imm } => {
-                write!(f, "auipc {}, {}", REG_NAMES[*rd as usize], *imm as i32)
-            }
-            InstructionDecoded::Sb { rs1, rs2, imm } => {
+            InstructionDecoded::ThLdia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "sb {}, {}({})",
-                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "th.ldia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Sh { rs1, rs2, imm } => {
+            InstructionDecoded::ThLrbu {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "sh {}, {}({})",
-                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "th.lrbu {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Sw { rs1, rs2, imm } => {
+            InstructionDecoded::ThLurbu {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "sw {}, {}({})",
-                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "th.lurbu {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Add { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLbuib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "add {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lbuib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Sub { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLbuia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "sub {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lbuia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Sll { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLrhu {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "sll {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lrhu {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Slt { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLurhu {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "slt {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lurhu {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Sltu { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLhuib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "sltu {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lhuib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Xor { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLhuia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "xor {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lhuia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Srl { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLrwu {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "srl {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lrwu {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Sra { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLurwu {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "sra {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lurwu {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Or { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLwuib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "or {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lwuib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::And { rd, rs1, rs2 } => {
+            InstructionDecoded::ThLwuia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "and {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.lwuia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Lui { rd, imm } => {
-                write!(f, "lui {}, {:#X}", REG_NAMES[*rd as usize], *imm)
-            }
-            InstructionDecoded::Beq { rs1, rs2, imm } => {
+            InstructionDecoded::ThSrb {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "beq {}, {}, {}",
-                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                    "th.srb {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Bne { rs1, rs2, imm } => {
+            InstructionDecoded::ThSurb {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "bne {}, {}, {}",
-                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                    "th.surb {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Blt { rs1, rs2, imm } => {
+            InstructionDecoded::ThSbib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "blt {}, {}, {}",
-                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                    "th.sbib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Bge { rs1, rs2, imm } => {
+            InstructionDecoded::ThSbia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "bge {}, {}, {}",
-                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                    "th.sbia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Bltu { rs1, rs2, imm } => {
+            InstructionDecoded::ThSrh {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "bltu {}, {}, {}",
-                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                    "th.srh {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Bgeu { rs1, rs2, imm } => {
+            InstructionDecoded::ThSurh {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "bgeu {}, {}, {}",
-                    REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize], *imm as i32
+                    "th.surh {}, {}, {}, {}",
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs1 as usize],
+                    REG_NAMES[*rs2 as usize],
+                    shift
                 )
             }
-            InstructionDecoded::Jalr { rd, rs1, imm } => {
-                let args = match (*imm as i32 == 0, rd == rs1) {
-                    (true, true) => format!("{}", REG_NAMES[*rd as usize]),
-                    (true, false) => {
-                        format!("{}, {}", REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize])
-                    }
-                    (false, true) => format!("{}({})", *imm as i32, REG_NAMES[*rd as usize]),
-                    (false, false) => format!(
-                        "{}, {}({})",
-                        REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
-                    ),
-                };
-                write!(f, "jalr {args}")
-            }
-            InstructionDecoded::Jal { rd, imm } => {
-                write!(f, "jal {}({})", *imm as i32, REG_NAMES[*rd as usize])
-            }
-            InstructionDecoded::ECall => {
-                write!(f, "ecall")
-            }
-            InstructionDecoded::EBreak => {
-                write!(f, "ebreak")
-            }
-            InstructionDecoded::SRet => {
-                write!(f, "sret")
-            }
-            InstructionDecoded::MRet => {
-                write!(f, "mret")
-            }
-            InstructionDecoded::SFenceVma => {
-                write!(f, "sfence.vma")
-            }
-            InstructionDecoded::CsrRw { rd, rs1, imm } => {
-                print_csr!(f, "csrw", "csrrw", rd, rs1, imm)
-            }
-            InstructionDecoded::CsrRs { rd, rs1, imm } => {
-                print_csr!(f, "csrs", "csrrs", rd, rs1, imm)
-            }
-            InstructionDecoded::CsrRc { rd, rs1, imm } => {
-                print_csr!(f, "csrc", "csrrc", rd, rs1, imm)
-            }
-            InstructionDecoded::CsrRwi { rd, rs1, imm } => {
-                print_csr!(f, "csrwi", "csrrwi", rd, rs1, imm)
-            }
-            InstructionDecoded::CsrRsi { rd, rs1, imm } => {
-                print_csr!(f, "csrsi", "csrrsi", rd, rs1, imm)
-            }
-            InstructionDecoded::CsrRci { rd, rs1, imm } => {
-                print_csr!(f, "csrci", "csrrci", rd, rs1, imm)
-            }
-            InstructionDecoded::Fence { pred, succ } => {
-                write!(f, "fence {}, {}", *pred as i32, *succ as i32)
-            }
-            InstructionDecoded::FenceI { pred, succ } => {
-                write!(f, "fence.i {}, {}", *pred as i32, *succ as i32)
-            }
-            InstructionDecoded::Flw {
+            InstructionDecoded::ThShib {
                 rd,
-                width,
                 rs1,
                 imm,
+                shift,
             } => {
                 write!(
                     f,
-                    "flw {}, {}, {}({})",
-                    REG_NAMES[*rd as usize], *width as i32, REG_NAMES[*rs1 as usize], *imm as i32
+                    "th.shib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::Fsw { rs1, rs2, imm } => {
+            InstructionDecoded::ThShia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "fsw {}, {}({})",
-                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
+                    "th.shia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::FmaddS { rd, rs1, rs2, rs3 } => {
+            InstructionDecoded::ThSrw {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "fmadd.s {}, {}, {}, {}",
+                    "th.srw {}, {}, {}, {}",
                     REG_NAMES[*rd as usize],
                     REG_NAMES[*rs1 as usize],
                     REG_NAMES[*rs2 as usize],
-                    REG_NAMES[*rs3 as usize]
+                    shift
                 )
             }
-            InstructionDecoded::FmsubS { rd, rs1, rs2, rs3 } => {
+            InstructionDecoded::ThSurw {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "fmsub.s {}, {}, {}, {}",
+                    "th.surw {}, {}, {}, {}",
                     REG_NAMES[*rd as usize],
                     REG_NAMES[*rs1 as usize],
                     REG_NAMES[*rs2 as usize],
-                    REG_NAMES[*rs3 as usize]
+                    shift
+                )
+            }
+            InstructionDecoded::ThSwib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.swib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
+                )
+            }
+            InstructionDecoded::ThSwia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
+                write!(
+                    f,
+                    "th.swia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::FnmaddS { rd, rs1, rs2, rs3 } => {
+            InstructionDecoded::ThSrd {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "fnmadd.s {}, {}, {}, {}",
+                    "th.srd {}, {}, {}, {}",
                     REG_NAMES[*rd as usize],
                     REG_NAMES[*rs1 as usize],
                     REG_NAMES[*rs2 as usize],
-                    REG_NAMES[*rs3 as usize]
+                    shift
                 )
             }
-            InstructionDecoded::FnmsubS { rd, rs1, rs2, rs3 } => {
+            InstructionDecoded::ThSurd {
+                rd,
+                rs1,
+                rs2,
+                shift,
+            } => {
                 write!(
                     f,
-                    "fnmsub.s {}, {}, {}, {}",
+                    "th.surd {}, {}, {}, {}",
                     REG_NAMES[*rd as usize],
                     REG_NAMES[*rs1 as usize],
                     REG_NAMES[*rs2 as usize],
-                    REG_NAMES[*rs3 as usize]
+                    shift
                 )
             }
-            InstructionDecoded::FaddS { rd, rs1, rs2 } => {
+            InstructionDecoded::ThSdib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "fadd.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.sdib {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::FsubS { rd, rs1, rs2 } => {
+            InstructionDecoded::ThSdia {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => {
                 write!(
                     f,
-                    "fsub.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "th.sdia {}, ({}), {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *imm as i32, shift
                 )
             }
-            InstructionDecoded::FmulS { rd, rs1, rs2 } => {
-                write!(
-                    f,
-                    "fmul.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
-                )
+            InstructionDecoded::Custom {
+                name, rd, rs1, rs2, ..
+            } => {
+                write!(f, "{}", name)?;
+                let regs = [rd, rs1, rs2].into_iter().flatten();
+                for (i, reg) in regs.enumerate() {
+                    let sep = if i == 0 { " " } else { ", " };
+                    write!(f, "{}{}", sep, REG_NAMES[*reg as usize])?;
+                }
+                Ok(())
             }
-            InstructionDecoded::FdivS { rd, rs1, rs2 } => {
-                write!(
-                    f,
-                    "fdiv.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
-                )
+            InstructionDecoded::Sspush { rs2 } => {
+                write!(f, "sspush {}", REG_NAMES[*rs2 as usize])
             }
-            InstructionDecoded::FsqrtS { rd, rs1 } => {
-                write!(
-                    f,
-                    "fsqrt.s {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
-                )
+            InstructionDecoded::Sspopchk { rs1 } => {
+                write!(f, "sspopchk {}", REG_NAMES[*rs1 as usize])
             }
-            InstructionDecoded::FsgnjS { rd, rs1, rs2 } => {
+            InstructionDecoded::Ssrdp { rd } => {
+                write!(f, "ssrdp {}", REG_NAMES[*rd as usize])
+            }
+            InstructionDecoded::SsamoswapW {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
                 write!(
                     f,
-                    "fsgnj.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "ssamoswap.w{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FsgnjnS { rd, rs1, rs2 } => {
+            InstructionDecoded::SsamoswapD {
+                rd,
+                rs1,
+                rs2,
+                rl,
+                aq,
+            } => {
                 write!(
                     f,
-                    "fsgnjn.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "ssamoswap.d{} {}, {}, ({})",
+                    aqrl_suffix(*aq, *rl),
+                    REG_NAMES[*rd as usize],
+                    REG_NAMES[*rs2 as usize],
+                    REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FsgnjxS { rd, rs1, rs2 } => {
+            InstructionDecoded::Lpad { label } => {
+                write!(f, "lpad {}", label)
+            }
+            InstructionDecoded::CNop => {
+                write!(f, "c.nop")
+            }
+            InstructionDecoded::CAddi4Spn { rd, nzuimm } => {
                 write!(
                     f,
-                    "fsgnjx.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.addi4spn {}, {}",
+                    REG_NAMES[*rd as usize], *nzuimm as i32
                 )
             }
-            InstructionDecoded::FminS { rd, rs1, rs2 } => {
+            InstructionDecoded::CSlli { rd, rs1, shamt } => {
                 write!(
                     f,
-                    "fmin.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.slli {}, {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *shamt as i32
                 )
             }
-            InstructionDecoded::FmaxS { rd, rs1, rs2 } => {
+            InstructionDecoded::CmJt { index } => write!(f, "cm.jt {}", *index),
+            InstructionDecoded::CmJalt { index } => write!(f, "cm.jalt {}", *index),
+            InstructionDecoded::CmPush { rlist, stack_adj } => {
+                write!(f, "cm.push {}, -{}", rlist_operand(*rlist), *stack_adj)
+            }
+            InstructionDecoded::CmPop { rlist, stack_adj } => {
+                write!(f, "cm.pop {}, {}", rlist_operand(*rlist), *stack_adj)
+            }
+            InstructionDecoded::CmPopretz { rlist, stack_adj } => {
+                write!(f, "cm.popretz {}, {}", rlist_operand(*rlist), *stack_adj)
+            }
+            InstructionDecoded::CmPopret { rlist, stack_adj } => {
+                write!(f, "cm.popret {}, {}", rlist_operand(*rlist), *stack_adj)
+            }
+            InstructionDecoded::CmMvsa01 { r1s, r2s } => {
                 write!(
                     f,
-                    "fmax.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "cm.mvsa01 {}, {}",
+                    REG_NAMES[*r1s as usize], REG_NAMES[*r2s as usize]
                 )
             }
-            InstructionDecoded::FcvtSW { rd, rs1 } => {
+            InstructionDecoded::CmMva01s { r1s, r2s } => {
                 write!(
                     f,
-                    "fcvt.s.w {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                    "cm.mva01s {}, {}",
+                    REG_NAMES[*r1s as usize], REG_NAMES[*r2s as usize]
                 )
             }
-            InstructionDecoded::FcvtSWU { rd, rs1 } => {
+            InstructionDecoded::CLbu { rd, rs1, imm } => {
                 write!(
                     f,
-                    "fcvt.s.wu {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                    "c.lbu {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FcvtWS { rd, rs1 } => {
+            InstructionDecoded::CLhu { rd, rs1, imm } => {
                 write!(
                     f,
-                    "fcvt.w.s {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                    "c.lhu {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FcvtWUS { rd, rs1 } => {
+            InstructionDecoded::CLh { rd, rs1, imm } => {
                 write!(
                     f,
-                    "fcvt.wu.s {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                    "c.lh {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FmvXW { rd, rs1 } => {
+            InstructionDecoded::CSb { rs1, rs2, imm } => {
                 write!(
                     f,
-                    "fmv.x.w {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                    "c.sb {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FmvWX { rd, rs1 } => {
+            InstructionDecoded::CSh { rs1, rs2, imm } => {
                 write!(
                     f,
-                    "fmv.w.x {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                    "c.sh {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FeqS { rd, rs1, rs2 } => {
+            InstructionDecoded::CZextB { rd } => write!(f, "c.zext.b {}", REG_NAMES[*rd as usize]),
+            InstructionDecoded::CSextB { rd } => write!(f, "c.sext.b {}", REG_NAMES[*rd as usize]),
+            InstructionDecoded::CZextH { rd } => write!(f, "c.zext.h {}", REG_NAMES[*rd as usize]),
+            InstructionDecoded::CSextH { rd } => write!(f, "c.sext.h {}", REG_NAMES[*rd as usize]),
+            InstructionDecoded::CZextW { rd } => write!(f, "c.zext.w {}", REG_NAMES[*rd as usize]),
+            InstructionDecoded::CNot { rd } => write!(f, "c.not {}", REG_NAMES[*rd as usize]),
+            InstructionDecoded::CMul { rd, rs2 } => {
                 write!(
                     f,
-                    "feq.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.mul {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::FltS { rd, rs1, rs2 } => {
+            InstructionDecoded::CAddiw { rd, imm } => {
+                write!(f, "c.addiw {}, {}", REG_NAMES[*rd as usize], *imm as i32)
+            }
+            InstructionDecoded::CLd { rd, rs1, imm } => {
                 write!(
                     f,
-                    "flt.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.ld {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FleS { rd, rs1, rs2 } => {
+            InstructionDecoded::CSd { rs1, rs2, imm } => {
                 write!(
                     f,
-                    "fle.s {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.sd {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::FClassS { rd, rs1 } => {
+            InstructionDecoded::CLdsp { rd, imm } => {
+                write!(f, "c.ldsp {}, {}(sp)", REG_NAMES[*rd as usize], *imm as i32)
+            }
+            InstructionDecoded::CSdsp { rs2, imm } => {
                 write!(
                     f,
-                    "fclass.s {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize]
+                    "c.sdsp {}, {}(sp)",
+                    REG_NAMES[*rs2 as usize], *imm as i32
                 )
             }
-            InstructionDecoded::Mul { rd, rs1, rs2 } => {
+            InstructionDecoded::CSubw { rd, rs2 } => {
                 write!(
                     f,
-                    "mul {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.subw {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Mulh { rd, rs1, rs2 } => {
+            InstructionDecoded::CAddw { rd, rs2 } => {
                 write!(
                     f,
-                    "mulh {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.addw {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Mulsu { rd, rs1, rs2 } => {
+            InstructionDecoded::CJr { rs1 } => write!(f, "c.jr {}", REG_NAMES[*rs1 as usize]),
+            InstructionDecoded::CMv { rd, rs2 } => {
                 write!(
                     f,
-                    "mulsu {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.mv {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Mulu { rd, rs1, rs2 } => {
+            InstructionDecoded::CEbreak => write!(f, "c.ebreak"),
+            InstructionDecoded::CJalr { rs1 } => write!(f, "c.jalr {}", REG_NAMES[*rs1 as usize]),
+            InstructionDecoded::CAdd { rd, rs2 } => {
                 write!(
                     f,
-                    "mulu {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.add {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::Div { rd, rs1, rs2 } => {
+            InstructionDecoded::CLwsp { rd, imm } => {
+                write!(f, "c.lwsp {}, {}(sp)", REG_NAMES[*rd as usize], *imm as i32)
+            }
+            InstructionDecoded::CSwsp { rs2, imm } => {
                 write!(
                     f,
-                    "div {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.swsp {}, {}(sp)",
+                    REG_NAMES[*rs2 as usize], *imm as i32
                 )
             }
-            InstructionDecoded::Divu { rd, rs1, rs2 } => {
+            InstructionDecoded::CFlwsp { rd, imm } => {
                 write!(
                     f,
-                    "divu {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.flwsp {}, {}(sp)",
+                    REG_NAMES[*rd as usize], *imm as i32
                 )
             }
-            InstructionDecoded::Rem { rd, rs1, rs2 } => {
+            InstructionDecoded::CFswsp { rs2, imm } => {
                 write!(
                     f,
-                    "rem {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.fswsp {}, {}(sp)",
+                    REG_NAMES[*rs2 as usize], *imm as i32
                 )
             }
-            InstructionDecoded::Remu { rd, rs1, rs2 } => {
+            InstructionDecoded::CFldsp { rd, imm } => {
                 write!(
                     f,
-                    "remu {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], REG_NAMES[*rs2 as usize]
+                    "c.fldsp {}, {}(sp)",
+                    REG_NAMES[*rd as usize], *imm as i32
                 )
             }
-            InstructionDecoded::LrW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CFsdsp { rs2, imm } => {
                 write!(
                     f,
-                    "lr.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.fsdsp {}, {}(sp)",
+                    REG_NAMES[*rs2 as usize], *imm as i32
                 )
             }
-            InstructionDecoded::ScW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CJ { imm } => write!(f, "c.j {}", *imm as i32),
+            InstructionDecoded::CBeqz { rs1, imm } => {
+                write!(f, "c.beqz {}, {}", REG_NAMES[*rs1 as usize], *imm as i32)
+            }
+            InstructionDecoded::CBnez { rs1, imm } => {
+                write!(f, "c.bnez {}, {}", REG_NAMES[*rs1 as usize], *imm as i32)
+            }
+            InstructionDecoded::CSrli { rd, shamt } => {
+                write!(f, "c.srli {}, {}", REG_NAMES[*rd as usize], *shamt as i32)
+            }
+            InstructionDecoded::CSrai { rd, shamt } => {
+                write!(f, "c.srai {}, {}", REG_NAMES[*rd as usize], *shamt as i32)
+            }
+            InstructionDecoded::CAndi { rd, imm } => {
+                write!(f, "c.andi {}, {}", REG_NAMES[*rd as usize], *imm as i32)
+            }
+            InstructionDecoded::CSub { rd, rs2 } => {
                 write!(
                     f,
-                    "sc.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.sub {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::AmoswapW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CXor { rd, rs2 } => {
                 write!(
                     f,
-                    "amoswap.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.xor {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::AmoaddW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::COr { rd, rs2 } => {
                 write!(
                     f,
-                    "amoadd.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.or {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::AmoandW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CAnd { rd, rs2 } => {
                 write!(
                     f,
-                    "amoand.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.and {}, {}",
+                    REG_NAMES[*rd as usize], REG_NAMES[*rs2 as usize]
                 )
             }
-            InstructionDecoded::AmoorW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CAddi { rd, imm } => {
+                write!(f, "c.addi {}, {}", REG_NAMES[*rd as usize], *imm as i32)
+            }
+            InstructionDecoded::CJal { imm } => write!(f, "c.jal {}", *imm as i32),
+            InstructionDecoded::CLi { rd, imm } => {
+                write!(f, "c.li {}, {}", REG_NAMES[*rd as usize], *imm as i32)
+            }
+            InstructionDecoded::CAddi16Sp { imm } => write!(f, "c.addi16sp {}", *imm as i32),
+            InstructionDecoded::CLui { rd, imm } => {
+                write!(f, "c.lui {}, {:#X}", REG_NAMES[*rd as usize], *imm)
+            }
+            InstructionDecoded::CLw { rd, rs1, imm } => {
                 write!(
                     f,
-                    "amoor.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.lw {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::AmoxorW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CFlw { rd, rs1, imm } => {
                 write!(
                     f,
-                    "amoxor.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.flw {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::AmomaxW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CFld { rd, rs1, imm } => {
                 write!(
                     f,
-                    "amomax.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.fld {}, {}({})",
+                    REG_NAMES[*rd as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::AmominW {
-                rd,
-                rs1,
-                rs2,
-                rl,
-                aq,
-            } => {
+            InstructionDecoded::CSw { rs1, rs2, imm } => {
                 write!(
                     f,
-                    "amomin.w {}, {}, {}, {}, {}",
-                    REG_NAMES[*rd as usize],
-                    REG_NAMES[*rs1 as usize],
-                    REG_NAMES[*rs2 as usize],
-                    *rl as i32,
-                    *aq as i32
+                    "c.sw {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::CNop => {
-                write!(f, "c.nop")
-            }
-            InstructionDecoded::CAddi4Spn { rd, nzuimm } => {
+            InstructionDecoded::CFsw { rs1, rs2, imm } => {
                 write!(
                     f,
-                    "c.addi4spn {}, {}",
-                    REG_NAMES[*rd as usize], *nzuimm as i32
+                    "c.fsw {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
-            InstructionDecoded::CSlli { rd, rs1, shamt } => {
+            InstructionDecoded::CFsd { rs1, rs2, imm } => {
                 write!(
                     f,
-                    "c.slli {}, {}, {}",
-                    REG_NAMES[*rd as usize], REG_NAMES[*rs1 as usize], *shamt as i32
+                    "c.fsd {}, {}({})",
+                    REG_NAMES[*rs2 as usize], *imm as i32, REG_NAMES[*rs1 as usize]
                 )
             }
         }
     }
 }
+
+#[test]
+fn categories() {
+    use crate::decoder::try_decode;
+    let category = |inst| try_decode(inst).unwrap().category();
+    assert_eq!(
+        category(0x06468613 /* addi x12 x13 100 */),
+        Category::Arithmetic
+    );
+    assert_eq!(category(0x0004a783 /* lw x15 0(x9) */), Category::Load);
+    assert_eq!(category(0x00f4a023 /* sw x15 0(x9) */), Category::Store);
+    assert_eq!(
+        category(0x0CF4A7AF /* amoswap.w x15 x15 (x9) */),
+        Category::Atomic
+    );
+    assert_eq!(category(0x00c58463 /* beq x11 x12 8 */), Category::Branch);
+    assert_eq!(category(0x0100006f /* jal x0 16 */), Category::Jump);
+    assert_eq!(category(0x0ff0000f /* fence iorw, iorw */), Category::Fence);
+    assert_eq!(
+        category(0xc0002573 /* csrrs x10 cycle x0 */),
+        Category::System
+    );
+    assert_eq!(category(0x00000073 /* ecall */), Category::System);
+    assert_eq!(
+        category(0x00c5f553 /* fadd.s f10 f11 f12 */),
+        Category::FloatingPoint
+    );
+    assert_eq!(
+        category(0x8221a0f7 /* vsm3me.vv v1 v2 v3 */),
+        Category::Vector
+    );
+}
+
+#[test]
+fn mnemonics() {
+    use crate::decoder::try_decode;
+    let mnemonic = |inst| try_decode(inst).unwrap().mnemonic();
+    assert_eq!(mnemonic(0x06468613 /* addi x12 x13 100 */), "addi");
+    assert_eq!(
+        mnemonic(0x0CF4A7AF /* amoswap.w.aq x15 x15 (x9) */),
+        "amoswap.w.aq"
+    );
+}
+
+#[test]
+fn atomics() {
+    use crate::decoder::try_decode;
+    assert_eq!(
+        try_decode(0x0CF4A7AF /* amoswap.w.aq x15 x15 (x9) */)
+            .unwrap()
+            .to_string(),
+        "amoswap.w.aq a5, a5, (s1)"
+    );
+    let amoadd = InstructionDecoded::AmoaddW {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+        rl: true,
+        aq: true,
+    };
+    assert_eq!(amoadd.to_string(), "amoadd.w.aqrl a0, a2, (a1)");
+    let amominu = InstructionDecoded::AmominuW {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+        rl: false,
+        aq: true,
+    };
+    assert_eq!(amominu.to_string(), "amominu.w.aq a0, a2, (a1)");
+    let lr = InstructionDecoded::LrW {
+        rd: 10,
+        rs1: 11,
+        rs2: 0,
+        rl: false,
+        aq: false,
+    };
+    assert_eq!(lr.to_string(), "lr.w a0, (a1)");
+}
@@ -1,6 +1,15 @@
 use crate::bit_ops::*;
-use crate::{decoded_inst::InstructionDecoded, error::DecodeError, instructions::*};
+use crate::{
+    decoded_inst::InstructionDecoded,
+    error::DecodeError,
+    exception::Exception,
+    instructions::*,
+    opcode::{Extension, InstructionInfo, OpcodeId},
+    rounding::RoundingMode,
+    warning::{warnings, DecodeOutcome},
+};
 use anyhow::{Context, Result};
+use std::sync::OnceLock;
 use paste::paste;
 
 const OPCODE_MASK: InstructionSize = crate::bit_ops::create_mask(7);
@@ -12,6 +21,19 @@ pub fn decode_rtype(inst: InstructionSize) -> Result<InstructionDecoded> {
     match inst.opcode() {
         ARITMETIC_REGISTER_MATCH => {
             match (inst.funct3(), inst.funct7()) {
+                // the non-temporal locality hints are adds into x0 that pick their level with rs2
+                (ntl_p1::FUNCT3, ntl_p1::FUNCT7) if inst.rd() == 0 && inst.rs1() == 0 && inst.rs2() == ntl_p1::RS2 => {
+                    Ok(InstructionDecoded::NtlP1)
+                }
+                (ntl_pall::FUNCT3, ntl_pall::FUNCT7) if inst.rd() == 0 && inst.rs1() == 0 && inst.rs2() == ntl_pall::RS2 => {
+                    Ok(InstructionDecoded::NtlPall)
+                }
+                (ntl_s1::FUNCT3, ntl_s1::FUNCT7) if inst.rd() == 0 && inst.rs1() == 0 && inst.rs2() == ntl_s1::RS2 => {
+                    Ok(InstructionDecoded::NtlS1)
+                }
+                (ntl_all::FUNCT3, ntl_all::FUNCT7) if inst.rd() == 0 && inst.rs1() == 0 && inst.rs2() == ntl_all::RS2 => {
+                    Ok(InstructionDecoded::NtlAll)
+                }
                 (add::FUNCT3, add::FUNCT7) => Ok(InstructionDecoded::Add {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
@@ -77,366 +99,3923 @@ pub fn decode_rtype(inst: InstructionSize) -> Result<InstructionDecoded> {
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
+                (sh1add::FUNCT3, sh1add::FUNCT7) => Ok(InstructionDecoded::Sh1add {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sh2add::FUNCT3, sh2add::FUNCT7) => Ok(InstructionDecoded::Sh2add {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sh3add::FUNCT3, sh3add::FUNCT7) => Ok(InstructionDecoded::Sh3add {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (andn::FUNCT3, andn::FUNCT7) => Ok(InstructionDecoded::Andn {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (orn::FUNCT3, orn::FUNCT7) => Ok(InstructionDecoded::Orn {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (xnor::FUNCT3, xnor::FUNCT7) => Ok(InstructionDecoded::Xnor {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (min::FUNCT3, min::FUNCT7) => Ok(InstructionDecoded::Min {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (minu::FUNCT3, minu::FUNCT7) => Ok(InstructionDecoded::Minu {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (max::FUNCT3, max::FUNCT7) => Ok(InstructionDecoded::Max {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (maxu::FUNCT3, maxu::FUNCT7) => Ok(InstructionDecoded::Maxu {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (rol::FUNCT3, rol::FUNCT7) => Ok(InstructionDecoded::Rol {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (ror::FUNCT3, ror::FUNCT7) => Ok(InstructionDecoded::Ror {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (zext_h::FUNCT3, zext_h::FUNCT7) if inst.rs2() == 0 => Ok(InstructionDecoded::ZextH {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                }),
+                (clmul::FUNCT3, clmul::FUNCT7) => Ok(InstructionDecoded::Clmul {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (clmulh::FUNCT3, clmulh::FUNCT7) => Ok(InstructionDecoded::Clmulh {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (clmulr::FUNCT3, clmulr::FUNCT7) => Ok(InstructionDecoded::Clmulr {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (bclr::FUNCT3, bclr::FUNCT7) => Ok(InstructionDecoded::Bclr {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (bext::FUNCT3, bext::FUNCT7) => Ok(InstructionDecoded::Bext {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (binv::FUNCT3, binv::FUNCT7) => Ok(InstructionDecoded::Binv {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (bset::FUNCT3, bset::FUNCT7) => Ok(InstructionDecoded::Bset {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (pack::FUNCT3, pack::FUNCT7) => Ok(InstructionDecoded::Pack {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (packh::FUNCT3, packh::FUNCT7) => Ok(InstructionDecoded::Packh {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (xperm4::FUNCT3, xperm4::FUNCT7) => Ok(InstructionDecoded::Xperm4 {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (xperm8::FUNCT3, xperm8::FUNCT7) => Ok(InstructionDecoded::Xperm8 {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (aes32esi::FUNCT3, funct7) if funct7 & 0x1f == aes32esi::FUNCT5 => Ok(InstructionDecoded::Aes32esi {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    bs: funct7 >> 5,
+                }),
+                (aes32esmi::FUNCT3, funct7) if funct7 & 0x1f == aes32esmi::FUNCT5 => Ok(InstructionDecoded::Aes32esmi {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    bs: funct7 >> 5,
+                }),
+                (aes32dsi::FUNCT3, funct7) if funct7 & 0x1f == aes32dsi::FUNCT5 => Ok(InstructionDecoded::Aes32dsi {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    bs: funct7 >> 5,
+                }),
+                (aes32dsmi::FUNCT3, funct7) if funct7 & 0x1f == aes32dsmi::FUNCT5 => Ok(InstructionDecoded::Aes32dsmi {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    bs: funct7 >> 5,
+                }),
+                (aes64es::FUNCT3, aes64es::FUNCT7) => Ok(InstructionDecoded::Aes64es {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (aes64esm::FUNCT3, aes64esm::FUNCT7) => Ok(InstructionDecoded::Aes64esm {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (aes64ds::FUNCT3, aes64ds::FUNCT7) => Ok(InstructionDecoded::Aes64ds {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (aes64dsm::FUNCT3, aes64dsm::FUNCT7) => Ok(InstructionDecoded::Aes64dsm {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (aes64ks2::FUNCT3, aes64ks2::FUNCT7) => Ok(InstructionDecoded::Aes64ks2 {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sha512sum0r::FUNCT3, sha512sum0r::FUNCT7) => Ok(InstructionDecoded::Sha512sum0r {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sha512sum1r::FUNCT3, sha512sum1r::FUNCT7) => Ok(InstructionDecoded::Sha512sum1r {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sha512sig0l::FUNCT3, sha512sig0l::FUNCT7) => Ok(InstructionDecoded::Sha512sig0l {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sha512sig1l::FUNCT3, sha512sig1l::FUNCT7) => Ok(InstructionDecoded::Sha512sig1l {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sha512sig0h::FUNCT3, sha512sig0h::FUNCT7) => Ok(InstructionDecoded::Sha512sig0h {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sha512sig1h::FUNCT3, sha512sig1h::FUNCT7) => Ok(InstructionDecoded::Sha512sig1h {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (sm4ed::FUNCT3, funct7) if funct7 & 0x1f == sm4ed::FUNCT5 => Ok(InstructionDecoded::Sm4ed {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    bs: funct7 >> 5,
+                }),
+                (sm4ks::FUNCT3, funct7) if funct7 & 0x1f == sm4ks::FUNCT5 => Ok(InstructionDecoded::Sm4ks {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    bs: funct7 >> 5,
+                }),
                 _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Arithmetic Register instruction (R-type)"),
             }
         }
-        ATOMIC_MATCH => {
-            let funct5 = get_bits(inst.funct7(), 5, 2);
-            let rl = is_set(inst.funct7(), 0);
-            let aq = is_set(inst.funct7(), 1);
-            match (inst.funct3(), funct5) {
-                (amoswap_w::FUNCT3, amoswap_w::FUNCT5) => Ok(InstructionDecoded::AmoswapW {
+        ARITMETIC_REGISTER_WORD_MATCH => {
+            match (inst.funct3(), inst.funct7()) {
+                (addw::FUNCT3, addw::FUNCT7) => Ok(InstructionDecoded::Addw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
-                    rl, aq,
                 }),
-                _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Atomic instruction"),
-            }
-        }
-        FLOATING_POINT_MATCH => {
-            let funct5 = get_bits(inst.funct7(), 5, 2);
-            let fmt = get_bits(inst.funct7(), 2, 0);
-            assert!(fmt == 0, "the fmt of an inst cannot be non 0 because we only support single precision floating point instructions currently!");
-            match (inst.funct3(), funct5) {
-                (fadd_s::FUNCT3, fadd_s::FUNCT5) => Ok(InstructionDecoded::FaddS {
+                (subw::FUNCT3, subw::FUNCT7) => Ok(InstructionDecoded::Subw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fsub_s::FUNCT3, fsub_s::FUNCT5) => Ok(InstructionDecoded::FsubS {
+                (sllw::FUNCT3, sllw::FUNCT7) => Ok(InstructionDecoded::Sllw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fmul_s::FUNCT3, fmul_s::FUNCT5) => Ok(InstructionDecoded::FmulS {
+                (srlw::FUNCT3, srlw::FUNCT7) => Ok(InstructionDecoded::Srlw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fdiv_s::FUNCT3, fdiv_s::FUNCT5) => Ok(InstructionDecoded::FdivS {
+                (sraw::FUNCT3, sraw::FUNCT7) => Ok(InstructionDecoded::Sraw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fsgnj_s::FUNCT3, fsgnj_s::FUNCT5) => Ok(InstructionDecoded::FsgnjS {
+                (mulw::FUNCT3, mulw::FUNCT7) => Ok(InstructionDecoded::Mulw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fsgnjn_s::FUNCT3, fsgnjn_s::FUNCT5) => Ok(InstructionDecoded::FsgnjnS {
+                (divw::FUNCT3, divw::FUNCT7) => Ok(InstructionDecoded::Divw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fsgnjx_s::FUNCT3, fsgnjx_s::FUNCT5) => Ok(InstructionDecoded::FsgnjxS {
+                (divuw::FUNCT3, divuw::FUNCT7) => Ok(InstructionDecoded::Divuw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fmin_s::FUNCT3, fmin_s::FUNCT5) => Ok(InstructionDecoded::FminS {
+                (remw::FUNCT3, remw::FUNCT7) => Ok(InstructionDecoded::Remw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fmax_s::FUNCT3, fmax_s::FUNCT5) => Ok(InstructionDecoded::FmaxS {
+                (remuw::FUNCT3, remuw::FUNCT7) => Ok(InstructionDecoded::Remuw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fcvt_w_s::FUNCT3, fcvt_w_s::FUNCT5) => match inst.rs2() {
-                    fcvt_w_s::RS2 => Ok(InstructionDecoded::FcvtWUS {
-                        rd: inst.rd(),
-                        rs1: inst.rs1(),
-                    }),
-                    fcvt_wu_s::RS2 => Ok(InstructionDecoded::FcvtWS {
-                        rd: inst.rd(),
-                        rs1: inst.rs1(),
-                    }),
-                    _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Floating Point instruction"),
-                }
-                (feq_s::FUNCT3, feq_s::FUNCT5) => Ok(InstructionDecoded::FeqS {
+                (add_uw::FUNCT3, add_uw::FUNCT7) => Ok(InstructionDecoded::AddUw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (flt_s::FUNCT3, flt_s::FUNCT5) => Ok(InstructionDecoded::FltS {
+                (sh1add_uw::FUNCT3, sh1add_uw::FUNCT7) => Ok(InstructionDecoded::Sh1addUw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fle_s::FUNCT3, fle_s::FUNCT5) => Ok(InstructionDecoded::FleS {
+                (sh2add_uw::FUNCT3, sh2add_uw::FUNCT7) => Ok(InstructionDecoded::Sh2addUw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                     rs2: inst.rs2(),
                 }),
-                (fclass_s::FUNCT3, fclass_s::FUNCT5) => Ok(InstructionDecoded::FClassS {
+                (sh3add_uw::FUNCT3, sh3add_uw::FUNCT7) => Ok(InstructionDecoded::Sh3addUw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
+                    rs2: inst.rs2(),
                 }),
-                (fcvt_s_w::FUNCT3, fcvt_s_w::FUNCT5) => Ok(InstructionDecoded::FcvtSW {
+                (zext_h::FUNCT3, zext_h::FUNCT7) if inst.rs2() == 0 => Ok(InstructionDecoded::ZextH {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
                 }),
-                (fcvt_s_wu::FUNCT3, fcvt_s_wu::FUNCT5) => Ok(InstructionDecoded::FcvtSWU {
+                (packw::FUNCT3, packw::FUNCT7) => Ok(InstructionDecoded::Packw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
+                    rs2: inst.rs2(),
                 }),
-                (fmv_x_w::FUNCT3, fmv_x_w::FUNCT5) => Ok(InstructionDecoded::FmvXW {
+                (rolw::FUNCT3, rolw::FUNCT7) => Ok(InstructionDecoded::Rolw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
+                    rs2: inst.rs2(),
                 }),
-                (fmv_w_x::FUNCT3, fmv_w_x::FUNCT5) => Ok(InstructionDecoded::FmvWX {
+                (rorw::FUNCT3, rorw::FUNCT7) => Ok(InstructionDecoded::Rorw {
                     rd: inst.rd(),
                     rs1: inst.rs1(),
+                    rs2: inst.rs2(),
                 }),
-                _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Floating Point instruction"),
+                _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Arithmetic Register Word instruction (R-type)"),
             }
         }
-
-        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown R-Type instruction"),
-    }
-}
-
-pub fn decode_itype(inst: InstructionSize) -> Result<InstructionDecoded> {
-    let iinst = itype::IType::new(inst);
-    match (iinst.opcode(), iinst.funct3(), iinst.imm()) {
-        (ARITMETIC_IMMEDIATE_MATCH, addi::FUNCT3, _) => Ok(InstructionDecoded::Addi {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
-        }),
-        (ARITMETIC_IMMEDIATE_MATCH, slti::FUNCT3, _) => Ok(InstructionDecoded::Slti {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
-        }),
-        (ARITMETIC_IMMEDIATE_MATCH, sltiu::FUNCT3, _) => Ok(InstructionDecoded::Sltiu {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
-        }),
-        (ARITMETIC_IMMEDIATE_MATCH, xori::FUNCT3, _) => Ok(InstructionDecoded::Xori {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
-        }),
-        (ARITMETIC_IMMEDIATE_MATCH, ori::FUNCT3, _) => Ok(InstructionDecoded::Ori {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
-        }),
-        (ARITMETIC_IMMEDIATE_MATCH, andi::FUNCT3, _) => Ok(InstructionDecoded::Andi {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
-        }),
-        imm @ (ARITMETIC_IMMEDIATE_MATCH, slli::FUNCT3, _) if (imm.2 >> 5) == slli::IMM => {
-            Ok(InstructionDecoded::Slli {
-                rd: iinst.rd(),
-                rs1: iinst.rs1(),
-                imm: get_bits(imm.2, 5, 0),
-            })
-        }
-        imm @ (ARITMETIC_IMMEDIATE_MATCH, srli::FUNCT3, _) if (imm.2 >> 5) == srli::IMM => {
-            Ok(InstructionDecoded::Srli {
-                rd: iinst.rd(),
-                rs1: iinst.rs1(),
-                imm: get_bits(imm.2, 5, 0),
-            })
-        }
-        imm @ (ARITMETIC_IMMEDIATE_MATCH, srai::FUNCT3, _) if (imm.2 >> 5) == srai::IMM => {
-            Ok(InstructionDecoded::Srai {
-                rd: iinst.rd(),
+        ATOMIC_MATCH => {
+            let funct5 = get_bits(inst.funct7(), 5, 2);
+            let rl = is_set(inst.funct7(), 0);
+            let aq = is_set(inst.funct7(), 1);
+            match (inst.funct3(), funct5) {
+                (ssamoswap_w::FUNCT3, ssamoswap_w::FUNCT5) => Ok(InstructionDecoded::SsamoswapW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoswap_w::FUNCT3, amoswap_w::FUNCT5) => Ok(InstructionDecoded::AmoswapW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoadd_w::FUNCT3, amoadd_w::FUNCT5) => Ok(InstructionDecoded::AmoaddW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoand_w::FUNCT3, amoand_w::FUNCT5) => Ok(InstructionDecoded::AmoandW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoor_w::FUNCT3, amoor_w::FUNCT5) => Ok(InstructionDecoded::AmoorW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoxor_w::FUNCT3, amoxor_w::FUNCT5) => Ok(InstructionDecoded::AmoxorW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amomax_w::FUNCT3, amomax_w::FUNCT5) => Ok(InstructionDecoded::AmomaxW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amomin_w::FUNCT3, amomin_w::FUNCT5) => Ok(InstructionDecoded::AmominW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amominu_w::FUNCT3, amominu_w::FUNCT5) => Ok(InstructionDecoded::AmominuW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amomaxu_w::FUNCT3, amomaxu_w::FUNCT5) => Ok(InstructionDecoded::AmomaxuW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (lr_w::FUNCT3, lr_w::FUNCT5) if inst.rs2() == 0 => Ok(InstructionDecoded::LrW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (sc_w::FUNCT3, sc_w::FUNCT5) => Ok(InstructionDecoded::ScW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (lr_d::FUNCT3, lr_d::FUNCT5) if inst.rs2() == 0 => Ok(InstructionDecoded::LrD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (sc_d::FUNCT3, sc_d::FUNCT5) => Ok(InstructionDecoded::ScD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (ssamoswap_d::FUNCT3, ssamoswap_d::FUNCT5) => Ok(InstructionDecoded::SsamoswapD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoswap_d::FUNCT3, amoswap_d::FUNCT5) => Ok(InstructionDecoded::AmoswapD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoadd_d::FUNCT3, amoadd_d::FUNCT5) => Ok(InstructionDecoded::AmoaddD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoxor_d::FUNCT3, amoxor_d::FUNCT5) => Ok(InstructionDecoded::AmoxorD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoand_d::FUNCT3, amoand_d::FUNCT5) => Ok(InstructionDecoded::AmoandD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amoor_d::FUNCT3, amoor_d::FUNCT5) => Ok(InstructionDecoded::AmoorD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amomin_d::FUNCT3, amomin_d::FUNCT5) => Ok(InstructionDecoded::AmominD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amomax_d::FUNCT3, amomax_d::FUNCT5) => Ok(InstructionDecoded::AmomaxD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amominu_d::FUNCT3, amominu_d::FUNCT5) => Ok(InstructionDecoded::AmominuD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                (amomaxu_d::FUNCT3, amomaxu_d::FUNCT5) => Ok(InstructionDecoded::AmomaxuD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rl, aq,
+                }),
+                _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Atomic instruction"),
+            }
+        }
+        FLOATING_POINT_MATCH => {
+            let funct5 = get_bits(inst.funct7(), 5, 2);
+            let fmt = get_bits(inst.funct7(), 2, 0);
+            match fmt {
+                SINGLE_PRECISION_FMT => {}
+                DOUBLE_PRECISION_FMT => return decode_double_precision(inst),
+                HALF_PRECISION_FMT => return decode_half_precision(inst),
+                #[cfg(feature = "q")]
+                QUAD_PRECISION_FMT => return decode_quad_precision(inst),
+                _ => return Err(DecodeError::UnknownInstructionFormat)
+                    .context("Unsupported floating point precision"),
+            }
+            let rm = || {
+                RoundingMode::from_bits(inst.funct3())
+                    .ok_or(DecodeError::UnknownInstructionFormat)
+                    .context("Reserved rounding mode")
+            };
+            match (inst.funct3(), funct5) {
+                (_, fadd_s::FUNCT5) => Ok(InstructionDecoded::FaddS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rm: rm()?,
+                }),
+                (_, fsub_s::FUNCT5) => Ok(InstructionDecoded::FsubS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rm: rm()?,
+                }),
+                (_, fmul_s::FUNCT5) => Ok(InstructionDecoded::FmulS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rm: rm()?,
+                }),
+                (_, fdiv_s::FUNCT5) => Ok(InstructionDecoded::FdivS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                    rm: rm()?,
+                }),
+                (fsgnj_s::FUNCT3, fsgnj_s::FUNCT5) => Ok(InstructionDecoded::FsgnjS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (fsgnjn_s::FUNCT3, fsgnjn_s::FUNCT5) => Ok(InstructionDecoded::FsgnjnS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (fsgnjx_s::FUNCT3, fsgnjx_s::FUNCT5) => Ok(InstructionDecoded::FsgnjxS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (fmin_s::FUNCT3, fmin_s::FUNCT5) => Ok(InstructionDecoded::FminS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (fmax_s::FUNCT3, fmax_s::FUNCT5) => Ok(InstructionDecoded::FmaxS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (_, fcvt_w_s::FUNCT5) => match inst.rs2() {
+                    fcvt_w_s::RS2 => Ok(InstructionDecoded::FcvtWS {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    fcvt_wu_s::RS2 => Ok(InstructionDecoded::FcvtWUS {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    fcvt_l_s::RS2 => Ok(InstructionDecoded::FcvtLS {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    fcvt_lu_s::RS2 => Ok(InstructionDecoded::FcvtLUS {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Floating Point instruction"),
+                }
+                (feq_s::FUNCT3, feq_s::FUNCT5) => Ok(InstructionDecoded::FeqS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (flt_s::FUNCT3, flt_s::FUNCT5) => Ok(InstructionDecoded::FltS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (fle_s::FUNCT3, fle_s::FUNCT5) => Ok(InstructionDecoded::FleS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (fclass_s::FUNCT3, fclass_s::FUNCT5) => Ok(InstructionDecoded::FClassS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                }),
+                (_, fcvt_s_w::FUNCT5) => match inst.rs2() {
+                    fcvt_s_w::RS2 => Ok(InstructionDecoded::FcvtSW {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    fcvt_s_wu::RS2 => Ok(InstructionDecoded::FcvtSWU {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    fcvt_s_l::RS2 => Ok(InstructionDecoded::FcvtSL {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    fcvt_s_lu::RS2 => Ok(InstructionDecoded::FcvtSLU {
+                        rd: inst.rd(),
+                        rs1: inst.rs1(),
+                        rm: rm()?,
+                    }),
+                    _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Floating Point instruction"),
+                }
+                (_, fcvt_s_d::FUNCT5) if inst.rs2() == fcvt_s_d::RS2 => Ok(InstructionDecoded::FcvtSD {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rm: rm()?,
+                }),
+                (_, fcvt_s_h::FUNCT5) if inst.rs2() == fcvt_s_h::RS2 => Ok(InstructionDecoded::FcvtSH {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rm: rm()?,
+                }),
+                #[cfg(feature = "q")]
+                (_, fcvt_s_q::FUNCT5) if inst.rs2() == fcvt_s_q::RS2 => Ok(InstructionDecoded::FcvtSQ {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rm: rm()?,
+                }),
+                (fli_s::FUNCT3, fli_s::FUNCT5) if inst.rs2() == fli_s::RS2 => Ok(InstructionDecoded::FliS {
+                    rd: inst.rd(),
+                    imm: inst.rs1(),
+                }),
+                (fminm_s::FUNCT3, fminm_s::FUNCT5) => Ok(InstructionDecoded::FminmS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (fmaxm_s::FUNCT3, fmaxm_s::FUNCT5) => Ok(InstructionDecoded::FmaxmS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rs2: inst.rs2(),
+                }),
+                (_, fround_s::FUNCT5) if inst.rs2() == fround_s::RS2 => Ok(InstructionDecoded::FroundS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rm: rm()?,
+                }),
+                (_, froundnx_s::FUNCT5) if inst.rs2() == froundnx_s::RS2 => Ok(InstructionDecoded::FroundnxS {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                    rm: rm()?,
+                }),
+                (fmv_x_w::FUNCT3, fmv_x_w::FUNCT5) if inst.rs2() == 0 => Ok(InstructionDecoded::FmvXW {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                }),
+                (fmv_w_x::FUNCT3, fmv_w_x::FUNCT5) if inst.rs2() == 0 => Ok(InstructionDecoded::FmvWX {
+                    rd: inst.rd(),
+                    rs1: inst.rs1(),
+                }),
+                _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Floating Point instruction"),
+            }
+        }
+
+        #[cfg(feature = "p-ext")]
+        OP_P_MATCH => decode_packed(inst),
+        #[cfg(feature = "thead")]
+        CUSTOM_0_MATCH => decode_thead(inst),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown R-Type instruction"),
+    }
+}
+
+// OP-FP with fmt = D
+fn decode_double_precision(inst: rtype::RType) -> Result<InstructionDecoded> {
+    let funct5 = get_bits(inst.funct7(), 5, 2);
+    let rm = || {
+        RoundingMode::from_bits(inst.funct3())
+            .ok_or(DecodeError::UnknownInstructionFormat)
+            .context("Reserved rounding mode")
+    };
+    match (inst.funct3(), funct5, inst.rs2()) {
+        (_, fadd_d::FUNCT5, _) => Ok(InstructionDecoded::FaddD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fsub_d::FUNCT5, _) => Ok(InstructionDecoded::FsubD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fmul_d::FUNCT5, _) => Ok(InstructionDecoded::FmulD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fdiv_d::FUNCT5, _) => Ok(InstructionDecoded::FdivD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fsqrt_d::FUNCT5, 0) => Ok(InstructionDecoded::FsqrtD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (fsgnj_d::FUNCT3, fsgnj_d::FUNCT5, _) => Ok(InstructionDecoded::FsgnjD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fsgnjn_d::FUNCT3, fsgnjn_d::FUNCT5, _) => Ok(InstructionDecoded::FsgnjnD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fsgnjx_d::FUNCT3, fsgnjx_d::FUNCT5, _) => Ok(InstructionDecoded::FsgnjxD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fmin_d::FUNCT3, fmin_d::FUNCT5, _) => Ok(InstructionDecoded::FminD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fmax_d::FUNCT3, fmax_d::FUNCT5, _) => Ok(InstructionDecoded::FmaxD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (_, fcvt_d_s::FUNCT5, fcvt_d_s::RS2) => Ok(InstructionDecoded::FcvtDS {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (feq_d::FUNCT3, feq_d::FUNCT5, _) => Ok(InstructionDecoded::FeqD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (flt_d::FUNCT3, flt_d::FUNCT5, _) => Ok(InstructionDecoded::FltD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fle_d::FUNCT3, fle_d::FUNCT5, _) => Ok(InstructionDecoded::FleD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fclass_d::FUNCT3, fclass_d::FUNCT5, 0) => Ok(InstructionDecoded::FClassD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        (_, fcvt_w_d::FUNCT5, fcvt_w_d::RS2) => Ok(InstructionDecoded::FcvtWD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_wu_d::FUNCT5, fcvt_wu_d::RS2) => Ok(InstructionDecoded::FcvtWUD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_d_w::FUNCT5, fcvt_d_w::RS2) => Ok(InstructionDecoded::FcvtDW {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_d_wu::FUNCT5, fcvt_d_wu::RS2) => Ok(InstructionDecoded::FcvtDWU {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_l_d::FUNCT5, fcvt_l_d::RS2) => Ok(InstructionDecoded::FcvtLD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_lu_d::FUNCT5, fcvt_lu_d::RS2) => Ok(InstructionDecoded::FcvtLUD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_d_l::FUNCT5, fcvt_d_l::RS2) => Ok(InstructionDecoded::FcvtDL {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_d_lu::FUNCT5, fcvt_d_lu::RS2) => Ok(InstructionDecoded::FcvtDLU {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (fmv_x_d::FUNCT3, fmv_x_d::FUNCT5, 0) => Ok(InstructionDecoded::FmvXD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        (fmv_d_x::FUNCT3, fmv_d_x::FUNCT5, 0) => Ok(InstructionDecoded::FmvDX {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        (fli_d::FUNCT3, fli_d::FUNCT5, fli_d::RS2) => Ok(InstructionDecoded::FliD {
+            rd: inst.rd(),
+            imm: inst.rs1(),
+        }),
+        (fminm_d::FUNCT3, fminm_d::FUNCT5, _) => Ok(InstructionDecoded::FminmD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fmaxm_d::FUNCT3, fmaxm_d::FUNCT5, _) => Ok(InstructionDecoded::FmaxmD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (_, fround_d::FUNCT5, fround_d::RS2) => Ok(InstructionDecoded::FroundD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, froundnx_d::FUNCT5, froundnx_d::RS2) => Ok(InstructionDecoded::FroundnxD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (fcvtmod_w_d::FUNCT3, fcvtmod_w_d::FUNCT5, fcvtmod_w_d::RS2) => Ok(InstructionDecoded::FcvtmodWD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        (_, fcvt_d_h::FUNCT5, fcvt_d_h::RS2) => Ok(InstructionDecoded::FcvtDH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        #[cfg(feature = "q")]
+        (_, fcvt_d_q::FUNCT5, fcvt_d_q::RS2) => Ok(InstructionDecoded::FcvtDQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Double Precision instruction"),
+    }
+}
+
+// OP-FP with fmt = H, Zfh
+fn decode_half_precision(inst: rtype::RType) -> Result<InstructionDecoded> {
+    let funct5 = get_bits(inst.funct7(), 5, 2);
+    let rm = || {
+        RoundingMode::from_bits(inst.funct3())
+            .ok_or(DecodeError::UnknownInstructionFormat)
+            .context("Reserved rounding mode")
+    };
+    match (inst.funct3(), funct5, inst.rs2()) {
+        (_, fadd_h::FUNCT5, _) => Ok(InstructionDecoded::FaddH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fsub_h::FUNCT5, _) => Ok(InstructionDecoded::FsubH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fmul_h::FUNCT5, _) => Ok(InstructionDecoded::FmulH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fdiv_h::FUNCT5, _) => Ok(InstructionDecoded::FdivH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fsqrt_h::FUNCT5, 0) => Ok(InstructionDecoded::FsqrtH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (fsgnj_h::FUNCT3, fsgnj_h::FUNCT5, _) => Ok(InstructionDecoded::FsgnjH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fsgnjn_h::FUNCT3, fsgnjn_h::FUNCT5, _) => Ok(InstructionDecoded::FsgnjnH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fsgnjx_h::FUNCT3, fsgnjx_h::FUNCT5, _) => Ok(InstructionDecoded::FsgnjxH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fmin_h::FUNCT3, fmin_h::FUNCT5, _) => Ok(InstructionDecoded::FminH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fmax_h::FUNCT3, fmax_h::FUNCT5, _) => Ok(InstructionDecoded::FmaxH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (_, fcvt_h_s::FUNCT5, fcvt_h_s::RS2) => Ok(InstructionDecoded::FcvtHS {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_h_d::FUNCT5, fcvt_h_d::RS2) => Ok(InstructionDecoded::FcvtHD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (feq_h::FUNCT3, feq_h::FUNCT5, _) => Ok(InstructionDecoded::FeqH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (flt_h::FUNCT3, flt_h::FUNCT5, _) => Ok(InstructionDecoded::FltH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fle_h::FUNCT3, fle_h::FUNCT5, _) => Ok(InstructionDecoded::FleH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fclass_h::FUNCT3, fclass_h::FUNCT5, 0) => Ok(InstructionDecoded::FClassH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        (_, fcvt_w_h::FUNCT5, fcvt_w_h::RS2) => Ok(InstructionDecoded::FcvtWH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_wu_h::FUNCT5, fcvt_wu_h::RS2) => Ok(InstructionDecoded::FcvtWUH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_l_h::FUNCT5, fcvt_l_h::RS2) => Ok(InstructionDecoded::FcvtLH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_lu_h::FUNCT5, fcvt_lu_h::RS2) => Ok(InstructionDecoded::FcvtLUH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_h_w::FUNCT5, fcvt_h_w::RS2) => Ok(InstructionDecoded::FcvtHW {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_h_wu::FUNCT5, fcvt_h_wu::RS2) => Ok(InstructionDecoded::FcvtHWU {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_h_l::FUNCT5, fcvt_h_l::RS2) => Ok(InstructionDecoded::FcvtHL {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_h_lu::FUNCT5, fcvt_h_lu::RS2) => Ok(InstructionDecoded::FcvtHLU {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (fmv_x_h::FUNCT3, fmv_x_h::FUNCT5, 0) => Ok(InstructionDecoded::FmvXH {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        (fmv_h_x::FUNCT3, fmv_h_x::FUNCT5, 0) => Ok(InstructionDecoded::FmvHX {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Half Precision instruction"),
+    }
+}
+
+// OP-FP with fmt = Q
+#[cfg(feature = "q")]
+fn decode_quad_precision(inst: rtype::RType) -> Result<InstructionDecoded> {
+    let funct5 = get_bits(inst.funct7(), 5, 2);
+    let rm = || {
+        RoundingMode::from_bits(inst.funct3())
+            .ok_or(DecodeError::UnknownInstructionFormat)
+            .context("Reserved rounding mode")
+    };
+    match (inst.funct3(), funct5, inst.rs2()) {
+        (_, fadd_q::FUNCT5, _) => Ok(InstructionDecoded::FaddQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fsub_q::FUNCT5, _) => Ok(InstructionDecoded::FsubQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fmul_q::FUNCT5, _) => Ok(InstructionDecoded::FmulQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fdiv_q::FUNCT5, _) => Ok(InstructionDecoded::FdivQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            rm: rm()?,
+        }),
+        (_, fsqrt_q::FUNCT5, 0) => Ok(InstructionDecoded::FsqrtQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (fsgnj_q::FUNCT3, fsgnj_q::FUNCT5, _) => Ok(InstructionDecoded::FsgnjQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fsgnjn_q::FUNCT3, fsgnjn_q::FUNCT5, _) => Ok(InstructionDecoded::FsgnjnQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fsgnjx_q::FUNCT3, fsgnjx_q::FUNCT5, _) => Ok(InstructionDecoded::FsgnjxQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fmin_q::FUNCT3, fmin_q::FUNCT5, _) => Ok(InstructionDecoded::FminQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fmax_q::FUNCT3, fmax_q::FUNCT5, _) => Ok(InstructionDecoded::FmaxQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (_, fcvt_q_s::FUNCT5, fcvt_q_s::RS2) => Ok(InstructionDecoded::FcvtQS {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_q_d::FUNCT5, fcvt_q_d::RS2) => Ok(InstructionDecoded::FcvtQD {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (feq_q::FUNCT3, feq_q::FUNCT5, _) => Ok(InstructionDecoded::FeqQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (flt_q::FUNCT3, flt_q::FUNCT5, _) => Ok(InstructionDecoded::FltQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fle_q::FUNCT3, fle_q::FUNCT5, _) => Ok(InstructionDecoded::FleQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (fclass_q::FUNCT3, fclass_q::FUNCT5, 0) => Ok(InstructionDecoded::FClassQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+        }),
+        (_, fcvt_w_q::FUNCT5, fcvt_w_q::RS2) => Ok(InstructionDecoded::FcvtWQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_wu_q::FUNCT5, fcvt_wu_q::RS2) => Ok(InstructionDecoded::FcvtWUQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_l_q::FUNCT5, fcvt_l_q::RS2) => Ok(InstructionDecoded::FcvtLQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_lu_q::FUNCT5, fcvt_lu_q::RS2) => Ok(InstructionDecoded::FcvtLUQ {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_q_w::FUNCT5, fcvt_q_w::RS2) => Ok(InstructionDecoded::FcvtQW {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_q_wu::FUNCT5, fcvt_q_wu::RS2) => Ok(InstructionDecoded::FcvtQWU {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_q_l::FUNCT5, fcvt_q_l::RS2) => Ok(InstructionDecoded::FcvtQL {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        (_, fcvt_q_lu::FUNCT5, fcvt_q_lu::RS2) => Ok(InstructionDecoded::FcvtQLU {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rm: rm()?,
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Quad Precision instruction"),
+    }
+}
+
+// OP-P, only the common packed add/subtract, multiply and pack instructions
+#[cfg(feature = "p-ext")]
+fn decode_packed(inst: rtype::RType) -> Result<InstructionDecoded> {
+    match (inst.funct3(), inst.funct7()) {
+        (add16::FUNCT3, add16::FUNCT7) => Ok(InstructionDecoded::Add16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (add8::FUNCT3, add8::FUNCT7) => Ok(InstructionDecoded::Add8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (sub16::FUNCT3, sub16::FUNCT7) => Ok(InstructionDecoded::Sub16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (sub8::FUNCT3, sub8::FUNCT7) => Ok(InstructionDecoded::Sub8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (radd16::FUNCT3, radd16::FUNCT7) => Ok(InstructionDecoded::Radd16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (radd8::FUNCT3, radd8::FUNCT7) => Ok(InstructionDecoded::Radd8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (rsub16::FUNCT3, rsub16::FUNCT7) => Ok(InstructionDecoded::Rsub16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (rsub8::FUNCT3, rsub8::FUNCT7) => Ok(InstructionDecoded::Rsub8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (kadd16::FUNCT3, kadd16::FUNCT7) => Ok(InstructionDecoded::Kadd16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (kadd8::FUNCT3, kadd8::FUNCT7) => Ok(InstructionDecoded::Kadd8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (ksub16::FUNCT3, ksub16::FUNCT7) => Ok(InstructionDecoded::Ksub16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (ksub8::FUNCT3, ksub8::FUNCT7) => Ok(InstructionDecoded::Ksub8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (ukadd16::FUNCT3, ukadd16::FUNCT7) => Ok(InstructionDecoded::Ukadd16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (ukadd8::FUNCT3, ukadd8::FUNCT7) => Ok(InstructionDecoded::Ukadd8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (uksub16::FUNCT3, uksub16::FUNCT7) => Ok(InstructionDecoded::Uksub16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (uksub8::FUNCT3, uksub8::FUNCT7) => Ok(InstructionDecoded::Uksub8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (smul16::FUNCT3, smul16::FUNCT7) => Ok(InstructionDecoded::Smul16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (smul8::FUNCT3, smul8::FUNCT7) => Ok(InstructionDecoded::Smul8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (umul16::FUNCT3, umul16::FUNCT7) => Ok(InstructionDecoded::Umul16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (umul8::FUNCT3, umul8::FUNCT7) => Ok(InstructionDecoded::Umul8 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (pkbb16::FUNCT3, pkbb16::FUNCT7) => Ok(InstructionDecoded::Pkbb16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (pkbt16::FUNCT3, pkbt16::FUNCT7) => Ok(InstructionDecoded::Pkbt16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (pktb16::FUNCT3, pktb16::FUNCT7) => Ok(InstructionDecoded::Pktb16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        (pktt16::FUNCT3, pktt16::FUNCT7) => Ok(InstructionDecoded::Pktt16 {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Packed SIMD instruction"),
+    }
+}
+
+// custom-0 as T-Head uses it, the cache operations keep their operation in the upper 12 bits and
+// everything else has a 2 bit shift under a 5 bit funct5
+#[cfg(feature = "thead")]
+fn decode_thead(inst: rtype::RType) -> Result<InstructionDecoded> {
+    let funct12 = inst.funct7() << 5 | inst.rs2();
+    let funct5 = inst.funct7() >> 2;
+    let shift = get_bits(inst.funct7(), 2, 0);
+    // the increment of the ia/ib forms is a signed 5 bit value in rs2
+    let imm = (((inst.rs2() << 27) as i32) >> 27) as InstructionSize;
+    match (inst.funct3(), funct12) {
+        (th_addsl::FUNCT3, _) if funct5 == th_addsl::FUNCT5 => Ok(InstructionDecoded::ThAddsl {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_dcache_call::FUNCT3, th_dcache_call::FUNCT12) if inst.rd() == 0 && inst.rs1() == 0 => Ok(InstructionDecoded::ThDcacheCall),
+        (th_dcache_iall::FUNCT3, th_dcache_iall::FUNCT12) if inst.rd() == 0 && inst.rs1() == 0 => Ok(InstructionDecoded::ThDcacheIall),
+        (th_dcache_ciall::FUNCT3, th_dcache_ciall::FUNCT12) if inst.rd() == 0 && inst.rs1() == 0 => Ok(InstructionDecoded::ThDcacheCiall),
+        (th_icache_iall::FUNCT3, th_icache_iall::FUNCT12) if inst.rd() == 0 && inst.rs1() == 0 => Ok(InstructionDecoded::ThIcacheIall),
+        (th_icache_ialls::FUNCT3, th_icache_ialls::FUNCT12) if inst.rd() == 0 && inst.rs1() == 0 => Ok(InstructionDecoded::ThIcacheIalls),
+        (th_dcache_cval1::FUNCT3, th_dcache_cval1::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThDcacheCval1 { rs1: inst.rs1() }),
+        (th_dcache_cva::FUNCT3, th_dcache_cva::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThDcacheCva { rs1: inst.rs1() }),
+        (th_dcache_iva::FUNCT3, th_dcache_iva::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThDcacheIva { rs1: inst.rs1() }),
+        (th_dcache_civa::FUNCT3, th_dcache_civa::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThDcacheCiva { rs1: inst.rs1() }),
+        (th_dcache_cpa::FUNCT3, th_dcache_cpa::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThDcacheCpa { rs1: inst.rs1() }),
+        (th_dcache_ipa::FUNCT3, th_dcache_ipa::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThDcacheIpa { rs1: inst.rs1() }),
+        (th_dcache_cipa::FUNCT3, th_dcache_cipa::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThDcacheCipa { rs1: inst.rs1() }),
+        (th_icache_iva::FUNCT3, th_icache_iva::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThIcacheIva { rs1: inst.rs1() }),
+        (th_icache_ipa::FUNCT3, th_icache_ipa::FUNCT12) if inst.rd() == 0 => Ok(InstructionDecoded::ThIcacheIpa { rs1: inst.rs1() }),
+        (th_lrb::FUNCT3, _) if funct5 == th_lrb::FUNCT5 => Ok(InstructionDecoded::ThLrb {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lurb::FUNCT3, _) if funct5 == th_lurb::FUNCT5 => Ok(InstructionDecoded::ThLurb {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lbib::FUNCT3, _) if funct5 == th_lbib::FUNCT5 => Ok(InstructionDecoded::ThLbib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lbia::FUNCT3, _) if funct5 == th_lbia::FUNCT5 => Ok(InstructionDecoded::ThLbia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lrh::FUNCT3, _) if funct5 == th_lrh::FUNCT5 => Ok(InstructionDecoded::ThLrh {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lurh::FUNCT3, _) if funct5 == th_lurh::FUNCT5 => Ok(InstructionDecoded::ThLurh {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lhib::FUNCT3, _) if funct5 == th_lhib::FUNCT5 => Ok(InstructionDecoded::ThLhib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lhia::FUNCT3, _) if funct5 == th_lhia::FUNCT5 => Ok(InstructionDecoded::ThLhia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lrw::FUNCT3, _) if funct5 == th_lrw::FUNCT5 => Ok(InstructionDecoded::ThLrw {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lurw::FUNCT3, _) if funct5 == th_lurw::FUNCT5 => Ok(InstructionDecoded::ThLurw {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lwib::FUNCT3, _) if funct5 == th_lwib::FUNCT5 => Ok(InstructionDecoded::ThLwib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lwia::FUNCT3, _) if funct5 == th_lwia::FUNCT5 => Ok(InstructionDecoded::ThLwia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lrd::FUNCT3, _) if funct5 == th_lrd::FUNCT5 => Ok(InstructionDecoded::ThLrd {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lurd::FUNCT3, _) if funct5 == th_lurd::FUNCT5 => Ok(InstructionDecoded::ThLurd {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_ldib::FUNCT3, _) if funct5 == th_ldib::FUNCT5 => Ok(InstructionDecoded::ThLdib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_ldia::FUNCT3, _) if funct5 == th_ldia::FUNCT5 => Ok(InstructionDecoded::ThLdia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lrbu::FUNCT3, _) if funct5 == th_lrbu::FUNCT5 => Ok(InstructionDecoded::ThLrbu {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lurbu::FUNCT3, _) if funct5 == th_lurbu::FUNCT5 => Ok(InstructionDecoded::ThLurbu {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lbuib::FUNCT3, _) if funct5 == th_lbuib::FUNCT5 => Ok(InstructionDecoded::ThLbuib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lbuia::FUNCT3, _) if funct5 == th_lbuia::FUNCT5 => Ok(InstructionDecoded::ThLbuia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lrhu::FUNCT3, _) if funct5 == th_lrhu::FUNCT5 => Ok(InstructionDecoded::ThLrhu {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lurhu::FUNCT3, _) if funct5 == th_lurhu::FUNCT5 => Ok(InstructionDecoded::ThLurhu {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lhuib::FUNCT3, _) if funct5 == th_lhuib::FUNCT5 => Ok(InstructionDecoded::ThLhuib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lhuia::FUNCT3, _) if funct5 == th_lhuia::FUNCT5 => Ok(InstructionDecoded::ThLhuia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lrwu::FUNCT3, _) if funct5 == th_lrwu::FUNCT5 => Ok(InstructionDecoded::ThLrwu {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lurwu::FUNCT3, _) if funct5 == th_lurwu::FUNCT5 => Ok(InstructionDecoded::ThLurwu {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_lwuib::FUNCT3, _) if funct5 == th_lwuib::FUNCT5 => Ok(InstructionDecoded::ThLwuib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_lwuia::FUNCT3, _) if funct5 == th_lwuia::FUNCT5 => Ok(InstructionDecoded::ThLwuia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_srb::FUNCT3, _) if funct5 == th_srb::FUNCT5 => Ok(InstructionDecoded::ThSrb {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_surb::FUNCT3, _) if funct5 == th_surb::FUNCT5 => Ok(InstructionDecoded::ThSurb {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_sbib::FUNCT3, _) if funct5 == th_sbib::FUNCT5 => Ok(InstructionDecoded::ThSbib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_sbia::FUNCT3, _) if funct5 == th_sbia::FUNCT5 => Ok(InstructionDecoded::ThSbia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_srh::FUNCT3, _) if funct5 == th_srh::FUNCT5 => Ok(InstructionDecoded::ThSrh {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_surh::FUNCT3, _) if funct5 == th_surh::FUNCT5 => Ok(InstructionDecoded::ThSurh {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_shib::FUNCT3, _) if funct5 == th_shib::FUNCT5 => Ok(InstructionDecoded::ThShib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_shia::FUNCT3, _) if funct5 == th_shia::FUNCT5 => Ok(InstructionDecoded::ThShia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_srw::FUNCT3, _) if funct5 == th_srw::FUNCT5 => Ok(InstructionDecoded::ThSrw {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_surw::FUNCT3, _) if funct5 == th_surw::FUNCT5 => Ok(InstructionDecoded::ThSurw {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_swib::FUNCT3, _) if funct5 == th_swib::FUNCT5 => Ok(InstructionDecoded::ThSwib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_swia::FUNCT3, _) if funct5 == th_swia::FUNCT5 => Ok(InstructionDecoded::ThSwia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_srd::FUNCT3, _) if funct5 == th_srd::FUNCT5 => Ok(InstructionDecoded::ThSrd {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_surd::FUNCT3, _) if funct5 == th_surd::FUNCT5 => Ok(InstructionDecoded::ThSurd {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            rs2: inst.rs2(),
+            shift,
+        }),
+        (th_sdib::FUNCT3, _) if funct5 == th_sdib::FUNCT5 => Ok(InstructionDecoded::ThSdib {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        (th_sdia::FUNCT3, _) if funct5 == th_sdia::FUNCT5 => Ok(InstructionDecoded::ThSdia {
+            rd: inst.rd(),
+            rs1: inst.rs1(),
+            imm,
+            shift,
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown T-Head instruction"),
+    }
+}
+
+// fmt is 0 for single, 1 double, 2 half and 3 quad precision
+pub fn decode_r4type(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let inst = r4type::R4Type::new(inst);
+    let rm = RoundingMode::from_bits(inst.rm())
+        .ok_or(DecodeError::UnknownInstructionFormat)
+        .context("Reserved rounding mode")?;
+    let (rd, rs1, rs2, rs3) = (inst.rd(), inst.rs1(), inst.rs2(), inst.rs3());
+    match (inst.fmt(), inst.opcode()) {
+        (SINGLE_PRECISION_FMT, FMADD_MATCH) => Ok(InstructionDecoded::FmaddS { rd, rs1, rs2, rs3, rm }),
+        (SINGLE_PRECISION_FMT, FMSUB_MATCH) => Ok(InstructionDecoded::FmsubS { rd, rs1, rs2, rs3, rm }),
+        (SINGLE_PRECISION_FMT, FNMSUB_MATCH) => Ok(InstructionDecoded::FnmsubS { rd, rs1, rs2, rs3, rm }),
+        (SINGLE_PRECISION_FMT, FNMADD_MATCH) => Ok(InstructionDecoded::FnmaddS { rd, rs1, rs2, rs3, rm }),
+        (DOUBLE_PRECISION_FMT, FMADD_MATCH) => Ok(InstructionDecoded::FmaddD { rd, rs1, rs2, rs3, rm }),
+        (DOUBLE_PRECISION_FMT, FMSUB_MATCH) => Ok(InstructionDecoded::FmsubD { rd, rs1, rs2, rs3, rm }),
+        (DOUBLE_PRECISION_FMT, FNMSUB_MATCH) => Ok(InstructionDecoded::FnmsubD { rd, rs1, rs2, rs3, rm }),
+        (DOUBLE_PRECISION_FMT, FNMADD_MATCH) => Ok(InstructionDecoded::FnmaddD { rd, rs1, rs2, rs3, rm }),
+        (HALF_PRECISION_FMT, FMADD_MATCH) => Ok(InstructionDecoded::FmaddH { rd, rs1, rs2, rs3, rm }),
+        (HALF_PRECISION_FMT, FMSUB_MATCH) => Ok(InstructionDecoded::FmsubH { rd, rs1, rs2, rs3, rm }),
+        (HALF_PRECISION_FMT, FNMSUB_MATCH) => Ok(InstructionDecoded::FnmsubH { rd, rs1, rs2, rs3, rm }),
+        (HALF_PRECISION_FMT, FNMADD_MATCH) => Ok(InstructionDecoded::FnmaddH { rd, rs1, rs2, rs3, rm }),
+        #[cfg(feature = "q")]
+        (QUAD_PRECISION_FMT, FMADD_MATCH) => Ok(InstructionDecoded::FmaddQ { rd, rs1, rs2, rs3, rm }),
+        #[cfg(feature = "q")]
+        (QUAD_PRECISION_FMT, FMSUB_MATCH) => Ok(InstructionDecoded::FmsubQ { rd, rs1, rs2, rs3, rm }),
+        #[cfg(feature = "q")]
+        (QUAD_PRECISION_FMT, FNMSUB_MATCH) => Ok(InstructionDecoded::FnmsubQ { rd, rs1, rs2, rs3, rm }),
+        #[cfg(feature = "q")]
+        (QUAD_PRECISION_FMT, FNMADD_MATCH) => Ok(InstructionDecoded::FnmaddQ { rd, rs1, rs2, rs3, rm }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unsupported fused multiply-add precision"),
+    }
+}
+
+pub fn decode_itype(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let iinst = itype::IType::new(inst);
+    match (iinst.opcode(), iinst.funct3(), iinst.imm()) {
+        (ARITMETIC_IMMEDIATE_MATCH, addi::FUNCT3, _) => Ok(InstructionDecoded::Addi {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, slti::FUNCT3, _) => Ok(InstructionDecoded::Slti {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sltiu::FUNCT3, _) => Ok(InstructionDecoded::Sltiu {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, xori::FUNCT3, _) => Ok(InstructionDecoded::Xori {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        // the prefetches are oris into x0, the low 5 bits of the offset pick which one it is
+        (ARITMETIC_IMMEDIATE_MATCH, prefetch_i::FUNCT3, imm) if iinst.rd() == 0 && get_bits(imm, 5, 0) == prefetch_i::RS2 => {
+            Ok(InstructionDecoded::PrefetchI {
+                rs1: iinst.rs1(),
+                imm: imm & !0x1f,
+            })
+        }
+        (ARITMETIC_IMMEDIATE_MATCH, prefetch_r::FUNCT3, imm) if iinst.rd() == 0 && get_bits(imm, 5, 0) == prefetch_r::RS2 => {
+            Ok(InstructionDecoded::PrefetchR {
+                rs1: iinst.rs1(),
+                imm: imm & !0x1f,
+            })
+        }
+        (ARITMETIC_IMMEDIATE_MATCH, prefetch_w::FUNCT3, imm) if iinst.rd() == 0 && get_bits(imm, 5, 0) == prefetch_w::RS2 => {
+            Ok(InstructionDecoded::PrefetchW {
+                rs1: iinst.rs1(),
+                imm: imm & !0x1f,
+            })
+        }
+        (ARITMETIC_IMMEDIATE_MATCH, ori::FUNCT3, _) => Ok(InstructionDecoded::Ori {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, andi::FUNCT3, _) => Ok(InstructionDecoded::Andi {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, slli::FUNCT3, _) if (imm.2 >> 6) == slli::IMM >> 1 => {
+            Ok(InstructionDecoded::Slli {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, srli::FUNCT3, _) if (imm.2 >> 6) == srli::IMM >> 1 => {
+            Ok(InstructionDecoded::Srli {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, srai::FUNCT3, _) if (imm.2 >> 6) == srai::IMM >> 1 => {
+            Ok(InstructionDecoded::Srai {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        (ARITMETIC_IMMEDIATE_MATCH, clz::FUNCT3, clz::IMM) => Ok(InstructionDecoded::Clz {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, ctz::FUNCT3, ctz::IMM) => Ok(InstructionDecoded::Ctz {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, cpop::FUNCT3, cpop::IMM) => Ok(InstructionDecoded::Cpop {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sext_b::FUNCT3, sext_b::IMM) => Ok(InstructionDecoded::SextB {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sext_h::FUNCT3, sext_h::IMM) => Ok(InstructionDecoded::SextH {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, orc_b::FUNCT3, orc_b::IMM) => Ok(InstructionDecoded::OrcB {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, rev8::FUNCT3, rev8::IMM | rev8::IMM_RV32) => Ok(InstructionDecoded::Rev8 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, rori::FUNCT3, _) if (imm.2 >> 6) == rori::IMM >> 1 => {
+            Ok(InstructionDecoded::Rori {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, bclri::FUNCT3, _) if (imm.2 >> 6) == bclri::IMM >> 1 => {
+            Ok(InstructionDecoded::Bclri {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, bexti::FUNCT3, _) if (imm.2 >> 6) == bexti::IMM >> 1 => {
+            Ok(InstructionDecoded::Bexti {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, binvi::FUNCT3, _) if (imm.2 >> 6) == binvi::IMM >> 1 => {
+            Ok(InstructionDecoded::Binvi {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, bseti::FUNCT3, _) if (imm.2 >> 6) == bseti::IMM >> 1 => {
+            Ok(InstructionDecoded::Bseti {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        (ARITMETIC_IMMEDIATE_MATCH, brev8::FUNCT3, brev8::IMM) => Ok(InstructionDecoded::Brev8 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, zip::FUNCT3, zip::IMM) => Ok(InstructionDecoded::Zip {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, unzip::FUNCT3, unzip::IMM) => Ok(InstructionDecoded::Unzip {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, aes64im::FUNCT3, aes64im::IMM) => Ok(InstructionDecoded::Aes64im {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        // rnum above 0xa is reserved
+        imm @ (ARITMETIC_IMMEDIATE_MATCH, aes64ks1i::FUNCT3, _)
+            if (imm.2 >> 4) == aes64ks1i::IMM >> 4 && get_bits(imm.2, 4, 0) <= 0xa =>
+        {
+            Ok(InstructionDecoded::Aes64ks1i {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                rnum: get_bits(imm.2, 4, 0),
+            })
+        }
+        (ARITMETIC_IMMEDIATE_MATCH, sha256sum0::FUNCT3, sha256sum0::IMM) => Ok(InstructionDecoded::Sha256sum0 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sha256sum1::FUNCT3, sha256sum1::IMM) => Ok(InstructionDecoded::Sha256sum1 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sha256sig0::FUNCT3, sha256sig0::IMM) => Ok(InstructionDecoded::Sha256sig0 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sha256sig1::FUNCT3, sha256sig1::IMM) => Ok(InstructionDecoded::Sha256sig1 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sha512sum0::FUNCT3, sha512sum0::IMM) => Ok(InstructionDecoded::Sha512sum0 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sha512sum1::FUNCT3, sha512sum1::IMM) => Ok(InstructionDecoded::Sha512sum1 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sha512sig0::FUNCT3, sha512sig0::IMM) => Ok(InstructionDecoded::Sha512sig0 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sha512sig1::FUNCT3, sha512sig1::IMM) => Ok(InstructionDecoded::Sha512sig1 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sm3p0::FUNCT3, sm3p0::IMM) => Ok(InstructionDecoded::Sm3p0 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_MATCH, sm3p1::FUNCT3, sm3p1::IMM) => Ok(InstructionDecoded::Sm3p1 {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_WORD_MATCH, addiw::FUNCT3, _) => Ok(InstructionDecoded::Addiw {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        imm @ (ARITMETIC_IMMEDIATE_WORD_MATCH, slliw::FUNCT3, _) if (imm.2 >> 5) == slliw::IMM => {
+            Ok(InstructionDecoded::Slliw {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 5, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_WORD_MATCH, srliw::FUNCT3, _) if (imm.2 >> 5) == srliw::IMM => {
+            Ok(InstructionDecoded::Srliw {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 5, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_WORD_MATCH, sraiw::FUNCT3, _) if (imm.2 >> 5) == sraiw::IMM => {
+            Ok(InstructionDecoded::Sraiw {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 5, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_WORD_MATCH, slli_uw::FUNCT3, _) if (imm.2 >> 6) == slli_uw::IMM >> 1 => {
+            Ok(InstructionDecoded::SlliUw {
+                rd: iinst.rd(),
+                rs1: iinst.rs1(),
+                imm: get_bits(imm.2, 6, 0),
+            })
+        }
+        imm @ (ARITMETIC_IMMEDIATE_WORD_MATCH, roriw::FUNCT3, _) if (imm.2 >> 5) == roriw::IMM => {
+            Ok(InstructionDecoded::Roriw {
+                rd: iinst.rd(),
                 rs1: iinst.rs1(),
                 imm: get_bits(imm.2, 5, 0),
             })
         }
+        (ARITMETIC_IMMEDIATE_WORD_MATCH, clzw::FUNCT3, clzw::IMM) => Ok(InstructionDecoded::Clzw {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_WORD_MATCH, ctzw::FUNCT3, ctzw::IMM) => Ok(InstructionDecoded::Ctzw {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
+        (ARITMETIC_IMMEDIATE_WORD_MATCH, cpopw::FUNCT3, cpopw::IMM) => Ok(InstructionDecoded::Cpopw {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+        }),
         // Load
         (LOAD_MATCH, lb::FUNCT3, _) => Ok(InstructionDecoded::Lb {
             rd: iinst.rd(),
             rs1: iinst.rs1(),
             imm: iinst.imm(),
         }),
-        (LOAD_MATCH, lh::FUNCT3, _) => Ok(InstructionDecoded::Lh {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
+        (LOAD_MATCH, lh::FUNCT3, _) => Ok(InstructionDecoded::Lh {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_MATCH, lw::FUNCT3, _) => Ok(InstructionDecoded::Lw {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_MATCH, lbu::FUNCT3, _) => Ok(InstructionDecoded::Lbu {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_MATCH, lhu::FUNCT3, _) => Ok(InstructionDecoded::Lhu {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_MATCH, lwu::FUNCT3, _) => Ok(InstructionDecoded::Lwu {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_MATCH, ld::FUNCT3, _) => Ok(InstructionDecoded::Ld {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_FP_MATCH, flw::FUNCT3, _) => Ok(InstructionDecoded::Flw {
+            rd: iinst.rd(),
+            width: flw::FUNCT3,
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_FP_MATCH, fld::FUNCT3, _) => Ok(InstructionDecoded::Fld {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (LOAD_FP_MATCH, flh::FUNCT3, _) => Ok(InstructionDecoded::Flh {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        #[cfg(feature = "q")]
+        (LOAD_FP_MATCH, flq::FUNCT3, _) => Ok(InstructionDecoded::Flq {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (JALR_MATCH, jalr::FUNCT3, _) => Ok(InstructionDecoded::Jalr {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.imm(),
+        }),
+        (FENCE_MATCH, cbo_inval::FUNCT3, cbo_inval::IMM) => Ok(InstructionDecoded::CboInval { rs1: iinst.rs1() }),
+        (FENCE_MATCH, cbo_clean::FUNCT3, cbo_clean::IMM) => Ok(InstructionDecoded::CboClean { rs1: iinst.rs1() }),
+        (FENCE_MATCH, cbo_flush::FUNCT3, cbo_flush::IMM) => Ok(InstructionDecoded::CboFlush { rs1: iinst.rs1() }),
+        (FENCE_MATCH, cbo_zero::FUNCT3, cbo_zero::IMM) => Ok(InstructionDecoded::CboZero { rs1: iinst.rs1() }),
+        // pause is the fence that only orders earlier writes against nothing
+        (FENCE_MATCH, pause::FUNCT3, pause::IMM) if iinst.rd() == 0 && iinst.rs1() == 0 => Ok(InstructionDecoded::Pause),
+        (FENCE_MATCH, fence::FUNCT3, _) => {
+            let pred = get_bits(iinst.imm(), 4, 4);
+            let succ = get_bits(iinst.imm(), 4, 0);
+            Ok(InstructionDecoded::Fence { pred, succ })
+        }
+        (FENCE_MATCH, fence_i::FUNCT3, _) => {
+            let pred = get_bits(iinst.imm(), 4, 4);
+            let succ = get_bits(iinst.imm(), 4, 0);
+            Ok(InstructionDecoded::FenceI { pred, succ })
+        }
+        (CSR_MATCH, csrrw::FUNCT3, _) => Ok(InstructionDecoded::CsrRw {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.uimm(),
+        }),
+        (CSR_MATCH, csrrs::FUNCT3, _) => Ok(InstructionDecoded::CsrRs {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.uimm(),
+        }),
+        (CSR_MATCH, csrrc::FUNCT3, _) => Ok(InstructionDecoded::CsrRc {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.uimm(),
+        }),
+        (CSR_MATCH, csrrwi::FUNCT3, _) => Ok(InstructionDecoded::CsrRwi {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.uimm(),
+        }),
+        (CSR_MATCH, csrrsi::FUNCT3, _) => Ok(InstructionDecoded::CsrRsi {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.uimm(),
+        }),
+        (CSR_MATCH, csrrci::FUNCT3, _) => Ok(InstructionDecoded::CsrRci {
+            rd: iinst.rd(),
+            rs1: iinst.rs1(),
+            imm: iinst.uimm(),
+        }),
+        // e-insts (ebreak, ecall)
+        (CSR_MATCH, sfencevma::FUNCT3, imm) if imm >> 5 == sfencevma::FUNCT7 && iinst.rd() == 0 => Ok(InstructionDecoded::SFenceVma {
+            rs1: iinst.rs1(),
+            rs2: get_bits(imm, 5, 0),
+        }),
+        (CSR_MATCH, ebreak::FUNCT3, ebreak::IMM) => Ok(InstructionDecoded::EBreak),
+        (CSR_MATCH, ecall::FUNCT3, ecall::IMM) => Ok(InstructionDecoded::ECall),
+        (CSR_MATCH, mret::FUNCT3, mret::IMM) => Ok(InstructionDecoded::MRet),
+        (CSR_MATCH, sret::FUNCT3, sret::IMM) => Ok(InstructionDecoded::SRet),
+        (CSR_MATCH, mnret::FUNCT3, mnret::IMM) => Ok(InstructionDecoded::MnRet),
+        (CSR_MATCH, dret::FUNCT3, dret::IMM) => Ok(InstructionDecoded::DRet),
+        // the shadow stack instructions are carved out of the may-be-operations, only the x1 and x5
+        // link registers are allowed
+        (CSR_MATCH, sspush::FUNCT3, _)
+            if iinst.uimm() >> 5 == sspush::FUNCT7
+                && iinst.rd() == 0
+                && iinst.rs1() == 0
+                && matches!(get_bits(iinst.uimm(), 5, 0), 1 | 5) =>
+        {
+            Ok(InstructionDecoded::Sspush {
+                rs2: get_bits(iinst.uimm(), 5, 0),
+            })
+        }
+        (CSR_MATCH, sspopchk::FUNCT3, _)
+            if iinst.uimm() == sspopchk::IMM && iinst.rd() == 0 && matches!(iinst.rs1(), 1 | 5) =>
+        {
+            Ok(InstructionDecoded::Sspopchk { rs1: iinst.rs1() })
+        }
+        // ssrdp into x0 is reserved, it's still decoded since it does nothing either way
+        (CSR_MATCH, ssrdp::FUNCT3, _) if iinst.uimm() == ssrdp::IMM && iinst.rs1() == 0 => {
+            Ok(InstructionDecoded::Ssrdp { rd: iinst.rd() })
+        }
+        (CSR_MATCH, wrs_nto::FUNCT3, wrs_nto::IMM) => Ok(InstructionDecoded::WrsNto),
+        (CSR_MATCH, wrs_sto::FUNCT3, wrs_sto::IMM) => Ok(InstructionDecoded::WrsSto),
+        (CSR_MATCH, sfence_w_inval::FUNCT3, sfence_w_inval::IMM) => Ok(InstructionDecoded::SFenceWInval),
+        (CSR_MATCH, sfence_inval_ir::FUNCT3, sfence_inval_ir::IMM) => Ok(InstructionDecoded::SFenceInvalIr),
+        // the rest of the Svinval fences keep rs2 in the low bits of the immediate
+        (CSR_MATCH, sinval_vma::FUNCT3, imm) if imm >> 5 == sinval_vma::FUNCT7 => Ok(InstructionDecoded::SinvalVma {
+            rs1: iinst.rs1(),
+            rs2: get_bits(imm, 5, 0),
+        }),
+        (CSR_MATCH, hinval_vvma::FUNCT3, imm) if imm >> 5 == hinval_vvma::FUNCT7 => Ok(InstructionDecoded::HinvalVvma {
+            rs1: iinst.rs1(),
+            rs2: get_bits(imm, 5, 0),
+        }),
+        (CSR_MATCH, hinval_gvma::FUNCT3, imm) if imm >> 5 == hinval_gvma::FUNCT7 => Ok(InstructionDecoded::HinvalGvma {
+            rs1: iinst.rs1(),
+            rs2: get_bits(imm, 5, 0),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown I-Type instruction"),
+    }
+}
+
+pub fn decode_stype(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let sinst = stype::SType::new(inst);
+    match (sinst.opcode(), sinst.funct3()) {
+        (STORE_MATCH, sb::FUNCT3) => Ok(InstructionDecoded::Sb {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        (STORE_MATCH, sh::FUNCT3) => Ok(InstructionDecoded::Sh {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        (STORE_MATCH, sw::FUNCT3) => Ok(InstructionDecoded::Sw {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        (STORE_MATCH, sd::FUNCT3) => Ok(InstructionDecoded::Sd {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        (STORE_FP_MATCH, fsw::FUNCT3) => Ok(InstructionDecoded::Fsw {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        (STORE_FP_MATCH, fsd::FUNCT3) => Ok(InstructionDecoded::Fsd {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        (STORE_FP_MATCH, fsh::FUNCT3) => Ok(InstructionDecoded::Fsh {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        #[cfg(feature = "q")]
+        (STORE_FP_MATCH, fsq::FUNCT3) => Ok(InstructionDecoded::Fsq {
+            rs1: sinst.rs1(),
+            rs2: sinst.rs2(),
+            imm: sinst.imm(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown S-Type instruction"),
+    }
+}
+
+pub fn decode_utype(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let uinst = utype::UType::new(inst);
+    match uinst.opcode() {
+        LUI_MATCH => Ok(InstructionDecoded::Lui {
+            rd: uinst.rd(),
+            imm: uinst.imm(),
+        }),
+        // an auipc into x0 is the landing pad, the immediate is the label it expects
+        AUIPC_MATCH if uinst.rd() == 0 => Ok(InstructionDecoded::Lpad { label: uinst.imm() }),
+        AUIPC_MATCH => Ok(InstructionDecoded::AuiPc {
+            rd: uinst.rd(),
+            imm: uinst.imm(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown U-Type instruction"),
+    }
+}
+
+pub fn decode_btype(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let binst = btype::BType::new(inst);
+    match binst.funct3() {
+        beq::FUNCT3 => Ok(InstructionDecoded::Beq {
+            rs1: binst.rs1(),
+            rs2: binst.rs2(),
+            imm: binst.imm(),
+        }),
+        bne::FUNCT3 => Ok(InstructionDecoded::Bne {
+            rs1: binst.rs1(),
+            rs2: binst.rs2(),
+            imm: binst.imm(),
+        }),
+        blt::FUNCT3 => Ok(InstructionDecoded::Blt {
+            rs1: binst.rs1(),
+            rs2: binst.rs2(),
+            imm: binst.imm(),
+        }),
+        bge::FUNCT3 => Ok(InstructionDecoded::Bge {
+            rs1: binst.rs1(),
+            rs2: binst.rs2(),
+            imm: binst.imm(),
+        }),
+        bltu::FUNCT3 => Ok(InstructionDecoded::Bltu {
+            rs1: binst.rs1(),
+            rs2: binst.rs2(),
+            imm: binst.imm(),
+        }),
+        bgeu::FUNCT3 => Ok(InstructionDecoded::Bgeu {
+            rs1: binst.rs1(),
+            rs2: binst.rs2(),
+            imm: binst.imm(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown B-Type instruction"),
+    }
+}
+
+pub fn decode_jtype(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let jinst = jtype::JType::new(inst);
+    match jinst.opcode() {
+        JAL_MATCH => Ok(InstructionDecoded::Jal {
+            rd: jinst.rd(),
+            imm: jinst.imm(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown J-Type instruction"),
+    }
+}
+
+// OPFVV/OPFVF, the floating point ops
+fn decode_vector_opf(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let vinst = opvtype::OpVType::new(inst);
+    let masked = vinst.vm() == 0;
+    match (vinst.funct3(), vinst.funct6(), vinst.vs1()) {
+        (vfwmaccbf16_vv::FUNCT3, vfwmaccbf16_vv::FUNCT6, _) => {
+            Ok(InstructionDecoded::Vfwmaccbf16Vv {
+                vd: vinst.vd(),
+                vs1: vinst.vs1(),
+                vs2: vinst.vs2(),
+                masked,
+            })
+        }
+        (vfwmaccbf16_vf::FUNCT3, vfwmaccbf16_vf::FUNCT6, _) => {
+            Ok(InstructionDecoded::Vfwmaccbf16Vf {
+                vd: vinst.vd(),
+                rs1: vinst.vs1(),
+                vs2: vinst.vs2(),
+                masked,
+            })
+        }
+        (
+            vfncvtbf16_f_f_w::FUNCT3,
+            vfncvtbf16_f_f_w::FUNCT6,
+            vfncvtbf16_f_f_w::VS1,
+        ) => Ok(InstructionDecoded::Vfncvtbf16FFW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (
+            vfwcvtbf16_f_f_v::FUNCT3,
+            vfwcvtbf16_f_f_v::FUNCT6,
+            vfwcvtbf16_f_f_v::VS1,
+        ) => Ok(InstructionDecoded::Vfwcvtbf16FFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfmv_f_s::FUNCT3, vfmv_f_s::FUNCT6, vfmv_f_s::VS1) if !masked => Ok(InstructionDecoded::VfmvFS {
+            rd: vinst.vd(),
+            vs2: vinst.vs2(),
+        }),
+        (vfmv_s_f::FUNCT3, vfmv_s_f::FUNCT6, _) if !masked && vinst.vs2() == vfmv_s_f::VS2 => Ok(InstructionDecoded::VfmvSF {
+            vd: vinst.vd(),
+            rs1: vinst.vs1(),
+        }),
+        (vfmerge_vfm::FUNCT3, vfmerge_vfm::FUNCT6, _) if masked => Ok(InstructionDecoded::VfmergeVfm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+        }),
+        (vfmv_v_f::FUNCT3, vfmv_v_f::FUNCT6, _) if vinst.vs2() == vfmv_v_f::VS2 => Ok(InstructionDecoded::VfmvVF {
+            vd: vinst.vd(),
+            rs1: vinst.vs1(),
+        }),
+        (vfcvt_xu_f_v::FUNCT3, vfcvt_xu_f_v::FUNCT6, vfcvt_xu_f_v::VS1) => Ok(InstructionDecoded::VfcvtXuFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfcvt_x_f_v::FUNCT3, vfcvt_x_f_v::FUNCT6, vfcvt_x_f_v::VS1) => Ok(InstructionDecoded::VfcvtXFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfcvt_f_xu_v::FUNCT3, vfcvt_f_xu_v::FUNCT6, vfcvt_f_xu_v::VS1) => Ok(InstructionDecoded::VfcvtFXuV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfcvt_f_x_v::FUNCT3, vfcvt_f_x_v::FUNCT6, vfcvt_f_x_v::VS1) => Ok(InstructionDecoded::VfcvtFXV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfcvt_rtz_xu_f_v::FUNCT3, vfcvt_rtz_xu_f_v::FUNCT6, vfcvt_rtz_xu_f_v::VS1) => Ok(InstructionDecoded::VfcvtRtzXuFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfcvt_rtz_x_f_v::FUNCT3, vfcvt_rtz_x_f_v::FUNCT6, vfcvt_rtz_x_f_v::VS1) => Ok(InstructionDecoded::VfcvtRtzXFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfwcvt_xu_f_v::FUNCT3, vfwcvt_xu_f_v::FUNCT6, vfwcvt_xu_f_v::VS1) => Ok(InstructionDecoded::VfwcvtXuFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfwcvt_x_f_v::FUNCT3, vfwcvt_x_f_v::FUNCT6, vfwcvt_x_f_v::VS1) => Ok(InstructionDecoded::VfwcvtXFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfwcvt_f_xu_v::FUNCT3, vfwcvt_f_xu_v::FUNCT6, vfwcvt_f_xu_v::VS1) => Ok(InstructionDecoded::VfwcvtFXuV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfwcvt_f_x_v::FUNCT3, vfwcvt_f_x_v::FUNCT6, vfwcvt_f_x_v::VS1) => Ok(InstructionDecoded::VfwcvtFXV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfwcvt_f_f_v::FUNCT3, vfwcvt_f_f_v::FUNCT6, vfwcvt_f_f_v::VS1) => Ok(InstructionDecoded::VfwcvtFFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfwcvt_rtz_xu_f_v::FUNCT3, vfwcvt_rtz_xu_f_v::FUNCT6, vfwcvt_rtz_xu_f_v::VS1) => Ok(InstructionDecoded::VfwcvtRtzXuFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfwcvt_rtz_x_f_v::FUNCT3, vfwcvt_rtz_x_f_v::FUNCT6, vfwcvt_rtz_x_f_v::VS1) => Ok(InstructionDecoded::VfwcvtRtzXFV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_xu_f_w::FUNCT3, vfncvt_xu_f_w::FUNCT6, vfncvt_xu_f_w::VS1) => Ok(InstructionDecoded::VfncvtXuFW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_x_f_w::FUNCT3, vfncvt_x_f_w::FUNCT6, vfncvt_x_f_w::VS1) => Ok(InstructionDecoded::VfncvtXFW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_f_xu_w::FUNCT3, vfncvt_f_xu_w::FUNCT6, vfncvt_f_xu_w::VS1) => Ok(InstructionDecoded::VfncvtFXuW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_f_x_w::FUNCT3, vfncvt_f_x_w::FUNCT6, vfncvt_f_x_w::VS1) => Ok(InstructionDecoded::VfncvtFXW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_f_f_w::FUNCT3, vfncvt_f_f_w::FUNCT6, vfncvt_f_f_w::VS1) => Ok(InstructionDecoded::VfncvtFFW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_rod_f_f_w::FUNCT3, vfncvt_rod_f_f_w::FUNCT6, vfncvt_rod_f_f_w::VS1) => Ok(InstructionDecoded::VfncvtRodFFW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_rtz_xu_f_w::FUNCT3, vfncvt_rtz_xu_f_w::FUNCT6, vfncvt_rtz_xu_f_w::VS1) => Ok(InstructionDecoded::VfncvtRtzXuFW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfncvt_rtz_x_f_w::FUNCT3, vfncvt_rtz_x_f_w::FUNCT6, vfncvt_rtz_x_f_w::VS1) => Ok(InstructionDecoded::VfncvtRtzXFW {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfsqrt_v::FUNCT3, vfsqrt_v::FUNCT6, vfsqrt_v::VS1) => Ok(InstructionDecoded::VfsqrtV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfrsqrt7_v::FUNCT3, vfrsqrt7_v::FUNCT6, vfrsqrt7_v::VS1) => Ok(InstructionDecoded::Vfrsqrt7V {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfrec7_v::FUNCT3, vfrec7_v::FUNCT6, vfrec7_v::VS1) => Ok(InstructionDecoded::Vfrec7V {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfclass_v::FUNCT3, vfclass_v::FUNCT6, vfclass_v::VS1) => Ok(InstructionDecoded::VfclassV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfadd_vv::FUNCT3, vfadd_vv::FUNCT6, _) => Ok(InstructionDecoded::VfaddVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfadd_vf::FUNCT3, vfadd_vf::FUNCT6, _) => Ok(InstructionDecoded::VfaddVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfredusum_vs::FUNCT3, vfredusum_vs::FUNCT6, _) => Ok(InstructionDecoded::VfredusumVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsub_vv::FUNCT3, vfsub_vv::FUNCT6, _) => Ok(InstructionDecoded::VfsubVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsub_vf::FUNCT3, vfsub_vf::FUNCT6, _) => Ok(InstructionDecoded::VfsubVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfredosum_vs::FUNCT3, vfredosum_vs::FUNCT6, _) => Ok(InstructionDecoded::VfredosumVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmin_vv::FUNCT3, vfmin_vv::FUNCT6, _) => Ok(InstructionDecoded::VfminVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmin_vf::FUNCT3, vfmin_vf::FUNCT6, _) => Ok(InstructionDecoded::VfminVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfredmin_vs::FUNCT3, vfredmin_vs::FUNCT6, _) => Ok(InstructionDecoded::VfredminVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmax_vv::FUNCT3, vfmax_vv::FUNCT6, _) => Ok(InstructionDecoded::VfmaxVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmax_vf::FUNCT3, vfmax_vf::FUNCT6, _) => Ok(InstructionDecoded::VfmaxVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfredmax_vs::FUNCT3, vfredmax_vs::FUNCT6, _) => Ok(InstructionDecoded::VfredmaxVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsgnj_vv::FUNCT3, vfsgnj_vv::FUNCT6, _) => Ok(InstructionDecoded::VfsgnjVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsgnj_vf::FUNCT3, vfsgnj_vf::FUNCT6, _) => Ok(InstructionDecoded::VfsgnjVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsgnjn_vv::FUNCT3, vfsgnjn_vv::FUNCT6, _) => Ok(InstructionDecoded::VfsgnjnVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsgnjn_vf::FUNCT3, vfsgnjn_vf::FUNCT6, _) => Ok(InstructionDecoded::VfsgnjnVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsgnjx_vv::FUNCT3, vfsgnjx_vv::FUNCT6, _) => Ok(InstructionDecoded::VfsgnjxVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfsgnjx_vf::FUNCT3, vfsgnjx_vf::FUNCT6, _) => Ok(InstructionDecoded::VfsgnjxVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfslide1up_vf::FUNCT3, vfslide1up_vf::FUNCT6, _) => Ok(InstructionDecoded::Vfslide1upVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfslide1down_vf::FUNCT3, vfslide1down_vf::FUNCT6, _) => Ok(InstructionDecoded::Vfslide1downVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfeq_vv::FUNCT3, vmfeq_vv::FUNCT6, _) => Ok(InstructionDecoded::VmfeqVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfeq_vf::FUNCT3, vmfeq_vf::FUNCT6, _) => Ok(InstructionDecoded::VmfeqVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfle_vv::FUNCT3, vmfle_vv::FUNCT6, _) => Ok(InstructionDecoded::VmfleVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfle_vf::FUNCT3, vmfle_vf::FUNCT6, _) => Ok(InstructionDecoded::VmfleVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmflt_vv::FUNCT3, vmflt_vv::FUNCT6, _) => Ok(InstructionDecoded::VmfltVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmflt_vf::FUNCT3, vmflt_vf::FUNCT6, _) => Ok(InstructionDecoded::VmfltVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfne_vv::FUNCT3, vmfne_vv::FUNCT6, _) => Ok(InstructionDecoded::VmfneVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfne_vf::FUNCT3, vmfne_vf::FUNCT6, _) => Ok(InstructionDecoded::VmfneVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfgt_vf::FUNCT3, vmfgt_vf::FUNCT6, _) => Ok(InstructionDecoded::VmfgtVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmfge_vf::FUNCT3, vmfge_vf::FUNCT6, _) => Ok(InstructionDecoded::VmfgeVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfdiv_vv::FUNCT3, vfdiv_vv::FUNCT6, _) => Ok(InstructionDecoded::VfdivVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfdiv_vf::FUNCT3, vfdiv_vf::FUNCT6, _) => Ok(InstructionDecoded::VfdivVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfrdiv_vf::FUNCT3, vfrdiv_vf::FUNCT6, _) => Ok(InstructionDecoded::VfrdivVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmul_vv::FUNCT3, vfmul_vv::FUNCT6, _) => Ok(InstructionDecoded::VfmulVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmul_vf::FUNCT3, vfmul_vf::FUNCT6, _) => Ok(InstructionDecoded::VfmulVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfrsub_vf::FUNCT3, vfrsub_vf::FUNCT6, _) => Ok(InstructionDecoded::VfrsubVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmadd_vv::FUNCT3, vfmadd_vv::FUNCT6, _) => Ok(InstructionDecoded::VfmaddVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmadd_vf::FUNCT3, vfmadd_vf::FUNCT6, _) => Ok(InstructionDecoded::VfmaddVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmadd_vv::FUNCT3, vfnmadd_vv::FUNCT6, _) => Ok(InstructionDecoded::VfnmaddVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmadd_vf::FUNCT3, vfnmadd_vf::FUNCT6, _) => Ok(InstructionDecoded::VfnmaddVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmsub_vv::FUNCT3, vfmsub_vv::FUNCT6, _) => Ok(InstructionDecoded::VfmsubVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmsub_vf::FUNCT3, vfmsub_vf::FUNCT6, _) => Ok(InstructionDecoded::VfmsubVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmsub_vv::FUNCT3, vfnmsub_vv::FUNCT6, _) => Ok(InstructionDecoded::VfnmsubVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmsub_vf::FUNCT3, vfnmsub_vf::FUNCT6, _) => Ok(InstructionDecoded::VfnmsubVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmacc_vv::FUNCT3, vfmacc_vv::FUNCT6, _) => Ok(InstructionDecoded::VfmaccVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmacc_vf::FUNCT3, vfmacc_vf::FUNCT6, _) => Ok(InstructionDecoded::VfmaccVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmacc_vv::FUNCT3, vfnmacc_vv::FUNCT6, _) => Ok(InstructionDecoded::VfnmaccVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmacc_vf::FUNCT3, vfnmacc_vf::FUNCT6, _) => Ok(InstructionDecoded::VfnmaccVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmsac_vv::FUNCT3, vfmsac_vv::FUNCT6, _) => Ok(InstructionDecoded::VfmsacVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfmsac_vf::FUNCT3, vfmsac_vf::FUNCT6, _) => Ok(InstructionDecoded::VfmsacVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmsac_vv::FUNCT3, vfnmsac_vv::FUNCT6, _) => Ok(InstructionDecoded::VfnmsacVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfnmsac_vf::FUNCT3, vfnmsac_vf::FUNCT6, _) => Ok(InstructionDecoded::VfnmsacVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwadd_vv::FUNCT3, vfwadd_vv::FUNCT6, _) => Ok(InstructionDecoded::VfwaddVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwadd_vf::FUNCT3, vfwadd_vf::FUNCT6, _) => Ok(InstructionDecoded::VfwaddVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwredusum_vs::FUNCT3, vfwredusum_vs::FUNCT6, _) => Ok(InstructionDecoded::VfwredusumVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwsub_vv::FUNCT3, vfwsub_vv::FUNCT6, _) => Ok(InstructionDecoded::VfwsubVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwsub_vf::FUNCT3, vfwsub_vf::FUNCT6, _) => Ok(InstructionDecoded::VfwsubVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwredosum_vs::FUNCT3, vfwredosum_vs::FUNCT6, _) => Ok(InstructionDecoded::VfwredosumVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwadd_wv::FUNCT3, vfwadd_wv::FUNCT6, _) => Ok(InstructionDecoded::VfwaddWv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwadd_wf::FUNCT3, vfwadd_wf::FUNCT6, _) => Ok(InstructionDecoded::VfwaddWf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwsub_wv::FUNCT3, vfwsub_wv::FUNCT6, _) => Ok(InstructionDecoded::VfwsubWv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwsub_wf::FUNCT3, vfwsub_wf::FUNCT6, _) => Ok(InstructionDecoded::VfwsubWf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwmul_vv::FUNCT3, vfwmul_vv::FUNCT6, _) => Ok(InstructionDecoded::VfwmulVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwmul_vf::FUNCT3, vfwmul_vf::FUNCT6, _) => Ok(InstructionDecoded::VfwmulVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwmacc_vv::FUNCT3, vfwmacc_vv::FUNCT6, _) => Ok(InstructionDecoded::VfwmaccVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwmacc_vf::FUNCT3, vfwmacc_vf::FUNCT6, _) => Ok(InstructionDecoded::VfwmaccVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwnmacc_vv::FUNCT3, vfwnmacc_vv::FUNCT6, _) => Ok(InstructionDecoded::VfwnmaccVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwnmacc_vf::FUNCT3, vfwnmacc_vf::FUNCT6, _) => Ok(InstructionDecoded::VfwnmaccVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwmsac_vv::FUNCT3, vfwmsac_vv::FUNCT6, _) => Ok(InstructionDecoded::VfwmsacVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwmsac_vf::FUNCT3, vfwmsac_vf::FUNCT6, _) => Ok(InstructionDecoded::VfwmsacVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwnmsac_vv::FUNCT3, vfwnmsac_vv::FUNCT6, _) => Ok(InstructionDecoded::VfwnmsacVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vfwnmsac_vf::FUNCT3, vfwnmsac_vf::FUNCT6, _) => Ok(InstructionDecoded::VfwnmsacVf {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Vector OPFVV/OPFVF instruction"),
+    }
+}
+
+// OPMVV/OPMVX, the multiply, divide, widening and reduction ops
+fn decode_vector_opm(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let vinst = opvtype::OpVType::new(inst);
+    let masked = vinst.vm() == 0;
+    match (vinst.funct3(), vinst.funct6(), vinst.vs1()) {
+        (vmv_x_s::FUNCT3, vmv_x_s::FUNCT6, vmv_x_s::VS1) if !masked => Ok(InstructionDecoded::VmvXS {
+            rd: vinst.vd(),
+            vs2: vinst.vs2(),
+        }),
+        (vmv_s_x::FUNCT3, vmv_s_x::FUNCT6, _) if !masked && vinst.vs2() == vmv_s_x::VS2 => Ok(InstructionDecoded::VmvSX {
+            vd: vinst.vd(),
+            rs1: vinst.vs1(),
+        }),
+        (vzext_vf8::FUNCT3, vzext_vf8::FUNCT6, vzext_vf8::VS1) => Ok(InstructionDecoded::VzextVf8 {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vsext_vf8::FUNCT3, vsext_vf8::FUNCT6, vsext_vf8::VS1) => Ok(InstructionDecoded::VsextVf8 {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vzext_vf4::FUNCT3, vzext_vf4::FUNCT6, vzext_vf4::VS1) => Ok(InstructionDecoded::VzextVf4 {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vsext_vf4::FUNCT3, vsext_vf4::FUNCT6, vsext_vf4::VS1) => Ok(InstructionDecoded::VsextVf4 {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vzext_vf2::FUNCT3, vzext_vf2::FUNCT6, vzext_vf2::VS1) => Ok(InstructionDecoded::VzextVf2 {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vsext_vf2::FUNCT3, vsext_vf2::FUNCT6, vsext_vf2::VS1) => Ok(InstructionDecoded::VsextVf2 {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (vbrev8_v::FUNCT3, vbrev8_v::FUNCT6, vbrev8_v::VS1) => Ok(InstructionDecoded::Vbrev8V {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (vrev8_v::FUNCT3, vrev8_v::FUNCT6, vrev8_v::VS1) => Ok(InstructionDecoded::Vrev8V {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (vbrev_v::FUNCT3, vbrev_v::FUNCT6, vbrev_v::VS1) => Ok(InstructionDecoded::VbrevV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (vclz_v::FUNCT3, vclz_v::FUNCT6, vclz_v::VS1) => Ok(InstructionDecoded::VclzV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (vctz_v::FUNCT3, vctz_v::FUNCT6, vctz_v::VS1) => Ok(InstructionDecoded::VctzV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (vcpop_v::FUNCT3, vcpop_v::FUNCT6, vcpop_v::VS1) => Ok(InstructionDecoded::VcpopV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vcompress_vm::FUNCT3, vcompress_vm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VcompressVm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmandn_mm::FUNCT3, vmandn_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmandnMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmand_mm::FUNCT3, vmand_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmandMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmor_mm::FUNCT3, vmor_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmorMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmxor_mm::FUNCT3, vmxor_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmxorMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmorn_mm::FUNCT3, vmorn_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmornMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmnand_mm::FUNCT3, vmnand_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmnandMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmnor_mm::FUNCT3, vmnor_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmnorMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vmxnor_mm::FUNCT3, vmxnor_mm::FUNCT6, _) if !masked => Ok(InstructionDecoded::VmxnorMm {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+        }),
+        (vcpop_m::FUNCT3, vcpop_m::FUNCT6, vcpop_m::VS1) => Ok(InstructionDecoded::VcpopM {
+            rd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vfirst_m::FUNCT3, vfirst_m::FUNCT6, vfirst_m::VS1) => Ok(InstructionDecoded::VfirstM {
+            rd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vmsbf_m::FUNCT3, vmsbf_m::FUNCT6, vmsbf_m::VS1) => Ok(InstructionDecoded::VmsbfM {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vmsof_m::FUNCT3, vmsof_m::FUNCT6, vmsof_m::VS1) => Ok(InstructionDecoded::VmsofM {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vmsif_m::FUNCT3, vmsif_m::FUNCT6, vmsif_m::VS1) => Ok(InstructionDecoded::VmsifM {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (viota_m::FUNCT3, viota_m::FUNCT6, viota_m::VS1) => Ok(InstructionDecoded::ViotaM {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            masked,
+        }),
+        (vid_v::FUNCT3, vid_v::FUNCT6, vid_v::VS1) if vinst.vs2() == vid_v::VS2 => Ok(InstructionDecoded::VidV {
+            vd: vinst.vd(),
+            masked,
+        }),
+        (vredsum_vs::FUNCT3, vredsum_vs::FUNCT6, _) => Ok(InstructionDecoded::VredsumVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vredand_vs::FUNCT3, vredand_vs::FUNCT6, _) => Ok(InstructionDecoded::VredandVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vredor_vs::FUNCT3, vredor_vs::FUNCT6, _) => Ok(InstructionDecoded::VredorVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vredxor_vs::FUNCT3, vredxor_vs::FUNCT6, _) => Ok(InstructionDecoded::VredxorVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vredminu_vs::FUNCT3, vredminu_vs::FUNCT6, _) => Ok(InstructionDecoded::VredminuVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vredmin_vs::FUNCT3, vredmin_vs::FUNCT6, _) => Ok(InstructionDecoded::VredminVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vredmaxu_vs::FUNCT3, vredmaxu_vs::FUNCT6, _) => Ok(InstructionDecoded::VredmaxuVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vredmax_vs::FUNCT3, vredmax_vs::FUNCT6, _) => Ok(InstructionDecoded::VredmaxVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vaaddu_vv::FUNCT3, vaaddu_vv::FUNCT6, _) => Ok(InstructionDecoded::VaadduVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vaaddu_vx::FUNCT3, vaaddu_vx::FUNCT6, _) => Ok(InstructionDecoded::VaadduVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vaadd_vv::FUNCT3, vaadd_vv::FUNCT6, _) => Ok(InstructionDecoded::VaaddVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vaadd_vx::FUNCT3, vaadd_vx::FUNCT6, _) => Ok(InstructionDecoded::VaaddVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vasubu_vv::FUNCT3, vasubu_vv::FUNCT6, _) => Ok(InstructionDecoded::VasubuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vasubu_vx::FUNCT3, vasubu_vx::FUNCT6, _) => Ok(InstructionDecoded::VasubuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vasub_vv::FUNCT3, vasub_vv::FUNCT6, _) => Ok(InstructionDecoded::VasubVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vasub_vx::FUNCT3, vasub_vx::FUNCT6, _) => Ok(InstructionDecoded::VasubVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vslide1up_vx::FUNCT3, vslide1up_vx::FUNCT6, _) => Ok(InstructionDecoded::Vslide1upVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vslide1down_vx::FUNCT3, vslide1down_vx::FUNCT6, _) => Ok(InstructionDecoded::Vslide1downVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vdivu_vv::FUNCT3, vdivu_vv::FUNCT6, _) => Ok(InstructionDecoded::VdivuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vdivu_vx::FUNCT3, vdivu_vx::FUNCT6, _) => Ok(InstructionDecoded::VdivuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vdiv_vv::FUNCT3, vdiv_vv::FUNCT6, _) => Ok(InstructionDecoded::VdivVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vdiv_vx::FUNCT3, vdiv_vx::FUNCT6, _) => Ok(InstructionDecoded::VdivVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vremu_vv::FUNCT3, vremu_vv::FUNCT6, _) => Ok(InstructionDecoded::VremuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vremu_vx::FUNCT3, vremu_vx::FUNCT6, _) => Ok(InstructionDecoded::VremuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vrem_vv::FUNCT3, vrem_vv::FUNCT6, _) => Ok(InstructionDecoded::VremVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vrem_vx::FUNCT3, vrem_vx::FUNCT6, _) => Ok(InstructionDecoded::VremVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmulhu_vv::FUNCT3, vmulhu_vv::FUNCT6, _) => Ok(InstructionDecoded::VmulhuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmulhu_vx::FUNCT3, vmulhu_vx::FUNCT6, _) => Ok(InstructionDecoded::VmulhuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmul_vv::FUNCT3, vmul_vv::FUNCT6, _) => Ok(InstructionDecoded::VmulVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmul_vx::FUNCT3, vmul_vx::FUNCT6, _) => Ok(InstructionDecoded::VmulVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmulhsu_vv::FUNCT3, vmulhsu_vv::FUNCT6, _) => Ok(InstructionDecoded::VmulhsuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmulhsu_vx::FUNCT3, vmulhsu_vx::FUNCT6, _) => Ok(InstructionDecoded::VmulhsuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmulh_vv::FUNCT3, vmulh_vv::FUNCT6, _) => Ok(InstructionDecoded::VmulhVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmulh_vx::FUNCT3, vmulh_vx::FUNCT6, _) => Ok(InstructionDecoded::VmulhVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmadd_vv::FUNCT3, vmadd_vv::FUNCT6, _) => Ok(InstructionDecoded::VmaddVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmadd_vx::FUNCT3, vmadd_vx::FUNCT6, _) => Ok(InstructionDecoded::VmaddVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vnmsub_vv::FUNCT3, vnmsub_vv::FUNCT6, _) => Ok(InstructionDecoded::VnmsubVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vnmsub_vx::FUNCT3, vnmsub_vx::FUNCT6, _) => Ok(InstructionDecoded::VnmsubVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vmacc_vv::FUNCT3, vmacc_vv::FUNCT6, _) => Ok(InstructionDecoded::VmaccVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vmacc_vx::FUNCT3, vmacc_vx::FUNCT6, _) => Ok(InstructionDecoded::VmaccVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vnmsac_vv::FUNCT3, vnmsac_vv::FUNCT6, _) => Ok(InstructionDecoded::VnmsacVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vnmsac_vx::FUNCT3, vnmsac_vx::FUNCT6, _) => Ok(InstructionDecoded::VnmsacVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwaddu_vv::FUNCT3, vwaddu_vv::FUNCT6, _) => Ok(InstructionDecoded::VwadduVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwaddu_vx::FUNCT3, vwaddu_vx::FUNCT6, _) => Ok(InstructionDecoded::VwadduVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwadd_vv::FUNCT3, vwadd_vv::FUNCT6, _) => Ok(InstructionDecoded::VwaddVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwadd_vx::FUNCT3, vwadd_vx::FUNCT6, _) => Ok(InstructionDecoded::VwaddVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsubu_vv::FUNCT3, vwsubu_vv::FUNCT6, _) => Ok(InstructionDecoded::VwsubuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsubu_vx::FUNCT3, vwsubu_vx::FUNCT6, _) => Ok(InstructionDecoded::VwsubuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsub_vv::FUNCT3, vwsub_vv::FUNCT6, _) => Ok(InstructionDecoded::VwsubVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsub_vx::FUNCT3, vwsub_vx::FUNCT6, _) => Ok(InstructionDecoded::VwsubVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwaddu_wv::FUNCT3, vwaddu_wv::FUNCT6, _) => Ok(InstructionDecoded::VwadduWv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwaddu_wx::FUNCT3, vwaddu_wx::FUNCT6, _) => Ok(InstructionDecoded::VwadduWx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwadd_wv::FUNCT3, vwadd_wv::FUNCT6, _) => Ok(InstructionDecoded::VwaddWv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwadd_wx::FUNCT3, vwadd_wx::FUNCT6, _) => Ok(InstructionDecoded::VwaddWx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsubu_wv::FUNCT3, vwsubu_wv::FUNCT6, _) => Ok(InstructionDecoded::VwsubuWv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsubu_wx::FUNCT3, vwsubu_wx::FUNCT6, _) => Ok(InstructionDecoded::VwsubuWx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsub_wv::FUNCT3, vwsub_wv::FUNCT6, _) => Ok(InstructionDecoded::VwsubWv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwsub_wx::FUNCT3, vwsub_wx::FUNCT6, _) => Ok(InstructionDecoded::VwsubWx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmulu_vv::FUNCT3, vwmulu_vv::FUNCT6, _) => Ok(InstructionDecoded::VwmuluVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmulu_vx::FUNCT3, vwmulu_vx::FUNCT6, _) => Ok(InstructionDecoded::VwmuluVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmulsu_vv::FUNCT3, vwmulsu_vv::FUNCT6, _) => Ok(InstructionDecoded::VwmulsuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmulsu_vx::FUNCT3, vwmulsu_vx::FUNCT6, _) => Ok(InstructionDecoded::VwmulsuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmul_vv::FUNCT3, vwmul_vv::FUNCT6, _) => Ok(InstructionDecoded::VwmulVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmul_vx::FUNCT3, vwmul_vx::FUNCT6, _) => Ok(InstructionDecoded::VwmulVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmaccu_vv::FUNCT3, vwmaccu_vv::FUNCT6, _) => Ok(InstructionDecoded::VwmaccuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmaccu_vx::FUNCT3, vwmaccu_vx::FUNCT6, _) => Ok(InstructionDecoded::VwmaccuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmacc_vv::FUNCT3, vwmacc_vv::FUNCT6, _) => Ok(InstructionDecoded::VwmaccVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmacc_vx::FUNCT3, vwmacc_vx::FUNCT6, _) => Ok(InstructionDecoded::VwmaccVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmaccus_vx::FUNCT3, vwmaccus_vx::FUNCT6, _) => Ok(InstructionDecoded::VwmaccusVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmaccsu_vv::FUNCT3, vwmaccsu_vv::FUNCT6, _) => Ok(InstructionDecoded::VwmaccsuVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        (vwmaccsu_vx::FUNCT3, vwmaccsu_vx::FUNCT6, _) => Ok(InstructionDecoded::VwmaccsuVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Vector OPMVV/OPMVX instruction"),
+    }
+}
+
+// vset{i}vl{i}, the top bits tell them apart
+fn decode_vector_config(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let rinst = rtype::RType::new(inst);
+    if !is_set(inst, 31) {
+        Ok(InstructionDecoded::Vsetvli {
+            rd: rinst.rd(),
+            rs1: rinst.rs1(),
+            vtypei: get_bits(inst, 11, 20),
+        })
+    } else if get_bits(inst, 2, 30) == vsetivli::TOP {
+        Ok(InstructionDecoded::Vsetivli {
+            rd: rinst.rd(),
+            uimm: rinst.rs1(),
+            vtypei: get_bits(inst, 10, 20),
+        })
+    } else if rinst.funct7() == vsetvl::FUNCT7 {
+        Ok(InstructionDecoded::Vsetvl {
+            rd: rinst.rd(),
+            rs1: rinst.rs1(),
+            rs2: rinst.rs2(),
+        })
+    } else {
+        Err(DecodeError::UnknownInstructionFormat).context("Unknown Vector configuration instruction")
+    }
+}
+
+// the width field of vector loads and stores, scalar fp ones use 1 to 4
+const VECTOR_WIDTHS: [InstructionSize; 4] = [0, 5, 6, 7];
+
+// vector loads and stores, they share LOAD-FP/STORE-FP with the scalar fp ones
+fn decode_vector_memory(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let vinst = vmemtype::VMemType::new(inst);
+    // mew is for element widths above 64 bits, which are reserved
+    if vinst.mew() != 0 {
+        return Err(DecodeError::UnknownInstructionFormat).context("Reserved vector element width (mew=1)");
+    }
+    let masked = vinst.vm() == 0;
+    match (vinst.opcode(), vinst.mop(), vinst.width(), vinst.rs2()) {
+        (LOAD_FP_MATCH, vle8_v::MOP, vle8_v::WIDTH, vle8_v::LUMOP) => Ok(InstructionDecoded::Vle8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vle16_v::MOP, vle16_v::WIDTH, vle16_v::LUMOP) => Ok(InstructionDecoded::Vle16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vle32_v::MOP, vle32_v::WIDTH, vle32_v::LUMOP) => Ok(InstructionDecoded::Vle32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vle64_v::MOP, vle64_v::WIDTH, vle64_v::LUMOP) => Ok(InstructionDecoded::Vle64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vle8ff_v::MOP, vle8ff_v::WIDTH, vle8ff_v::LUMOP) => Ok(InstructionDecoded::Vle8ffV {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vle16ff_v::MOP, vle16ff_v::WIDTH, vle16ff_v::LUMOP) => Ok(InstructionDecoded::Vle16ffV {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vle32ff_v::MOP, vle32ff_v::WIDTH, vle32ff_v::LUMOP) => Ok(InstructionDecoded::Vle32ffV {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vle64ff_v::MOP, vle64ff_v::WIDTH, vle64ff_v::LUMOP) => Ok(InstructionDecoded::Vle64ffV {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vse8_v::MOP, vse8_v::WIDTH, vse8_v::SUMOP) => Ok(InstructionDecoded::Vse8V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vse16_v::MOP, vse16_v::WIDTH, vse16_v::SUMOP) => Ok(InstructionDecoded::Vse16V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vse32_v::MOP, vse32_v::WIDTH, vse32_v::SUMOP) => Ok(InstructionDecoded::Vse32V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vse64_v::MOP, vse64_v::WIDTH, vse64_v::SUMOP) => Ok(InstructionDecoded::Vse64V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vlse8_v::MOP, vlse8_v::WIDTH, _) => Ok(InstructionDecoded::Vlse8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vlse16_v::MOP, vlse16_v::WIDTH, _) => Ok(InstructionDecoded::Vlse16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vlse32_v::MOP, vlse32_v::WIDTH, _) => Ok(InstructionDecoded::Vlse32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vlse64_v::MOP, vlse64_v::WIDTH, _) => Ok(InstructionDecoded::Vlse64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsse8_v::MOP, vsse8_v::WIDTH, _) => Ok(InstructionDecoded::Vsse8V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsse16_v::MOP, vsse16_v::WIDTH, _) => Ok(InstructionDecoded::Vsse16V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsse32_v::MOP, vsse32_v::WIDTH, _) => Ok(InstructionDecoded::Vsse32V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsse64_v::MOP, vsse64_v::WIDTH, _) => Ok(InstructionDecoded::Vsse64V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            rs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vluxei8_v::MOP, vluxei8_v::WIDTH, _) => Ok(InstructionDecoded::Vluxei8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vluxei16_v::MOP, vluxei16_v::WIDTH, _) => Ok(InstructionDecoded::Vluxei16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vluxei32_v::MOP, vluxei32_v::WIDTH, _) => Ok(InstructionDecoded::Vluxei32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vluxei64_v::MOP, vluxei64_v::WIDTH, _) => Ok(InstructionDecoded::Vluxei64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vloxei8_v::MOP, vloxei8_v::WIDTH, _) => Ok(InstructionDecoded::Vloxei8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vloxei16_v::MOP, vloxei16_v::WIDTH, _) => Ok(InstructionDecoded::Vloxei16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vloxei32_v::MOP, vloxei32_v::WIDTH, _) => Ok(InstructionDecoded::Vloxei32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (LOAD_FP_MATCH, vloxei64_v::MOP, vloxei64_v::WIDTH, _) => Ok(InstructionDecoded::Vloxei64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsuxei8_v::MOP, vsuxei8_v::WIDTH, _) => Ok(InstructionDecoded::Vsuxei8V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsuxei16_v::MOP, vsuxei16_v::WIDTH, _) => Ok(InstructionDecoded::Vsuxei16V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsuxei32_v::MOP, vsuxei32_v::WIDTH, _) => Ok(InstructionDecoded::Vsuxei32V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsuxei64_v::MOP, vsuxei64_v::WIDTH, _) => Ok(InstructionDecoded::Vsuxei64V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsoxei8_v::MOP, vsoxei8_v::WIDTH, _) => Ok(InstructionDecoded::Vsoxei8V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsoxei16_v::MOP, vsoxei16_v::WIDTH, _) => Ok(InstructionDecoded::Vsoxei16V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsoxei32_v::MOP, vsoxei32_v::WIDTH, _) => Ok(InstructionDecoded::Vsoxei32V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        (STORE_FP_MATCH, vsoxei64_v::MOP, vsoxei64_v::WIDTH, _) => Ok(InstructionDecoded::Vsoxei64V {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+            vs2: vinst.rs2(),
+            nf: vinst.nf(),
+            masked,
+        }),
+        // the whole register insts need vd/vs3 aligned to the number of registers
+        (LOAD_FP_MATCH, vl1re8_v::MOP, vl1re8_v::WIDTH, vl1re8_v::LUMOP) if vinst.nf() == vl1re8_v::NF && vinst.vd().is_multiple_of(vl1re8_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl1re8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl1re16_v::MOP, vl1re16_v::WIDTH, vl1re16_v::LUMOP) if vinst.nf() == vl1re16_v::NF && vinst.vd().is_multiple_of(vl1re16_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl1re16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl1re32_v::MOP, vl1re32_v::WIDTH, vl1re32_v::LUMOP) if vinst.nf() == vl1re32_v::NF && vinst.vd().is_multiple_of(vl1re32_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl1re32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl1re64_v::MOP, vl1re64_v::WIDTH, vl1re64_v::LUMOP) if vinst.nf() == vl1re64_v::NF && vinst.vd().is_multiple_of(vl1re64_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl1re64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl2re8_v::MOP, vl2re8_v::WIDTH, vl2re8_v::LUMOP) if vinst.nf() == vl2re8_v::NF && vinst.vd().is_multiple_of(vl2re8_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl2re8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl2re16_v::MOP, vl2re16_v::WIDTH, vl2re16_v::LUMOP) if vinst.nf() == vl2re16_v::NF && vinst.vd().is_multiple_of(vl2re16_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl2re16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl2re32_v::MOP, vl2re32_v::WIDTH, vl2re32_v::LUMOP) if vinst.nf() == vl2re32_v::NF && vinst.vd().is_multiple_of(vl2re32_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl2re32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl2re64_v::MOP, vl2re64_v::WIDTH, vl2re64_v::LUMOP) if vinst.nf() == vl2re64_v::NF && vinst.vd().is_multiple_of(vl2re64_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl2re64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl4re8_v::MOP, vl4re8_v::WIDTH, vl4re8_v::LUMOP) if vinst.nf() == vl4re8_v::NF && vinst.vd().is_multiple_of(vl4re8_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl4re8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl4re16_v::MOP, vl4re16_v::WIDTH, vl4re16_v::LUMOP) if vinst.nf() == vl4re16_v::NF && vinst.vd().is_multiple_of(vl4re16_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl4re16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl4re32_v::MOP, vl4re32_v::WIDTH, vl4re32_v::LUMOP) if vinst.nf() == vl4re32_v::NF && vinst.vd().is_multiple_of(vl4re32_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl4re32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl4re64_v::MOP, vl4re64_v::WIDTH, vl4re64_v::LUMOP) if vinst.nf() == vl4re64_v::NF && vinst.vd().is_multiple_of(vl4re64_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl4re64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl8re8_v::MOP, vl8re8_v::WIDTH, vl8re8_v::LUMOP) if vinst.nf() == vl8re8_v::NF && vinst.vd().is_multiple_of(vl8re8_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl8re8V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl8re16_v::MOP, vl8re16_v::WIDTH, vl8re16_v::LUMOP) if vinst.nf() == vl8re16_v::NF && vinst.vd().is_multiple_of(vl8re16_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl8re16V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl8re32_v::MOP, vl8re32_v::WIDTH, vl8re32_v::LUMOP) if vinst.nf() == vl8re32_v::NF && vinst.vd().is_multiple_of(vl8re32_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl8re32V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (LOAD_FP_MATCH, vl8re64_v::MOP, vl8re64_v::WIDTH, vl8re64_v::LUMOP) if vinst.nf() == vl8re64_v::NF && vinst.vd().is_multiple_of(vl8re64_v::NF + 1) && !masked => Ok(InstructionDecoded::Vl8re64V {
+            vd: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (STORE_FP_MATCH, vs1r_v::MOP, vs1r_v::WIDTH, vs1r_v::SUMOP) if vinst.nf() == vs1r_v::NF && vinst.vd().is_multiple_of(vs1r_v::NF + 1) && !masked => Ok(InstructionDecoded::Vs1rV {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (STORE_FP_MATCH, vs2r_v::MOP, vs2r_v::WIDTH, vs2r_v::SUMOP) if vinst.nf() == vs2r_v::NF && vinst.vd().is_multiple_of(vs2r_v::NF + 1) && !masked => Ok(InstructionDecoded::Vs2rV {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (STORE_FP_MATCH, vs4r_v::MOP, vs4r_v::WIDTH, vs4r_v::SUMOP) if vinst.nf() == vs4r_v::NF && vinst.vd().is_multiple_of(vs4r_v::NF + 1) && !masked => Ok(InstructionDecoded::Vs4rV {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        (STORE_FP_MATCH, vs8r_v::MOP, vs8r_v::WIDTH, vs8r_v::SUMOP) if vinst.nf() == vs8r_v::NF && vinst.vd().is_multiple_of(vs8r_v::NF + 1) && !masked => Ok(InstructionDecoded::Vs8rV {
+            vs3: vinst.vd(),
+            rs1: vinst.rs1(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Vector load/store instruction"),
+    }
+}
+
+pub fn decode_vector(inst: InstructionSize) -> Result<InstructionDecoded> {
+    let vinst = opvtype::OpVType::new(inst);
+    #[cfg(feature = "zvk")]
+    let masked = vinst.vm() == 0;
+    if let LOAD_FP_MATCH | STORE_FP_MATCH = vinst.opcode() {
+        return decode_vector_memory(inst);
+    }
+    // none of the vector crypto instructions can be masked
+    if vinst.opcode() == OP_VE_MATCH && vinst.vm() == 0 {
+        return Err(DecodeError::UnknownInstructionFormat)
+            .context("Vector crypto instructions must be unmasked (vm=1)");
+    }
+    if vinst.opcode() == OP_V_MATCH && vinst.funct3() == vsetvli::FUNCT3 {
+        return decode_vector_config(inst);
+    }
+    if vinst.opcode() == OP_V_MATCH && matches!(vinst.funct3(), OPMVV | OPMVX) {
+        return decode_vector_opm(inst);
+    }
+    if vinst.opcode() == OP_V_MATCH && matches!(vinst.funct3(), OPFVV | OPFVF) {
+        return decode_vector_opf(inst);
+    }
+    match (vinst.opcode(), vinst.funct3(), vinst.funct6(), vinst.vs1()) {
+        // both register groups have to be aligned to their size
+        (OP_V_MATCH, vmv1r_v::FUNCT3, vmv1r_v::FUNCT6, vmv1r_v::SIMM) if vinst.vm() == 1 && vinst.vd().is_multiple_of(vmv1r_v::SIMM + 1) && vinst.vs2().is_multiple_of(vmv1r_v::SIMM + 1) => Ok(InstructionDecoded::Vmv1rV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+        }),
+        (OP_V_MATCH, vmv2r_v::FUNCT3, vmv2r_v::FUNCT6, vmv2r_v::SIMM) if vinst.vm() == 1 && vinst.vd().is_multiple_of(vmv2r_v::SIMM + 1) && vinst.vs2().is_multiple_of(vmv2r_v::SIMM + 1) => Ok(InstructionDecoded::Vmv2rV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+        }),
+        (OP_V_MATCH, vmv4r_v::FUNCT3, vmv4r_v::FUNCT6, vmv4r_v::SIMM) if vinst.vm() == 1 && vinst.vd().is_multiple_of(vmv4r_v::SIMM + 1) && vinst.vs2().is_multiple_of(vmv4r_v::SIMM + 1) => Ok(InstructionDecoded::Vmv4rV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+        }),
+        (OP_V_MATCH, vmv8r_v::FUNCT3, vmv8r_v::FUNCT6, vmv8r_v::SIMM) if vinst.vm() == 1 && vinst.vd().is_multiple_of(vmv8r_v::SIMM + 1) && vinst.vs2().is_multiple_of(vmv8r_v::SIMM + 1) => Ok(InstructionDecoded::Vmv8rV {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+        }),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vandn_vv::FUNCT3, vandn_vv::FUNCT6, _) => Ok(InstructionDecoded::VandnVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vandn_vx::FUNCT3, vandn_vx::FUNCT6, _) => Ok(InstructionDecoded::VandnVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vror_vv::FUNCT3, vror_vv::FUNCT6, _) => Ok(InstructionDecoded::VrorVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vror_vx::FUNCT3, vror_vx::FUNCT6, _) => Ok(InstructionDecoded::VrorVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
+        }),
+        // the rotate amount is 6 bits, its top bit is the bottom bit of funct6
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vror_vi::FUNCT3, funct6, _) if funct6 >> 1 == vror_vi::FUNCT5 => Ok(InstructionDecoded::VrorVi {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            uimm: (funct6 & 1) << 5 | vinst.vs1(),
+            masked,
+        }),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vrol_vv::FUNCT3, vrol_vv::FUNCT6, _) => Ok(InstructionDecoded::VrolVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
         }),
-        (LOAD_MATCH, lw::FUNCT3, _) => Ok(InstructionDecoded::Lw {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vrol_vx::FUNCT3, vrol_vx::FUNCT6, _) => Ok(InstructionDecoded::VrolVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
         }),
-        (LOAD_MATCH, lbu::FUNCT3, _) => Ok(InstructionDecoded::Lbu {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vwsll_vv::FUNCT3, vwsll_vv::FUNCT6, _) => Ok(InstructionDecoded::VwsllVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
+            masked,
         }),
-        (LOAD_MATCH, lhu::FUNCT3, _) => Ok(InstructionDecoded::Lhu {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vwsll_vx::FUNCT3, vwsll_vx::FUNCT6, _) => Ok(InstructionDecoded::VwsllVx {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            rs1: vinst.vs1(),
+            masked,
         }),
-        (JALR_MATCH, jalr::FUNCT3, _) => Ok(InstructionDecoded::Jalr {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_V_MATCH, vwsll_vi::FUNCT3, vwsll_vi::FUNCT6, _) => Ok(InstructionDecoded::VwsllVi {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            uimm: vinst.vs1(),
+            masked,
         }),
-        (FENCE_MATCH, fence::FUNCT3, _) => {
-            let pred = get_bits(iinst.imm(), 4, 0);
-            let succ = get_bits(iinst.imm() >> 4, 4, 0);
-            Ok(InstructionDecoded::Fence { pred, succ })
-        }
-        (FENCE_MATCH, fence_i::FUNCT3, _) => {
-            let pred = get_bits(iinst.imm(), 4, 0);
-            let succ = get_bits(iinst.imm(), 4, 4);
-            Ok(InstructionDecoded::FenceI { pred, succ })
-        }
-        (CSR_MATCH, csrrw::FUNCT3, _) => Ok(InstructionDecoded::CsrRw {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.uimm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vghsh_vv::FUNCT3, vghsh_vv::FUNCT6, _) => Ok(InstructionDecoded::VghshVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
         }),
-        (CSR_MATCH, csrrs::FUNCT3, _) => Ok(InstructionDecoded::CsrRs {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.uimm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vgmul_vv::FUNCT3, vgmul_vv::FUNCT6, vgmul_vv::VS1) => Ok(InstructionDecoded::VgmulVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        (CSR_MATCH, csrrc::FUNCT3, _) => Ok(InstructionDecoded::CsrRc {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.uimm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesdf_vv::FUNCT3, vaesdf_vv::FUNCT6, vaesdf_vv::VS1) => Ok(InstructionDecoded::VaesdfVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        (CSR_MATCH, csrrwi::FUNCT3, _) => Ok(InstructionDecoded::CsrRwi {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.uimm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesdf_vs::FUNCT3, vaesdf_vs::FUNCT6, vaesdf_vs::VS1) => Ok(InstructionDecoded::VaesdfVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        (CSR_MATCH, csrrsi::FUNCT3, _) => Ok(InstructionDecoded::CsrRsi {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.uimm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesdm_vv::FUNCT3, vaesdm_vv::FUNCT6, vaesdm_vv::VS1) => Ok(InstructionDecoded::VaesdmVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        (CSR_MATCH, csrrci::FUNCT3, _) => Ok(InstructionDecoded::CsrRci {
-            rd: iinst.rd(),
-            rs1: iinst.rs1(),
-            imm: iinst.uimm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesdm_vs::FUNCT3, vaesdm_vs::FUNCT6, vaesdm_vs::VS1) => Ok(InstructionDecoded::VaesdmVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        // e-insts (ebreak, ecall)
-        (CSR_MATCH, sfencevma::FUNCT3, sfencevma::IMM) => Ok(InstructionDecoded::SFenceVma),
-        (CSR_MATCH, ebreak::FUNCT3, ebreak::IMM) => Ok(InstructionDecoded::EBreak),
-        (CSR_MATCH, ecall::FUNCT3, ecall::IMM) => Ok(InstructionDecoded::ECall),
-        (CSR_MATCH, mret::FUNCT3, mret::IMM) => Ok(InstructionDecoded::MRet),
-        (CSR_MATCH, sret::FUNCT3, sret::IMM) => Ok(InstructionDecoded::SRet),
-        // TODO: SFenceVMA
-        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown I-Type instruction"),
-    }
-}
-
-pub fn decode_stype(inst: InstructionSize) -> Result<InstructionDecoded> {
-    let sinst = stype::SType::new(inst);
-    match sinst.funct3() {
-        sb::FUNCT3 => Ok(InstructionDecoded::Sb {
-            rs1: sinst.rs1(),
-            rs2: sinst.rs2(),
-            imm: sinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesef_vv::FUNCT3, vaesef_vv::FUNCT6, vaesef_vv::VS1) => Ok(InstructionDecoded::VaesefVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        sh::FUNCT3 => Ok(InstructionDecoded::Sh {
-            rs1: sinst.rs1(),
-            rs2: sinst.rs2(),
-            imm: sinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesef_vs::FUNCT3, vaesef_vs::FUNCT6, vaesef_vs::VS1) => Ok(InstructionDecoded::VaesefVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        sw::FUNCT3 => Ok(InstructionDecoded::Sw {
-            rs1: sinst.rs1(),
-            rs2: sinst.rs2(),
-            imm: sinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesem_vv::FUNCT3, vaesem_vv::FUNCT6, vaesem_vv::VS1) => Ok(InstructionDecoded::VaesemVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown S-Type instruction"),
-    }
-}
-
-pub fn decode_utype(inst: InstructionSize) -> Result<InstructionDecoded> {
-    let uinst = utype::UType::new(inst);
-    match uinst.opcode() {
-        LUI_MATCH => Ok(InstructionDecoded::Lui {
-            rd: uinst.rd(),
-            imm: uinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesem_vs::FUNCT3, vaesem_vs::FUNCT6, vaesem_vs::VS1) => Ok(InstructionDecoded::VaesemVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        AUIPC_MATCH => Ok(InstructionDecoded::AuiPc {
-            rd: uinst.rd(),
-            imm: uinst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaesz_vs::FUNCT3, vaesz_vs::FUNCT6, vaesz_vs::VS1) => Ok(InstructionDecoded::VaeszVs {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
         }),
-        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown U-Type instruction"),
-    }
-}
-
-pub fn decode_btype(inst: InstructionSize) -> Result<InstructionDecoded> {
-    let binst = btype::BType::new(inst);
-    match binst.funct3() {
-        beq::FUNCT3 => Ok(InstructionDecoded::Beq {
-            rs1: binst.rs1(),
-            rs2: binst.rs2(),
-            imm: binst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaeskf1_vi::FUNCT3, vaeskf1_vi::FUNCT6, _) => Ok(InstructionDecoded::Vaeskf1Vi {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            uimm: vinst.vs1(),
         }),
-        bne::FUNCT3 => Ok(InstructionDecoded::Bne {
-            rs1: binst.rs1(),
-            rs2: binst.rs2(),
-            imm: binst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vaeskf2_vi::FUNCT3, vaeskf2_vi::FUNCT6, _) => Ok(InstructionDecoded::Vaeskf2Vi {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            uimm: vinst.vs1(),
         }),
-        blt::FUNCT3 => Ok(InstructionDecoded::Blt {
-            rs1: binst.rs1(),
-            rs2: binst.rs2(),
-            imm: binst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vsha2ms_vv::FUNCT3, vsha2ms_vv::FUNCT6, _) => Ok(InstructionDecoded::Vsha2msVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
         }),
-        bge::FUNCT3 => Ok(InstructionDecoded::Bge {
-            rs1: binst.rs1(),
-            rs2: binst.rs2(),
-            imm: binst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vsha2ch_vv::FUNCT3, vsha2ch_vv::FUNCT6, _) => Ok(InstructionDecoded::Vsha2chVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
         }),
-        bltu::FUNCT3 => Ok(InstructionDecoded::Bltu {
-            rs1: binst.rs1(),
-            rs2: binst.rs2(),
-            imm: binst.imm(),
+        #[cfg(feature = "zvk")]
+        (OP_VE_MATCH, vsha2cl_vv::FUNCT3, vsha2cl_vv::FUNCT6, _) => Ok(InstructionDecoded::Vsha2clVv {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            vs1: vinst.vs1(),
         }),
-        bgeu::FUNCT3 => Ok(InstructionDecoded::Bgeu {
-            rs1: binst.rs1(),
-            rs2: binst.rs2(),
-            imm: binst.imm(),
+        (OP_VE_MATCH, vsm4k_vi::FUNCT3, vsm4k_vi::FUNCT6, _) => Ok(InstructionDecoded::Vsm4kVi {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            uimm: vinst.vs1(),
         }),
-        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown B-Type instruction"),
-    }
-}
-
-pub fn decode_jtype(inst: InstructionSize) -> Result<InstructionDecoded> {
-    let jinst = jtype::JType::new(inst);
-    match jinst.opcode() {
-        JAL_MATCH => Ok(InstructionDecoded::Jal {
-            rd: jinst.rd(),
-            imm: jinst.imm(),
+        (OP_VE_MATCH, vsm4r_vv::FUNCT3, vsm4r_vv::FUNCT6, vsm4r_vv::VS1) => {
+            Ok(InstructionDecoded::Vsm4rVv {
+                vd: vinst.vd(),
+                vs2: vinst.vs2(),
+            })
+        }
+        (OP_VE_MATCH, vsm4r_vs::FUNCT3, vsm4r_vs::FUNCT6, vsm4r_vs::VS1) => {
+            Ok(InstructionDecoded::Vsm4rVs {
+                vd: vinst.vd(),
+                vs2: vinst.vs2(),
+            })
+        }
+        (OP_VE_MATCH, vsm3me_vv::FUNCT3, vsm3me_vv::FUNCT6, _) => {
+            Ok(InstructionDecoded::Vsm3meVv {
+                vd: vinst.vd(),
+                vs2: vinst.vs2(),
+                vs1: vinst.vs1(),
+            })
+        }
+        (OP_VE_MATCH, vsm3c_vi::FUNCT3, vsm3c_vi::FUNCT6, _) => Ok(InstructionDecoded::Vsm3cVi {
+            vd: vinst.vd(),
+            vs2: vinst.vs2(),
+            uimm: vinst.vs1(),
         }),
-        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown J-Type instruction"),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown Vector instruction"),
     }
 }
 
 pub fn try_decode(inst: InstructionSize) -> Result<InstructionDecoded> {
     // if its a compressed inst then dont bother with regular decoding, instead decode it as compressed and return the result
-    match inst & COMPRESSED_MASK {
+    if let 0..=2 = inst & COMPRESSED_MASK {
         // its a compressed instruction
-        0 | 1 | 2 => return try_decode_compressed(inst),
-        // otherwise just continue with regular decoding
-        _ => (),
+        return try_decode_compressed(inst as compressed::CompressedSize);
     }
 
     let fmt = match inst & OPCODE_MASK {
-        FLOATING_POINT_MATCH | ATOMIC_MATCH | ARITMETIC_REGISTER_MATCH => InstructionFormat::RType,
-        STORE_MATCH => InstructionFormat::SType,
+        LOAD_FP_MATCH | STORE_FP_MATCH if VECTOR_WIDTHS.contains(&get_bits(inst, 3, 12)) => InstructionFormat::OpV,
+        #[cfg(feature = "p-ext")]
+        OP_P_MATCH if get_bits(inst, 3, 12) != OPMVV => InstructionFormat::RType,
+        #[cfg(feature = "thead")]
+        CUSTOM_0_MATCH => InstructionFormat::RType,
+        FLOATING_POINT_MATCH | ATOMIC_MATCH | ARITMETIC_REGISTER_MATCH | ARITMETIC_REGISTER_WORD_MATCH => InstructionFormat::RType,
+        STORE_MATCH | STORE_FP_MATCH => InstructionFormat::SType,
         BRANCH_MATCH => InstructionFormat::BType,
         JAL_MATCH => InstructionFormat::JType,
-        ARITMETIC_IMMEDIATE_MATCH | FENCE_MATCH | LOAD_MATCH | CSR_MATCH | JALR_MATCH => {
+        ARITMETIC_IMMEDIATE_MATCH | ARITMETIC_IMMEDIATE_WORD_MATCH | FENCE_MATCH | LOAD_MATCH | LOAD_FP_MATCH | CSR_MATCH | JALR_MATCH => {
             InstructionFormat::IType
         }
         LUI_MATCH | AUIPC_MATCH => InstructionFormat::UType,
+        FMADD_MATCH | FMSUB_MATCH | FNMSUB_MATCH | FNMADD_MATCH => InstructionFormat::R4Type,
+        OP_V_MATCH | OP_VE_MATCH => InstructionFormat::OpV,
         _ => Err(DecodeError::UnknownInstructionFormat)
             .context(format!("Failed to decode inst {inst}"))?,
     };
@@ -448,14 +4027,622 @@ pub fn try_decode(inst: InstructionSize) -> Result<InstructionDecoded> {
         InstructionFormat::UType => decode_utype(inst)?,
         InstructionFormat::BType => decode_btype(inst)?,
         InstructionFormat::JType => decode_jtype(inst)?,
+        InstructionFormat::R4Type => decode_r4type(inst)?,
+        InstructionFormat::OpV => decode_vector(inst)?,
+    };
+
+    Ok(inst)
+}
+
+// instructions that have a variant but no decode arm yet, they're left out of `Decoder::supported`
+const NOT_DECODED: &[&str] = &[
+    "fsqrt.s", "mulhsu", "div",
+    "divu", "rem", "remu",
+];
+
+// anything instruction bits can be held in. bytes are little endian the same way they'd be in
+// memory, 16 bit inputs only hold compressed instructions
+pub trait Decode {
+    fn bits(self) -> Result<InstructionSize>;
+}
+
+impl Decode for InstructionSize {
+    fn bits(self) -> Result<InstructionSize> {
+        Ok(self)
+    }
+}
+
+impl Decode for u16 {
+    fn bits(self) -> Result<InstructionSize> {
+        let inst = self as InstructionSize;
+        if !compressed::is_compressed(inst) {
+            return Err(DecodeError::UnknownInstruction)
+                .context("Only half of a 32 bit instruction");
+        }
+        Ok(inst)
+    }
+}
+
+impl Decode for [u8; 2] {
+    fn bits(self) -> Result<InstructionSize> {
+        u16::from_le_bytes(self).bits()
+    }
+}
+
+impl Decode for [u8; 4] {
+    fn bits(self) -> Result<InstructionSize> {
+        Ok(InstructionSize::from_le_bytes(self))
+    }
+}
+
+// decodes the instruction at the start of the slice, anything after it is ignored
+impl Decode for &[u8] {
+    fn bits(self) -> Result<InstructionSize> {
+        match self {
+            [a, b, ..] if compressed::is_compressed(*a as InstructionSize) => [*a, *b].bits(),
+            [a, b, c, d, ..] => [*a, *b, *c, *d].bits(),
+            _ => Err(DecodeError::UnknownInstruction).context("Instruction is cut off"),
+        }
+    }
+}
+
+// instructions that only exist on rv32
+const RV32_ONLY: &[&str] = &[
+    "zip", "unzip", "aes32esi", "aes32esmi", "aes32dsi", "aes32dsmi",
+    "sha512sum0r", "sha512sum1r", "sha512sig0l", "sha512sig1l", "sha512sig0h", "sha512sig1h",
+    "c.flw", "c.fsw", "c.jal", "c.flwsp", "c.fswsp",
+];
+
+// instructions that only exist on rv64, they're illegal when decoding for a 32 bit hart
+const RV64_ONLY: &[&str] = &[
+    "lwu", "ld", "sd", "addiw", "slliw", "srliw", "sraiw", "addw", "subw", "sllw", "srlw", "sraw",
+    "mulw", "divw", "divuw", "remw", "remuw", "lr.d", "sc.d", "amoswap.d", "amoadd.d", "amoxor.d",
+    "amoand.d", "amoor.d", "amomin.d", "amomax.d", "amominu.d", "amomaxu.d", "fmv.x.d", "fmv.d.x",
+    "fcvt.l.s", "fcvt.lu.s", "fcvt.s.l", "fcvt.s.lu", "fcvt.l.d", "fcvt.lu.d", "fcvt.d.l", "fcvt.d.lu",
+    "fcvt.l.q", "fcvt.lu.q", "fcvt.q.l", "fcvt.q.lu", "fcvt.l.h", "fcvt.lu.h", "fcvt.h.l", "fcvt.h.lu",
+    "rolw", "rorw", "roriw", "clzw", "ctzw", "cpopw", "add.uw", "sh1add.uw", "sh2add.uw", "sh3add.uw", "slli.uw", "packw",
+    "aes64es", "aes64esm", "aes64ds", "aes64dsm", "aes64ks2", "aes64im", "aes64ks1i",
+    "sha512sum0", "sha512sum1", "sha512sig0", "sha512sig1",
+    "th.lrd", "th.lurd", "th.ldib", "th.ldia", "th.lrwu", "th.lurwu", "th.lwuib", "th.lwuia", "th.srd", "th.surd", "th.sdib", "th.sdia",
+    "ssamoswap.d", "c.addiw", "c.ld", "c.sd", "c.ldsp", "c.sdsp", "c.subw", "c.addw", "c.zext.w",
+];
+
+// the width of the integer registers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Xlen {
+    #[default]
+    Rv32,
+    Rv64,
+}
+
+// how much of the half precision extensions a hart has
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HalfPrecision {
+    // all of Zfh
+    #[default]
+    Zfh,
+    // only the Zfhmin loads, stores, moves and conversions
+    Zfhmin,
+}
+
+// Zcmp and Zcmt reuse the encodings of c.fsdsp so a hart has one or the other
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompressedDouble {
+    // c.fld, c.fsd, c.fldsp and c.fsdsp
+    #[default]
+    Zcd,
+    // cm.push, cm.pop and the rest of Zcmp, along with the Zcmt table jumps, instead
+    Zcmp,
+}
+
+// the instructions that go away with `CompressedDouble::Zcmp`
+const ZCD: &[&str] = &["c.fld", "c.fsd", "c.fldsp", "c.fsdsp"];
+
+// decodes an instruction from one of the custom opcode spaces, None hands it back to the decoder
+pub type CustomDecoder = fn(InstructionSize) -> Option<InstructionDecoded>;
+
+const CUSTOM_OPCODES: [InstructionSize; 4] =
+    [CUSTOM_0_MATCH, CUSTOM_1_MATCH, CUSTOM_2_MATCH, CUSTOM_3_MATCH];
+
+// decoding settings for a specific hart, the free functions above decode everything they know about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decoder {
+    // whether the C extension is enabled, without it IALIGN is 32 and compressed insts are illegal
+    pub compressed: bool,
+    // rv32 rejects the rv64 only instructions and shift amounts of 32 or more
+    pub xlen: Xlen,
+    pub half_precision: HalfPrecision,
+    pub compressed_double: CompressedDouble,
+    // hand back the 32 bit equivalent of compressed insts (where there is one), see `decompress`
+    pub decompress: bool,
+    // hooks for custom-0..custom-3 in that order, see `register_custom`
+    pub custom: [Option<CustomDecoder>; 4],
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self {
+            compressed: true,
+            xlen: Xlen::default(),
+            half_precision: HalfPrecision::default(),
+            compressed_double: CompressedDouble::default(),
+            decompress: false,
+            custom: [None; 4],
+        }
+    }
+}
+
+impl Decoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the instruction address alignment in bits
+    pub fn ialign(&self) -> InstructionSize {
+        if self.compressed {
+            16
+        } else {
+            32
+        }
+    }
+
+    // checks a fetch (or jump target) address the same way the hart would
+    pub fn check_fetch_alignment(&self, addr: InstructionSize) -> Result<(), Exception> {
+        match addr % (self.ialign() / 8) {
+            0 => Ok(()),
+            _ => Err(Exception::InstructionAddressMisaligned),
+        }
+    }
+
+    // hands everything in a custom opcode space (`CUSTOM_0_MATCH`..`CUSTOM_3_MATCH`) to `hook`
+    // before the built in decoding sees it, a second hook for the same space replaces the first
+    pub fn register_custom(&mut self, opcode: InstructionSize, hook: CustomDecoder) -> Result<()> {
+        let Some(slot) = CUSTOM_OPCODES.iter().position(|&custom| custom == opcode) else {
+            return Err(DecodeError::UnknownInstructionFormat)
+                .context(format!("{opcode:#x} isn't one of the custom opcodes"));
+        };
+        self.custom[slot] = Some(hook);
+        Ok(())
+    }
+
+    fn decode_custom(&self, inst: InstructionSize) -> Option<InstructionDecoded> {
+        let slot = CUSTOM_OPCODES.iter().position(|&custom| custom == inst & OPCODE_MASK)?;
+        self.custom[slot].and_then(|hook| hook(inst))
+    }
+
+    // everything this decoder can produce, for docs, autocompletion and coverage reports
+    pub fn supported(&self) -> impl Iterator<Item = InstructionInfo> + '_ {
+        OpcodeId::all()
+            .filter(|id| !NOT_DECODED.contains(&id.name()))
+            .map(OpcodeId::info)
+            // Zcb, Zcmp, Zcmt and c.fld/c.fsd are compressed too, not just what's tagged C
+            .filter(|info| self.compressed || !compressed::is_compressed(info.match_value))
+            .filter(|info| self.xlen == Xlen::Rv64 || !RV64_ONLY.contains(&info.name))
+            .filter(|info| self.xlen == Xlen::Rv32 || !RV32_ONLY.contains(&info.name))
+            .filter(|info| cfg!(feature = "q") || info.extension != Extension::Q)
+            .filter(|info| cfg!(feature = "p-ext") || info.extension != Extension::P)
+            .filter(|info| {
+                self.custom.iter().any(Option::is_some) || info.extension != Extension::Custom
+            })
+            .filter(|info| {
+                cfg!(feature = "thead")
+                    || !matches!(
+                        info.extension,
+                        Extension::XTheadBa | Extension::XTheadCmo | Extension::XTheadMemIdx
+                    )
+            })
+            .filter(|info| {
+                cfg!(feature = "zvk")
+                    || !matches!(
+                        info.extension,
+                        Extension::Zvbb | Extension::Zvkg | Extension::Zvkned | Extension::Zvknh
+                    )
+            })
+            .filter(|info| {
+                self.half_precision == HalfPrecision::Zfh || info.extension != Extension::Zfh
+            })
+            .filter(|info| match self.compressed_double {
+                CompressedDouble::Zcd => !matches!(info.extension, Extension::Zcmp | Extension::Zcmt),
+                CompressedDouble::Zcmp => !ZCD.contains(&info.name),
+            })
+    }
+
+    pub fn decode<T: Decode>(&self, inst: T) -> Result<InstructionDecoded> {
+        let inst = inst.bits()?;
+        if !self.compressed && compressed::is_compressed(inst) {
+            return Err(DecodeError::UnknownInstructionFormat)
+                .context("Compressed instruction but the C extension is disabled");
+        }
+        if let Some(decoded) = self.decode_custom(inst) {
+            return Ok(decoded);
+        }
+        let zcmp = self.compressed_double == CompressedDouble::Zcmp;
+        let decoded = match self.xlen {
+            _ if zcmp && inst & COMPRESSED_MASK == 2 && get_bits(inst, 3, 13) == c_fsdsp::FUNCT3 => {
+                try_decode_zcmp(inst as compressed::CompressedSize, self.xlen)?
+            }
+            Xlen::Rv64 if compressed::is_compressed(inst) => {
+                try_decode_compressed_rv64(inst as compressed::CompressedSize)?
+            }
+            _ => try_decode(inst)?,
+        };
+        if zcmp && ZCD.contains(&decoded.opcode_id().name()) {
+            return Err(DecodeError::UnknownInstruction)
+                .context("Zcmp takes the place of the compressed double precision loads and stores");
+        }
+        if self.half_precision == HalfPrecision::Zfhmin
+            && decoded.opcode_id().info().extension == Extension::Zfh
+        {
+            return Err(DecodeError::UnsupportedExtension(Extension::Zfh))
+                .context("Only Zfhmin is enabled");
+        }
+        if self.xlen == Xlen::Rv32 {
+            if RV64_ONLY.contains(&decoded.opcode_id().name()) {
+                return Err(DecodeError::UnknownInstruction).context("RV64 only instruction");
+            }
+            if let InstructionDecoded::Slli { imm, .. }
+            | InstructionDecoded::Srli { imm, .. }
+            | InstructionDecoded::Srai { imm, .. }
+            | InstructionDecoded::Rori { imm, .. }
+            | InstructionDecoded::Bclri { imm, .. }
+            | InstructionDecoded::Bexti { imm, .. }
+            | InstructionDecoded::Binvi { imm, .. }
+            | InstructionDecoded::Bseti { imm, .. } = decoded
+            {
+                if imm >= 32 {
+                    return Err(DecodeError::UnknownInstruction)
+                        .context("Shift amount is wider than XLEN");
+                }
+            }
+            if let InstructionDecoded::CSlli { shamt, .. }
+            | InstructionDecoded::CSrli { shamt, .. }
+            | InstructionDecoded::CSrai { shamt, .. } = decoded
+            {
+                if shamt >= 32 {
+                    return Err(DecodeError::UnknownInstruction)
+                        .context("Shift amount is wider than XLEN");
+                }
+            }
+        }
+        if self.xlen == Xlen::Rv64 && RV32_ONLY.contains(&decoded.opcode_id().name()) {
+            return Err(DecodeError::UnknownInstruction).context("RV32 only instruction");
+        }
+        // the rv32 zext.h is pack with rs2 = zero, which is still pack on rv64
+        if let InstructionDecoded::ZextH { rd, rs1 } = decoded {
+            if self.xlen == Xlen::Rv64 && inst & OPCODE_MASK == ARITMETIC_REGISTER_MATCH {
+                return Ok(InstructionDecoded::Pack { rd, rs1, rs2: 0 });
+            }
+        }
+        if let InstructionDecoded::Rev8 { .. } | InstructionDecoded::ZextH { .. } = decoded {
+            // the opcode table only has the rv64 encodings of these two
+            let info = decoded.opcode_id().info();
+            if (inst & info.mask == info.match_value) != (self.xlen == Xlen::Rv64) {
+                return Err(DecodeError::UnknownInstruction)
+                    .context("Encoding is for a different XLEN");
+            }
+        }
+        if self.decompress {
+            return Ok(decoded.decompress().unwrap_or(decoded));
+        }
+        Ok(decoded)
+    }
+
+    // for fetch paths that read one 16 bit parcel at a time, `instruction_length` tells whether
+    // the parcel is a whole instruction or the first half of a longer one
+    pub fn decode_halfword(&self, parcel: u16) -> Result<InstructionDecoded> {
+        self.decode(parcel)
+    }
+
+    // the whole compressed space is only 2^16 halfwords so every one of them gets decoded once (the
+    // first time this is called for an xlen and c.fld/Zcmp pair) and after that decoding one is just
+    // indexing into the table. nothing else changes what a compressed inst decodes to, except for
+    // `decompress` which isn't applied here
+    pub fn compressed_table(&self) -> &'static [Option<InstructionDecoded>] {
+        type Table = OnceLock<Box<[Option<InstructionDecoded>]>>;
+        static TABLES: [Table; 4] = [const { OnceLock::new() }; 4];
+        let index = match (self.xlen, self.compressed_double) {
+            (Xlen::Rv32, CompressedDouble::Zcd) => 0,
+            (Xlen::Rv32, CompressedDouble::Zcmp) => 1,
+            (Xlen::Rv64, CompressedDouble::Zcd) => 2,
+            (Xlen::Rv64, CompressedDouble::Zcmp) => 3,
+        };
+        let decoder = Decoder {
+            xlen: self.xlen,
+            compressed_double: self.compressed_double,
+            ..Decoder::new()
+        };
+        TABLES[index].get_or_init(|| (0..=u16::MAX).map(|half| decoder.decode(half).ok()).collect())
+    }
+
+    // `None` for halfwords that aren't a (known) compressed instruction, or for all of them without
+    // the C extension
+    pub fn lookup_compressed(&self, inst: u16) -> Option<&'static InstructionDecoded> {
+        if !self.compressed {
+            return None;
+        }
+        self.compressed_table()[inst as usize].as_ref()
+    }
+
+    pub fn decode_outcome<T: Decode>(&self, inst: T) -> Result<DecodeOutcome> {
+        let inst = inst.bits()?;
+        let decoded = self.decode(inst)?;
+        Ok(DecodeOutcome {
+            warnings: warnings(inst, &decoded),
+            inst: decoded,
+        })
+    }
+}
+
+// a whole compressed instruction, the low two bits being set means it's only the first parcel of
+// a longer one
+pub fn try_decode_compressed(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    match inst & COMPRESSED_MASK as compressed::CompressedSize {
+        0 => decode_compressed_quadrant0(inst),
+        1 => decode_compressed_quadrant1(inst),
+        2 => decode_compressed_quadrant2(inst),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown compressed instruction"),
+    }
+}
+
+// `try_decode_compressed` gives the rv32 meaning of the encodings rv64 reuses, this gives the rv64
+// one (c.addiw instead of c.jal, c.ld instead of c.flw etc) and falls back to it for the rest
+pub fn try_decode_compressed_rv64(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    use compressed::{citype::CIType, cltype::CLType, csstype::CSSType, cstype::CSType};
+    let ci = CIType::new(inst);
+    let load = CLType::new(inst);
+    let store = CSType::new(inst);
+    let css = CSSType::new(inst);
+    let rd = ci.rd() as InstructionSize;
+    match (inst as InstructionSize & COMPRESSED_MASK, ci.funct3() as InstructionSize) {
+        (0, c_ld::FUNCT3) => Ok(InstructionDecoded::CLd {
+            rd: load.rd() as InstructionSize,
+            rs1: load.rs1() as InstructionSize,
+            imm: load.double_offset(),
+        }),
+        (0, c_sd::FUNCT3) => Ok(InstructionDecoded::CSd {
+            rs1: store.rs1() as InstructionSize,
+            rs2: store.rs2() as InstructionSize,
+            imm: store.double_offset(),
+        }),
+        (1, c_addiw::FUNCT3) if rd == 0 => Err(DecodeError::UnknownInstruction).context("c.addiw with rd = x0 is reserved"),
+        (1, c_addiw::FUNCT3) => Ok(InstructionDecoded::CAddiw { rd, imm: ci.imm() }),
+        (2, c_ldsp::FUNCT3) if rd == 0 => Err(DecodeError::UnknownInstruction).context("c.ldsp with rd = x0 is reserved"),
+        (2, c_ldsp::FUNCT3) => Ok(InstructionDecoded::CLdsp { rd, imm: ci.double_offset() }),
+        (2, c_sdsp::FUNCT3) => Ok(InstructionDecoded::CSdsp { rs2: css.rs2() as InstructionSize, imm: css.double_offset() }),
+        _ => try_decode_compressed(inst),
+    }
+}
+
+// quadrant 0, c.addi4spn and the loads and stores on x8-x15
+fn decode_compressed_quadrant0(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    use compressed::{cltype::CLType, cstype::CSType, cwitype::CIWType};
+    let load = CLType::new(inst);
+    let store = CSType::new(inst);
+    match load.funct3() as InstructionSize {
+        c_addi4spn::FUNCT3 => {
+            let inst = CIWType::new(inst);
+            // this covers the all zero halfword, which is defined to be illegal
+            if inst.imm() == 0 {
+                return Err(DecodeError::UnknownInstruction).context("c.addi4spn with a zero immediate is reserved");
+            }
+            Ok(InstructionDecoded::CAddi4Spn {
+                rd: inst.rd() as InstructionSize,
+                nzuimm: inst.imm(),
+            })
+        }
+        c_fld::FUNCT3 => Ok(InstructionDecoded::CFld {
+            rd: load.rd() as InstructionSize,
+            rs1: load.rs1() as InstructionSize,
+            imm: load.double_offset(),
+        }),
+        c_lbu::FUNCT3 => decode_compressed_byte_half(inst),
+        c_lw::FUNCT3 => Ok(InstructionDecoded::CLw {
+            rd: load.rd() as InstructionSize,
+            rs1: load.rs1() as InstructionSize,
+            imm: load.word_offset(),
+        }),
+        c_flw::FUNCT3 => Ok(InstructionDecoded::CFlw {
+            rd: load.rd() as InstructionSize,
+            rs1: load.rs1() as InstructionSize,
+            imm: load.word_offset(),
+        }),
+        c_fsd::FUNCT3 => Ok(InstructionDecoded::CFsd {
+            rs1: store.rs1() as InstructionSize,
+            rs2: store.rs2() as InstructionSize,
+            imm: store.double_offset(),
+        }),
+        c_sw::FUNCT3 => Ok(InstructionDecoded::CSw {
+            rs1: store.rs1() as InstructionSize,
+            rs2: store.rs2() as InstructionSize,
+            imm: store.word_offset(),
+        }),
+        c_fsw::FUNCT3 => Ok(InstructionDecoded::CFsw {
+            rs1: store.rs1() as InstructionSize,
+            rs2: store.rs2() as InstructionSize,
+            imm: store.word_offset(),
+        }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown compressed quadrant 0 instruction"),
+    }
+}
+
+// the Zcb byte and halfword loads and stores
+fn decode_compressed_byte_half(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    use compressed::{clbtype::CLBType, clhtype::CLHType, csbtype::CSBType, cshtype::CSHType};
+    let (lb, sb) = (CLBType::new(inst), CSBType::new(inst));
+    let (lh, sh) = (CLHType::new(inst), CSHType::new(inst));
+    // the halfword forms only have uimm[1], bit 6 is a funct1 for them
+    match (lb.funct6() as InstructionSize, lh.funct1() as InstructionSize) {
+        (c_lbu::FUNCT6, _) => Ok(InstructionDecoded::CLbu {
+            rd: lb.rd() as InstructionSize,
+            rs1: lb.rs1() as InstructionSize,
+            imm: lb.uimm(),
+        }),
+        (c_lhu::FUNCT6, c_lhu::FUNCT1) => Ok(InstructionDecoded::CLhu {
+            rd: lh.rd() as InstructionSize,
+            rs1: lh.rs1() as InstructionSize,
+            imm: lh.uimm(),
+        }),
+        (c_lh::FUNCT6, c_lh::FUNCT1) => Ok(InstructionDecoded::CLh {
+            rd: lh.rd() as InstructionSize,
+            rs1: lh.rs1() as InstructionSize,
+            imm: lh.uimm(),
+        }),
+        (c_sb::FUNCT6, _) => Ok(InstructionDecoded::CSb {
+            rs1: sb.rs1() as InstructionSize,
+            rs2: sb.rs2() as InstructionSize,
+            imm: sb.uimm(),
+        }),
+        (c_sh::FUNCT6, c_sh::FUNCT1) => Ok(InstructionDecoded::CSh {
+            rs1: sh.rs1() as InstructionSize,
+            rs2: sh.rs2() as InstructionSize,
+            imm: sh.uimm(),
+        }),
+        _ => Err(DecodeError::UnknownInstruction).context("Unknown compressed byte/halfword instruction"),
+    }
+}
+
+// quadrant 1, the CI immediates, arithmetic, jumps and branches
+fn decode_compressed_quadrant1(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    use compressed::{cbtype::CBType, citype::CIType, cjtype::CJType};
+    let ci = CIType::new(inst);
+    let cj = CJType::new(inst);
+    let cb = CBType::new(inst);
+    let rd = ci.rd() as InstructionSize;
+    match ci.funct3() as InstructionSize {
+        c_addi::FUNCT3 if rd == 0 && ci.imm() == 0 => Ok(InstructionDecoded::CNop),
+        c_addi::FUNCT3 => Ok(InstructionDecoded::CAddi { rd, imm: ci.imm() }),
+        // this is c.addiw on rv64
+        c_jal::FUNCT3 => Ok(InstructionDecoded::CJal { imm: cj.imm() }),
+        c_li::FUNCT3 => Ok(InstructionDecoded::CLi { rd, imm: ci.imm() }),
+        c_lui::FUNCT3 if rd == c_lui::ADDI16SP_RD => match ci.addi16sp_imm() {
+            0 => Err(DecodeError::UnknownInstruction).context("c.addi16sp with a zero immediate is reserved"),
+            imm => Ok(InstructionDecoded::CAddi16Sp { imm }),
+        },
+        c_lui::FUNCT3 => match ci.imm() {
+            0 => Err(DecodeError::UnknownInstruction).context("c.lui with a zero immediate is reserved"),
+            // keep the 20 bit field like lui does
+            imm => Ok(InstructionDecoded::CLui { rd, imm: imm & 0xfffff }),
+        },
+        c_srli::FUNCT3 => decode_compressed_alu(inst),
+        c_j::FUNCT3 => Ok(InstructionDecoded::CJ { imm: cj.imm() }),
+        c_beqz::FUNCT3 => Ok(InstructionDecoded::CBeqz { rs1: cb.rs1() as InstructionSize, imm: cb.offset() }),
+        c_bnez::FUNCT3 => Ok(InstructionDecoded::CBnez { rs1: cb.rs1() as InstructionSize, imm: cb.offset() }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown compressed quadrant 1 instruction"),
+    }
+}
+
+// the rest of quadrant 1 funct3 = 100, shifts and andi in CB and the register-register ops in CA
+fn decode_compressed_alu(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    use compressed::{catype::CAType, cbtype::CBType, cutype::CUType};
+    let cb = CBType::new(inst);
+    let rd = cb.rs1() as InstructionSize;
+    match cb.funct2() as InstructionSize {
+        c_srli::FUNCT2 => return Ok(InstructionDecoded::CSrli { rd, shamt: cb.shamt() }),
+        c_srai::FUNCT2 => return Ok(InstructionDecoded::CSrai { rd, shamt: cb.shamt() }),
+        c_andi::FUNCT2 => return Ok(InstructionDecoded::CAndi { rd, imm: cb.imm() }),
+        _ => {}
+    }
+
+    let ca = CAType::new(inst);
+    let cu = CUType::new(inst);
+    let unary = match (cu.funct6() as InstructionSize, cu.funct5() as InstructionSize) {
+        (c_zext_b::FUNCT6, c_zext_b::FUNCT5) => Some(InstructionDecoded::CZextB { rd }),
+        (c_sext_b::FUNCT6, c_sext_b::FUNCT5) => Some(InstructionDecoded::CSextB { rd }),
+        (c_zext_h::FUNCT6, c_zext_h::FUNCT5) => Some(InstructionDecoded::CZextH { rd }),
+        (c_sext_h::FUNCT6, c_sext_h::FUNCT5) => Some(InstructionDecoded::CSextH { rd }),
+        (c_zext_w::FUNCT6, c_zext_w::FUNCT5) => Some(InstructionDecoded::CZextW { rd }),
+        (c_not::FUNCT6, c_not::FUNCT5) => Some(InstructionDecoded::CNot { rd }),
+        _ => None,
     };
+    if let Some(inst) = unary {
+        return Ok(inst);
+    }
+
+    let rs2 = ca.rs2() as InstructionSize;
+    match (ca.funct6() as InstructionSize, ca.funct2() as InstructionSize) {
+        (c_sub::FUNCT6, c_sub::FUNCT2) => Ok(InstructionDecoded::CSub { rd, rs2 }),
+        (c_xor::FUNCT6, c_xor::FUNCT2) => Ok(InstructionDecoded::CXor { rd, rs2 }),
+        (c_or::FUNCT6, c_or::FUNCT2) => Ok(InstructionDecoded::COr { rd, rs2 }),
+        (c_and::FUNCT6, c_and::FUNCT2) => Ok(InstructionDecoded::CAnd { rd, rs2 }),
+        (c_subw::FUNCT6, c_subw::FUNCT2) => Ok(InstructionDecoded::CSubw { rd, rs2 }),
+        (c_addw::FUNCT6, c_addw::FUNCT2) => Ok(InstructionDecoded::CAddw { rd, rs2 }),
+        (c_mul::FUNCT6, c_mul::FUNCT2) => Ok(InstructionDecoded::CMul { rd, rs2 }),
+        _ => Err(DecodeError::UnknownInstruction).context("Unknown compressed arithmetic instruction"),
+    }
+}
+
+// quadrant 2, c.slli and the sp relative loads and stores
+fn decode_compressed_quadrant2(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    use compressed::{citype::CIType, csstype::CSSType};
+    let load = CIType::new(inst);
+    let store = CSSType::new(inst);
+    let rd = load.rd() as InstructionSize;
+    let rs2 = store.rs2() as InstructionSize;
+    match load.funct3() as InstructionSize {
+        c_slli::FUNCT3 => Ok(InstructionDecoded::CSlli { rd, rs1: rd, shamt: load.shamt() }),
+        c_fldsp::FUNCT3 => Ok(InstructionDecoded::CFldsp { rd, imm: load.double_offset() }),
+        c_lwsp::FUNCT3 if rd == 0 => Err(DecodeError::UnknownInstruction).context("c.lwsp with rd = x0 is reserved"),
+        c_lwsp::FUNCT3 => Ok(InstructionDecoded::CLwsp { rd, imm: load.word_offset() }),
+        c_flwsp::FUNCT3 => Ok(InstructionDecoded::CFlwsp { rd, imm: load.word_offset() }),
+        c_mv::FUNCT3 => decode_compressed_crtype(inst),
+        c_fsdsp::FUNCT3 => Ok(InstructionDecoded::CFsdsp { rs2, imm: store.double_offset() }),
+        c_swsp::FUNCT3 => Ok(InstructionDecoded::CSwsp { rs2, imm: store.word_offset() }),
+        c_fswsp::FUNCT3 => Ok(InstructionDecoded::CFswsp { rs2, imm: store.word_offset() }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown compressed quadrant 2 instruction"),
+    }
+}
 
-    Ok(inst)
+// c.jr, c.mv, c.ebreak, c.jalr and c.add, rs2 = x0 picks the jumps and rs1 = x0 too picks c.ebreak
+fn decode_compressed_crtype(inst: compressed::CompressedSize) -> Result<InstructionDecoded> {
+    use compressed::crtype::CRType;
+    let inst = CRType::new(inst);
+    let rs1 = inst.rs1() as InstructionSize;
+    let rs2 = inst.rs2() as InstructionSize;
+    match (inst.funct4() as InstructionSize, rs1, rs2) {
+        (c_mv::FUNCT4, 0, 0) => Err(DecodeError::UnknownInstruction).context("c.jr with rs1 = x0 is reserved"),
+        (c_mv::FUNCT4, rs1, 0) => Ok(InstructionDecoded::CJr { rs1 }),
+        (c_mv::FUNCT4, rd, rs2) => Ok(InstructionDecoded::CMv { rd, rs2 }),
+        (c_add::FUNCT4, 0, 0) => Ok(InstructionDecoded::CEbreak),
+        (c_add::FUNCT4, rs1, 0) => Ok(InstructionDecoded::CJalr { rs1 }),
+        (c_add::FUNCT4, rd, rs2) => Ok(InstructionDecoded::CAdd { rd, rs2 }),
+        _ => Err(DecodeError::UnknownInstructionFormat).context("Unknown compressed register instruction"),
+    }
 }
 
-pub fn try_decode_compressed(_inst: InstructionSize) -> Result<InstructionDecoded> {
-    Err(DecodeError::UnknownInstructionFormat)
-        .context(format!("Compressed instructions are not supported yet"))
+// the c.fsdsp encodings the way Zcmp and Zcmt use them, the frame cm.push/cm.pop adjust sp by
+// depends on the size of the saved registers
+pub fn try_decode_zcmp(inst: compressed::CompressedSize, xlen: Xlen) -> Result<InstructionDecoded> {
+    use compressed::{cmjttype::CMJTType, cmmvtype::CMMVType, cmpptype::CMPPType};
+    let pp = CMPPType::new(inst);
+    let mv = CMMVType::new(inst);
+    let jt = CMJTType::new(inst);
+    let rlist = pp.rlist() as InstructionSize;
+    let stack_adj = pp.stack_adj(match xlen {
+        Xlen::Rv32 => 4,
+        Xlen::Rv64 => 8,
+    });
+    if let cm_push::FUNCT5 | cm_pop::FUNCT5 | cm_popretz::FUNCT5 | cm_popret::FUNCT5 = pp.funct5() as InstructionSize {
+        if rlist < 4 {
+            return Err(DecodeError::UnknownInstruction).context("Zcmp rlist values below 4 are reserved");
+        }
+    }
+    let (r1s, r2s) = (mv.r1s(), mv.r2s());
+    match (pp.funct5() as InstructionSize, mv.funct6() as InstructionSize, mv.funct2() as InstructionSize) {
+        (cm_push::FUNCT5, _, _) => Ok(InstructionDecoded::CmPush { rlist, stack_adj }),
+        (cm_pop::FUNCT5, _, _) => Ok(InstructionDecoded::CmPop { rlist, stack_adj }),
+        (cm_popretz::FUNCT5, _, _) => Ok(InstructionDecoded::CmPopretz { rlist, stack_adj }),
+        (cm_popret::FUNCT5, _, _) => Ok(InstructionDecoded::CmPopret { rlist, stack_adj }),
+        (_, cm_mvsa01::FUNCT6, cm_mvsa01::FUNCT2) if r1s == r2s => {
+            Err(DecodeError::UnknownInstruction).context("cm.mvsa01 needs two different registers")
+        }
+        (_, cm_mvsa01::FUNCT6, cm_mvsa01::FUNCT2) => Ok(InstructionDecoded::CmMvsa01 { r1s, r2s }),
+        (_, cm_mva01s::FUNCT6, cm_mva01s::FUNCT2) => Ok(InstructionDecoded::CmMva01s { r1s, r2s }),
+        (_, cm_jt::FUNCT6, _) => match jt.index() as InstructionSize {
+            index if index < cm_jalt::MIN_INDEX => Ok(InstructionDecoded::CmJt { index }),
+            index => Ok(InstructionDecoded::CmJalt { index }),
+        },
+        _ => Err(DecodeError::UnknownInstruction).context("Unknown Zcmp instruction"),
+    }
 }
 
 macro_rules! decode_test {
@@ -482,16 +4669,1292 @@ decode_test!(
     }
 );
 
+decode_test!(
+    amoadd_w,
+    0x00b5252f, /* amoadd.w a0, a1, (a0) */
+    InstructionDecoded::AmoaddW {
+        rd: 10,
+        rs1: 10,
+        rs2: 11,
+        rl: false,
+        aq: false,
+    }
+);
+
+decode_test!(
+    amoor_w,
+    0x46b6252f, /* amoor.w.aqrl a0, a1, (a2) */
+    InstructionDecoded::AmoorW {
+        rd: 10,
+        rs1: 12,
+        rs2: 11,
+        rl: true,
+        aq: true,
+    }
+);
+
+decode_test!(
+    amomaxu_w,
+    0xe0b5252f, /* amomaxu.w a0, a1, (a0) */
+    InstructionDecoded::AmomaxuW {
+        rd: 10,
+        rs1: 10,
+        rs2: 11,
+        rl: false,
+        aq: false,
+    }
+);
+
+decode_test!(
+    lr_w,
+    0x1405a52f, /* lr.w.aq a0, (a1) */
+    InstructionDecoded::LrW {
+        rd: 10,
+        rs1: 11,
+        rs2: 0,
+        rl: false,
+        aq: true,
+    }
+);
+
+decode_test!(
+    sc_w,
+    0x1ac5a52f, /* sc.w.rl a0, a2, (a1) */
+    InstructionDecoded::ScW {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+        rl: true,
+        aq: false,
+    }
+);
+
+decode_test!(
+    fmadd_s,
+    0x68c5f543, /* fmadd.s fa0, fa1, fa2, fa3 */
+    InstructionDecoded::FmaddS {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+        rs3: 13,
+        rm: RoundingMode::Dyn,
+    }
+);
+
+#[test]
+fn fused_multiply_add() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert!(text(0x68c5f547 /* fmsub.s */).starts_with("fmsub.s"));
+    assert!(text(0x68c5f54b /* fnmsub.s */).starts_with("fnmsub.s"));
+    assert!(text(0x68c5f54f /* fnmadd.s */).starts_with("fnmadd.s"));
+    assert!(text(0x6cc5f543 /* fmadd.h */).starts_with("fmadd.h"));
+    assert_eq!(text(0x6ac5f543), "fmadd.d a0, a1, a2, a3");
+    assert_eq!(text(0x6ac5f547), "fmsub.d a0, a1, a2, a3");
+    assert_eq!(text(0x6ac5f54b), "fnmsub.d a0, a1, a2, a3");
+    assert_eq!(text(0x6ac5f54f), "fnmadd.d a0, a1, a2, a3");
+}
+
 decode_test!(
     fcvt_s_w,
     0xd00777d3, /* fcvt.s.w fa5, a4 */
-    InstructionDecoded::FcvtSW { rd: 15, rs1: 14 }
+    InstructionDecoded::FcvtSW {
+        rd: 15,
+        rs1: 14,
+        rm: RoundingMode::Dyn,
+    }
 );
 
 decode_test!(
     fcvt_w_s,
     0xc00777d3, /* fcvt.w.s a5, fa4 */
-    InstructionDecoded::FcvtWUS { rd: 15, rs1: 14 }
+    InstructionDecoded::FcvtWS {
+        rd: 15,
+        rs1: 14,
+        rm: RoundingMode::Dyn,
+    }
+);
+
+#[test]
+fn rounding_mode() {
+    let inst = try_decode(0xc0051553 /* fcvt.w.s a0, fa0, rtz */).unwrap();
+    assert_eq!(
+        inst,
+        InstructionDecoded::FcvtWS {
+            rd: 10,
+            rs1: 10,
+            rm: RoundingMode::Rtz,
+        }
+    );
+    assert!(inst.to_string().ends_with(", rtz"));
+    assert!(!try_decode(0x00b57553 /* fadd.s fa0, fa0, fa1 */).unwrap().to_string().contains("dyn"));
+    // rm 5 and 6 are reserved
+    assert!(try_decode(0x00b55553).is_err());
+    assert!(try_decode(0x68c5d543).is_err());
+}
+
+decode_test!(
+    fcvt_w_d,
+    0xc2079553, /* fcvt.w.d a0, fa5, rtz */
+    InstructionDecoded::FcvtWD {
+        rd: 10,
+        rs1: 15,
+        rm: RoundingMode::Rtz,
+    }
+);
+
+decode_test!(
+    fadd_d,
+    0x02c5f553, /* fadd.d fa0, fa1, fa2 */
+    InstructionDecoded::FaddD {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+        rm: RoundingMode::Dyn,
+    }
+);
+
+decode_test!(
+    fadd_d_rne,
+    0x02c58553, /* fadd.d fa0, fa1, fa2, rne */
+    InstructionDecoded::FaddD {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+        rm: RoundingMode::Rne,
+    }
+);
+
+#[test]
+fn double_precision() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x02c58553 /* fadd.d fa0, fa1, fa2, rne */), "fadd.d a0, a1, a2, rne");
+    assert_eq!(text(0x5a05f553 /* fsqrt.d fa0, fa1 */), "fsqrt.d a0, a1");
+    assert_eq!(text(0x2ac58553 /* fmin.d fa0, fa1, fa2 */), "fmin.d a0, a1, a2");
+    assert_eq!(text(0xa2c5a553 /* feq.d a0, fa1, fa2 */), "feq.d a0, a1, a2");
+    assert_eq!(text(0xe2059553 /* fclass.d a0, fa1 */), "fclass.d a0, a1");
+    assert_eq!(text(0x4015f553 /* fcvt.s.d fa0, fa1 */), "fcvt.s.d a0, a1");
+    assert_eq!(text(0x4205f553 /* fcvt.d.s fa0, fa1 */), "fcvt.d.s a0, a1");
+    assert_eq!(text(0xd215f553 /* fcvt.d.wu fa0, a1 */), "fcvt.d.wu a0, a1");
+}
+
+decode_test!(
+    vsm4k_vi,
+    0x8621a0f7, /* vsm4k.vi v1, v2, 3 */
+    InstructionDecoded::Vsm4kVi {
+        vd: 1,
+        vs2: 2,
+        uimm: 3
+    }
+);
+
+decode_test!(
+    vsm4r_vv,
+    0xa22820f7, /* vsm4r.vv v1, v2 */
+    InstructionDecoded::Vsm4rVv { vd: 1, vs2: 2 }
+);
+
+decode_test!(
+    vsm4r_vs,
+    0xa62820f7, /* vsm4r.vs v1, v2 */
+    InstructionDecoded::Vsm4rVs { vd: 1, vs2: 2 }
+);
+
+decode_test!(
+    vsm3me_vv,
+    0x8221a0f7, /* vsm3me.vv v1, v2, v3 */
+    InstructionDecoded::Vsm3meVv {
+        vd: 1,
+        vs2: 2,
+        vs1: 3
+    }
+);
+
+decode_test!(
+    vsm3c_vi,
+    0xae43a2f7, /* vsm3c.vi v5, v4, 7 */
+    InstructionDecoded::Vsm3cVi {
+        vd: 5,
+        vs2: 4,
+        uimm: 7
+    }
+);
+
+decode_test!(
+    vfwmaccbf16_vv,
+    0xee2190d7, /* vfwmaccbf16.vv v1, v3, v2 */
+    InstructionDecoded::Vfwmaccbf16Vv {
+        vd: 1,
+        vs1: 3,
+        vs2: 2,
+        masked: false
+    }
+);
+
+decode_test!(
+    vfwmaccbf16_vf,
+    0xec2550d7, /* vfwmaccbf16.vf v1, fa0, v2, v0.t */
+    InstructionDecoded::Vfwmaccbf16Vf {
+        vd: 1,
+        rs1: 10,
+        vs2: 2,
+        masked: true
+    }
+);
+
+decode_test!(
+    vfncvtbf16_f_f_w,
+    0x4a4e9257, /* vfncvtbf16.f.f.w v4, v4 */
+    InstructionDecoded::Vfncvtbf16FFW {
+        vd: 4,
+        vs2: 4,
+        masked: false
+    }
+);
+
+decode_test!(
+    vfwcvtbf16_f_f_v,
+    0x4a269457, /* vfwcvtbf16.f.f.v v8, v2 */
+    InstructionDecoded::Vfwcvtbf16FFV {
+        vd: 8,
+        vs2: 2,
+        masked: false
+    }
+);
+
+#[test]
+fn fetch_alignment() {
+    let decoder = Decoder::new();
+    assert_eq!(decoder.ialign(), 16);
+    assert_eq!(decoder.check_fetch_alignment(0x1002), Ok(()));
+    assert_eq!(
+        decoder.check_fetch_alignment(0x1001),
+        Err(Exception::InstructionAddressMisaligned)
+    );
+
+    let decoder = Decoder {
+        compressed: false,
+        ..Decoder::new()
+    };
+    assert_eq!(decoder.ialign(), 32);
+    assert_eq!(decoder.check_fetch_alignment(0x1004), Ok(()));
+    assert_eq!(
+        decoder.check_fetch_alignment(0x1002),
+        Err(Exception::InstructionAddressMisaligned)
+    );
+    assert!(decoder.decode(0x8602u32 /* c.jr x12 */).is_err());
+    assert!(decoder.decode(0x06468613u32 /* addi x12 x13 100 */).is_ok());
+}
+
+#[test]
+fn masked_vector_crypto() {
+    // vsm3me.vv with vm=0
+    assert!(try_decode(0x8021a0f7).is_err());
+}
+
+#[test]
+fn counter_csr_names() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert!(text(0xc0002573 /* csrrs x10 cycle x0 */).contains("cycle"));
+    assert!(text(0xc1f02573 /* csrrs x10 hpmcounter31 x0 */).contains("hpmcounter31"));
+    assert!(text(0x72302573 /* csrrs x10 mhpmevent3h x0 */).contains("mhpmevent3h"));
+}
+
+#[test]
+fn debug_csr_names() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert!(text(0x7a402573 /* csrrs x10 tinfo x0 */).contains("tinfo"));
+    assert!(text(0x7a502573 /* csrrs x10 tcontrol x0 */).contains("tcontrol"));
+    assert!(text(0x7b202573 /* csrrs x10 dscratch0 x0 */).contains("dscratch0"));
+    assert!(text(0x7b302573 /* csrrs x10 dscratch1 x0 */).contains("dscratch1"));
+}
+
+#[test]
+fn compressed_lookup() {
+    let decoder = Decoder::new();
+    assert_eq!(decoder.compressed_table().len(), 1 << 16);
+    for half in [0x0000u16, 0x0001, 0x8602, 0xffff] {
+        assert_eq!(decoder.lookup_compressed(half), decoder.decode(half).ok().as_ref());
+    }
+    // the low two bits being set means it's the first half of a 32 bit instruction
+    assert_eq!(decoder.lookup_compressed(0x8613), None);
+
+    // every config gets its own table
+    let rv64 = Decoder { xlen: Xlen::Rv64, ..Decoder::new() };
+    let zcmp = Decoder { compressed_double: CompressedDouble::Zcmp, ..Decoder::new() };
+    let text = |decoder: &Decoder, half| decoder.lookup_compressed(half).map(|inst| inst.to_string());
+    assert_eq!(text(&decoder, 0x6502).as_deref(), Some("c.flwsp a0, 0(sp)"));
+    assert_eq!(text(&rv64, 0x6502).as_deref(), Some("c.ldsp a0, 0(sp)"));
+    // c.fsdsp, or cm.push with Zcmp
+    assert!(matches!(decoder.lookup_compressed(0xb842), Some(InstructionDecoded::CFsdsp { .. })));
+    assert!(matches!(zcmp.lookup_compressed(0xb842), Some(InstructionDecoded::CmPush { .. })));
+    let disabled = Decoder { compressed: false, ..Decoder::new() };
+    assert_eq!(disabled.lookup_compressed(0x8602), None);
+}
+
+#[test]
+fn decode_inputs() {
+    let decoder = Decoder::new();
+    let addi = try_decode(0x06468613 /* addi x12 x13 100 */).unwrap();
+    assert_eq!(decoder.decode(0x06468613u32).unwrap(), addi);
+    assert_eq!(decoder.decode([0x13u8, 0x86, 0x46, 0x06]).unwrap(), addi);
+    assert_eq!(decoder.decode(&[0x13u8, 0x86, 0x46, 0x06, 0xff][..]).unwrap(), addi);
+    assert!(decoder.decode(&[0x13u8, 0x86, 0x46][..]).is_err());
+    assert!(decoder.decode(0x8613u16).is_err());
+
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    assert_eq!(
+        decoder.decode_halfword(0x8082 /* c.jr ra */).unwrap(),
+        try_decode_compressed(0x8082).unwrap()
+    );
+    assert_eq!(rv64.decode_halfword(0x6588).unwrap().to_string(), "c.ld a0, 8(a1)");
+    assert!(decoder.decode_halfword(0x8613).is_err());
+    assert!(try_decode_compressed(0x8613).is_err());
+
+    assert_eq!(0x8602u16.bits().unwrap(), 0x8602);
+    assert_eq!([0x02u8, 0x86].bits().unwrap(), 0x8602);
+    assert_eq!((&[0x02u8, 0x86, 0x13][..]).bits().unwrap(), 0x8602);
+}
+
+#[test]
+fn supported() {
+    let names = |decoder: Decoder| {
+        decoder
+            .supported()
+            .map(|info| info.name)
+            .collect::<Vec<_>>()
+    };
+    let all = names(Decoder::new());
+    assert!(all.contains(&"addi"));
+    assert!(all.contains(&"vsm3c.vi"));
+    assert!(!all.contains(&"lwu"));
+    assert!(all.contains(&"c.lw") && all.contains(&"c.flw"));
+    let uncompressed = names(Decoder {
+        compressed: false,
+        ..Decoder::new()
+    });
+    assert!(!uncompressed.contains(&"c.lw"));
+    assert!(!uncompressed.contains(&"c.lbu") && !uncompressed.contains(&"c.fld"));
+    let rv64 = names(Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    });
+    assert!(rv64.contains(&"lwu") && rv64.contains(&"sraw"));
+}
+
+#[test]
+fn rv64() {
+    let rv32 = Decoder::new();
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let text = |inst| rv64.decode(inst).unwrap().to_string();
+    assert_eq!(text(0x00813503u32 /* ld a0, 8(sp) */), "ld a0, 8(sp)");
+    assert_eq!(text(0x00a13423u32 /* sd a0, 8(sp) */), "sd a0, 8(sp)");
+    assert_eq!(text(0x0085e503u32 /* lwu a0, 8(a1) */), "lwu a0, 8(a1)");
+    assert_eq!(text(0xfff5051bu32 /* addiw a0, a0, -1 */), "addiw a0, a0, -1");
+    assert_eq!(text(0x0035151bu32 /* slliw a0, a0, 3 */), "slliw a0, a0, 3");
+    assert_eq!(text(0x0035551bu32 /* srliw a0, a0, 3 */), "srliw a0, a0, 3");
+    assert_eq!(text(0x4035551bu32 /* sraiw a0, a0, 3 */), "sraiw a0, a0, 3");
+    assert_eq!(text(0x00b5053bu32 /* addw a0, a0, a1 */), "addw a0, a0, a1");
+    assert_eq!(text(0x40b5053bu32 /* subw a0, a0, a1 */), "subw a0, a0, a1");
+    assert_eq!(text(0x00b5153bu32 /* sllw a0, a0, a1 */), "sllw a0, a0, a1");
+    assert_eq!(text(0x00b5553bu32 /* srlw a0, a0, a1 */), "srlw a0, a0, a1");
+    assert_eq!(text(0x40b5553bu32 /* sraw a0, a0, a1 */), "sraw a0, a0, a1");
+    assert!(rv32.decode(0x00813503u32).is_err());
+    assert_eq!(text(0x02b5053bu32 /* mulw a0, a0, a1 */), "mulw a0, a0, a1");
+    assert_eq!(text(0x02b5453bu32 /* divw a0, a0, a1 */), "divw a0, a0, a1");
+    assert_eq!(text(0x02b5553bu32 /* divuw a0, a0, a1 */), "divuw a0, a0, a1");
+    assert_eq!(text(0x02b5653bu32 /* remw a0, a0, a1 */), "remw a0, a0, a1");
+    assert_eq!(text(0x02b5753bu32 /* remuw a0, a0, a1 */), "remuw a0, a0, a1");
+    assert!(rv32.decode(0x02b5053bu32).is_err());
+    assert_eq!(text(0x1405352fu32 /* lr.d.aq a0, (a0) */), "lr.d.aq a0, (a0)");
+    assert_eq!(text(0x1ab5352fu32 /* sc.d.rl a0, a1, (a0) */), "sc.d.rl a0, a1, (a0)");
+    assert_eq!(text(0x00b5352fu32 /* amoadd.d a0, a1, (a0) */), "amoadd.d a0, a1, (a0)");
+    assert_eq!(text(0x40b5352fu32 /* amoor.d a0, a1, (a0) */), "amoor.d a0, a1, (a0)");
+    assert_eq!(text(0xe6b5352fu32 /* amomaxu.d.aqrl a0, a1, (a0) */), "amomaxu.d.aqrl a0, a1, (a0)");
+    // lr has no rs2
+    assert!(try_decode(0x1015352f).is_err());
+    assert!(rv32.decode(0x00b5352fu32).is_err());
+    assert_eq!(text(0xe2050553u32 /* fmv.x.d a0, fa0 */), "fmv.x.d a0, a0");
+    assert_eq!(text(0xf20585d3u32 /* fmv.d.x fa1, a1 */), "fmv.d.x a1, a1");
+    assert!(rv32.decode(0xe2050553u32).is_err());
+    assert_eq!(text(0xc0251553u32 /* fcvt.l.s a0, fa0, rtz */), "fcvt.l.s a0, a0, rtz");
+    assert_eq!(text(0xc0357553u32 /* fcvt.lu.s a0, fa0 */), "fcvt.lu.s a0, a0");
+    assert_eq!(text(0xd0257553u32 /* fcvt.s.l fa0, a0 */), "fcvt.s.l a0, a0");
+    assert_eq!(text(0xd0357553u32 /* fcvt.s.lu fa0, a0 */), "fcvt.s.lu a0, a0");
+    assert_eq!(text(0xc2251553u32 /* fcvt.l.d a0, fa0, rtz */), "fcvt.l.d a0, a0, rtz");
+    assert_eq!(text(0xc2357553u32 /* fcvt.lu.d a0, fa0 */), "fcvt.lu.d a0, a0");
+    assert_eq!(text(0xd2257553u32 /* fcvt.d.l fa0, a0 */), "fcvt.d.l a0, a0");
+    assert_eq!(text(0xd2357553u32 /* fcvt.d.lu fa0, a0 */), "fcvt.d.lu a0, a0");
+    assert!(rv32.decode(0xc0251553u32).is_err());
+
+    // rv64 shifts have a 6 bit shamt
+    assert_eq!(text(0x02051513u32 /* slli a0, a0, 32 */), "slli a0, a0, 32");
+    assert_eq!(text(0x43f55513u32 /* srai a0, a0, 63 */), "srai a0, a0, 63");
+    assert!(rv32.decode(0x02051513u32).is_err());
+    assert!(rv32.decode(0x01f51513u32 /* slli a0, a0, 31 */).is_ok());
+    // the word shifts don't
+    assert!(try_decode(0x0205151b /* slliw a0, a0, 32 */).is_err());
+}
+
+#[cfg(feature = "q")]
+#[test]
+fn quad_precision() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x6c5f553 /* fadd.q fa0, fa1, fa2 */), "fadd.q a0, a1, a2");
+    assert_eq!(text(0x4615f553 /* fcvt.q.d fa0, fa1 */), "fcvt.q.d a0, a1");
+    assert_eq!(text(0x6ec5f543 /* fmadd.q fa0, fa1, fa2, fa3 */), "fmadd.q a0, a1, a2, a3");
+    assert_eq!(text(0x1014507 /* flq fa0, 16(sp) */), "flq a0, 16(sp)");
+    assert_eq!(text(0xa14827 /* fsq fa0, 16(sp) */), "fsq a0, 16(sp)");
+}
+
+#[cfg(not(feature = "q"))]
+#[test]
+fn quad_precision_disabled() {
+    assert!(try_decode(0x6c5f553 /* fadd.q fa0, fa1, fa2 */).is_err());
+    assert!(try_decode(0x1014507 /* flq fa0, 16(sp) */).is_err());
+    assert!(!Decoder::new().supported().any(|info| info.name == "fadd.q"));
+}
+
+#[test]
+fn fp_loads_and_stores() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x01012507 /* flw fa0, 16(sp) */), "flw a0, 16(sp)");
+    assert_eq!(text(0x00a12827 /* fsw fa0, 16(sp) */), "fsw a0, 16(sp)");
+    assert_eq!(text(0x01013507 /* fld fa0, 16(sp) */), "fld a0, 16(sp)");
+    assert_eq!(text(0x00a13827 /* fsd fa0, 16(sp) */), "fsd a0, 16(sp)");
+    // every scalar width shares LOAD-FP/STORE-FP, only the vector widths go elsewhere
+    assert_eq!(text(0x01011507 /* flh fa0, 16(sp) */), "flh a0, 16(sp)");
+    assert_eq!(text(0x00a11827 /* fsh fa0, 16(sp) */), "fsh a0, 16(sp)");
+    let zfhmin = Decoder {
+        half_precision: HalfPrecision::Zfhmin,
+        ..Decoder::new()
+    };
+    for inst in [0x01011507u32, 0x00a11827, 0x01012507, 0x01013507] {
+        assert!(zfhmin.decode(inst).is_ok(), "{inst:#010x}");
+    }
+    assert_eq!(
+        try_decode(0xff813507 /* fld fa0, -8(sp) */).unwrap(),
+        InstructionDecoded::Fld {
+            rd: 10,
+            rs1: 2,
+            imm: -8i32 as InstructionSize,
+        }
+    );
+}
+
+#[test]
+fn half_precision() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x04c5f553 /* fadd.h fa0, fa1, fa2 */), "fadd.h a0, a1, a2");
+    assert_eq!(text(0x4405f553 /* fcvt.h.s fa0, fa1 */), "fcvt.h.s a0, a1");
+    assert_eq!(text(0x4025f553 /* fcvt.s.h fa0, fa1 */), "fcvt.s.h a0, a1");
+    assert_eq!(text(0x4225f553 /* fcvt.d.h fa0, fa1 */), "fcvt.d.h a0, a1");
+    assert_eq!(text(0xc4051553 /* fcvt.w.h a0, fa0, rtz */), "fcvt.w.h a0, a0, rtz");
+    assert_eq!(text(0xe4058553 /* fmv.x.h a0, fa1 */), "fmv.x.h a0, a1");
+    assert_eq!(text(0x01011507 /* flh fa0, 16(sp) */), "flh a0, 16(sp)");
+    assert_eq!(text(0x00a11827 /* fsh fa0, 16(sp) */), "fsh a0, 16(sp)");
+}
+
+#[test]
+fn zfhmin() {
+    let decoder = Decoder {
+        half_precision: HalfPrecision::Zfhmin,
+        ..Decoder::new()
+    };
+    assert!(decoder.decode(0x01011507u32 /* flh fa0, 16(sp) */).is_ok());
+    assert!(decoder.decode(0x4405f553u32 /* fcvt.h.s fa0, fa1 */).is_ok());
+    let err = decoder.decode(0x04c5f553u32 /* fadd.h fa0, fa1, fa2 */).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<DecodeError>(),
+        Some(&DecodeError::UnsupportedExtension(Extension::Zfh))
+    );
+    let names = decoder.supported().map(|info| info.name).collect::<Vec<_>>();
+    assert!(names.contains(&"fmv.x.h") && !names.contains(&"fmul.h"));
+}
+
+#[test]
+fn zfa() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0xf0180553 /* fli.s fa0, 1.0 */), "fli.s a0, 1.0");
+    assert_eq!(text(0xf21f8553 /* fli.d fa0, nan */), "fli.d a0, nan");
+    assert_eq!(text(0x28b52553 /* fminm.s fa0, fa0, fa1 */), "fminm.s a0, a0, a1");
+    assert_eq!(text(0x2ab53553 /* fmaxm.d fa0, fa0, fa1 */), "fmaxm.d a0, a0, a1");
+    assert_eq!(text(0x40459553 /* fround.s fa0, fa1, rtz */), "fround.s a0, a1, rtz");
+    assert_eq!(text(0x4255f553 /* froundnx.d fa0, fa1 */), "froundnx.d a0, a1");
+    assert_eq!(text(0xc2859553 /* fcvtmod.w.d a0, fa1, rtz */), "fcvtmod.w.d a0, a1, rtz");
+    // fcvtmod only exists with rtz
+    assert!(try_decode(0xc285f553).is_err());
+    // fmv.w.x needs rs2 = 0, rs2 = 1 is fli.s
+    assert!(matches!(try_decode(0xf0058553).unwrap(), InstructionDecoded::FmvWX { .. }));
+}
+
+#[test]
+fn zba() {
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let text = |inst| rv64.decode(inst).unwrap().to_string();
+    assert_eq!(text(0x20c5a533u32 /* sh1add a0, a1, a2 */), "sh1add a0, a1, a2");
+    assert_eq!(text(0x20c5c533u32 /* sh2add a0, a1, a2 */), "sh2add a0, a1, a2");
+    assert_eq!(text(0x20c5e533u32 /* sh3add a0, a1, a2 */), "sh3add a0, a1, a2");
+    assert_eq!(text(0x08c5853bu32 /* add.uw a0, a1, a2 */), "add.uw a0, a1, a2");
+    assert_eq!(text(0x0a85951bu32 /* slli.uw a0, a1, 40 */), "slli.uw a0, a1, 40");
+    assert_eq!(text(0x20c5a53bu32 /* sh1add.uw a0, a1, a2 */), "sh1add.uw a0, a1, a2");
+    assert_eq!(text(0x20c5c53bu32 /* sh2add.uw a0, a1, a2 */), "sh2add.uw a0, a1, a2");
+    assert_eq!(text(0x20c5e53bu32 /* sh3add.uw a0, a1, a2 */), "sh3add.uw a0, a1, a2");
+    // the .uw forms only exist on rv64
+    assert!(Decoder::new().decode(0x08c5853bu32).is_err());
+    assert!(Decoder::new().decode(0x20c5e53bu32).is_err());
+    assert!(Decoder::new().decode(0x20c5a533u32).is_ok());
+}
+
+#[test]
+fn zbb() {
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x40c5f533 /* andn a0, a1, a2 */), "andn a0, a1, a2");
+    assert_eq!(text(0x40c5e533 /* orn a0, a1, a2 */), "orn a0, a1, a2");
+    assert_eq!(text(0x40c5c533 /* xnor a0, a1, a2 */), "xnor a0, a1, a2");
+    assert_eq!(text(0x0ac5c533 /* min a0, a1, a2 */), "min a0, a1, a2");
+    assert_eq!(text(0x0ac5d533 /* minu a0, a1, a2 */), "minu a0, a1, a2");
+    assert_eq!(text(0x0ac5e533 /* max a0, a1, a2 */), "max a0, a1, a2");
+    assert_eq!(text(0x0ac5f533 /* maxu a0, a1, a2 */), "maxu a0, a1, a2");
+    assert_eq!(text(0x60c59533 /* rol a0, a1, a2 */), "rol a0, a1, a2");
+    assert_eq!(text(0x60c5d533 /* ror a0, a1, a2 */), "ror a0, a1, a2");
+    assert_eq!(text(0x60059513 /* clz a0, a1 */), "clz a0, a1");
+    assert_eq!(text(0x60159513 /* ctz a0, a1 */), "ctz a0, a1");
+    assert_eq!(text(0x60259513 /* cpop a0, a1 */), "cpop a0, a1");
+    assert_eq!(text(0x60459513 /* sext.b a0, a1 */), "sext.b a0, a1");
+    assert_eq!(text(0x60559513 /* sext.h a0, a1 */), "sext.h a0, a1");
+    assert_eq!(text(0x2875d513 /* orc.b a0, a1 */), "orc.b a0, a1");
+    assert_eq!(text(0x6035d513 /* rori a0, a1, 3 */), "rori a0, a1, 3");
+    assert!(Decoder::new().decode(0x6205d513u32 /* rori a0, a1, 32 */).is_err());
+    // rev8 and zext.h only decode with the encoding of the decoder's xlen
+    assert_eq!(Decoder::new().decode(0x6985d513u32).unwrap().to_string(), "rev8 a0, a1");
+    assert_eq!(rv64.decode(0x6b85d513u32).unwrap().to_string(), "rev8 a0, a1");
+    assert!(Decoder::new().decode(0x6b85d513u32).is_err());
+    assert!(rv64.decode(0x6985d513u32).is_err());
+    assert_eq!(Decoder::new().decode(0x0805c533u32).unwrap().to_string(), "zext.h a0, a1");
+    assert_eq!(rv64.decode(0x0805c53bu32).unwrap().to_string(), "zext.h a0, a1");
+    // the word forms only exist on rv64
+    let word = |inst: u32| rv64.decode(inst).unwrap().to_string();
+    assert_eq!(word(0x60c5953b /* rolw a0, a1, a2 */), "rolw a0, a1, a2");
+    assert_eq!(word(0x60c5d53b /* rorw a0, a1, a2 */), "rorw a0, a1, a2");
+    assert_eq!(word(0x6035d51b /* roriw a0, a1, 3 */), "roriw a0, a1, 3");
+    assert_eq!(word(0x6005951b /* clzw a0, a1 */), "clzw a0, a1");
+    assert_eq!(word(0x6015951b /* ctzw a0, a1 */), "ctzw a0, a1");
+    assert_eq!(word(0x6025951b /* cpopw a0, a1 */), "cpopw a0, a1");
+    assert!(rv64.decode(0x6205d51bu32 /* roriw a0, a1, 32 */).is_err());
+    assert!(Decoder::new().decode(0x60c5953bu32).is_err());
+    assert!(Decoder::new().decode(0x6005951bu32).is_err());
+}
+
+decode_test!(
+    clmul,
+    0x0ac59533, /* clmul a0, a1, a2 */
+    InstructionDecoded::Clmul {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+    }
+);
+
+decode_test!(
+    clmulh,
+    0x0ac5b533, /* clmulh a0, a1, a2 */
+    InstructionDecoded::Clmulh {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+    }
+);
+
+decode_test!(
+    clmulr,
+    0x0ac5a533, /* clmulr a0, a1, a2 */
+    InstructionDecoded::Clmulr {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+    }
+);
+
+#[test]
+fn zbs() {
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x48c59533 /* bclr a0, a1, a2 */), "bclr a0, a1, a2");
+    assert_eq!(text(0x48c5d533 /* bext a0, a1, a2 */), "bext a0, a1, a2");
+    assert_eq!(text(0x68c59533 /* binv a0, a1, a2 */), "binv a0, a1, a2");
+    assert_eq!(text(0x28c59533 /* bset a0, a1, a2 */), "bset a0, a1, a2");
+    assert_eq!(text(0x48559513 /* bclri a0, a1, 5 */), "bclri a0, a1, 5");
+    assert_eq!(text(0x4855d513 /* bexti a0, a1, 5 */), "bexti a0, a1, 5");
+    assert_eq!(text(0x68559513 /* binvi a0, a1, 5 */), "binvi a0, a1, 5");
+    assert_eq!(text(0x28559513 /* bseti a0, a1, 5 */), "bseti a0, a1, 5");
+    // bit 63 only exists on rv64
+    assert_eq!(rv64.decode(0x2bf59513u32).unwrap().to_string(), "bseti a0, a1, 63");
+    assert!(Decoder::new().decode(0x2bf59513u32).is_err());
+    assert!(Decoder::new().decode(0x49f5d513u32 /* bexti a0, a1, 31 */).is_ok());
+}
+
+#[test]
+fn zbkb() {
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x08c5c533 /* pack a0, a1, a2 */), "pack a0, a1, a2");
+    assert_eq!(text(0x08c5f533 /* packh a0, a1, a2 */), "packh a0, a1, a2");
+    assert_eq!(text(0x08c5c53b /* packw a0, a1, a2 */), "packw a0, a1, a2");
+    assert_eq!(text(0x6875d513 /* brev8 a0, a1 */), "brev8 a0, a1");
+    assert_eq!(text(0x08f59513 /* zip a0, a1 */), "zip a0, a1");
+    assert_eq!(text(0x08f5d513 /* unzip a0, a1 */), "unzip a0, a1");
+    // pack with rs2 = zero is zext.h on rv32 but stays pack on rv64
+    assert_eq!(Decoder::new().decode(0x0805c533u32).unwrap().to_string(), "zext.h a0, a1");
+    assert_eq!(rv64.decode(0x0805c533u32).unwrap().to_string(), "pack a0, a1, zero");
+    assert!(rv64.decode(0x08f59513u32).is_err());
+    assert!(Decoder::new().decode(0x08c5c53bu32).is_err());
+}
+
+decode_test!(
+    xperm4,
+    0x28c5a533, /* xperm4 a0, a1, a2 */
+    InstructionDecoded::Xperm4 {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+    }
+);
+
+decode_test!(
+    xperm8,
+    0x28c5c533, /* xperm8 a0, a1, a2 */
+    InstructionDecoded::Xperm8 {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+    }
 );
 
+#[test]
+fn aes() {
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let rv32 = |inst| Decoder::new().decode(inst).unwrap().to_string();
+    let text = |inst| rv64.decode(inst).unwrap().to_string();
+    assert_eq!(rv32(0x22c58533u32 /* aes32esi a0, a1, a2, 0 */), "aes32esi a0, a1, a2, 0");
+    assert_eq!(rv32(0x66c58533u32 /* aes32esmi a0, a1, a2, 1 */), "aes32esmi a0, a1, a2, 1");
+    assert_eq!(rv32(0xaac58533u32 /* aes32dsi a0, a1, a2, 2 */), "aes32dsi a0, a1, a2, 2");
+    assert_eq!(rv32(0xeec58533u32 /* aes32dsmi a0, a1, a2, 3 */), "aes32dsmi a0, a1, a2, 3");
+    assert_eq!(text(0x32c58533u32 /* aes64es a0, a1, a2 */), "aes64es a0, a1, a2");
+    assert_eq!(text(0x36c58533u32 /* aes64esm a0, a1, a2 */), "aes64esm a0, a1, a2");
+    assert_eq!(text(0x3ac58533u32 /* aes64ds a0, a1, a2 */), "aes64ds a0, a1, a2");
+    assert_eq!(text(0x3ec58533u32 /* aes64dsm a0, a1, a2 */), "aes64dsm a0, a1, a2");
+    assert_eq!(text(0x7ec58533u32 /* aes64ks2 a0, a1, a2 */), "aes64ks2 a0, a1, a2");
+    assert_eq!(text(0x30059513u32 /* aes64im a0, a1 */), "aes64im a0, a1");
+    assert_eq!(text(0x31a59513u32 /* aes64ks1i a0, a1, 10 */), "aes64ks1i a0, a1, 10");
+    assert!(try_decode(0x31b59513 /* rnum = 11 */).is_err());
+    assert!(rv64.decode(0x22c58533u32).is_err());
+    assert!(Decoder::new().decode(0x32c58533u32).is_err());
+}
+
+#[test]
+fn sha2() {
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let rv32 = |inst| Decoder::new().decode(inst).unwrap().to_string();
+    let text = |inst| rv64.decode(inst).unwrap().to_string();
+    assert_eq!(rv32(0x10059513u32 /* sha256sum0 a0, a1 */), "sha256sum0 a0, a1");
+    assert_eq!(rv32(0x10159513u32 /* sha256sum1 a0, a1 */), "sha256sum1 a0, a1");
+    assert_eq!(rv32(0x10259513u32 /* sha256sig0 a0, a1 */), "sha256sig0 a0, a1");
+    assert_eq!(text(0x10359513u32 /* sha256sig1 a0, a1 */), "sha256sig1 a0, a1");
+    assert_eq!(text(0x10459513u32 /* sha512sum0 a0, a1 */), "sha512sum0 a0, a1");
+    assert_eq!(text(0x10759513u32 /* sha512sig1 a0, a1 */), "sha512sig1 a0, a1");
+    assert_eq!(rv32(0x50c58533u32 /* sha512sum0r a0, a1, a2 */), "sha512sum0r a0, a1, a2");
+    assert_eq!(rv32(0x52c58533u32 /* sha512sum1r a0, a1, a2 */), "sha512sum1r a0, a1, a2");
+    assert_eq!(rv32(0x54c58533u32 /* sha512sig0l a0, a1, a2 */), "sha512sig0l a0, a1, a2");
+    assert_eq!(rv32(0x5ec58533u32 /* sha512sig1h a0, a1, a2 */), "sha512sig1h a0, a1, a2");
+    // the sha512 forms depend on xlen
+    assert!(Decoder::new().decode(0x10459513u32).is_err());
+    assert!(rv64.decode(0x50c58533u32).is_err());
+}
+
+#[test]
+fn shangmi() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x30c58533 /* sm4ed a0, a1, a2, 0 */), "sm4ed a0, a1, a2, 0");
+    assert_eq!(text(0xf0c58533 /* sm4ed a0, a1, a2, 3 */), "sm4ed a0, a1, a2, 3");
+    assert_eq!(text(0x74c58533 /* sm4ks a0, a1, a2, 1 */), "sm4ks a0, a1, a2, 1");
+    assert_eq!(text(0x10859513 /* sm3p0 a0, a1 */), "sm3p0 a0, a1");
+    assert_eq!(text(0x10959513 /* sm3p1 a0, a1 */), "sm3p1 a0, a1");
+    assert_eq!(
+        try_decode(0xb4c58533 /* sm4ks a0, a1, a2, 2 */).unwrap(),
+        InstructionDecoded::Sm4ks {
+            rd: 10,
+            rs1: 11,
+            rs2: 12,
+            bs: 2
+        }
+    );
+}
+
+#[test]
+fn vector_config() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x0d15f557 /* vsetvli a0, a1, e32, m2, ta, ma */), "vsetvli a0, a1, e32, m2, ta, ma");
+    assert_eq!(text(0x0035f557 /* vsetvli a0, a1, e8, m8, tu, mu */), "vsetvli a0, a1, e8, m8, tu, mu");
+    assert_eq!(text(0xcd027557 /* vsetivli a0, 4, e32, m1, ta, ma */), "vsetivli a0, 4, e32, m1, ta, ma");
+    assert_eq!(text(0x80c5f557 /* vsetvl a0, a1, a2 */), "vsetvl a0, a1, a2");
+    // reserved lmul
+    assert_eq!(text(0x0045f557), "vsetvli a0, a1, 4");
+    assert_eq!(
+        try_decode(0x0d15f557).unwrap(),
+        InstructionDecoded::Vsetvli {
+            rd: 10,
+            rs1: 11,
+            vtypei: 0b1101_0001,
+        }
+    );
+}
+
+#[test]
+fn vector_unit_stride() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x02050087 /* vle8.v v1, (a0) */), "vle8.v v1, (a0)");
+    assert_eq!(text(0x00056087 /* vle32.v v1, (a0), v0.t */), "vle32.v v1, (a0), v0.t");
+    assert_eq!(text(0x0205f127 /* vse64.v v2, (a1) */), "vse64.v v2, (a1)");
+    assert_eq!(text(0x0205d127 /* vse16.v v2, (a1) */), "vse16.v v2, (a1)");
+    assert_eq!(text(0x42055207 /* vlseg3e16.v v4, (a0) */), "vlseg3e16.v v4, (a0)");
+    assert_eq!(
+        try_decode(0xe2050227 /* vsseg8e8.v v4, (a0) */).unwrap(),
+        InstructionDecoded::Vse8V {
+            vs3: 4,
+            rs1: 10,
+            nf: 7,
+            masked: false,
+        }
+    );
+    // fault-only-first, lumop = 0b10000, with and without segments
+    assert_eq!(text(0x03050087 /* vle8ff.v v1, (a0) */), "vle8ff.v v1, (a0)");
+    assert_eq!(text(0x23050407 /* vlseg2e8ff.v v8, (a0) */), "vlseg2e8ff.v v8, (a0)");
+    assert_eq!(
+        try_decode(0x61056207 /* vlseg4e32ff.v v4, (a0), v0.t */).unwrap(),
+        InstructionDecoded::Vle32ffV {
+            vd: 4,
+            rs1: 10,
+            nf: 3,
+            masked: true,
+        }
+    );
+    // mew = 1 is reserved
+    assert!(try_decode(0x12050087).is_err());
+    // flh is still scalar
+    assert_eq!(text(0x00851507 /* flh fa0, 8(a0) */), "flh a0, 8(a0)");
+}
+
+#[test]
+fn vector_strided_indexed() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x0ab56087 /* vlse32.v v1, (a0), a1 */), "vlse32.v v1, (a0), a1");
+    assert_eq!(text(0x08b57127 /* vsse64.v v2, (a0), a1, v0.t */), "vsse64.v v2, (a0), a1, v0.t");
+    assert_eq!(text(0x06250087 /* vluxei8.v v1, (a0), v2 */), "vluxei8.v v1, (a0), v2");
+    assert_eq!(text(0x0e255087 /* vloxei16.v v1, (a0), v2 */), "vloxei16.v v1, (a0), v2");
+    assert_eq!(text(0x062560a7 /* vsuxei32.v v1, (a0), v2 */), "vsuxei32.v v1, (a0), v2");
+    assert_eq!(text(0x0e2570a7 /* vsoxei64.v v1, (a0), v2 */), "vsoxei64.v v1, (a0), v2");
+    assert_eq!(text(0x2ab56087 /* vlsseg2e32.v v1, (a0), a1 */), "vlsseg2e32.v v1, (a0), a1");
+    assert_eq!(text(0xe6250087 /* vluxseg8ei8.v v1, (a0), v2 */), "vluxseg8ei8.v v1, (a0), v2");
+}
+
+#[test]
+fn vector_opm() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x963120d7 /* vmul.vv v1, v3, v2 */), "vmul.vv v1, v3, v2");
+    assert_eq!(text(0x943560d7 /* vmul.vx v1, v3, a0, v0.t */), "vmul.vx v1, v3, a0, v0.t");
+    assert_eq!(text(0xb63120d7 /* vmacc.vv v1, v2, v3 */), "vmacc.vv v1, v2, v3");
+    assert_eq!(text(0xbe3560d7 /* vnmsac.vx v1, a0, v3 */), "vnmsac.vx v1, a0, v3");
+    assert_eq!(text(0xee3120d7 /* vwmul.vv v1, v3, v2 */), "vwmul.vv v1, v3, v2");
+    assert_eq!(text(0xd63560d7 /* vwadd.wx v1, v3, a0 */), "vwadd.wx v1, v3, a0");
+    assert_eq!(text(0x023120d7 /* vredsum.vs v1, v3, v2 */), "vredsum.vs v1, v3, v2");
+    assert_eq!(text(0x42302557 /* vmv.x.s a0, v3 */), "vmv.x.s a0, v3");
+    assert_eq!(text(0x420560d7 /* vmv.s.x v1, a0 */), "vmv.s.x v1, a0");
+    assert_eq!(text(0x4a3320d7 /* vzext.vf2 v1, v3 */), "vzext.vf2 v1, v3");
+    assert_eq!(text(0x5e3120d7 /* vcompress.vm v1, v3, v2 */), "vcompress.vm v1, v3, v2");
+    // vmv.x.s can't be masked
+    assert!(try_decode(0x40302557).is_err());
+}
+
+#[test]
+fn vector_opf() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x023110d7 /* vfadd.vv v1, v3, v2 */), "vfadd.vv v1, v3, v2");
+    assert_eq!(text(0x903550d7 /* vfmul.vf v1, v3, fa0, v0.t */), "vfmul.vf v1, v3, a0, v0.t");
+    assert_eq!(text(0xa23110d7 /* vfmadd.vv v1, v2, v3 */), "vfmadd.vv v1, v2, v3");
+    assert_eq!(text(0xb23550d7 /* vfmacc.vf v1, fa0, v3 */), "vfmacc.vf v1, a0, v3");
+    assert_eq!(text(0x0e3110d7 /* vfredosum.vs v1, v3, v2 */), "vfredosum.vs v1, v3, v2");
+    assert_eq!(text(0x4a3090d7 /* vfcvt.x.f.v v1, v3 */), "vfcvt.x.f.v v1, v3");
+    assert_eq!(text(0x4a3a90d7 /* vfncvt.rod.f.f.w v1, v3 */), "vfncvt.rod.f.f.w v1, v3");
+    assert_eq!(text(0x4e3010d7 /* vfsqrt.v v1, v3 */), "vfsqrt.v v1, v3");
+    assert_eq!(text(0x42301557 /* vfmv.f.s fa0, v3 */), "vfmv.f.s a0, v3");
+    assert_eq!(text(0x5e0550d7 /* vfmv.v.f v1, fa0 */), "vfmv.v.f v1, a0");
+    assert_eq!(text(0x5c3550d7 /* vfmerge.vfm v1, v3, fa0, v0 */), "vfmerge.vfm v1, v3, a0, v0");
+    assert_eq!(text(0x623110d7 /* vmfeq.vv v1, v3, v2 */), "vmfeq.vv v1, v3, v2");
+    // the bf16 conversions share VFUNARY0
+    assert_eq!(text(0x4a3e90d7 /* vfncvtbf16.f.f.w v1, v3 */), "vfncvtbf16.f.f.w v1, v3");
+}
+
+#[test]
+fn vector_mask() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x663120d7 /* vmand.mm v1, v3, v2 */), "vmand.mm v1, v3, v2");
+    assert_eq!(text(0x6a3120d7 /* vmor.mm v1, v3, v2 */), "vmor.mm v1, v3, v2");
+    assert_eq!(text(0x6e3120d7 /* vmxor.mm v1, v3, v2 */), "vmxor.mm v1, v3, v2");
+    assert_eq!(text(0x42382557 /* vcpop.m a0, v3 */), "vcpop.m a0, v3");
+    assert_eq!(text(0x4038a557 /* vfirst.m a0, v3, v0.t */), "vfirst.m a0, v3, v0.t");
+    assert_eq!(text(0x5230a0d7 /* vmsbf.m v1, v3 */), "vmsbf.m v1, v3");
+    assert_eq!(text(0x5231a0d7 /* vmsif.m v1, v3 */), "vmsif.m v1, v3");
+    assert_eq!(text(0x523120d7 /* vmsof.m v1, v3 */), "vmsof.m v1, v3");
+    assert_eq!(text(0x523820d7 /* viota.m v1, v3 */), "viota.m v1, v3");
+    assert_eq!(text(0x5008a0d7 /* vid.v v1, v0.t */), "vid.v v1, v0.t");
+    // the mask logical ops are never masked themselves
+    assert!(try_decode(0x643120d7).is_err());
+}
+
+#[test]
+fn vector_whole_register() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x02850087 /* vl1re8.v v1, (a0) */), "vl1re8.v v1, (a0)");
+    assert_eq!(text(0x22856107 /* vl2re32.v v2, (a0) */), "vl2re32.v v2, (a0)");
+    assert_eq!(text(0xe2857407 /* vl8re64.v v8, (a0) */), "vl8re64.v v8, (a0)");
+    assert_eq!(text(0x028500a7 /* vs1r.v v1, (a0) */), "vs1r.v v1, (a0)");
+    assert_eq!(text(0x62850227 /* vs4r.v v4, (a0) */), "vs4r.v v4, (a0)");
+    assert_eq!(text(0x9e2030d7 /* vmv1r.v v1, v2 */), "vmv1r.v v1, v2");
+    assert_eq!(text(0x9f03b457 /* vmv8r.v v8, v16 */), "vmv8r.v v8, v16");
+    // 3 registers isn't a whole register count
+    assert!(try_decode(0x42850087).is_err());
+    assert!(try_decode(0x9e4134d7).is_err());
+    // the register groups have to be aligned to their size
+    assert!(try_decode(0x62856887 /* vl4re32.v v17, (a0) */).is_err());
+    assert!(try_decode(0x228501a7 /* vs2r.v v3, (a0) */).is_err());
+    assert!(try_decode(0x9e43b457 /* vmv8r.v v8, v4 */).is_err());
+}
+
+#[cfg(feature = "zvk")]
+#[test]
+fn vector_crypto() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x062180d7 /* vandn.vv v1, v2, v3 */), "vandn.vv v1, v2, v3");
+    assert_eq!(text(0x042540d7 /* vandn.vx v1, v2, a0, v0.t */), "vandn.vx v1, v2, a0, v0.t");
+    assert_eq!(text(0x562fb0d7 /* vror.vi v1, v2, 63 */), "vror.vi v1, v2, 63");
+    assert_eq!(text(0x5221b0d7 /* vror.vi v1, v2, 3 */), "vror.vi v1, v2, 3");
+    assert_eq!(text(0xd6243257 /* vwsll.vi v4, v2, 8 */), "vwsll.vi v4, v2, 8");
+    assert_eq!(text(0x4a24a0d7 /* vrev8.v v1, v2 */), "vrev8.v v1, v2");
+    assert_eq!(text(0x482720d7 /* vcpop.v v1, v2, v0.t */), "vcpop.v v1, v2, v0.t");
+    assert_eq!(text(0xb221a0f7 /* vghsh.vv v1, v2, v3 */), "vghsh.vv v1, v2, v3");
+    assert_eq!(text(0xa22020f7 /* vaesdm.vv v1, v2 */), "vaesdm.vv v1, v2");
+    assert_eq!(text(0xa623a0f7 /* vaesz.vs v1, v2 */), "vaesz.vs v1, v2");
+    assert_eq!(text(0x8a20a0f7 /* vaeskf1.vi v1, v2, 1 */), "vaeskf1.vi v1, v2, 1");
+    assert_eq!(text(0xb621a0f7 /* vsha2ms.vv v1, v2, v3 */), "vsha2ms.vv v1, v2, v3");
+    // the OP-VE crypto instructions can't be masked
+    assert!(try_decode(0xb421a0f7).is_err());
+}
+
+#[cfg(not(feature = "zvk"))]
+#[test]
+fn vector_crypto_disabled() {
+    assert!(try_decode(0x062180d7 /* vandn.vv v1, v2, v3 */).is_err());
+    assert!(try_decode(0xa22020f7 /* vaesdm.vv v1, v2 */).is_err());
+    assert!(!Decoder::new().supported().any(|info| info.name == "vaesdm.vv"));
+}
+
+#[test]
+fn sfence_vma() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x12000073 /* sfence.vma */), "sfence.vma");
+    assert_eq!(text(0x12050073 /* sfence.vma a0 */), "sfence.vma a0");
+    assert_eq!(text(0x12b50073 /* sfence.vma a0, a1 */), "sfence.vma a0, a1");
+    assert_eq!(
+        try_decode(0x12b50073).unwrap(),
+        InstructionDecoded::SFenceVma { rs1: 10, rs2: 11 }
+    );
+    // rd has to be zero
+    assert!(try_decode(0x12b50573 /* sfence.vma a0, a1 with rd = a0 */).is_err());
+}
+
+#[test]
+fn svinval() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x16b50073 /* sinval.vma a0, a1 */), "sinval.vma a0, a1");
+    assert_eq!(text(0x16000073 /* sinval.vma zero, zero */), "sinval.vma zero, zero");
+    assert_eq!(text(0x18000073 /* sfence.w.inval */), "sfence.w.inval");
+    assert_eq!(text(0x18100073 /* sfence.inval.ir */), "sfence.inval.ir");
+    assert_eq!(text(0x26b50073 /* hinval.vvma a0, a1 */), "hinval.vvma a0, a1");
+    assert_eq!(text(0x66b50073 /* hinval.gvma a0, a1 */), "hinval.gvma a0, a1");
+}
+
+#[test]
+fn pause() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x0100000f /* pause */), "pause");
+    // any other fence is still a fence
+    assert!(matches!(try_decode(0x0ff0000f), Ok(InstructionDecoded::Fence { .. })));
+    // pred is imm[7:4] and succ imm[3:0], `fence rw, w`
+    assert_eq!(try_decode(0x0310000f).unwrap(), InstructionDecoded::Fence { pred: 3, succ: 1 });
+}
+
+#[test]
+fn ntl() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x00200033 /* ntl.p1 */), "ntl.p1");
+    assert_eq!(text(0x00300033 /* ntl.pall */), "ntl.pall");
+    assert_eq!(text(0x00400033 /* ntl.s1 */), "ntl.s1");
+    assert_eq!(text(0x00500033 /* ntl.all */), "ntl.all");
+    // other adds into x0 are just adds
+    assert!(matches!(try_decode(0x00600033), Ok(InstructionDecoded::Add { .. })));
+}
+
+#[test]
+fn cache_block() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x0005200f /* cbo.inval (a0) */), "cbo.inval (a0)");
+    assert_eq!(text(0x0015200f /* cbo.clean (a0) */), "cbo.clean (a0)");
+    assert_eq!(text(0x0025200f /* cbo.flush (a0) */), "cbo.flush (a0)");
+    assert_eq!(text(0x0045200f /* cbo.zero (a0) */), "cbo.zero (a0)");
+    assert_eq!(text(0x00056013 /* prefetch.i 0(a0) */), "prefetch.i 0(a0)");
+    assert_eq!(text(0x02156013 /* prefetch.r 32(a0) */), "prefetch.r 32(a0)");
+    assert_eq!(text(0xfe356013 /* prefetch.w -32(a0) */), "prefetch.w -32(a0)");
+    // an ori with a destination is still an ori
+    assert_eq!(text(0x02156593 /* ori a1, a0, 33 */), "ori a1, a0, 33");
+}
+
+#[test]
+fn zawrs() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x00d00073 /* wrs.nto */), "wrs.nto");
+    assert_eq!(text(0x01d00073 /* wrs.sto */), "wrs.sto");
+}
+
+#[cfg(feature = "p-ext")]
+#[test]
+fn packed_simd() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x40c58577 /* add16 a0, a1, a2 */), "add16 a0, a1, a2");
+    assert_eq!(text(0x48c58577 /* add8 a0, a1, a2 */), "add8 a0, a1, a2");
+    assert_eq!(text(0x4ac58577 /* sub8 a0, a1, a2 */), "sub8 a0, a1, a2");
+    assert_eq!(text(0xa8c58577 /* smul8 a0, a1, a2 */), "smul8 a0, a1, a2");
+    assert_eq!(text(0x0ec59577 /* pkbb16 a0, a1, a2 */), "pkbb16 a0, a1, a2");
+    assert_eq!(text(0x3ec59577 /* pktt16 a0, a1, a2 */), "pktt16 a0, a1, a2");
+}
+
+#[cfg(not(feature = "p-ext"))]
+#[test]
+fn packed_simd_disabled() {
+    assert!(try_decode(0x40c58577 /* add16 a0, a1, a2 */).is_err());
+    assert!(!Decoder::new().supported().any(|info| info.name == "add16"));
+}
+
+#[cfg(feature = "thead")]
+#[test]
+fn thead() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x04c5950b /* th.addsl a0, a1, a2, 2 */), "th.addsl a0, a1, a2, 2");
+    assert_eq!(text(0x0010000b /* th.dcache.call */), "th.dcache.call");
+    assert_eq!(text(0x0255000b /* th.dcache.cva a0 */), "th.dcache.cva a0");
+    assert_eq!(text(0x0100000b /* th.icache.iall */), "th.icache.iall");
+    assert_eq!(text(0x42c5c50b /* th.lrw a0, a1, a2, 1 */), "th.lrw a0, a1, a2, 1");
+    assert_eq!(text(0x5fc5c50b /* th.lwia a0, (a1), -4, 3 */), "th.lwia a0, (a1), -4, 3");
+    assert_eq!(text(0x08c5d50b /* th.sbib a0, (a1), 12, 0 */), "th.sbib a0, (a1), 12, 0");
+    assert_eq!(text(0x60c5d50b /* th.srd a0, a1, a2, 0 */), "th.srd a0, a1, a2, 0");
+}
+
+#[cfg(not(feature = "thead"))]
+#[test]
+fn thead_disabled() {
+    assert!(try_decode(0x04c5950b /* th.addsl a0, a1, a2, 2 */).is_err());
+    assert!(!Decoder::new().supported().any(|info| info.name == "th.addsl"));
+}
+
+#[test]
+fn custom_hooks() {
+    // a made up accelerator op, `acc.mac rd, rs1, rs2` in custom-1 with funct3 = 0
+    fn accelerator(bits: InstructionSize) -> Option<InstructionDecoded> {
+        let inst = rtype::RType::new(bits);
+        (inst.funct3() == 0).then(|| InstructionDecoded::Custom {
+            name: "acc.mac",
+            bits,
+            rd: Some(inst.rd()),
+            rs1: Some(inst.rs1()),
+            rs2: Some(inst.rs2()),
+        })
+    }
+    let mut decoder = Decoder::new();
+    assert!(decoder.decode(0x00c5852bu32).is_err());
+    decoder.register_custom(CUSTOM_1_MATCH, accelerator).unwrap();
+    assert_eq!(decoder.decode(0x00c5852bu32).unwrap().to_string(), "acc.mac a0, a1, a2");
+    // the hook turned it down
+    assert!(decoder.decode(0x00c5952bu32).is_err());
+    // other opcodes never reach it
+    assert_eq!(decoder.decode(0x00c58533u32).unwrap().to_string(), "add a0, a1, a2");
+    assert!(decoder.supported().any(|info| info.name == "custom"));
+    assert!(decoder.register_custom(ARITMETIC_REGISTER_MATCH, accelerator).is_err());
+}
+
+#[test]
+fn trap_returns() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x30200073 /* mret */), "mret");
+    assert_eq!(text(0x70200073 /* mnret */), "mnret");
+    assert_eq!(text(0x7b200073 /* dret */), "dret");
+}
+
+#[test]
+fn control_flow_integrity() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0xce104073 /* sspush ra */), "sspush ra");
+    assert_eq!(text(0xce504073 /* sspush t0 */), "sspush t0");
+    assert_eq!(text(0xcdc0c073 /* sspopchk ra */), "sspopchk ra");
+    assert_eq!(text(0xcdc04573 /* ssrdp a0 */), "ssrdp a0");
+    assert_eq!(text(0x48b5252f /* ssamoswap.w a0, a1, (a0) */), "ssamoswap.w a0, a1, (a0)");
+    assert_eq!(text(0x00001017 /* lpad 1 */), "lpad 1");
+    assert_eq!(text(0x00001517 /* auipc a0, 1 */), "auipc a0, 1");
+    // only the link registers can be pushed
+    assert!(try_decode(0xce204073).is_err());
+}
+
+#[test]
+fn compressed_quadrant0() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x0808 /* c.addi4spn a0, sp, 16 */), "c.addi4spn a0, 16");
+    assert_eq!(text(0x41c8 /* c.lw a0, 4(a1) */), "c.lw a0, 4(a1)");
+    assert_eq!(text(0xc588 /* c.sw a0, 8(a1) */), "c.sw a0, 8(a1)");
+    assert_eq!(text(0x61c8 /* c.flw fa0, 4(a1) */), "c.flw a0, 4(a1)");
+    assert_eq!(text(0xe588 /* c.fsw fa0, 8(a1) */), "c.fsw a0, 8(a1)");
+    assert_eq!(text(0x2588 /* c.fld fa0, 8(a1) */), "c.fld a0, 8(a1)");
+    assert_eq!(text(0xbfe0 /* c.fsd fs0, 248(a5) */), "c.fsd s0, 248(a5)");
+    assert_eq!(text(0x5ffc /* c.lw a5, 124(a5) */), "c.lw a5, 124(a5)");
+    // the all zero halfword is illegal
+    assert!(try_decode(0x0000).is_err());
+}
+
+#[test]
+fn compressed_quadrant1() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x0001 /* c.nop */), "c.nop");
+    assert_eq!(text(0x157d /* c.addi a0, -1 */), "c.addi a0, -1");
+    assert_eq!(text(0x2011 /* c.jal 4 */), "c.jal 4");
+    assert_eq!(text(0x3ff5 /* c.jal -4 */), "c.jal -4");
+    assert_eq!(text(0x4515 /* c.li a0, 5 */), "c.li a0, 5");
+    assert_eq!(text(0x557d /* c.li a0, -1 */), "c.li a0, -1");
+    assert_eq!(text(0x6141 /* c.addi16sp sp, 16 */), "c.addi16sp 16");
+    assert_eq!(text(0x7179 /* c.addi16sp sp, -48 */), "c.addi16sp -48");
+    assert_eq!(text(0x6505 /* c.lui a0, 1 */), "c.lui a0, 0x1");
+    assert_eq!(text(0x757d /* c.lui a0, 0xfffff */), "c.lui a0, 0xFFFFF");
+    // zero immediates are reserved for c.addi16sp and c.lui
+    assert!(try_decode(0x6101).is_err());
+    assert!(try_decode(0x6501).is_err());
+}
+
+#[test]
+fn compressed_alu() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x8105 /* c.srli a0, 1 */), "c.srli a0, 1");
+    assert_eq!(text(0x857d /* c.srai a0, 31 */), "c.srai a0, 31");
+    assert_eq!(text(0x897d /* c.andi a0, 31 */), "c.andi a0, 31");
+    assert_eq!(text(0x9941 /* c.andi a0, -16 */), "c.andi a0, -16");
+    assert_eq!(text(0x8d0d /* c.sub a0, a1 */), "c.sub a0, a1");
+    assert_eq!(text(0x8d2d /* c.xor a0, a1 */), "c.xor a0, a1");
+    assert_eq!(text(0x8d4d /* c.or a0, a1 */), "c.or a0, a1");
+    assert_eq!(text(0x8d6d /* c.and a0, a1 */), "c.and a0, a1");
+    // c.subw is rv64 only
+    assert!(Decoder::new().decode(0x9d0du16).is_err());
+}
+
+#[test]
+fn compressed_jumps() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0xa801 /* c.j 16 */), "c.j 16");
+    assert_eq!(text(0xbffd /* c.j -2 */), "c.j -2");
+    assert_eq!(text(0xaffd /* c.j 2046 */), "c.j 2046");
+    assert_eq!(text(0xb001 /* c.j -2048 */), "c.j -2048");
+    assert_eq!(text(0xc501 /* c.beqz a0, 8 */), "c.beqz a0, 8");
+    assert_eq!(text(0xcffd /* c.beqz a5, 254 */), "c.beqz a5, 254");
+    assert_eq!(text(0xfd7d /* c.bnez a0, -2 */), "c.bnez a0, -2");
+    assert_eq!(text(0xf001 /* c.bnez s0, -256 */), "c.bnez s0, -256");
+    let beqz = try_decode(0xc501).unwrap();
+    assert_eq!(beqz.branch_target(0x1000), Some(0x1008));
+}
+
+#[test]
+fn compressed_quadrant2() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x050e /* c.slli a0, 3 */), "c.slli a0, a0, 3");
+    assert_eq!(text(0x4532 /* c.lwsp a0, 12(sp) */), "c.lwsp a0, 12(sp)");
+    assert_eq!(text(0x50fe /* c.lwsp ra, 252(sp) */), "c.lwsp ra, 252(sp)");
+    assert_eq!(text(0xc606 /* c.swsp ra, 12(sp) */), "c.swsp ra, 12(sp)");
+    assert_eq!(text(0x6532 /* c.flwsp fa0, 12(sp) */), "c.flwsp a0, 12(sp)");
+    assert_eq!(text(0xe62a /* c.fswsp fa0, 12(sp) */), "c.fswsp a0, 12(sp)");
+    assert_eq!(text(0x2522 /* c.fldsp fa0, 8(sp) */), "c.fldsp a0, 8(sp)");
+    assert_eq!(text(0xa42a /* c.fsdsp fa0, 8(sp) */), "c.fsdsp a0, 8(sp)");
+    // c.lwsp into x0 is reserved
+    assert!(try_decode(0x4032).is_err());
+}
+
+#[test]
+fn compressed_registers() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x8082 /* c.jr ra */), "c.jr ra");
+    assert_eq!(text(0x852e /* c.mv a0, a1 */), "c.mv a0, a1");
+    assert_eq!(text(0x9002 /* c.ebreak */), "c.ebreak");
+    assert_eq!(text(0x9502 /* c.jalr a0 */), "c.jalr a0");
+    assert_eq!(text(0x952e /* c.add a0, a1 */), "c.add a0, a1");
+    // c.jr with rs1 = x0 is reserved
+    assert!(try_decode(0x8002).is_err());
+}
+
+#[test]
+fn compressed_rv64() {
+    let rv32 = Decoder::new();
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let text = |decoder: &Decoder, inst: u16| decoder.decode(inst).unwrap().to_string();
+    assert_eq!(text(&rv64, 0x357d /* c.addiw a0, -1 */), "c.addiw a0, -1");
+    assert_eq!(text(&rv64, 0x6588 /* c.ld a0, 8(a1) */), "c.ld a0, 8(a1)");
+    assert_eq!(text(&rv64, 0xe588 /* c.sd a0, 8(a1) */), "c.sd a0, 8(a1)");
+    assert_eq!(text(&rv64, 0x6522 /* c.ldsp a0, 8(sp) */), "c.ldsp a0, 8(sp)");
+    assert_eq!(text(&rv64, 0xe406 /* c.sdsp ra, 8(sp) */), "c.sdsp ra, 8(sp)");
+    assert_eq!(text(&rv64, 0x9d0d /* c.subw a0, a1 */), "c.subw a0, a1");
+    assert_eq!(text(&rv64, 0x9d2d /* c.addw a0, a1 */), "c.addw a0, a1");
+    assert_eq!(text(&rv64, 0x1502 /* c.slli a0, 32 */), "c.slli a0, a0, 32");
+
+    // the same encodings on rv32
+    assert_eq!(text(&rv32, 0x357d), "c.jal -338");
+    assert_eq!(text(&rv32, 0x6588), "c.flw a0, 8(a1)");
+    assert_eq!(text(&rv32, 0xe588), "c.fsw a0, 8(a1)");
+    assert_eq!(text(&rv32, 0x6522), "c.flwsp a0, 8(sp)");
+    assert_eq!(text(&rv32, 0xe406), "c.fswsp ra, 8(sp)");
+    assert!(rv32.decode(0x9d0du16).is_err());
+    assert!(rv32.decode(0x1502u16).is_err());
+    // rd = x0 is reserved for c.addiw
+    assert!(rv64.decode(0x2005u16).is_err());
+}
+
+#[test]
+fn zcb() {
+    let text = |inst| try_decode(inst).unwrap().to_string();
+    assert_eq!(text(0x8188 /* c.lbu a0, 0(a1) */), "c.lbu a0, 0(a1)");
+    assert_eq!(text(0x81e8 /* c.lbu a0, 3(a1) */), "c.lbu a0, 3(a1)");
+    assert_eq!(text(0x85a8 /* c.lhu a0, 2(a1) */), "c.lhu a0, 2(a1)");
+    assert_eq!(text(0x85e8 /* c.lh a0, 2(a1) */), "c.lh a0, 2(a1)");
+    assert_eq!(text(0x89c8 /* c.sb a0, 1(a1) */), "c.sb a0, 1(a1)");
+    assert_eq!(text(0x8da8 /* c.sh a0, 2(a1) */), "c.sh a0, 2(a1)");
+    assert_eq!(text(0x9d61 /* c.zext.b a0 */), "c.zext.b a0");
+    assert_eq!(text(0x9d65 /* c.sext.b a0 */), "c.sext.b a0");
+    assert_eq!(text(0x9d69 /* c.zext.h a0 */), "c.zext.h a0");
+    assert_eq!(text(0x9d6d /* c.sext.h a0 */), "c.sext.h a0");
+    assert_eq!(text(0x9d75 /* c.not a0 */), "c.not a0");
+    assert_eq!(text(0x9d4d /* c.mul a0, a1 */), "c.mul a0, a1");
+    // c.zext.w is rv64 only
+    assert!(Decoder::new().decode(0x9d71u16).is_err());
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    assert_eq!(rv64.decode(0x9d71u16).unwrap().to_string(), "c.zext.w a0");
+    // bit 6 set is reserved for c.sh
+    assert!(try_decode(0x8de8).is_err());
+}
+
+#[test]
+fn zcmp() {
+    let decoder = Decoder {
+        compressed_double: CompressedDouble::Zcmp,
+        ..Decoder::new()
+    };
+    let text = |decoder: &Decoder, inst: u16| decoder.decode(inst).unwrap().to_string();
+    assert_eq!(text(&decoder, 0xb876 /* cm.push {ra, s0-s2}, -32 */), "cm.push {ra, s0-s2}, -32");
+    assert_eq!(text(&decoder, 0xb842 /* cm.push {ra}, -16 */), "cm.push {ra}, -16");
+    assert_eq!(text(&decoder, 0xb8fe /* cm.push {ra, s0-s11}, -112 */), "cm.push {ra, s0-s11}, -112");
+    assert_eq!(text(&decoder, 0xba76 /* cm.pop {ra, s0-s2}, 32 */), "cm.pop {ra, s0-s2}, 32");
+    assert_eq!(text(&decoder, 0xbc52 /* cm.popretz {ra, s0}, 16 */), "cm.popretz {ra, s0}, 16");
+    assert_eq!(text(&decoder, 0xbe56 /* cm.popret {ra, s0}, 32 */), "cm.popret {ra, s0}, 32");
+    assert_eq!(text(&decoder, 0xac26 /* cm.mvsa01 s0, s1 */), "cm.mvsa01 s0, s1");
+    assert_eq!(text(&decoder, 0xace2 /* cm.mva01s s1, s0 */), "cm.mva01s s1, s0");
+    // rv64 saves twice as many bytes per register
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..decoder
+    };
+    assert_eq!(text(&rv64, 0xb876), "cm.push {ra, s0-s2}, -48");
+    assert_eq!(text(&rv64, 0xb8fe), "cm.push {ra, s0-s11}, -160");
+    // rlist 0-3 is reserved, so is moving both values into the same register, and c.fld is gone
+    // along with c.fsdsp
+    assert!(decoder.decode(0xaca6u16).is_err());
+    assert!(decoder.decode(0xb802u16).is_err());
+    assert!(decoder.decode(0x2588u16).is_err());
+    // the same encoding is c.fsdsp without Zcmp
+    assert_eq!(text(&Decoder::new(), 0xb876), "c.fsdsp t4, 48(sp)");
+}
+
+#[test]
+fn zcmt() {
+    let decoder = Decoder {
+        compressed_double: CompressedDouble::Zcmp,
+        ..Decoder::new()
+    };
+    let text = |inst: u16| decoder.decode(inst).unwrap().to_string();
+    assert_eq!(text(0xa002 /* cm.jt 0 */), "cm.jt 0");
+    assert_eq!(text(0xa016 /* cm.jt 5 */), "cm.jt 5");
+    assert_eq!(text(0xa07e /* cm.jt 31 */), "cm.jt 31");
+    assert_eq!(text(0xa082 /* cm.jalt 32 */), "cm.jalt 32");
+    assert_eq!(text(0xa3fe /* cm.jalt 255 */), "cm.jalt 255");
+    let jalt = decoder.decode(0xa082u16).unwrap().effects();
+    assert_eq!(
+        jalt.control_flow,
+        Some(crate::effects::ControlFlow::IndirectJump { link: true })
+    );
+}
+
+#[test]
+fn decompressing_decoder() {
+    let decoder = Decoder {
+        decompress: true,
+        ..Decoder::new()
+    };
+    let text = |decoder: &Decoder, inst: u16| decoder.decode(inst).unwrap().to_string();
+    assert_eq!(text(&decoder, 0x4502 /* c.lwsp a0, 0(sp) */), "lw a0, 0(sp)");
+    assert_eq!(text(&decoder, 0x9002 /* c.ebreak */), "ebreak");
+    assert_eq!(text(&decoder, 0x2000 /* c.fld fs0, 0(s0) */), "fld s0, 0(s0)");
+    // without a base equivalent the compressed inst comes back as is
+    let zcmp = Decoder {
+        compressed_double: CompressedDouble::Zcmp,
+        ..decoder
+    };
+    assert_eq!(text(&zcmp, 0xb842 /* cm.push {ra}, -16 */), "cm.push {ra}, -16");
+    // decompression happens after the xlen checks
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..decoder
+    };
+    assert_eq!(text(&rv64, 0x2505 /* c.addiw a0, 1 */), "addiw a0, a0, 1");
+    assert_eq!(text(&decoder, 0x2505 /* c.jal 1568 */), "jal 1568(ra)");
+}
+
 // TODO: add more tests!
@@ -0,0 +1,270 @@
+use std::ops::Range;
+
+use crate::instructions::InstructionSize;
+use crate::stream::Decoded;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HunkKind {
+    Inserted,
+    Removed,
+    Changed,
+}
+
+// a run of lines that differ, as indices into the old and new listing. one of the ranges is empty
+// for pure insertions/removals
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    pub old: Range<usize>,
+    pub new: Range<usize>,
+}
+
+impl Hunk {
+    pub fn kind(&self) -> HunkKind {
+        match (self.old.is_empty(), self.new.is_empty()) {
+            (true, _) => HunkKind::Inserted,
+            (_, true) => HunkKind::Removed,
+            _ => HunkKind::Changed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Equal,
+    Remove,
+    Insert,
+}
+
+// myers' diff, the shortest edit script between `old` and `new`
+fn edits<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    let index = |k: isize| (k + max) as usize;
+
+    let mut v = vec![0isize; 2 * max as usize + 2];
+    let mut trace = Vec::new();
+    'outer: for d in 0..=max {
+        // only the diagonals the walk back can look at, -d..=d+1. keeping all of `v` would make
+        // the trace grow with the length of the input instead of with the number of edits
+        trace.push(v[index(-d)..=index(d + 1)].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+                v[index(k + 1)]
+            } else {
+                v[index(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index(k)] = x;
+            if x >= n && y >= m {
+                break 'outer;
+            }
+        }
+    }
+
+    // walk back through the trace to find the path that got there
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let (d, k) = (d as isize, x - y);
+        // the saved part of `v` starts at diagonal -d
+        let at = |k: isize| v[(k + d) as usize];
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            edits.push(Edit::Equal);
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            edits.push(if x == prev_x {
+                Edit::Insert
+            } else {
+                Edit::Remove
+            });
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+    edits.reverse();
+    edits
+}
+
+// the places `old` and `new` differ, in order
+pub fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Hunk> {
+    // the common start and end are skipped up front, for patched firmware that's nearly everything
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let (mut i, mut j) = (prefix, prefix);
+    for edit in edits(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    ) {
+        let (old_step, new_step) = match edit {
+            Edit::Equal => {
+                (i, j) = (i + 1, j + 1);
+                continue;
+            }
+            Edit::Remove => (1, 0),
+            Edit::Insert => (0, 1),
+        };
+        match hunks.last_mut() {
+            Some(hunk) if hunk.old.end == i && hunk.new.end == j => {
+                hunk.old.end += old_step;
+                hunk.new.end += new_step;
+            }
+            _ => hunks.push(Hunk {
+                old: i..i + old_step,
+                new: j..j + new_step,
+            }),
+        }
+        (i, j) = (i + old_step, j + new_step);
+    }
+    hunks
+}
+
+// one line of a listing the way the diff compares them, addresses aren't part of the comparison
+// since everything after an insertion moves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub addr: InstructionSize,
+    pub text: String,
+}
+
+impl From<Decoded> for Line {
+    fn from(decoded: Decoded) -> Self {
+        let text = match decoded.inst {
+            Ok(inst) => inst.to_string(),
+            Err(_) => format!(".word {:#x}", decoded.raw),
+        };
+        Self {
+            addr: decoded.addr,
+            text,
+        }
+    }
+}
+
+// diffs two listings by instruction text only
+pub fn diff_listings(old: &[Line], new: &[Line]) -> Vec<Hunk> {
+    let old = old.iter().map(|line| &line.text).collect::<Vec<_>>();
+    let new = new.iter().map(|line| &line.text).collect::<Vec<_>>();
+    diff(&old, &new)
+}
+
+#[test]
+fn hunks() {
+    let old = ["a", "b", "c", "d", "e"];
+    assert_eq!(diff(&old, &old), vec![]);
+
+    let new = ["a", "b", "x", "d", "e"];
+    let hunks = diff(&old, &new);
+    assert_eq!(
+        hunks,
+        vec![Hunk {
+            old: 2..3,
+            new: 2..3
+        }]
+    );
+    assert_eq!(hunks[0].kind(), HunkKind::Changed);
+
+    let new = ["a", "b", "x", "y", "c", "d"];
+    let hunks = diff(&old, &new);
+    assert_eq!(
+        hunks,
+        vec![
+            Hunk {
+                old: 2..2,
+                new: 2..4
+            },
+            Hunk {
+                old: 4..5,
+                new: 6..6
+            }
+        ]
+    );
+    assert_eq!(hunks[0].kind(), HunkKind::Inserted);
+    assert_eq!(hunks[1].kind(), HunkKind::Removed);
+
+    assert_eq!(
+        diff(&[] as &[&str], &["a"]),
+        vec![Hunk {
+            old: 0..0,
+            new: 0..1
+        }]
+    );
+    assert_eq!(
+        diff(&["a"], &[] as &[&str]),
+        vec![Hunk {
+            old: 0..1,
+            new: 0..0
+        }]
+    );
+}
+
+#[test]
+fn shortest_edits() {
+    // the example from the paper, 5 edits is the shortest
+    let old = "abcabba".chars().collect::<Vec<_>>();
+    let new = "cbabac".chars().collect::<Vec<_>>();
+    let hunks = diff(&old, &new);
+    let edits = hunks
+        .iter()
+        .map(|hunk| hunk.old.len() + hunk.new.len())
+        .sum::<usize>();
+    assert_eq!(edits, 5);
+
+    // putting the new side of every hunk in place of the old one gives `new` back
+    let mut patched: Vec<char> = Vec::new();
+    let mut at = 0;
+    for hunk in &hunks {
+        patched.extend(&old[at..hunk.old.start]);
+        patched.extend(&new[hunk.new.clone()]);
+        at = hunk.old.end;
+    }
+    patched.extend(&old[at..]);
+    assert_eq!(patched, new);
+}
+
+#[test]
+fn listings() {
+    use crate::stream::InstructionStream;
+    let old = [
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+        0x6f, 0x00, 0x00, 0x01, /* jal x0 16 */
+    ];
+    let new = [
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+        0x13, 0x00, 0x00, 0x00, /* nop */
+        0x6f, 0x00, 0x00, 0x01, /* jal x0 16 */
+    ];
+    let listing = |bytes| {
+        InstructionStream::new(bytes, 0x1000)
+            .unwrap()
+            .map(Line::from)
+            .collect::<Vec<_>>()
+    };
+    let (old, new) = (listing(&old[..]), listing(&new[..]));
+    let hunks = diff_listings(&old, &new);
+    assert_eq!(
+        hunks,
+        vec![Hunk {
+            old: 1..1,
+            new: 1..2
+        }]
+    );
+    assert_eq!(new[1].addr, 0x1004);
+}
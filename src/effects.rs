@@ -0,0 +1,2738 @@
+use crate::decoded_inst::InstructionDecoded;
+use crate::instructions::compressed::rlist_registers;
+use crate::instructions::{InstructionSize, SignedInstructionSize};
+
+// the Zicfiss shadow stack pointer
+const SSP: InstructionSize = 0x011;
+// the Zcmt jump table base
+const JVT: InstructionSize = 0x017;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegisterFile {
+    Int,
+    Float,
+    Vector,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegisterAccess {
+    pub file: RegisterFile,
+    pub num: InstructionSize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryKind {
+    Load,
+    Store,
+    // atomics read and write the same address in one go
+    ReadModifyWrite,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryAccess {
+    pub kind: MemoryKind,
+    // the address is the value of `base` plus `offset`
+    pub base: InstructionSize,
+    pub offset: SignedInstructionSize,
+    // in bytes
+    pub width: u8,
+    pub signed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsrAccess {
+    pub csr: InstructionSize,
+    pub read: bool,
+    pub write: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlFlow {
+    // conditional, pc relative
+    Branch {
+        offset: SignedInstructionSize,
+    },
+    // unconditional, pc relative
+    Jump {
+        offset: SignedInstructionSize,
+        link: bool,
+    },
+    // target comes from a register
+    IndirectJump {
+        link: bool,
+    },
+    Trap,
+    TrapReturn,
+}
+
+// everything an instruction does to architectural state, x0 is never listed since reading it is
+// always zero and writing it does nothing
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Effects {
+    pub reads: Vec<RegisterAccess>,
+    pub writes: Vec<RegisterAccess>,
+    pub memory: Option<MemoryAccess>,
+    pub csr: Option<CsrAccess>,
+    pub control_flow: Option<ControlFlow>,
+}
+
+fn x(num: InstructionSize) -> Option<RegisterAccess> {
+    (num != 0).then_some(RegisterAccess {
+        file: RegisterFile::Int,
+        num,
+    })
+}
+
+fn f(num: InstructionSize) -> Option<RegisterAccess> {
+    Some(RegisterAccess {
+        file: RegisterFile::Float,
+        num,
+    })
+}
+
+fn v(num: InstructionSize) -> Option<RegisterAccess> {
+    Some(RegisterAccess {
+        file: RegisterFile::Vector,
+        num,
+    })
+}
+
+// masked vector instructions also read the mask out of v0
+fn v0_if(masked: bool) -> Option<RegisterAccess> {
+    masked.then_some(RegisterAccess {
+        file: RegisterFile::Vector,
+        num: 0,
+    })
+}
+
+// cm.pop and friends reload the rlist from the frame they free
+fn zcmp_pop(e: Effects, rlist: InstructionSize) -> Effects {
+    rlist_registers(rlist)
+        .iter()
+        .fold(e.read(x(2)), |e, &reg| e.write(x(reg)))
+        .write(x(2))
+        .memory(MemoryKind::Load, 2, 0, 4, false)
+}
+
+impl Effects {
+    fn read(mut self, reg: Option<RegisterAccess>) -> Self {
+        if let Some(reg) = reg {
+            if !self.reads.contains(&reg) {
+                self.reads.push(reg);
+            }
+        }
+        self
+    }
+
+    fn write(mut self, reg: Option<RegisterAccess>) -> Self {
+        if let Some(reg) = reg {
+            self.writes.push(reg);
+        }
+        self
+    }
+
+    fn memory(
+        mut self,
+        kind: MemoryKind,
+        base: InstructionSize,
+        offset: InstructionSize,
+        width: u8,
+        signed: bool,
+    ) -> Self {
+        self.memory = Some(MemoryAccess {
+            kind,
+            base,
+            offset: offset as SignedInstructionSize,
+            width,
+            signed,
+        });
+        self
+    }
+
+    fn csr(mut self, csr: InstructionSize, read: bool, write: bool) -> Self {
+        self.csr = Some(CsrAccess { csr, read, write });
+        self
+    }
+
+    fn control_flow(mut self, control_flow: ControlFlow) -> Self {
+        self.control_flow = Some(control_flow);
+        self
+    }
+}
+
+impl InstructionDecoded {
+    pub fn effects(&self) -> Effects {
+        use InstructionDecoded as I;
+        let e = Effects::default();
+
+        match *self {
+            I::Lb { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 1, true)
+            }
+            I::Lh { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 2, true)
+            }
+            I::Lw { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 4, true)
+            }
+            I::Lbu { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 1, false)
+            }
+            I::Lhu { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 2, false)
+            }
+            I::Lwu { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 4, false)
+            }
+            I::Ld { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 8, false)
+            }
+            I::Sb { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 1, false)
+            }
+            I::Sh { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 2, false)
+            }
+            I::Sw { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 4, false)
+            }
+            I::Sd { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 8, false)
+            }
+            I::Flw { rd, rs1, imm, .. } => {
+                e.read(x(rs1))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 4, false)
+            }
+            I::Fsw { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 4, false)
+            }
+            I::Fld { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 8, false)
+            }
+            I::Fsd { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 8, false)
+            }
+
+            I::Addi { rd, rs1, .. }
+            | I::Slli { rd, rs1, .. }
+            | I::Slti { rd, rs1, .. }
+            | I::Sltiu { rd, rs1, .. }
+            | I::Xori { rd, rs1, .. }
+            | I::Srli { rd, rs1, .. }
+            | I::Srai { rd, rs1, .. }
+            | I::Ori { rd, rs1, .. }
+            | I::Andi { rd, rs1, .. }
+            | I::Addiw { rd, rs1, .. }
+            | I::Slliw { rd, rs1, .. }
+            | I::Srliw { rd, rs1, .. }
+            | I::Sraiw { rd, rs1, .. }
+            | I::CSlli { rd, rs1, .. } => e.read(x(rs1)).write(x(rd)),
+            I::CAddi4Spn { rd, .. } => e.read(x(2)).write(x(rd)),
+            I::CmJt { .. } => e
+                .csr(JVT, true, false)
+                .control_flow(ControlFlow::IndirectJump { link: false }),
+            I::CmJalt { .. } => e
+                .csr(JVT, true, false)
+                .write(x(1))
+                .control_flow(ControlFlow::IndirectJump { link: true }),
+            // the saved registers sit between the old and new sp, only the bottom of that frame is
+            // given
+            I::CmPush { rlist, stack_adj } => rlist_registers(rlist)
+                .iter()
+                .fold(e.read(x(2)), |e, &reg| e.read(x(reg)))
+                .write(x(2))
+                .memory(MemoryKind::Store, 2, stack_adj.wrapping_neg(), 4, false),
+            I::CmPop { rlist, .. } => zcmp_pop(e, rlist),
+            I::CmPopretz { rlist, .. } => zcmp_pop(e, rlist)
+                .write(x(10))
+                .control_flow(ControlFlow::IndirectJump { link: false }),
+            I::CmPopret { rlist, .. } => {
+                zcmp_pop(e, rlist).control_flow(ControlFlow::IndirectJump { link: false })
+            }
+            I::CmMvsa01 { r1s, r2s } => e.read(x(10)).read(x(11)).write(x(r1s)).write(x(r2s)),
+            I::CmMva01s { r1s, r2s } => e.read(x(r1s)).read(x(r2s)).write(x(10)).write(x(11)),
+            I::CLbu { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 1, false)
+            }
+            I::CLhu { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 2, false)
+            }
+            I::CLh { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 2, true)
+            }
+            I::CSb { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 1, false)
+            }
+            I::CSh { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 2, false)
+            }
+            I::CZextB { rd }
+            | I::CSextB { rd }
+            | I::CZextH { rd }
+            | I::CSextH { rd }
+            | I::CZextW { rd }
+            | I::CNot { rd } => e.read(x(rd)).write(x(rd)),
+            I::CMul { rd, rs2 } => e.read(x(rd)).read(x(rs2)).write(x(rd)),
+            I::CAddiw { rd, .. } => e.read(x(rd)).write(x(rd)),
+            I::CSubw { rd, rs2 } | I::CAddw { rd, rs2 } => e.read(x(rd)).read(x(rs2)).write(x(rd)),
+            I::CLd { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 8, false)
+            }
+            I::CSd { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 8, false)
+            }
+            I::CLdsp { rd, imm } => {
+                e.read(x(2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, 2, imm, 8, false)
+            }
+            I::CSdsp { rs2, imm } => {
+                e.read(x(2))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, 2, imm, 8, false)
+            }
+            I::CJr { rs1 } => e
+                .read(x(rs1))
+                .control_flow(ControlFlow::IndirectJump { link: false }),
+            I::CJalr { rs1 } => e
+                .read(x(rs1))
+                .write(x(1))
+                .control_flow(ControlFlow::IndirectJump { link: true }),
+            I::CMv { rd, rs2 } => e.read(x(rs2)).write(x(rd)),
+            I::CAdd { rd, rs2 } => e.read(x(rd)).read(x(rs2)).write(x(rd)),
+            I::CEbreak => e.control_flow(ControlFlow::Trap),
+            I::CLwsp { rd, imm } => {
+                e.read(x(2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, 2, imm, 4, true)
+            }
+            I::CSwsp { rs2, imm } => {
+                e.read(x(2))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, 2, imm, 4, false)
+            }
+            I::CFlwsp { rd, imm } => {
+                e.read(x(2))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, 2, imm, 4, false)
+            }
+            I::CFswsp { rs2, imm } => {
+                e.read(x(2))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, 2, imm, 4, false)
+            }
+            I::CFldsp { rd, imm } => {
+                e.read(x(2))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, 2, imm, 8, false)
+            }
+            I::CFsdsp { rs2, imm } => {
+                e.read(x(2))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, 2, imm, 8, false)
+            }
+            I::CJ { imm } => e.control_flow(ControlFlow::Jump {
+                offset: imm as SignedInstructionSize,
+                link: false,
+            }),
+            I::CBeqz { rs1, imm } | I::CBnez { rs1, imm } => {
+                e.read(x(rs1)).control_flow(ControlFlow::Branch {
+                    offset: imm as SignedInstructionSize,
+                })
+            }
+            I::CSrli { rd, .. } | I::CSrai { rd, .. } | I::CAndi { rd, .. } => {
+                e.read(x(rd)).write(x(rd))
+            }
+            I::CSub { rd, rs2 }
+            | I::CXor { rd, rs2 }
+            | I::COr { rd, rs2 }
+            | I::CAnd { rd, rs2 } => e.read(x(rd)).read(x(rs2)).write(x(rd)),
+            I::CAddi { rd, .. } => e.read(x(rd)).write(x(rd)),
+            I::CAddi16Sp { .. } => e.read(x(2)).write(x(2)),
+            I::CLi { rd, .. } | I::CLui { rd, .. } => e.write(x(rd)),
+            I::CJal { imm } => e.write(x(1)).control_flow(ControlFlow::Jump {
+                offset: imm as SignedInstructionSize,
+                link: true,
+            }),
+            I::CLw { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 4, true)
+            }
+            I::CSw { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 4, false)
+            }
+            I::CFlw { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 4, false)
+            }
+            I::CFsw { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 4, false)
+            }
+            I::CFld { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 8, false)
+            }
+            I::CFsd { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 8, false)
+            }
+            I::Lui { rd, .. } | I::AuiPc { rd, .. } => e.write(x(rd)),
+
+            I::Add { rd, rs1, rs2 }
+            | I::Sub { rd, rs1, rs2 }
+            | I::Sll { rd, rs1, rs2 }
+            | I::Slt { rd, rs1, rs2 }
+            | I::Sltu { rd, rs1, rs2 }
+            | I::Xor { rd, rs1, rs2 }
+            | I::Srl { rd, rs1, rs2 }
+            | I::Sra { rd, rs1, rs2 }
+            | I::Or { rd, rs1, rs2 }
+            | I::And { rd, rs1, rs2 }
+            | I::Addw { rd, rs1, rs2 }
+            | I::Subw { rd, rs1, rs2 }
+            | I::Sllw { rd, rs1, rs2 }
+            | I::Srlw { rd, rs1, rs2 }
+            | I::Sraw { rd, rs1, rs2 }
+            | I::Mulw { rd, rs1, rs2 }
+            | I::Divw { rd, rs1, rs2 }
+            | I::Divuw { rd, rs1, rs2 }
+            | I::Remw { rd, rs1, rs2 }
+            | I::Remuw { rd, rs1, rs2 }
+            | I::Mul { rd, rs1, rs2 }
+            | I::Mulh { rd, rs1, rs2 }
+            | I::Mulsu { rd, rs1, rs2 }
+            | I::Mulu { rd, rs1, rs2 }
+            | I::Div { rd, rs1, rs2 }
+            | I::Divu { rd, rs1, rs2 }
+            | I::Rem { rd, rs1, rs2 }
+            | I::Remu { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+
+            I::Beq { rs1, rs2, imm }
+            | I::Bne { rs1, rs2, imm }
+            | I::Blt { rs1, rs2, imm }
+            | I::Bge { rs1, rs2, imm }
+            | I::Bltu { rs1, rs2, imm }
+            | I::Bgeu { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .control_flow(ControlFlow::Branch {
+                        offset: imm as SignedInstructionSize,
+                    })
+            }
+            I::Jal { rd, imm } => e.write(x(rd)).control_flow(ControlFlow::Jump {
+                offset: imm as SignedInstructionSize,
+                link: rd != 0,
+            }),
+            I::Jalr { rd, rs1, .. } => e
+                .read(x(rs1))
+                .write(x(rd))
+                .control_flow(ControlFlow::IndirectJump { link: rd != 0 }),
+
+            I::ECall | I::EBreak => e.control_flow(ControlFlow::Trap),
+            I::SRet | I::MRet | I::MnRet | I::DRet => e.control_flow(ControlFlow::TrapReturn),
+            I::Fence { .. } | I::FenceI { .. } | I::Pause | I::CNop => e,
+            I::CboInval { rs1 } | I::CboClean { rs1 } | I::CboFlush { rs1 } => e.read(x(rs1)),
+            // zeroes a whole cache block, the block size isn't known here so only the first byte is
+            I::CboZero { rs1 } => e.read(x(rs1)).memory(MemoryKind::Store, rs1, 0, 1, false),
+            // prefetches are hints, they never fault and don't architecturally touch memory
+            I::PrefetchI { rs1, .. } | I::PrefetchR { rs1, .. } | I::PrefetchW { rs1, .. } => {
+                e.read(x(rs1))
+            }
+            // the locality hints only tell the memory system about the next access
+            I::NtlP1 | I::NtlPall | I::NtlS1 | I::NtlAll => e,
+            I::SFenceWInval | I::SFenceInvalIr => e,
+            // stalls until the reservation set is written or a timeout, nothing visible changes
+            I::WrsNto | I::WrsSto => e,
+            // rs1 is the virtual address and rs2 the address space, x0 for all of them
+            I::SFenceVma { rs1, rs2 }
+            | I::SinvalVma { rs1, rs2 }
+            | I::HinvalVvma { rs1, rs2 }
+            | I::HinvalGvma { rs1, rs2 } => e.read(x(rs1)).read(x(rs2)),
+
+            // csrrw doesn't read the csr when rd is x0 and csrrs/csrrc don't write it when there's
+            // nothing to set or clear
+            I::CsrRw { rd, rs1, imm } => e.read(x(rs1)).write(x(rd)).csr(imm, rd != 0, true),
+            I::CsrRs { rd, rs1, imm } | I::CsrRc { rd, rs1, imm } => {
+                e.read(x(rs1)).write(x(rd)).csr(imm, true, rs1 != 0)
+            }
+            I::CsrRwi { rd, imm, .. } => e.write(x(rd)).csr(imm, rd != 0, true),
+            I::CsrRsi { rd, rs1, imm } | I::CsrRci { rd, rs1, imm } => {
+                e.write(x(rd)).csr(imm, true, rs1 != 0)
+            }
+
+            I::FmaddS {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FmsubS {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmaddS {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmsubS {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FmaddD {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FmsubD {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmsubD {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmaddD {
+                rd, rs1, rs2, rs3, ..
+            } => e.read(f(rs1)).read(f(rs2)).read(f(rs3)).write(f(rd)),
+            I::FaddS { rd, rs1, rs2, .. }
+            | I::FsubS { rd, rs1, rs2, .. }
+            | I::FmulS { rd, rs1, rs2, .. }
+            | I::FdivS { rd, rs1, rs2, .. }
+            | I::FsgnjS { rd, rs1, rs2 }
+            | I::FsgnjnS { rd, rs1, rs2 }
+            | I::FsgnjxS { rd, rs1, rs2 }
+            | I::FminS { rd, rs1, rs2 }
+            | I::FmaxS { rd, rs1, rs2 } => e.read(f(rs1)).read(f(rs2)).write(f(rd)),
+            I::FsqrtS { rd, rs1, .. } => e.read(f(rs1)).write(f(rd)),
+            I::FaddD { rd, rs1, rs2, .. }
+            | I::FsubD { rd, rs1, rs2, .. }
+            | I::FmulD { rd, rs1, rs2, .. }
+            | I::FdivD { rd, rs1, rs2, .. }
+            | I::FsgnjD { rd, rs1, rs2 }
+            | I::FsgnjnD { rd, rs1, rs2 }
+            | I::FsgnjxD { rd, rs1, rs2 }
+            | I::FminD { rd, rs1, rs2 }
+            | I::FmaxD { rd, rs1, rs2 } => e.read(f(rs1)).read(f(rs2)).write(f(rd)),
+            I::FsqrtD { rd, rs1, .. } | I::FcvtSD { rd, rs1, .. } | I::FcvtDS { rd, rs1, .. } => {
+                e.read(f(rs1)).write(f(rd))
+            }
+            I::FeqD { rd, rs1, rs2 } | I::FltD { rd, rs1, rs2 } | I::FleD { rd, rs1, rs2 } => {
+                e.read(f(rs1)).read(f(rs2)).write(x(rd))
+            }
+            I::FeqS { rd, rs1, rs2 } | I::FltS { rd, rs1, rs2 } | I::FleS { rd, rs1, rs2 } => {
+                e.read(f(rs1)).read(f(rs2)).write(x(rd))
+            }
+            I::FcvtWS { rd, rs1, .. }
+            | I::FcvtWUS { rd, rs1, .. }
+            | I::FmvXW { rd, rs1 }
+            | I::FmvXD { rd, rs1 }
+            | I::FcvtLS { rd, rs1, .. }
+            | I::FcvtLUS { rd, rs1, .. }
+            | I::FcvtLD { rd, rs1, .. }
+            | I::FcvtLUD { rd, rs1, .. }
+            | I::FcvtWD { rd, rs1, .. }
+            | I::FcvtWUD { rd, rs1, .. }
+            | I::FClassD { rd, rs1 }
+            | I::FClassS { rd, rs1 } => e.read(f(rs1)).write(x(rd)),
+            I::FcvtSW { rd, rs1, .. }
+            | I::FcvtSWU { rd, rs1, .. }
+            | I::FmvWX { rd, rs1 }
+            | I::FmvDX { rd, rs1 }
+            | I::FcvtSL { rd, rs1, .. }
+            | I::FcvtSLU { rd, rs1, .. }
+            | I::FcvtDL { rd, rs1, .. }
+            | I::FcvtDLU { rd, rs1, .. }
+            | I::FcvtDW { rd, rs1, .. }
+            | I::FcvtDWU { rd, rs1, .. } => e.read(x(rs1)).write(f(rd)),
+
+            I::LrW { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, true)
+            }
+            I::ScW { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 4, false)
+            }
+            I::AmoswapW { rd, rs1, rs2, .. }
+            | I::AmoaddW { rd, rs1, rs2, .. }
+            | I::AmoandW { rd, rs1, rs2, .. }
+            | I::AmoorW { rd, rs1, rs2, .. }
+            | I::AmoxorW { rd, rs1, rs2, .. }
+            | I::AmomaxW { rd, rs1, rs2, .. }
+            | I::AmominW { rd, rs1, rs2, .. }
+            | I::AmominuW { rd, rs1, rs2, .. }
+            | I::AmomaxuW { rd, rs1, rs2, .. } => e.read(x(rs1)).read(x(rs2)).write(x(rd)).memory(
+                MemoryKind::ReadModifyWrite,
+                rs1,
+                0,
+                4,
+                true,
+            ),
+            I::LrD { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::ScD { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 8, false)
+            }
+            I::AmoswapD { rd, rs1, rs2, .. }
+            | I::AmoaddD { rd, rs1, rs2, .. }
+            | I::AmoxorD { rd, rs1, rs2, .. }
+            | I::AmoandD { rd, rs1, rs2, .. }
+            | I::AmoorD { rd, rs1, rs2, .. }
+            | I::AmominD { rd, rs1, rs2, .. }
+            | I::AmomaxD { rd, rs1, rs2, .. }
+            | I::AmominuD { rd, rs1, rs2, .. }
+            | I::AmomaxuD { rd, rs1, rs2, .. } => e.read(x(rs1)).read(x(rs2)).write(x(rd)).memory(
+                MemoryKind::ReadModifyWrite,
+                rs1,
+                0,
+                8,
+                false,
+            ),
+
+            // Q extension
+            I::FaddQ { rd, rs1, rs2, .. }
+            | I::FsubQ { rd, rs1, rs2, .. }
+            | I::FmulQ { rd, rs1, rs2, .. }
+            | I::FdivQ { rd, rs1, rs2, .. }
+            | I::FsgnjQ { rd, rs1, rs2 }
+            | I::FsgnjnQ { rd, rs1, rs2 }
+            | I::FsgnjxQ { rd, rs1, rs2 }
+            | I::FminQ { rd, rs1, rs2 }
+            | I::FmaxQ { rd, rs1, rs2 } => e.read(f(rs1)).read(f(rs2)).write(f(rd)),
+            I::FsqrtQ { rd, rs1, .. }
+            | I::FcvtSQ { rd, rs1, .. }
+            | I::FcvtQS { rd, rs1, .. }
+            | I::FcvtDQ { rd, rs1, .. }
+            | I::FcvtQD { rd, rs1, .. } => e.read(f(rs1)).write(f(rd)),
+            I::FeqQ { rd, rs1, rs2 } | I::FltQ { rd, rs1, rs2 } | I::FleQ { rd, rs1, rs2 } => {
+                e.read(f(rs1)).read(f(rs2)).write(x(rd))
+            }
+            I::FClassQ { rd, rs1 }
+            | I::FcvtWQ { rd, rs1, .. }
+            | I::FcvtWUQ { rd, rs1, .. }
+            | I::FcvtLQ { rd, rs1, .. }
+            | I::FcvtLUQ { rd, rs1, .. } => e.read(f(rs1)).write(x(rd)),
+            I::FcvtQW { rd, rs1, .. }
+            | I::FcvtQWU { rd, rs1, .. }
+            | I::FcvtQL { rd, rs1, .. }
+            | I::FcvtQLU { rd, rs1, .. } => e.read(x(rs1)).write(f(rd)),
+            I::FmaddQ {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FmsubQ {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmsubQ {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmaddQ {
+                rd, rs1, rs2, rs3, ..
+            } => e.read(f(rs1)).read(f(rs2)).read(f(rs3)).write(f(rd)),
+            I::Flq { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 16, false)
+            }
+            I::Fsq { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 16, false)
+            }
+
+            // Zfh extension
+            I::FaddH { rd, rs1, rs2, .. }
+            | I::FsubH { rd, rs1, rs2, .. }
+            | I::FmulH { rd, rs1, rs2, .. }
+            | I::FdivH { rd, rs1, rs2, .. }
+            | I::FsgnjH { rd, rs1, rs2 }
+            | I::FsgnjnH { rd, rs1, rs2 }
+            | I::FsgnjxH { rd, rs1, rs2 }
+            | I::FminH { rd, rs1, rs2 }
+            | I::FmaxH { rd, rs1, rs2 } => e.read(f(rs1)).read(f(rs2)).write(f(rd)),
+            I::FsqrtH { rd, rs1, .. }
+            | I::FcvtSH { rd, rs1, .. }
+            | I::FcvtHS { rd, rs1, .. }
+            | I::FcvtDH { rd, rs1, .. }
+            | I::FcvtHD { rd, rs1, .. } => e.read(f(rs1)).write(f(rd)),
+            I::FeqH { rd, rs1, rs2 } | I::FltH { rd, rs1, rs2 } | I::FleH { rd, rs1, rs2 } => {
+                e.read(f(rs1)).read(f(rs2)).write(x(rd))
+            }
+            I::FClassH { rd, rs1 }
+            | I::FcvtWH { rd, rs1, .. }
+            | I::FcvtWUH { rd, rs1, .. }
+            | I::FcvtLH { rd, rs1, .. }
+            | I::FcvtLUH { rd, rs1, .. }
+            | I::FmvXH { rd, rs1 } => e.read(f(rs1)).write(x(rd)),
+            I::FcvtHW { rd, rs1, .. }
+            | I::FcvtHWU { rd, rs1, .. }
+            | I::FcvtHL { rd, rs1, .. }
+            | I::FcvtHLU { rd, rs1, .. }
+            | I::FmvHX { rd, rs1 } => e.read(x(rs1)).write(f(rd)),
+            I::FmaddH {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FmsubH {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmsubH {
+                rd, rs1, rs2, rs3, ..
+            }
+            | I::FnmaddH {
+                rd, rs1, rs2, rs3, ..
+            } => e.read(f(rs1)).read(f(rs2)).read(f(rs3)).write(f(rd)),
+            I::Flh { rd, rs1, imm } => {
+                e.read(x(rs1))
+                    .write(f(rd))
+                    .memory(MemoryKind::Load, rs1, imm, 2, false)
+            }
+            I::Fsh { rs1, rs2, imm } => {
+                e.read(x(rs1))
+                    .read(f(rs2))
+                    .memory(MemoryKind::Store, rs1, imm, 2, false)
+            }
+            I::FliS { rd, .. } | I::FliD { rd, .. } => e.write(f(rd)),
+            I::FminmS { rd, rs1, rs2 }
+            | I::FmaxmS { rd, rs1, rs2 }
+            | I::FminmD { rd, rs1, rs2 }
+            | I::FmaxmD { rd, rs1, rs2 } => e.read(f(rs1)).read(f(rs2)).write(f(rd)),
+            I::FroundS { rd, rs1, .. }
+            | I::FroundnxS { rd, rs1, .. }
+            | I::FroundD { rd, rs1, .. }
+            | I::FroundnxD { rd, rs1, .. } => e.read(f(rs1)).write(f(rd)),
+            I::FcvtmodWD { rd, rs1 } => e.read(f(rs1)).write(x(rd)),
+            I::Sh1add { rd, rs1, rs2 }
+            | I::Sh2add { rd, rs1, rs2 }
+            | I::Sh3add { rd, rs1, rs2 }
+            | I::AddUw { rd, rs1, rs2 }
+            | I::Sh1addUw { rd, rs1, rs2 }
+            | I::Sh2addUw { rd, rs1, rs2 }
+            | I::Sh3addUw { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            I::SlliUw { rd, rs1, .. } => e.read(x(rs1)).write(x(rd)),
+            I::Andn { rd, rs1, rs2 }
+            | I::Orn { rd, rs1, rs2 }
+            | I::Xnor { rd, rs1, rs2 }
+            | I::Min { rd, rs1, rs2 }
+            | I::Minu { rd, rs1, rs2 }
+            | I::Max { rd, rs1, rs2 }
+            | I::Maxu { rd, rs1, rs2 }
+            | I::Rol { rd, rs1, rs2 }
+            | I::Ror { rd, rs1, rs2 }
+            | I::Rolw { rd, rs1, rs2 }
+            | I::Rorw { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            I::Clz { rd, rs1 }
+            | I::Ctz { rd, rs1 }
+            | I::Cpop { rd, rs1 }
+            | I::SextB { rd, rs1 }
+            | I::SextH { rd, rs1 }
+            | I::OrcB { rd, rs1 }
+            | I::Rev8 { rd, rs1 }
+            | I::ZextH { rd, rs1 }
+            | I::Rori { rd, rs1, .. }
+            | I::Clzw { rd, rs1 }
+            | I::Ctzw { rd, rs1 }
+            | I::Cpopw { rd, rs1 }
+            | I::Roriw { rd, rs1, .. } => e.read(x(rs1)).write(x(rd)),
+            I::Clmul { rd, rs1, rs2 } | I::Clmulh { rd, rs1, rs2 } | I::Clmulr { rd, rs1, rs2 } => {
+                e.read(x(rs1)).read(x(rs2)).write(x(rd))
+            }
+            I::Bclr { rd, rs1, rs2 }
+            | I::Bext { rd, rs1, rs2 }
+            | I::Binv { rd, rs1, rs2 }
+            | I::Bset { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            I::Bclri { rd, rs1, .. }
+            | I::Bexti { rd, rs1, .. }
+            | I::Binvi { rd, rs1, .. }
+            | I::Bseti { rd, rs1, .. } => e.read(x(rs1)).write(x(rd)),
+            I::Pack { rd, rs1, rs2 } | I::Packh { rd, rs1, rs2 } | I::Packw { rd, rs1, rs2 } => {
+                e.read(x(rs1)).read(x(rs2)).write(x(rd))
+            }
+            I::Brev8 { rd, rs1 } | I::Zip { rd, rs1 } | I::Unzip { rd, rs1 } => {
+                e.read(x(rs1)).write(x(rd))
+            }
+            I::Xperm4 { rd, rs1, rs2 } | I::Xperm8 { rd, rs1, rs2 } => {
+                e.read(x(rs1)).read(x(rs2)).write(x(rd))
+            }
+            I::Aes32esi { rd, rs1, rs2, .. }
+            | I::Aes32esmi { rd, rs1, rs2, .. }
+            | I::Aes32dsi { rd, rs1, rs2, .. }
+            | I::Aes32dsmi { rd, rs1, rs2, .. }
+            | I::Aes64es { rd, rs1, rs2 }
+            | I::Aes64esm { rd, rs1, rs2 }
+            | I::Aes64ds { rd, rs1, rs2 }
+            | I::Aes64dsm { rd, rs1, rs2 }
+            | I::Aes64ks2 { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            I::Aes64im { rd, rs1 } | I::Aes64ks1i { rd, rs1, .. } => e.read(x(rs1)).write(x(rd)),
+            I::Sha256sum0 { rd, rs1 }
+            | I::Sha256sum1 { rd, rs1 }
+            | I::Sha256sig0 { rd, rs1 }
+            | I::Sha256sig1 { rd, rs1 }
+            | I::Sha512sum0 { rd, rs1 }
+            | I::Sha512sum1 { rd, rs1 }
+            | I::Sha512sig0 { rd, rs1 }
+            | I::Sha512sig1 { rd, rs1 } => e.read(x(rs1)).write(x(rd)),
+            I::Sha512sum0r { rd, rs1, rs2 }
+            | I::Sha512sum1r { rd, rs1, rs2 }
+            | I::Sha512sig0l { rd, rs1, rs2 }
+            | I::Sha512sig1l { rd, rs1, rs2 }
+            | I::Sha512sig0h { rd, rs1, rs2 }
+            | I::Sha512sig1h { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            I::Sm4ed { rd, rs1, rs2, .. } | I::Sm4ks { rd, rs1, rs2, .. } => {
+                e.read(x(rs1)).read(x(rs2)).write(x(rd))
+            }
+            I::Sm3p0 { rd, rs1 } | I::Sm3p1 { rd, rs1 } => e.read(x(rs1)).write(x(rd)),
+            // vl and vtype are written too
+            I::Vsetvli { rd, rs1, .. } => e.read(x(rs1)).write(x(rd)),
+            I::Vsetivli { rd, .. } => e.write(x(rd)),
+            I::Vsetvl { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            // the access is per element, how many depends on vl (which the ff loads can shrink)
+            I::Vle8V {
+                vd, rs1, masked, ..
+            }
+            | I::Vle8ffV {
+                vd, rs1, masked, ..
+            } => e.read(x(rs1)).read(v0_if(masked)).write(v(vd)).memory(
+                MemoryKind::Load,
+                rs1,
+                0,
+                1,
+                false,
+            ),
+            I::Vle16V {
+                vd, rs1, masked, ..
+            }
+            | I::Vle16ffV {
+                vd, rs1, masked, ..
+            } => e.read(x(rs1)).read(v0_if(masked)).write(v(vd)).memory(
+                MemoryKind::Load,
+                rs1,
+                0,
+                2,
+                false,
+            ),
+            I::Vle32V {
+                vd, rs1, masked, ..
+            }
+            | I::Vle32ffV {
+                vd, rs1, masked, ..
+            } => e.read(x(rs1)).read(v0_if(masked)).write(v(vd)).memory(
+                MemoryKind::Load,
+                rs1,
+                0,
+                4,
+                false,
+            ),
+            I::Vle64V {
+                vd, rs1, masked, ..
+            }
+            | I::Vle64ffV {
+                vd, rs1, masked, ..
+            } => e.read(x(rs1)).read(v0_if(masked)).write(v(vd)).memory(
+                MemoryKind::Load,
+                rs1,
+                0,
+                8,
+                false,
+            ),
+            I::Vse8V {
+                vs3, rs1, masked, ..
+            } => e.read(x(rs1)).read(v(vs3)).read(v0_if(masked)).memory(
+                MemoryKind::Store,
+                rs1,
+                0,
+                1,
+                false,
+            ),
+            I::Vse16V {
+                vs3, rs1, masked, ..
+            } => e.read(x(rs1)).read(v(vs3)).read(v0_if(masked)).memory(
+                MemoryKind::Store,
+                rs1,
+                0,
+                2,
+                false,
+            ),
+            I::Vse32V {
+                vs3, rs1, masked, ..
+            } => e.read(x(rs1)).read(v(vs3)).read(v0_if(masked)).memory(
+                MemoryKind::Store,
+                rs1,
+                0,
+                4,
+                false,
+            ),
+            I::Vse64V {
+                vs3, rs1, masked, ..
+            } => e.read(x(rs1)).read(v(vs3)).read(v0_if(masked)).memory(
+                MemoryKind::Store,
+                rs1,
+                0,
+                8,
+                false,
+            ),
+            I::Vlse8V {
+                vd,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v0_if(masked))
+                .write(v(vd))
+                .memory(MemoryKind::Load, rs1, 0, 1, false),
+            I::Vlse16V {
+                vd,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v0_if(masked))
+                .write(v(vd))
+                .memory(MemoryKind::Load, rs1, 0, 2, false),
+            I::Vlse32V {
+                vd,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v0_if(masked))
+                .write(v(vd))
+                .memory(MemoryKind::Load, rs1, 0, 4, false),
+            I::Vlse64V {
+                vd,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v0_if(masked))
+                .write(v(vd))
+                .memory(MemoryKind::Load, rs1, 0, 8, false),
+            I::Vsse8V {
+                vs3,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v(vs3))
+                .read(v0_if(masked))
+                .memory(MemoryKind::Store, rs1, 0, 1, false),
+            I::Vsse16V {
+                vs3,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v(vs3))
+                .read(v0_if(masked))
+                .memory(MemoryKind::Store, rs1, 0, 2, false),
+            I::Vsse32V {
+                vs3,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v(vs3))
+                .read(v0_if(masked))
+                .memory(MemoryKind::Store, rs1, 0, 4, false),
+            I::Vsse64V {
+                vs3,
+                rs1,
+                rs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(x(rs2))
+                .read(v(vs3))
+                .read(v0_if(masked))
+                .memory(MemoryKind::Store, rs1, 0, 8, false),
+            // the index width is in the mnemonic, the data elements are sew wide which only vtype
+            // knows so the width is left at 0
+            I::Vluxei8V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vluxei16V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vluxei32V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vluxei64V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vloxei8V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vloxei16V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vloxei32V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vloxei64V {
+                vd,
+                rs1,
+                vs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(v(vs2))
+                .read(v0_if(masked))
+                .write(v(vd))
+                .memory(MemoryKind::Load, rs1, 0, 0, false),
+            I::Vsuxei8V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vsuxei16V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vsuxei32V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vsuxei64V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vsoxei8V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vsoxei16V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vsoxei32V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            }
+            | I::Vsoxei64V {
+                vs3,
+                rs1,
+                vs2,
+                masked,
+                ..
+            } => e
+                .read(x(rs1))
+                .read(v(vs2))
+                .read(v(vs3))
+                .read(v0_if(masked))
+                .memory(MemoryKind::Store, rs1, 0, 0, false),
+            I::VredsumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VredandVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VredorVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VredxorVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VredminuVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VredminVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VredmaxuVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VredmaxVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VaadduVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VaaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VasubuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VasubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VdivuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VdivVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VremuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VremVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmulhuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmulhsuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmulhVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwadduVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwsubuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwadduWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwaddWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwsubuWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwsubWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwmuluVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwmulsuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => e.read(v(vs1)).read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::VaadduVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VaaddVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VasubuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VasubVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::Vslide1upVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::Vslide1downVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VdivuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VdivVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VremuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VremVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmulhuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmulVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmulhsuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmulhVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwadduVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwaddVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwsubuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwsubVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwadduWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwaddWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwsubuWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwsubWx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwmuluVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwmulsuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwmulVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => e.read(x(rs1)).read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            // the multiply-adds accumulate into vd
+            I::VmaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VnmsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VnmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwmaccuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwmaccsuVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => e
+                .read(v(vd))
+                .read(v(vs1))
+                .read(v(vs2))
+                .read(v0_if(masked))
+                .write(v(vd)),
+            I::VmaddVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VnmsubVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmaccVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VnmsacVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwmaccuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwmaccVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwmaccusVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwmaccsuVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => e
+                .read(v(vd))
+                .read(x(rs1))
+                .read(v(vs2))
+                .read(v0_if(masked))
+                .write(v(vd)),
+            I::VmvXS { rd, vs2 } => e.read(v(vs2)).write(x(rd)),
+            I::VmvSX { vd, rs1 } => e.read(x(rs1)).write(v(vd)),
+            I::VzextVf8 { vd, vs2, masked }
+            | I::VsextVf8 { vd, vs2, masked }
+            | I::VzextVf4 { vd, vs2, masked }
+            | I::VsextVf4 { vd, vs2, masked }
+            | I::VzextVf2 { vd, vs2, masked }
+            | I::VsextVf2 { vd, vs2, masked } => e.read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::VcompressVm { vd, vs2, vs1 } => e.read(v(vs1)).read(v(vs2)).write(v(vd)),
+            I::VfaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfredusumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfredosumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfminVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfredminVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfmaxVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfredmaxVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfsgnjVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfsgnjnVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfsgnjxVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmfeqVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmfleVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmfltVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VmfneVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfdivVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwredusumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwredosumVs {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwaddWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwsubWv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwmulVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => e.read(v(vs1)).read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::VfaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfminVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfmaxVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfsgnjVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfsgnjnVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfsgnjxVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::Vfslide1upVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::Vfslide1downVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmfeqVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmfleVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmfltVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmfneVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmfgtVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VmfgeVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfdivVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfrdivVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfmulVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfrsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwaddWf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwsubWf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwmulVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => e.read(f(rs1)).read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::VfmaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfnmaddVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfmsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfnmsubVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfnmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfnmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwnmaccVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VfwnmsacVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => e
+                .read(v(vd))
+                .read(v(vs1))
+                .read(v(vs2))
+                .read(v0_if(masked))
+                .write(v(vd)),
+            I::VfmaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfnmaddVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfmsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfnmsubVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfnmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfnmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwnmaccVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VfwnmsacVf {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => e
+                .read(v(vd))
+                .read(f(rs1))
+                .read(v(vs2))
+                .read(v0_if(masked))
+                .write(v(vd)),
+            I::VfcvtXuFV { vd, vs2, masked }
+            | I::VfcvtXFV { vd, vs2, masked }
+            | I::VfcvtFXuV { vd, vs2, masked }
+            | I::VfcvtFXV { vd, vs2, masked }
+            | I::VfcvtRtzXuFV { vd, vs2, masked }
+            | I::VfcvtRtzXFV { vd, vs2, masked }
+            | I::VfwcvtXuFV { vd, vs2, masked }
+            | I::VfwcvtXFV { vd, vs2, masked }
+            | I::VfwcvtFXuV { vd, vs2, masked }
+            | I::VfwcvtFXV { vd, vs2, masked }
+            | I::VfwcvtFFV { vd, vs2, masked }
+            | I::VfwcvtRtzXuFV { vd, vs2, masked }
+            | I::VfwcvtRtzXFV { vd, vs2, masked }
+            | I::VfncvtXuFW { vd, vs2, masked }
+            | I::VfncvtXFW { vd, vs2, masked }
+            | I::VfncvtFXuW { vd, vs2, masked }
+            | I::VfncvtFXW { vd, vs2, masked }
+            | I::VfncvtFFW { vd, vs2, masked }
+            | I::VfncvtRodFFW { vd, vs2, masked }
+            | I::VfncvtRtzXuFW { vd, vs2, masked }
+            | I::VfncvtRtzXFW { vd, vs2, masked }
+            | I::VfsqrtV { vd, vs2, masked }
+            | I::Vfrsqrt7V { vd, vs2, masked }
+            | I::Vfrec7V { vd, vs2, masked }
+            | I::VfclassV { vd, vs2, masked } => e.read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::VfmvFS { rd, vs2 } => e.read(v(vs2)).write(f(rd)),
+            I::VfmvSF { vd, rs1 } | I::VfmvVF { vd, rs1 } => e.read(f(rs1)).write(v(vd)),
+            I::VfmergeVfm { vd, vs2, rs1 } => {
+                e.read(f(rs1)).read(v(vs2)).read(v0_if(true)).write(v(vd))
+            }
+            I::VmandnMm { vd, vs2, vs1 }
+            | I::VmandMm { vd, vs2, vs1 }
+            | I::VmorMm { vd, vs2, vs1 }
+            | I::VmxorMm { vd, vs2, vs1 }
+            | I::VmornMm { vd, vs2, vs1 }
+            | I::VmnandMm { vd, vs2, vs1 }
+            | I::VmnorMm { vd, vs2, vs1 }
+            | I::VmxnorMm { vd, vs2, vs1 } => e.read(v(vs1)).read(v(vs2)).write(v(vd)),
+            I::VcpopM { rd, vs2, masked } | I::VfirstM { rd, vs2, masked } => {
+                e.read(v(vs2)).read(v0_if(masked)).write(x(rd))
+            }
+            I::VmsbfM { vd, vs2, masked }
+            | I::VmsofM { vd, vs2, masked }
+            | I::VmsifM { vd, vs2, masked }
+            | I::ViotaM { vd, vs2, masked } => e.read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::VidV { vd, masked } => e.read(v0_if(masked)).write(v(vd)),
+            // whole register accesses move nr * VLEN bits, the width is the element width
+            I::Vl1re8V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, false)
+            }
+            I::Vl1re16V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, false)
+            }
+            I::Vl1re32V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, false)
+            }
+            I::Vl1re64V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::Vl2re8V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, false)
+            }
+            I::Vl2re16V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, false)
+            }
+            I::Vl2re32V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, false)
+            }
+            I::Vl2re64V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::Vl4re8V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, false)
+            }
+            I::Vl4re16V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, false)
+            }
+            I::Vl4re32V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, false)
+            }
+            I::Vl4re64V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::Vl8re8V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, false)
+            }
+            I::Vl8re16V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, false)
+            }
+            I::Vl8re32V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, false)
+            }
+            I::Vl8re64V { vd, rs1 } => {
+                e.read(x(rs1))
+                    .write(v(vd))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::Vs1rV { vs3, rs1 } => {
+                e.read(x(rs1))
+                    .read(v(vs3))
+                    .memory(MemoryKind::Store, rs1, 0, 1, false)
+            }
+            I::Vs2rV { vs3, rs1 } => {
+                e.read(x(rs1))
+                    .read(v(vs3))
+                    .memory(MemoryKind::Store, rs1, 0, 1, false)
+            }
+            I::Vs4rV { vs3, rs1 } => {
+                e.read(x(rs1))
+                    .read(v(vs3))
+                    .memory(MemoryKind::Store, rs1, 0, 1, false)
+            }
+            I::Vs8rV { vs3, rs1 } => {
+                e.read(x(rs1))
+                    .read(v(vs3))
+                    .memory(MemoryKind::Store, rs1, 0, 1, false)
+            }
+            I::Vmv1rV { vd, vs2 }
+            | I::Vmv2rV { vd, vs2 }
+            | I::Vmv4rV { vd, vs2 }
+            | I::Vmv8rV { vd, vs2 } => e.read(v(vs2)).write(v(vd)),
+            I::VandnVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VrorVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VrolVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            }
+            | I::VwsllVv {
+                vd,
+                vs2,
+                vs1,
+                masked,
+            } => e.read(v(vs1)).read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::VandnVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VrorVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VrolVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            }
+            | I::VwsllVx {
+                vd,
+                vs2,
+                rs1,
+                masked,
+            } => e.read(x(rs1)).read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            I::Vbrev8V { vd, vs2, masked }
+            | I::Vrev8V { vd, vs2, masked }
+            | I::VbrevV { vd, vs2, masked }
+            | I::VclzV { vd, vs2, masked }
+            | I::VctzV { vd, vs2, masked }
+            | I::VcpopV { vd, vs2, masked }
+            | I::VrorVi {
+                vd, vs2, masked, ..
+            }
+            | I::VwsllVi {
+                vd, vs2, masked, ..
+            } => e.read(v(vs2)).read(v0_if(masked)).write(v(vd)),
+            // the ghash, aes rounds and sha2 compression all use vd as part of the state
+            I::VghshVv { vd, vs2, vs1 }
+            | I::Vsha2msVv { vd, vs2, vs1 }
+            | I::Vsha2chVv { vd, vs2, vs1 }
+            | I::Vsha2clVv { vd, vs2, vs1 } => e.read(v(vd)).read(v(vs1)).read(v(vs2)).write(v(vd)),
+            I::VgmulVv { vd, vs2 }
+            | I::VaesdfVv { vd, vs2 }
+            | I::VaesdfVs { vd, vs2 }
+            | I::VaesdmVv { vd, vs2 }
+            | I::VaesdmVs { vd, vs2 }
+            | I::VaesefVv { vd, vs2 }
+            | I::VaesefVs { vd, vs2 }
+            | I::VaesemVv { vd, vs2 }
+            | I::VaesemVs { vd, vs2 }
+            | I::VaeszVs { vd, vs2 }
+            | I::Vaeskf2Vi { vd, vs2, .. } => e.read(v(vd)).read(v(vs2)).write(v(vd)),
+            I::Vaeskf1Vi { vd, vs2, .. } => e.read(v(vs2)).write(v(vd)),
+            I::Add16 { rd, rs1, rs2 }
+            | I::Add8 { rd, rs1, rs2 }
+            | I::Sub16 { rd, rs1, rs2 }
+            | I::Sub8 { rd, rs1, rs2 }
+            | I::Radd16 { rd, rs1, rs2 }
+            | I::Radd8 { rd, rs1, rs2 }
+            | I::Rsub16 { rd, rs1, rs2 }
+            | I::Rsub8 { rd, rs1, rs2 }
+            | I::Kadd16 { rd, rs1, rs2 }
+            | I::Kadd8 { rd, rs1, rs2 }
+            | I::Ksub16 { rd, rs1, rs2 }
+            | I::Ksub8 { rd, rs1, rs2 }
+            | I::Ukadd16 { rd, rs1, rs2 }
+            | I::Ukadd8 { rd, rs1, rs2 }
+            | I::Uksub16 { rd, rs1, rs2 }
+            | I::Uksub8 { rd, rs1, rs2 }
+            | I::Pkbb16 { rd, rs1, rs2 }
+            | I::Pkbt16 { rd, rs1, rs2 }
+            | I::Pktb16 { rd, rs1, rs2 }
+            | I::Pktt16 { rd, rs1, rs2 } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            // the widening multiplies write the rd, rd + 1 pair on rv32
+            I::Smul16 { rd, rs1, rs2 }
+            | I::Smul8 { rd, rs1, rs2 }
+            | I::Umul16 { rd, rs1, rs2 }
+            | I::Umul8 { rd, rs1, rs2 } => {
+                e.read(x(rs1)).read(x(rs2)).write(x(rd)).write(x(rd | 1))
+            }
+            I::ThAddsl { rd, rs1, rs2, .. } => e.read(x(rs1)).read(x(rs2)).write(x(rd)),
+            I::ThDcacheCall
+            | I::ThDcacheIall
+            | I::ThDcacheCiall
+            | I::ThIcacheIall
+            | I::ThIcacheIalls => e,
+            I::ThDcacheCval1 { rs1 }
+            | I::ThDcacheCva { rs1 }
+            | I::ThDcacheIva { rs1 }
+            | I::ThDcacheCiva { rs1 }
+            | I::ThDcacheCpa { rs1 }
+            | I::ThDcacheIpa { rs1 }
+            | I::ThDcacheCipa { rs1 }
+            | I::ThIcacheIva { rs1 }
+            | I::ThIcacheIpa { rs1 } => e.read(x(rs1)),
+            // the address is rs1 + (rs2 << shift), only the base is known here
+            I::ThLrb { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, true)
+            }
+            I::ThLurb { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, true)
+            }
+            I::ThLrh { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, true)
+            }
+            I::ThLurh { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, true)
+            }
+            I::ThLrw { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, true)
+            }
+            I::ThLurw { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, true)
+            }
+            I::ThLrd { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::ThLurd { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::ThLrbu { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, false)
+            }
+            I::ThLurbu { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 1, false)
+            }
+            I::ThLrhu { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, false)
+            }
+            I::ThLurhu { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 2, false)
+            }
+            I::ThLrwu { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, false)
+            }
+            I::ThLurwu { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .write(x(rd))
+                    .memory(MemoryKind::Load, rs1, 0, 4, false)
+            }
+            I::ThSrb { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 1, false)
+            }
+            I::ThSurb { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 1, false)
+            }
+            I::ThSrh { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 2, false)
+            }
+            I::ThSurh { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 2, false)
+            }
+            I::ThSrw { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 4, false)
+            }
+            I::ThSurw { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 4, false)
+            }
+            I::ThSrd { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 8, false)
+            }
+            I::ThSurd { rd, rs1, rs2, .. } => {
+                e.read(x(rs1))
+                    .read(x(rs2))
+                    .read(x(rd))
+                    .memory(MemoryKind::Store, rs1, 0, 8, false)
+            }
+            // rs1 is bumped by imm << shift, before the access for the ib forms and after it for ia
+            I::ThLbib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).write(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Load,
+                rs1,
+                imm << shift,
+                1,
+                true,
+            ),
+            I::ThLbia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Load, rs1, 0, 1, true)
+            }
+            I::ThLhib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).write(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Load,
+                rs1,
+                imm << shift,
+                2,
+                true,
+            ),
+            I::ThLhia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Load, rs1, 0, 2, true)
+            }
+            I::ThLwib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).write(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Load,
+                rs1,
+                imm << shift,
+                4,
+                true,
+            ),
+            I::ThLwia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Load, rs1, 0, 4, true)
+            }
+            I::ThLdib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).write(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Load,
+                rs1,
+                imm << shift,
+                8,
+                false,
+            ),
+            I::ThLdia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Load, rs1, 0, 8, false)
+            }
+            I::ThLbuib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).write(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Load,
+                rs1,
+                imm << shift,
+                1,
+                false,
+            ),
+            I::ThLbuia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Load, rs1, 0, 1, false)
+            }
+            I::ThLhuib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).write(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Load,
+                rs1,
+                imm << shift,
+                2,
+                false,
+            ),
+            I::ThLhuia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Load, rs1, 0, 2, false)
+            }
+            I::ThLwuib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).write(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Load,
+                rs1,
+                imm << shift,
+                4,
+                false,
+            ),
+            I::ThLwuia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .write(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Load, rs1, 0, 4, false)
+            }
+            I::ThSbib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).read(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Store,
+                rs1,
+                imm << shift,
+                1,
+                false,
+            ),
+            I::ThSbia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .read(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Store, rs1, 0, 1, false)
+            }
+            I::ThShib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).read(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Store,
+                rs1,
+                imm << shift,
+                2,
+                false,
+            ),
+            I::ThShia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .read(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Store, rs1, 0, 2, false)
+            }
+            I::ThSwib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).read(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Store,
+                rs1,
+                imm << shift,
+                4,
+                false,
+            ),
+            I::ThSwia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .read(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Store, rs1, 0, 4, false)
+            }
+            I::ThSdib {
+                rd,
+                rs1,
+                imm,
+                shift,
+            } => e.read(x(rs1)).read(x(rd)).write(x(rs1)).memory(
+                MemoryKind::Store,
+                rs1,
+                imm << shift,
+                8,
+                false,
+            ),
+            I::ThSdia { rd, rs1, .. } => {
+                e.read(x(rs1))
+                    .read(x(rd))
+                    .write(x(rs1))
+                    .memory(MemoryKind::Store, rs1, 0, 8, false)
+            }
+            I::Custom { rd, rs1, rs2, .. } => e
+                .read(rs1.and_then(x))
+                .read(rs2.and_then(x))
+                .write(rd.and_then(x)),
+            // the shadow stack is addressed through the ssp csr rather than a register, so the
+            // accesses it makes aren't reported as memory ones
+            I::Sspush { rs2 } => e.read(x(rs2)).csr(SSP, true, true),
+            I::Sspopchk { rs1 } => e.read(x(rs1)).csr(SSP, true, true),
+            I::Ssrdp { rd } => e.write(x(rd)).csr(SSP, true, false),
+            I::SsamoswapW { rd, rs1, rs2, .. } => e.read(x(rs1)).read(x(rs2)).write(x(rd)).memory(
+                MemoryKind::ReadModifyWrite,
+                rs1,
+                0,
+                4,
+                true,
+            ),
+            I::SsamoswapD { rd, rs1, rs2, .. } => e.read(x(rs1)).read(x(rs2)).write(x(rd)).memory(
+                MemoryKind::ReadModifyWrite,
+                rs1,
+                0,
+                8,
+                true,
+            ),
+            I::Lpad { .. } => e,
+
+            I::Vsm4kVi { vd, vs2, .. } => e.read(v(vs2)).write(v(vd)),
+            // the sm4 rounds and sm3 compression use vd as part of the state
+            I::Vsm4rVv { vd, vs2 } | I::Vsm4rVs { vd, vs2 } | I::Vsm3cVi { vd, vs2, .. } => {
+                e.read(v(vd)).read(v(vs2)).write(v(vd))
+            }
+            I::Vsm3meVv { vd, vs2, vs1 } => e.read(v(vs1)).read(v(vs2)).write(v(vd)),
+            I::Vfwmaccbf16Vv {
+                vd,
+                vs1,
+                vs2,
+                masked,
+            } => e
+                .read(v(vd))
+                .read(v(vs1))
+                .read(v(vs2))
+                .read(v0_if(masked))
+                .write(v(vd)),
+            I::Vfwmaccbf16Vf {
+                vd,
+                rs1,
+                vs2,
+                masked,
+            } => e
+                .read(v(vd))
+                .read(f(rs1))
+                .read(v(vs2))
+                .read(v0_if(masked))
+                .write(v(vd)),
+            I::Vfncvtbf16FFW { vd, vs2, masked } | I::Vfwcvtbf16FFV { vd, vs2, masked } => {
+                e.read(v(vs2)).read(v0_if(masked)).write(v(vd))
+            }
+        }
+    }
+}
+
+#[test]
+fn effects() {
+    use crate::decoder::try_decode;
+    let effects = try_decode(0x00c12603 /* lw x12, 12(sp) */)
+        .unwrap()
+        .effects();
+    assert_eq!(effects.reads, vec![x(2).unwrap()]);
+    assert_eq!(effects.writes, vec![x(12).unwrap()]);
+    assert_eq!(
+        effects.memory,
+        Some(MemoryAccess {
+            kind: MemoryKind::Load,
+            base: 2,
+            offset: 12,
+            width: 4,
+            signed: true
+        })
+    );
+
+    let effects = try_decode(0xfe078ce3 /* beq x15, x0, -8 */)
+        .unwrap()
+        .effects();
+    assert_eq!(effects.reads, vec![x(15).unwrap()]);
+    assert!(effects.writes.is_empty());
+    assert_eq!(
+        effects.control_flow,
+        Some(ControlFlow::Branch { offset: -8 })
+    );
+
+    let effects = try_decode(0xf14025f3 /* csrrs x11, mhartid, x0 */)
+        .unwrap()
+        .effects();
+    assert_eq!(
+        effects.csr,
+        Some(CsrAccess {
+            csr: 0xf14,
+            read: true,
+            write: false
+        })
+    );
+
+    let effects = try_decode(0xd00777d3 /* fcvt.s.w fa5, a4 */)
+        .unwrap()
+        .effects();
+    assert_eq!(effects.reads, vec![x(14).unwrap()]);
+    assert_eq!(effects.writes, vec![f(15).unwrap()]);
+}
@@ -0,0 +1,580 @@
+use std::io::{self, Write};
+
+use anyhow::{Context, Result};
+
+use crate::decoder::{Decoder, Xlen};
+use crate::error::ElfError;
+use crate::instructions::InstructionSize;
+use crate::listing::{write_listing, ListingOptions};
+use crate::stream::InstructionStream;
+
+const MAGIC: &[u8; 4] = b"\x7fELF";
+const ELFCLASS32: u8 = 1;
+const ELFCLASS64: u8 = 2;
+const ELFDATA2LSB: u8 = 1;
+const EM_RISCV: u16 = 243;
+
+const SHT_SYMTAB: u32 = 2;
+const SHT_NOBITS: u32 = 8;
+const SHF_EXECINSTR: u32 = 4;
+
+const STT_NOTYPE: u8 = 0;
+const STT_FUNC: u8 = 2;
+
+// where things are in the headers, section headers and symbols of each class
+struct Layout {
+    xlen: Xlen,
+    // e_shoff, e_shnum and e_shstrndx
+    header: [usize; 3],
+    section_size: usize,
+    // sh_name, sh_type, sh_flags, sh_addr, sh_offset, sh_size and sh_link
+    section: [usize; 7],
+    symbol_size: usize,
+    // st_name, st_value, st_size, st_info and st_shndx
+    symbol: [usize; 5],
+}
+
+const ELF32: Layout = Layout {
+    xlen: Xlen::Rv32,
+    header: [32, 48, 50],
+    section_size: 40,
+    section: [0, 4, 8, 12, 16, 20, 24],
+    symbol_size: 16,
+    symbol: [0, 4, 8, 12, 14],
+};
+
+const ELF64: Layout = Layout {
+    xlen: Xlen::Rv64,
+    header: [40, 60, 62],
+    section_size: 64,
+    section: [0, 4, 8, 16, 24, 32, 40],
+    symbol_size: 24,
+    symbol: [0, 8, 16, 4, 6],
+};
+
+impl Layout {
+    // the fields that are 32 bits in ELF32 and 64 bits in ELF64
+    fn word(&self, bytes: &[u8], offset: usize) -> Result<u64> {
+        match self.xlen {
+            Xlen::Rv32 => u32_at(bytes, offset).map(u64::from),
+            Xlen::Rv64 => read(bytes, offset).map(u64::from_le_bytes),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub name: &'a str,
+    pub addr: InstructionSize,
+    // empty for sections that take no space in the file (.bss)
+    pub bytes: &'a [u8],
+    pub executable: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Function,
+    // untyped symbols, local labels like `.loop` end up as these
+    Label,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol<'a> {
+    pub name: &'a str,
+    pub addr: InstructionSize,
+    pub size: InstructionSize,
+    pub kind: SymbolKind,
+    // index into `Elf::sections`, `None` for absolute/undefined symbols
+    pub section: Option<usize>,
+}
+
+// one function symbol along with its code and the labels inside it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function<'a> {
+    pub name: &'a str,
+    pub addr: InstructionSize,
+    pub bytes: &'a [u8],
+    // sorted by address, the function's own symbol isn't included
+    pub labels: Vec<(InstructionSize, &'a str)>,
+    // a default one with the XLEN from the class of the file it came from
+    pub decoder: Decoder,
+}
+
+impl<'a> Function<'a> {
+    pub fn stream(&self) -> InstructionStream<'a> {
+        // nothing can start at an odd address so a function there starts decoding a byte later,
+        // after that the start is always the beginning of the buffer so this can't fail
+        let skip = (self.addr & 1) as usize;
+        let bytes = self.bytes.get(skip..).unwrap_or_default();
+        InstructionStream::new(bytes, self.addr.wrapping_add(skip as InstructionSize))
+            .unwrap()
+            .with_decoder(self.decoder)
+    }
+}
+
+// just enough of a little endian RISC-V ELF file to find code and what it's called, program
+// headers and relocations are ignored. 64 bit files work as long as every section is below 4 GiB
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf<'a> {
+    pub sections: Vec<Section<'a>>,
+    pub symbols: Vec<Symbol<'a>>,
+    // ELFCLASS32 is rv32 and ELFCLASS64 rv64
+    pub xlen: Xlen,
+}
+
+fn read<const N: usize>(bytes: &[u8], offset: usize) -> Result<[u8; N]> {
+    offset
+        .checked_add(N)
+        .and_then(|end| bytes.get(offset..end))
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ElfError::Truncated)
+        .context("Read past the end of the file")
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> Result<u16> {
+    read(bytes, offset).map(u16::from_le_bytes)
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> Result<u32> {
+    read(bytes, offset).map(u32::from_le_bytes)
+}
+
+fn slice(bytes: &[u8], offset: u64, size: u64) -> Result<&[u8]> {
+    let offset = usize::try_from(offset).unwrap_or(usize::MAX);
+    let size = usize::try_from(size).unwrap_or(usize::MAX);
+    bytes
+        .get(offset..offset.saturating_add(size))
+        .ok_or(ElfError::Truncated)
+        .context("Section points outside the file")
+}
+
+// a nul terminated string out of a string table
+fn name(table: &[u8], offset: u32) -> &str {
+    let rest = table.get(offset as usize..).unwrap_or_default();
+    let end = rest.iter().position(|b| *b == 0).unwrap_or(rest.len());
+    std::str::from_utf8(&rest[..end]).unwrap_or_default()
+}
+
+impl<'a> Elf<'a> {
+    pub fn is_elf(bytes: &[u8]) -> bool {
+        bytes.starts_with(MAGIC)
+    }
+
+    pub fn parse(bytes: &'a [u8]) -> Result<Self> {
+        if !Self::is_elf(bytes) {
+            return Err(ElfError::NotElf).context("Missing the ELF magic number");
+        }
+        let ident: [u8; 16] = read(bytes, 0)?;
+        let layout = match ident[4] {
+            ELFCLASS32 => &ELF32,
+            ELFCLASS64 => &ELF64,
+            _ => return Err(ElfError::Unsupported("class")).context("Unknown ELF class"),
+        };
+        if ident[5] != ELFDATA2LSB {
+            return Err(ElfError::Unsupported("big endian"))
+                .context("Only little endian files are supported");
+        }
+        if u16_at(bytes, 18)? != EM_RISCV {
+            return Err(ElfError::Unsupported("machine")).context("Not a RISC-V file");
+        }
+
+        let [shoff, shnum, shstrndx] = layout.header;
+        let shoff = usize::try_from(layout.word(bytes, shoff)?).unwrap_or(usize::MAX);
+        let shnum = u16_at(bytes, shnum)? as usize;
+        let shstrndx = u16_at(bytes, shstrndx)? as usize;
+        // the whole table has to be in the file, after that none of the offsets into it can overflow
+        let table = shnum
+            .checked_mul(layout.section_size)
+            .and_then(|size| shoff.checked_add(size))
+            .filter(|end| *end <= bytes.len());
+        if table.is_none() {
+            return Err(ElfError::Truncated).context("Section headers point outside the file");
+        }
+        let header = |index: usize| shoff + index * layout.section_size;
+
+        // the raw headers first, names and symbols need other sections to be resolved
+        let [name_at, type_at, flags_at, addr_at, offset_at, size_at, link_at] = layout.section;
+        let mut raw = Vec::with_capacity(shnum);
+        for index in 0..shnum {
+            let at = header(index);
+            let kind = u32_at(bytes, at + type_at)?;
+            let data = match kind {
+                SHT_NOBITS => &[][..],
+                _ => slice(
+                    bytes,
+                    layout.word(bytes, at + offset_at)?,
+                    layout.word(bytes, at + size_at)?,
+                )?,
+            };
+            let addr = InstructionSize::try_from(layout.word(bytes, at + addr_at)?)
+                .map_err(|_| ElfError::Unsupported("address"))
+                .context("Section is above 4 GiB")?;
+            raw.push((
+                u32_at(bytes, at + name_at)?,
+                kind,
+                layout.word(bytes, at + flags_at)?,
+                addr,
+                data,
+                u32_at(bytes, at + link_at)?,
+            ));
+        }
+        let shstrtab = raw
+            .get(shstrndx)
+            .map(|section| section.4)
+            .unwrap_or_default();
+
+        let sections = raw
+            .iter()
+            .map(|(name_offset, _, flags, addr, data, _)| Section {
+                name: name(shstrtab, *name_offset),
+                addr: *addr,
+                bytes: data,
+                executable: flags & SHF_EXECINSTR as u64 != 0,
+            })
+            .collect::<Vec<_>>();
+
+        let [name_at, value_at, size_at, info_at, shndx_at] = layout.symbol;
+        let mut symbols = Vec::new();
+        for (_, _, _, _, data, link) in raw.iter().filter(|section| section.1 == SHT_SYMTAB) {
+            let strtab = raw
+                .get(*link as usize)
+                .map(|section| section.4)
+                .unwrap_or_default();
+            // the first entry is always the null symbol
+            for entry in data.chunks_exact(layout.symbol_size).skip(1) {
+                let info = entry[info_at];
+                let shndx = u16_at(entry, shndx_at)? as usize;
+                // absolute symbols can be anywhere, they can't point into a section that's
+                // accepted above so they're just dropped
+                let Ok(addr) = InstructionSize::try_from(layout.word(entry, value_at)?) else {
+                    continue;
+                };
+                let size = layout.word(entry, size_at)?;
+                symbols.push(Symbol {
+                    name: name(strtab, u32_at(entry, name_at)?),
+                    addr,
+                    size: InstructionSize::try_from(size).unwrap_or(InstructionSize::MAX),
+                    kind: match info & 0xf {
+                        STT_FUNC => SymbolKind::Function,
+                        STT_NOTYPE => SymbolKind::Label,
+                        _ => SymbolKind::Other,
+                    },
+                    section: (shndx != 0 && shndx < sections.len()).then_some(shndx),
+                });
+            }
+        }
+        symbols.sort_by_key(|symbol| symbol.addr);
+
+        Ok(Self {
+            sections,
+            symbols,
+            xlen: layout.xlen,
+        })
+    }
+
+    // every function symbol in an executable section, sorted by address
+    pub fn functions(&self) -> Vec<Function<'a>> {
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.kind == SymbolKind::Function)
+            .filter_map(|symbol| self.function_at(symbol))
+            .collect()
+    }
+
+    pub fn function(&self, name: &str) -> Option<Function<'a>> {
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.kind == SymbolKind::Function && symbol.name == name)
+            .find_map(|symbol| self.function_at(symbol))
+    }
+
+    fn function_at(&self, symbol: &Symbol<'a>) -> Option<Function<'a>> {
+        let section = self.sections.get(symbol.section?)?;
+        if !section.executable {
+            return None;
+        }
+        let start = symbol.addr.checked_sub(section.addr)? as usize;
+        // functions without a size run until the next one (or the end of the section)
+        let size = match symbol.size {
+            0 => self
+                .symbols
+                .iter()
+                .filter(|next| next.kind == SymbolKind::Function && next.addr > symbol.addr)
+                .map(|next| next.addr - symbol.addr)
+                .next()
+                .unwrap_or(InstructionSize::MAX),
+            size => size,
+        } as usize;
+        let end = start.saturating_add(size).min(section.bytes.len());
+        let bytes = section.bytes.get(start..end)?;
+        let range = symbol.addr..symbol.addr.wrapping_add(bytes.len() as InstructionSize);
+
+        let labels = self
+            .symbols
+            .iter()
+            // `$x`/`$d` are mapping symbols, not labels anyone wrote
+            .filter(|label| label.kind == SymbolKind::Label && !label.name.starts_with('$'))
+            .filter(|label| !label.name.is_empty() && label.section == symbol.section)
+            .filter(|label| label.addr != symbol.addr && range.contains(&label.addr))
+            .map(|label| (label.addr, label.name))
+            .collect();
+        Some(Function {
+            name: symbol.name,
+            addr: symbol.addr,
+            bytes,
+            labels,
+            decoder: Decoder {
+                xlen: self.xlen,
+                ..Decoder::new()
+            },
+        })
+    }
+}
+
+// a listing with a header for every function and its labels on their own line, the listing is
+// restarted at every label so nothing gets folded across a branch target. a label at an odd
+// address (data in .text) gets its first byte printed as `.byte` and the listing goes on from the
+// next parcel
+pub fn write_functions<'a, W, I>(
+    out: &mut W,
+    functions: I,
+    options: ListingOptions,
+) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Function<'a>>,
+{
+    for (i, function) in functions.into_iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(
+            out,
+            "{:08x} <{}> ({} bytes):",
+            function.addr,
+            function.name,
+            function.bytes.len()
+        )?;
+
+        let mut start = 0;
+        let mut labels = function.labels.iter().peekable();
+        while start < function.bytes.len() {
+            let addr = function.addr.wrapping_add(start as InstructionSize);
+            while let Some((_, label)) = labels.next_if(|(at, _)| *at <= addr) {
+                writeln!(out, "{label}:")?;
+            }
+            if !addr.is_multiple_of(2) {
+                let byte = function.bytes[start];
+                writeln!(out, "{addr:08x}: .byte {byte:#04x} # misaligned")?;
+                start += 1;
+                continue;
+            }
+            let end = match labels.peek() {
+                Some((at, _)) => at.wrapping_sub(function.addr) as usize,
+                None => function.bytes.len(),
+            };
+            let stream = InstructionStream::new(&function.bytes[start..end], addr)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?
+                .with_decoder(function.decoder);
+            write_listing(out, stream, options)?;
+            start = end;
+        }
+    }
+    Ok(())
+}
+
+// builds a minimal file with one .text section and a symbol table
+#[cfg(test)]
+fn build(layout: &Layout, text: &[u8], addr: u32, symbols: &[(&str, u32, u32, u8)]) -> Vec<u8> {
+    let wide = layout.xlen == Xlen::Rv64;
+    let word = |value: u32| match wide {
+        true => u64::from(value).to_le_bytes().to_vec(),
+        false => value.to_le_bytes().to_vec(),
+    };
+    let mut strtab = vec![0u8];
+    let mut symtab = vec![0u8; layout.symbol_size];
+    for (name, value, size, kind) in symbols {
+        // st_info, st_other and st_shndx come right after the name in ELF64 and last in ELF32
+        let info = [*kind, 0, 1, 0];
+        symtab.extend((strtab.len() as u32).to_le_bytes());
+        if wide {
+            symtab.extend(info);
+        }
+        symtab.extend(word(*value));
+        symtab.extend(word(*size));
+        if !wide {
+            symtab.extend(info);
+        }
+        strtab.extend(name.as_bytes());
+        strtab.push(0);
+    }
+    let shstrtab = b"\0.text\0.symtab\0.strtab\0.shstrtab\0";
+
+    let (class, header_size) = match wide {
+        true => (ELFCLASS64, 64),
+        false => (ELFCLASS32, 52),
+    };
+    let mut file = vec![0u8; header_size];
+    file[..6].copy_from_slice(&[0x7f, b'E', b'L', b'F', class, ELFDATA2LSB]);
+    file[18..20].copy_from_slice(&EM_RISCV.to_le_bytes());
+    let mut offsets = Vec::new();
+    for data in [text, &symtab, &strtab, shstrtab] {
+        offsets.push(file.len() as u32);
+        file.extend(data);
+    }
+    let [shoff_at, shnum_at, shstrndx_at] = layout.header;
+    let shoff = word(file.len() as u32);
+    file[shoff_at..shoff_at + shoff.len()].copy_from_slice(&shoff);
+    file[shnum_at..shnum_at + 2].copy_from_slice(&5u16.to_le_bytes());
+    file[shstrndx_at..shstrndx_at + 2].copy_from_slice(&4u16.to_le_bytes());
+
+    // null, .text, .symtab, .strtab, .shstrtab as (name, type, flags, addr, data index, link)
+    let headers = [
+        (1u32, 1, 2 | SHF_EXECINSTR, addr, 0, 0u32),
+        (7, SHT_SYMTAB, 0, 0, 1, 3),
+        (15, 3, 0, 0, 2, 0),
+        (23, 3, 0, 0, 3, 0),
+    ];
+    let sizes = [text.len(), symtab.len(), strtab.len(), shstrtab.len()];
+    file.extend(vec![0u8; layout.section_size]);
+    for (name, kind, flags, addr, data, link) in headers {
+        file.extend(name.to_le_bytes());
+        file.extend(kind.to_le_bytes());
+        for field in [flags, addr, offsets[data], sizes[data] as u32] {
+            file.extend(word(field));
+        }
+        file.extend(link.to_le_bytes());
+        file.extend(0u32.to_le_bytes());
+        // addralign and entsize
+        file.extend(word(0));
+        file.extend(word(0));
+    }
+    file
+}
+
+#[test]
+fn functions() {
+    let text = [
+        0x13, 0x05, 0x15, 0x00, /* addi a0, a0, 1 */
+        0xe3, 0x0e, 0x05, 0xfe, /* beq a0, zero, -4 */
+        0x67, 0x80, 0x00, 0x00, /* jalr zero, 0(ra) */
+        0x73, 0x00, 0x00, 0x00, /* ecall */
+    ];
+    let file = build(
+        &ELF32,
+        &text,
+        0x1000,
+        &[
+            ("main", 0x1000, 12, STT_FUNC),
+            (".loop", 0x1004, 0, STT_NOTYPE),
+            ("$x", 0x1000, 0, STT_NOTYPE),
+            ("exit", 0x100c, 0, STT_FUNC),
+        ],
+    );
+    let elf = Elf::parse(&file).unwrap();
+    assert_eq!(elf.sections[1].name, ".text");
+    assert!(elf.sections[1].executable);
+
+    let main = elf.function("main").unwrap();
+    assert_eq!(main.bytes.len(), 12);
+    assert_eq!(main.labels, vec![(0x1004, ".loop")]);
+    assert_eq!(main.stream().count(), 3);
+    // no size, runs to the end of .text
+    assert_eq!(elf.function("exit").unwrap().bytes.len(), 4);
+    assert_eq!(elf.function("missing"), None);
+
+    let mut out = Vec::new();
+    write_functions(&mut out, elf.functions(), ListingOptions::default()).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "00001000 <main> (12 bytes):\n\
+         00001000: addi a0, a0, 1\n\
+         .loop:\n\
+         00001004: beq a0, zero, -4\n\
+         00001008: jalr zero, ra\n\
+         \n\
+         0000100c <exit> (4 bytes):\n\
+         0000100c: ecall\n"
+    );
+
+    assert!(Elf::parse(&text).is_err());
+    assert!(Elf::parse(&file[..60]).is_err());
+}
+
+#[test]
+fn elf64() {
+    let text = [
+        0x02, 0x65, /* c.ldsp a0, 0(sp) */
+        0x03, 0x35, 0x85, 0x00, /* ld a0, 8(a0) */
+        0x82, 0x80, /* c.jr ra */
+    ];
+    let file = build(&ELF64, &text, 0x10000, &[("load", 0x10000, 8, STT_FUNC)]);
+    let elf = Elf::parse(&file).unwrap();
+    assert_eq!(elf.xlen, Xlen::Rv64);
+    assert_eq!(
+        Elf::parse(&build(&ELF32, &text, 0, &[])).unwrap().xlen,
+        Xlen::Rv32
+    );
+
+    let load = elf.function("load").unwrap();
+    assert_eq!(load.bytes.len(), 8);
+    let text = load
+        .stream()
+        .map(|item| item.inst.unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(text, vec!["c.ldsp a0, 0(sp)", "ld a0, 8(a0)", "c.jr ra"]);
+}
+
+#[test]
+fn section_headers_out_of_range() {
+    // e_shoff as far out as it goes, this used to overflow instead of failing
+    let mut file = build(&ELF64, &[0x73, 0x00, 0x00, 0x00], 0x1000, &[]);
+    file[40..48].copy_from_slice(&u64::MAX.to_le_bytes());
+    file[60..62].copy_from_slice(&1u16.to_le_bytes());
+    let err = Elf::parse(&file).unwrap_err();
+    assert_eq!(err.downcast_ref(), Some(&ElfError::Truncated));
+
+    let mut file = build(&ELF32, &[0x73, 0x00, 0x00, 0x00], 0x1000, &[]);
+    file[32..36].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        Elf::parse(&file).unwrap_err().downcast_ref(),
+        Some(&ElfError::Truncated)
+    );
+}
+
+#[test]
+fn misaligned_labels() {
+    let text = [
+        0x13, 0x05, 0x15, 0x00, /* addi a0, a0, 1 */
+        0x2a, 0x2b, /* data */
+        0x73, 0x00, 0x00, 0x00, /* ecall */
+    ];
+    let file = build(
+        &ELF32,
+        &text,
+        0x1000,
+        &[
+            ("main", 0x1000, 10, STT_FUNC),
+            ("data", 0x1005, 0, STT_NOTYPE),
+            ("odd", 0x1005, 5, STT_FUNC),
+        ],
+    );
+    let elf = Elf::parse(&file).unwrap();
+    let mut out = Vec::new();
+    write_functions(&mut out, elf.function("main"), ListingOptions::default()).unwrap();
+    // the listing goes on past the label instead of giving up on the whole file
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "00001000 <main> (10 bytes):\n\
+         00001000: addi a0, a0, 1\n\
+         00001004: .word 0x2a\n\
+         data:\n\
+         00001005: .byte 0x2b # misaligned\n\
+         00001006: ecall\n"
+    );
+    // the stream of a function at an odd address starts at the next parcel
+    let odd = elf.function("odd").unwrap();
+    let addrs = odd.stream().map(|item| item.addr).collect::<Vec<_>>();
+    assert_eq!(addrs, vec![0x1006]);
+}
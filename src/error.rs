@@ -2,10 +2,20 @@ use std::fmt::{Display, Formatter, Result};
 
 use thiserror::Error;
 
+use crate::opcode::Extension;
+
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum DecodeError {
     UnknownInstruction,
     UnknownInstructionFormat,
+    // instructions are made of 16 bit parcels so decoding can't start on an odd address
+    MisalignedStart(u32),
+    // the start address is before the buffer or past its end
+    StartOutOfRange(u32),
+    // a valid encoding longer than 32 bits, none of which are decoded, holds its length in bytes
+    UnsupportedLength(usize),
+    // a known instruction from an extension the `Decoder` was set up without
+    UnsupportedExtension(Extension),
 }
 
 impl Display for DecodeError {
@@ -13,3 +23,19 @@ impl Display for DecodeError {
         write!(f, "{:?}", self)
     }
 }
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ElfError {
+    NotElf,
+    // valid ELF, just not something this crate can disassemble (big endian, not RISC-V, sections
+    // above 4 GiB)
+    Unsupported(&'static str),
+    // an offset or size points outside the file
+    Truncated,
+}
+
+impl Display for ElfError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:?}", self)
+    }
+}
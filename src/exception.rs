@@ -0,0 +1,33 @@
+use crate::instructions::InstructionSize;
+
+// the synchronous exceptions from the privileged spec, the discriminant is the mcause/scause code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Exception {
+    InstructionAddressMisaligned = 0,
+    InstructionAccessFault = 1,
+    IllegalInstruction = 2,
+    Breakpoint = 3,
+    LoadAddressMisaligned = 4,
+    LoadAccessFault = 5,
+    StoreAddressMisaligned = 6,
+    StoreAccessFault = 7,
+    EnvironmentCallFromU = 8,
+    EnvironmentCallFromS = 9,
+    EnvironmentCallFromM = 11,
+    InstructionPageFault = 12,
+    LoadPageFault = 13,
+    StorePageFault = 15,
+}
+
+impl Exception {
+    pub fn cause(&self) -> InstructionSize {
+        *self as InstructionSize
+    }
+}
+
+#[test]
+fn causes() {
+    assert_eq!(Exception::InstructionAddressMisaligned.cause(), 0);
+    assert_eq!(Exception::EnvironmentCallFromM.cause(), 11);
+    assert_eq!(Exception::StorePageFault.cause(), 15);
+}
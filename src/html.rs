@@ -0,0 +1,192 @@
+use std::io::{self, Write};
+
+use crate::decoded_inst::{InstructionDecoded, CSRS, REG_NAMES};
+use crate::instructions::InstructionSize;
+
+// the css classes given to each kind of token, frontends style these however they like
+pub const ADDRESS_CLASS: &str = "addr";
+pub const MNEMONIC_CLASS: &str = "mnemonic";
+pub const REGISTER_CLASS: &str = "reg";
+pub const CSR_CLASS: &str = "csr";
+pub const IMMEDIATE_CLASS: &str = "imm";
+pub const TARGET_CLASS: &str = "target";
+pub const PUNCT_CLASS: &str = "punct";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Mnemonic,
+    Register,
+    Csr,
+    Immediate,
+    Punctuation,
+}
+
+impl TokenKind {
+    pub fn class(&self) -> &'static str {
+        match self {
+            TokenKind::Mnemonic => MNEMONIC_CLASS,
+            TokenKind::Register => REGISTER_CLASS,
+            TokenKind::Csr => CSR_CLASS,
+            TokenKind::Immediate => IMMEDIATE_CLASS,
+            TokenKind::Punctuation => PUNCT_CLASS,
+        }
+    }
+}
+
+// the id used for the anchor of an instruction, branch targets link to this
+pub fn anchor(addr: InstructionSize) -> String {
+    format!("addr-{addr:08x}")
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn classify(word: &str) -> TokenKind {
+    let is_vreg = word
+        .strip_prefix('v')
+        .is_some_and(|n| n.parse::<u8>().is_ok_and(|n| n < 32));
+    if REG_NAMES.contains(&word) || is_vreg {
+        TokenKind::Register
+    } else if CSRS.values().any(|name| *name == word) {
+        TokenKind::Csr
+    } else {
+        TokenKind::Immediate
+    }
+}
+
+// splits the textual form of an instruction into classified tokens
+pub fn tokenize(inst: &InstructionDecoded) -> Vec<(TokenKind, String)> {
+    let text = inst.to_string();
+    let (mnemonic, operands) = text.split_once(' ').unwrap_or((text.as_str(), ""));
+
+    let mut tokens = vec![(TokenKind::Mnemonic, mnemonic.to_string())];
+    if !operands.is_empty() {
+        tokens.push((TokenKind::Punctuation, " ".to_string()));
+    }
+
+    let mut word = String::new();
+    for c in operands.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.' {
+            word.push(c);
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.push((classify(&word), std::mem::take(&mut word)));
+        }
+        tokens.push((TokenKind::Punctuation, c.to_string()));
+    }
+    if !word.is_empty() {
+        tokens.push((classify(&word), word));
+    }
+
+    tokens
+}
+
+pub fn write_instruction<W: Write>(
+    out: &mut W,
+    addr: InstructionSize,
+    inst: &InstructionDecoded,
+) -> io::Result<()> {
+    let id = anchor(addr);
+    write!(
+        out,
+        "<span class=\"inst\" id=\"{id}\"><a class=\"{ADDRESS_CLASS}\" href=\"#{id}\">{addr:08x}</a>: "
+    )?;
+
+    let target = inst.branch_target(addr);
+    for (kind, text) in tokenize(inst) {
+        match (kind, target) {
+            // the only immediate of a branch or jump is its offset so link it to where it goes
+            (TokenKind::Immediate, Some(target)) => write!(
+                out,
+                "<a class=\"{} {TARGET_CLASS}\" href=\"#{}\">{}</a>",
+                kind.class(),
+                anchor(target),
+                escape(&text)
+            )?,
+            (TokenKind::Punctuation, _) => write!(out, "{}", escape(&text))?,
+            _ => write!(
+                out,
+                "<span class=\"{}\">{}</span>",
+                kind.class(),
+                escape(&text)
+            )?,
+        }
+    }
+
+    write!(out, "</span>")
+}
+
+pub fn write_listing<'a, W, I>(out: &mut W, insts: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = (InstructionSize, &'a InstructionDecoded)>,
+{
+    writeln!(out, "<pre class=\"riscv-disasm\">")?;
+    for (addr, inst) in insts {
+        write_instruction(out, addr, inst)?;
+        writeln!(out)?;
+    }
+    writeln!(out, "</pre>")
+}
+
+#[test]
+fn tokens() {
+    use crate::decoder::try_decode;
+    let inst = try_decode(0x00c12603 /* lw x12, 12(sp) */).unwrap();
+    assert_eq!(
+        tokenize(&inst),
+        vec![
+            (TokenKind::Mnemonic, "lw".to_string()),
+            (TokenKind::Punctuation, " ".to_string()),
+            (TokenKind::Register, "a2".to_string()),
+            (TokenKind::Punctuation, ",".to_string()),
+            (TokenKind::Punctuation, " ".to_string()),
+            (TokenKind::Immediate, "12".to_string()),
+            (TokenKind::Punctuation, "(".to_string()),
+            (TokenKind::Register, "sp".to_string()),
+            (TokenKind::Punctuation, ")".to_string()),
+        ]
+    );
+    let inst = try_decode(0xf14025f3 /* csrrs x11, mhartid, x0 */).unwrap();
+    assert!(tokenize(&inst).contains(&(TokenKind::Csr, "mhartid".to_string())));
+}
+
+#[test]
+fn branch_links() {
+    use crate::decoder::try_decode;
+    let inst = try_decode(0xfe078ce3 /* beq x15, x0, -8 */).unwrap();
+    let mut out = Vec::new();
+    write_instruction(&mut out, 0x1000, &inst).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("<span class=\"inst\" id=\"addr-00001000\">"));
+    assert!(out.contains("<span class=\"mnemonic\">beq</span>"));
+    assert!(out.contains("<a class=\"imm target\" href=\"#addr-00000ff8\">-8</a>"));
+}
+
+#[test]
+fn listing() {
+    use crate::decoder::try_decode;
+    let insts = [
+        (0x0, try_decode(0x06468613 /* addi x12 x13 100 */).unwrap()),
+        (0x4, try_decode(0x0100006f /* jal x0 16 */).unwrap()),
+    ];
+    let mut out = Vec::new();
+    write_listing(&mut out, insts.iter().map(|(addr, inst)| (*addr, inst))).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with("<pre class=\"riscv-disasm\">\n"));
+    assert!(out.contains("id=\"addr-00000004\""));
+    assert!(out.contains("href=\"#addr-00000014\""));
+    assert!(out.ends_with("</pre>\n"));
+}
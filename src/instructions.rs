@@ -11,6 +11,8 @@ pub enum InstructionFormat {
     UType,
     BType,
     JType,
+    R4Type,
+    OpV,
 }
 
 instructions! {
@@ -89,6 +91,62 @@ instructions! {
     jalr {
         pub const FUNCT3: u32 = 0;
     }
+    // rv64 word ops, they work on the low 32 bits and sign extend the result
+    addiw {
+        pub const FUNCT3: u32 = 0;
+    }
+    slliw {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0;
+    }
+    srliw {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 0;
+    }
+    sraiw {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 32;
+    }
+    addw {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0;
+    }
+    subw {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 32;
+    }
+    sllw {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 0;
+    }
+    srlw {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT7: u32 = 0;
+    }
+    sraw {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT7: u32 = 32;
+    }
+    mulw {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 1;
+    }
+    divw {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 1;
+    }
+    divuw {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT7: u32 = 1;
+    }
+    remw {
+        pub const FUNCT3: u32 = 6;
+        pub const FUNCT7: u32 = 1;
+    }
+    remuw {
+        pub const FUNCT3: u32 = 7;
+        pub const FUNCT7: u32 = 1;
+    }
     // control
     ecall {
         pub const FUNCT3: u32 = 0;
@@ -96,7 +154,7 @@ instructions! {
     }
     sfencevma {
         pub const FUNCT3: u32 = 0;
-        pub const IMM: u32 = 0x120;
+        pub const FUNCT7: u32 = 0b0001001;
     }
     ebreak {
         pub const FUNCT3: u32 = 0;
@@ -110,6 +168,46 @@ instructions! {
         pub const FUNCT3: u32 = 0;
         pub const IMM: u32 = 0x102;
     }
+    // Smrnmi
+    mnret {
+        pub const FUNCT3: u32 = 0;
+        pub const IMM: u32 = 0x702;
+    }
+    // Sdext (debug mode)
+    dret {
+        pub const FUNCT3: u32 = 0;
+        pub const IMM: u32 = 0x7b2;
+    }
+    // Zawrs
+    wrs_nto {
+        pub const FUNCT3: u32 = 0;
+        pub const IMM: u32 = 0x00d;
+    }
+    wrs_sto {
+        pub const FUNCT3: u32 = 0;
+        pub const IMM: u32 = 0x01d;
+    }
+    // Svinval
+    sinval_vma {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0001011;
+    }
+    sfence_w_inval {
+        pub const FUNCT3: u32 = 0;
+        pub const IMM: u32 = 0x180;
+    }
+    sfence_inval_ir {
+        pub const FUNCT3: u32 = 0;
+        pub const IMM: u32 = 0x181;
+    }
+    hinval_vvma {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0010011;
+    }
+    hinval_gvma {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0110011;
+    }
     // M type
     mul {
         pub const FUNCT3: u32 = 0;
@@ -164,6 +262,12 @@ instructions! {
         pub const FUNCT3: u32 = 5;
         pub const FUNCT7: u32 = 0;
     }
+    lwu {
+        pub const FUNCT3: u32 = 6;
+    }
+    ld {
+        pub const FUNCT3: u32 = 3;
+    }
     // store
     sb {
         pub const FUNCT3: u32 = 0;
@@ -177,6 +281,9 @@ instructions! {
         pub const FUNCT3: u32 = 2;
         pub const FUNCT7: u32 = 0;
     }
+    sd {
+        pub const FUNCT3: u32 = 3;
+    }
     // branch
     beq {
         pub const FUNCT3: u32 = 0;
@@ -224,6 +331,62 @@ instructions! {
     fence_i {
         pub const FUNCT3: u32 = 1;
     }
+    // Zicbom / Zicboz (cache block management)
+    cbo_inval {
+        pub const FUNCT3: u32 = 2;
+        pub const IMM: u32 = 0;
+    }
+    cbo_clean {
+        pub const FUNCT3: u32 = 2;
+        pub const IMM: u32 = 1;
+    }
+    cbo_flush {
+        pub const FUNCT3: u32 = 2;
+        pub const IMM: u32 = 2;
+    }
+    cbo_zero {
+        pub const FUNCT3: u32 = 2;
+        pub const IMM: u32 = 4;
+    }
+    // Zicbop (cache block prefetch)
+    prefetch_i {
+        pub const FUNCT3: u32 = 6;
+        pub const RS2: u32 = 0;
+    }
+    prefetch_r {
+        pub const FUNCT3: u32 = 6;
+        pub const RS2: u32 = 1;
+    }
+    prefetch_w {
+        pub const FUNCT3: u32 = 6;
+        pub const RS2: u32 = 3;
+    }
+    // Zihintntl
+    ntl_p1 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0;
+        pub const RS2: u32 = 2;
+    }
+    ntl_pall {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0;
+        pub const RS2: u32 = 3;
+    }
+    ntl_s1 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0;
+        pub const RS2: u32 = 4;
+    }
+    ntl_all {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0;
+        pub const RS2: u32 = 5;
+    }
+    // Zihintpause
+    pause {
+        pub const FUNCT3: u32 = 0;
+        pub const IMM: u32 = 0x010;
+    }
     // atomic
     lr_w {
         pub const FUNCT3: u32 = 2;
@@ -247,7 +410,7 @@ instructions! {
     }
     amoor_w {
         pub const FUNCT3: u32 = 2;
-        pub const FUNCT5: u32 = 10;
+        pub const FUNCT5: u32 = 8;
     }
     amoxor_w {
         pub const FUNCT3: u32 = 2;
@@ -262,29 +425,101 @@ instructions! {
         pub const FUNCT5: u32 = 16;
     }
 
-    // ????
-    amominu_w {}
-    amomaxu_w {}
+    amominu_w {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 24;
+    }
+    amomaxu_w {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 28;
+    }
+    // rv64 doubleword atomics
+    lr_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 2;
+    }
+    sc_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 3;
+    }
+    amoswap_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 1;
+    }
+    // Zicfiss
+    ssamoswap_w {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 0b01001;
+    }
+    ssamoswap_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 0b01001;
+    }
+    sspush {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 0b1100111;
+    }
+    sspopchk {
+        pub const FUNCT3: u32 = 4;
+        pub const IMM: u32 = 0xcdc;
+    }
+    ssrdp {
+        pub const FUNCT3: u32 = 4;
+        pub const IMM: u32 = 0xcdc;
+    }
+    amoadd_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 0;
+    }
+    amoxor_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 4;
+    }
+    amoand_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 12;
+    }
+    amoor_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 8;
+    }
+    amomin_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 16;
+    }
+    amomax_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 20;
+    }
+    amominu_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 24;
+    }
+    amomaxu_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 28;
+    }
 
     // F extention instructions
+    flw {
+        pub const FUNCT3: u32 = 2;
+    }
+    fsw {
+        pub const FUNCT3: u32 = 2;
+    }
     fadd_s {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 0;
     }
     fsub_s {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 1;
     }
     fmul_s {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 2;
     }
     fdiv_s {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 3;
     }
     fsqrt_s {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 11;
     }
     fsgnj_s {
@@ -308,22 +543,36 @@ instructions! {
         pub const FUNCT5: u32 = 5;
     }
     fcvt_w_s {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 24;
         pub const RS2: u32 = 0;
     }
     fcvt_wu_s {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 24;
         pub const RS2: u32 = 1;
     }
     fcvt_s_w {
-        pub const FUNCT3: u32 = 7;
         pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 0;
     }
     fcvt_s_wu {
-        pub const FUNCT3: u32 = 7;
-        pub const FUNCT5: u32 = 27;
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 1;
+    }
+    fcvt_l_s {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_lu_s {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 3;
+    }
+    fcvt_s_l {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_s_lu {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 3;
     }
     fmv_x_w {
         pub const FUNCT3: u32 = 0;
@@ -349,154 +598,3847 @@ instructions! {
         pub const FUNCT3: u32 = 1;
         pub const FUNCT5: u32 = 28;
     }
-
-    // utype
-    lui { /* Nothing here */ }
-    auipc { /* Nothing here */ }
-}
-
-pub mod compressed {
-    use super::InstructionSize;
-
-    pub type CompressedSize = u16;
-
-    pub fn is_compressed(inst: InstructionSize) -> bool {
-        const COMPRESSED_MASK: CompressedSize = 0b11;
-        match (inst & 0xFFFF) as u16 & COMPRESSED_MASK {
-            0 | 1 | 2 => true,
-            _ => false,
-        }
+    // D extention instructions
+    fld {
+        pub const FUNCT3: u32 = 3;
     }
-
-    pub mod crtype {
-        use super::CompressedSize;
-        use bitfield::bitfield;
-
-        bitfield! {
-            pub struct CRType(CompressedSize);
-            impl Debug;
-            pub opcode, _: 1, 0;
-            rs2, _: 6, 2; // must be 0
-            rs1, _: 11, 7; // rs1 != 0
-            pub funct4, _: 15, 12;
-        }
-
-        impl CRType {
-            pub fn new(inst: CompressedSize) -> Self {
-                Self(inst)
-            }
-        }
-
-        #[test]
-        fn crtype() {
-            let inst = CRType(0x8602 /* c.jr x12 */);
-            assert_eq!(inst.opcode(), 2);
-            assert_eq!(inst.funct4(), 8);
-            assert_eq!(inst.rs1(), 12);
-            assert_eq!(inst.rs2(), 0);
-        }
+    fsd {
+        pub const FUNCT3: u32 = 3;
     }
-
-    pub mod csstype {
-        // TODO: Implement compressed S-Type
+    fmv_x_d {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 28;
     }
-
-    pub mod cwitype {
-        // TODO: Implement compressed W-Type
+    fmv_d_x {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 30;
     }
-
-    pub mod citype {
-        // TODO: Implement compressed I-Type
+    fcvt_l_d {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 2;
     }
-
-    pub mod cjtype {
-        // TODO: Implement compressed J-Type
+    fcvt_lu_d {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 3;
     }
-
-    pub mod cbtype {
-        // TODO: Implement compressed B-Type
+    fcvt_d_l {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 2;
     }
-
-    pub mod cltype {
-        // TODO: Implement compressed L-Type
+    fcvt_d_lu {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 3;
     }
-
-    pub mod cstype {
-        // TODO: Implement cs-type
+    fadd_d {
+        pub const FUNCT5: u32 = 0;
     }
-}
-
-pub const LOAD_MATCH: InstructionSize = 3;
-pub const FENCE_MATCH: InstructionSize = 15;
-pub const ARITMETIC_IMMEDIATE_MATCH: InstructionSize = 19;
-pub const AUIPC_MATCH: InstructionSize = 23;
-pub const LUI_MATCH: InstructionSize = 55;
-pub const STORE_MATCH: InstructionSize = 35;
-pub const ARITMETIC_REGISTER_MATCH: InstructionSize = 51;
-
-// TODO: maybe this is correct, check it
-pub const FLOATING_POINT_MATCH: InstructionSize = 83;
-
-pub const BRANCH_MATCH: InstructionSize = 99;
-pub const CSR_MATCH: InstructionSize = 115;
-pub const JALR_MATCH: InstructionSize = 103;
-pub const JAL_MATCH: InstructionSize = 111;
-pub const ATOMIC_MATCH: InstructionSize = 47;
-
-pub mod rtype {
-    use super::InstructionSize;
-    use bitfield::bitfield;
+    fsub_d {
+        pub const FUNCT5: u32 = 1;
+    }
+    fmul_d {
+        pub const FUNCT5: u32 = 2;
+    }
+    fdiv_d {
+        pub const FUNCT5: u32 = 3;
+    }
+    fsqrt_d {
+        pub const FUNCT5: u32 = 11;
+    }
+    fsgnj_d {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 4;
+    }
+    fsgnjn_d {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 4;
+    }
+    fsgnjx_d {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 4;
+    }
+    fmin_d {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 5;
+    }
+    fmax_d {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 5;
+    }
+    fcvt_s_d {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 1;
+    }
+    fcvt_d_s {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 0;
+    }
+    feq_d {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 20;
+    }
+    flt_d {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 20;
+    }
+    fle_d {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 20;
+    }
+    fclass_d {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 28;
+    }
+    fcvt_w_d {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_wu_d {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 1;
+    }
+    fcvt_d_w {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_d_wu {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 1;
+    }
+    // Q extention instructions
+    flq {
+        pub const FUNCT3: u32 = 4;
+    }
+    fsq {
+        pub const FUNCT3: u32 = 4;
+    }
+    fadd_q {
+        pub const FUNCT5: u32 = 0;
+    }
+    fsub_q {
+        pub const FUNCT5: u32 = 1;
+    }
+    fmul_q {
+        pub const FUNCT5: u32 = 2;
+    }
+    fdiv_q {
+        pub const FUNCT5: u32 = 3;
+    }
+    fsqrt_q {
+        pub const FUNCT5: u32 = 11;
+    }
+    fsgnj_q {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 4;
+    }
+    fsgnjn_q {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 4;
+    }
+    fsgnjx_q {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 4;
+    }
+    fmin_q {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 5;
+    }
+    fmax_q {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 5;
+    }
+    fcvt_s_q {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 3;
+    }
+    fcvt_q_s {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_d_q {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 3;
+    }
+    fcvt_q_d {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 1;
+    }
+    feq_q {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 20;
+    }
+    flt_q {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 20;
+    }
+    fle_q {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 20;
+    }
+    fclass_q {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 28;
+    }
+    fcvt_w_q {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_wu_q {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 1;
+    }
+    fcvt_l_q {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_lu_q {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 3;
+    }
+    fcvt_q_w {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_q_wu {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 1;
+    }
+    fcvt_q_l {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_q_lu {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 3;
+    }
+    // Zfh extention instructions
+    flh {
+        pub const FUNCT3: u32 = 1;
+    }
+    fsh {
+        pub const FUNCT3: u32 = 1;
+    }
+    // Zfa extention instructions
+    fli_s {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 30;
+        pub const RS2: u32 = 1;
+    }
+    fli_d {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 30;
+        pub const RS2: u32 = 1;
+    }
+    fminm_s {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 5;
+    }
+    fmaxm_s {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 5;
+    }
+    fminm_d {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 5;
+    }
+    fmaxm_d {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT5: u32 = 5;
+    }
+    fround_s {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 4;
+    }
+    froundnx_s {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 5;
+    }
+    fround_d {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 4;
+    }
+    froundnx_d {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 5;
+    }
+    fcvtmod_w_d {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 8;
+    }
+    fadd_h {
+        pub const FUNCT5: u32 = 0;
+    }
+    fsub_h {
+        pub const FUNCT5: u32 = 1;
+    }
+    fmul_h {
+        pub const FUNCT5: u32 = 2;
+    }
+    fdiv_h {
+        pub const FUNCT5: u32 = 3;
+    }
+    fsqrt_h {
+        pub const FUNCT5: u32 = 11;
+    }
+    fsgnj_h {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 4;
+    }
+    fsgnjn_h {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 4;
+    }
+    fsgnjx_h {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 4;
+    }
+    fmin_h {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 5;
+    }
+    fmax_h {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 5;
+    }
+    fcvt_s_h {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_h_s {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_d_h {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_h_d {
+        pub const FUNCT5: u32 = 8;
+        pub const RS2: u32 = 1;
+    }
+    feq_h {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT5: u32 = 20;
+    }
+    flt_h {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 20;
+    }
+    fle_h {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 20;
+    }
+    fclass_h {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 28;
+    }
+    fcvt_w_h {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_wu_h {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 1;
+    }
+    fcvt_l_h {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_lu_h {
+        pub const FUNCT5: u32 = 24;
+        pub const RS2: u32 = 3;
+    }
+    fcvt_h_w {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 0;
+    }
+    fcvt_h_wu {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 1;
+    }
+    fcvt_h_l {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 2;
+    }
+    fcvt_h_lu {
+        pub const FUNCT5: u32 = 26;
+        pub const RS2: u32 = 3;
+    }
+    fmv_x_h {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 28;
+    }
+    fmv_h_x {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 30;
+    }
+
+    // Zba (address generation)
+    sh1add {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT7: u32 = 16;
+    }
+    sh2add {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 16;
+    }
+    sh3add {
+        pub const FUNCT3: u32 = 6;
+        pub const FUNCT7: u32 = 16;
+    }
+    add_uw {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 4;
+    }
+    sh1add_uw {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT7: u32 = 16;
+    }
+    sh2add_uw {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 16;
+    }
+    sh3add_uw {
+        pub const FUNCT3: u32 = 6;
+        pub const FUNCT7: u32 = 16;
+    }
+    slli_uw {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 4;
+    }
+
+    // Zbb (basic bit manipulation)
+    andn {
+        pub const FUNCT3: u32 = 7;
+        pub const FUNCT7: u32 = 32;
+    }
+    orn {
+        pub const FUNCT3: u32 = 6;
+        pub const FUNCT7: u32 = 32;
+    }
+    xnor {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 32;
+    }
+    min {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 5;
+    }
+    minu {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT7: u32 = 5;
+    }
+    max {
+        pub const FUNCT3: u32 = 6;
+        pub const FUNCT7: u32 = 5;
+    }
+    maxu {
+        pub const FUNCT3: u32 = 7;
+        pub const FUNCT7: u32 = 5;
+    }
+    rol {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 48;
+    }
+    ror {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT7: u32 = 48;
+    }
+    clz {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x600;
+    }
+    ctz {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x601;
+    }
+    cpop {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x602;
+    }
+    sext_b {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x604;
+    }
+    sext_h {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x605;
+    }
+    orc_b {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 0x287;
+    }
+    rori {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 48;
+    }
+    rev8 {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 0x6b8;
+        pub const IMM_RV32: u32 = 0x698;
+    }
+    zext_h {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 4;
+    }
+    rolw {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 48;
+    }
+    rorw {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT7: u32 = 48;
+    }
+    roriw {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 48;
+    }
+    clzw {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x600;
+    }
+    ctzw {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x601;
+    }
+    cpopw {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x602;
+    }
+
+    // Zbc (carry-less multiplication)
+    clmul {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 5;
+    }
+    clmulh {
+        pub const FUNCT3: u32 = 3;
+        pub const FUNCT7: u32 = 5;
+    }
+    clmulr {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT7: u32 = 5;
+    }
+
+    // Zbs (single bit)
+    bclr {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 36;
+    }
+    bext {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT7: u32 = 36;
+    }
+    binv {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 52;
+    }
+    bset {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 20;
+    }
+    bclri {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 36;
+    }
+    bexti {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 36;
+    }
+    binvi {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 52;
+    }
+    bseti {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 20;
+    }
+
+    // Zbkb (bit manipulation for cryptography)
+    pack {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 4;
+    }
+    packh {
+        pub const FUNCT3: u32 = 7;
+        pub const FUNCT7: u32 = 4;
+    }
+    packw {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 4;
+    }
+    brev8 {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 0x687;
+    }
+    zip {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x8f;
+    }
+    unzip {
+        pub const FUNCT3: u32 = 5;
+        pub const IMM: u32 = 0x8f;
+    }
+
+    // Zbkx (crossbar permutation)
+    xperm4 {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT7: u32 = 20;
+    }
+    xperm8 {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT7: u32 = 20;
+    }
+
+    // Zknd/Zkne (AES)
+    aes32esi {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 0x11;
+    }
+    aes32esmi {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 0x13;
+    }
+    aes32dsi {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 0x15;
+    }
+    aes32dsmi {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 0x17;
+    }
+    aes64es {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x19;
+    }
+    aes64esm {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x1b;
+    }
+    aes64ds {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x1d;
+    }
+    aes64dsm {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x1f;
+    }
+    aes64ks2 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x3f;
+    }
+    aes64im {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x300;
+    }
+    aes64ks1i {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x310;
+    }
+
+    // Zknh (SHA-2)
+    sha256sum0 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x100;
+    }
+    sha256sum1 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x101;
+    }
+    sha256sig0 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x102;
+    }
+    sha256sig1 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x103;
+    }
+    sha512sum0 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x104;
+    }
+    sha512sum1 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x105;
+    }
+    sha512sig0 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x106;
+    }
+    sha512sig1 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x107;
+    }
+    sha512sum0r {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x28;
+    }
+    sha512sum1r {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x29;
+    }
+    sha512sig0l {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x2a;
+    }
+    sha512sig1l {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x2b;
+    }
+    sha512sig0h {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x2e;
+    }
+    sha512sig1h {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0x2f;
+    }
+
+    // Zksed/Zksh (ShangMi SM4 and SM3)
+    sm4ed {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 0x18;
+    }
+    sm4ks {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT5: u32 = 0x1a;
+    }
+    sm3p0 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x108;
+    }
+    sm3p1 {
+        pub const FUNCT3: u32 = 1;
+        pub const IMM: u32 = 0x109;
+    }
+
+    // V (configuration)
+    vsetvli {
+        pub const FUNCT3: u32 = 7;
+    }
+    vsetivli {
+        pub const FUNCT3: u32 = 7;
+        pub const TOP: u32 = 0b11;
+    }
+    vsetvl {
+        pub const FUNCT3: u32 = 7;
+        pub const FUNCT7: u32 = 0b1000000;
+    }
+
+    // V (unit-stride loads and stores)
+    vle8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0;
+    }
+    vle16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0;
+    }
+    vle32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0;
+    }
+    vle64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0;
+    }
+    vse8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0;
+    }
+    vse16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0;
+    }
+    vse32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0;
+    }
+    vse64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0;
+    }
+    vle8ff_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b10000;
+    }
+    vle16ff_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b10000;
+    }
+    vle32ff_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b10000;
+    }
+    vle64ff_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b10000;
+    }
+
+    // V (strided and indexed loads and stores)
+    vlse8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 2;
+    }
+    vlse16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 2;
+    }
+    vlse32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 2;
+    }
+    vlse64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 2;
+    }
+    vsse8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 2;
+    }
+    vsse16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 2;
+    }
+    vsse32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 2;
+    }
+    vsse64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 2;
+    }
+    vluxei8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 1;
+    }
+    vluxei16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 1;
+    }
+    vluxei32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 1;
+    }
+    vluxei64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 1;
+    }
+    vloxei8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 3;
+    }
+    vloxei16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 3;
+    }
+    vloxei32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 3;
+    }
+    vloxei64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 3;
+    }
+    vsuxei8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 1;
+    }
+    vsuxei16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 1;
+    }
+    vsuxei32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 1;
+    }
+    vsuxei64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 1;
+    }
+    vsoxei8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 3;
+    }
+    vsoxei16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 3;
+    }
+    vsoxei32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 3;
+    }
+    vsoxei64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 3;
+    }
+
+    // V (OPMVV/OPMVX multiply, divide, widening and reduction)
+    vredsum_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000000;
+    }
+    vredand_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000001;
+    }
+    vredor_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000010;
+    }
+    vredxor_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000011;
+    }
+    vredminu_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000100;
+    }
+    vredmin_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000101;
+    }
+    vredmaxu_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000110;
+    }
+    vredmax_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b000111;
+    }
+    vaaddu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b001000;
+    }
+    vaaddu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b001000;
+    }
+    vaadd_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b001001;
+    }
+    vaadd_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b001001;
+    }
+    vasubu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b001010;
+    }
+    vasubu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b001010;
+    }
+    vasub_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b001011;
+    }
+    vasub_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b001011;
+    }
+    vslide1up_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b001110;
+    }
+    vslide1down_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b001111;
+    }
+    vdivu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100000;
+    }
+    vdivu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100000;
+    }
+    vdiv_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100001;
+    }
+    vdiv_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100001;
+    }
+    vremu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100010;
+    }
+    vremu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100010;
+    }
+    vrem_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100011;
+    }
+    vrem_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100011;
+    }
+    vmulhu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100100;
+    }
+    vmulhu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100100;
+    }
+    vmul_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100101;
+    }
+    vmul_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100101;
+    }
+    vmulhsu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100110;
+    }
+    vmulhsu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100110;
+    }
+    vmulh_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100111;
+    }
+    vmulh_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b100111;
+    }
+    vmadd_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101001;
+    }
+    vmadd_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b101001;
+    }
+    vnmsub_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101011;
+    }
+    vnmsub_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b101011;
+    }
+    vmacc_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101101;
+    }
+    vmacc_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b101101;
+    }
+    vnmsac_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101111;
+    }
+    vnmsac_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b101111;
+    }
+    vwaddu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110000;
+    }
+    vwaddu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110000;
+    }
+    vwadd_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110001;
+    }
+    vwadd_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110001;
+    }
+    vwsubu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110010;
+    }
+    vwsubu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110010;
+    }
+    vwsub_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110011;
+    }
+    vwsub_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110011;
+    }
+    vwaddu_wv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110100;
+    }
+    vwaddu_wx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110100;
+    }
+    vwadd_wv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110101;
+    }
+    vwadd_wx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110101;
+    }
+    vwsubu_wv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110110;
+    }
+    vwsubu_wx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110110;
+    }
+    vwsub_wv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b110111;
+    }
+    vwsub_wx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b110111;
+    }
+    vwmulu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b111000;
+    }
+    vwmulu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b111000;
+    }
+    vwmulsu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b111010;
+    }
+    vwmulsu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b111010;
+    }
+    vwmul_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b111011;
+    }
+    vwmul_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b111011;
+    }
+    vwmaccu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b111100;
+    }
+    vwmaccu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b111100;
+    }
+    vwmacc_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b111101;
+    }
+    vwmacc_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b111101;
+    }
+    vwmaccus_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b111110;
+    }
+    vwmaccsu_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b111111;
+    }
+    vwmaccsu_vx {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b111111;
+    }
+    vmv_x_s {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010000;
+        pub const VS1: u32 = 0;
+    }
+    vmv_s_x {
+        pub const FUNCT3: u32 = super::OPMVX;
+        pub const FUNCT6: u32 = 0b010000;
+        pub const VS2: u32 = 0;
+    }
+    vzext_vf8 {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00010;
+    }
+    vsext_vf8 {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00011;
+    }
+    vzext_vf4 {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00100;
+    }
+    vsext_vf4 {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00101;
+    }
+    vzext_vf2 {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00110;
+    }
+    vsext_vf2 {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00111;
+    }
+    vcompress_vm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010111;
+    }
+
+    // V (OPFVV/OPFVF floating point)
+    vfadd_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000000;
+    }
+    vfadd_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b000000;
+    }
+    vfredusum_vs {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000001;
+    }
+    vfsub_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000010;
+    }
+    vfsub_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b000010;
+    }
+    vfredosum_vs {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000011;
+    }
+    vfmin_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000100;
+    }
+    vfmin_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b000100;
+    }
+    vfredmin_vs {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000101;
+    }
+    vfmax_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000110;
+    }
+    vfmax_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b000110;
+    }
+    vfredmax_vs {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b000111;
+    }
+    vfsgnj_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b001000;
+    }
+    vfsgnj_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b001000;
+    }
+    vfsgnjn_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b001001;
+    }
+    vfsgnjn_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b001001;
+    }
+    vfsgnjx_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b001010;
+    }
+    vfsgnjx_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b001010;
+    }
+    vfslide1up_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b001110;
+    }
+    vfslide1down_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b001111;
+    }
+    vmfeq_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b011000;
+    }
+    vmfeq_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b011000;
+    }
+    vmfle_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b011001;
+    }
+    vmfle_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b011001;
+    }
+    vmflt_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b011011;
+    }
+    vmflt_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b011011;
+    }
+    vmfne_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b011100;
+    }
+    vmfne_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b011100;
+    }
+    vmfgt_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b011101;
+    }
+    vmfge_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b011111;
+    }
+    vfdiv_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b100000;
+    }
+    vfdiv_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b100000;
+    }
+    vfrdiv_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b100001;
+    }
+    vfmul_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b100100;
+    }
+    vfmul_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b100100;
+    }
+    vfrsub_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b100111;
+    }
+    vfmadd_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101000;
+    }
+    vfmadd_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101000;
+    }
+    vfnmadd_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101001;
+    }
+    vfnmadd_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101001;
+    }
+    vfmsub_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101010;
+    }
+    vfmsub_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101010;
+    }
+    vfnmsub_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101011;
+    }
+    vfnmsub_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101011;
+    }
+    vfmacc_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101100;
+    }
+    vfmacc_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101100;
+    }
+    vfnmacc_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101101;
+    }
+    vfnmacc_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101101;
+    }
+    vfmsac_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101110;
+    }
+    vfmsac_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101110;
+    }
+    vfnmsac_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b101111;
+    }
+    vfnmsac_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b101111;
+    }
+    vfwadd_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b110000;
+    }
+    vfwadd_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b110000;
+    }
+    vfwredusum_vs {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b110001;
+    }
+    vfwsub_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b110010;
+    }
+    vfwsub_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b110010;
+    }
+    vfwredosum_vs {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b110011;
+    }
+    vfwadd_wv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b110100;
+    }
+    vfwadd_wf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b110100;
+    }
+    vfwsub_wv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b110110;
+    }
+    vfwsub_wf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b110110;
+    }
+    vfwmul_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b111000;
+    }
+    vfwmul_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b111000;
+    }
+    vfwmacc_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b111100;
+    }
+    vfwmacc_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b111100;
+    }
+    vfwnmacc_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b111101;
+    }
+    vfwnmacc_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b111101;
+    }
+    vfwmsac_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b111110;
+    }
+    vfwmsac_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b111110;
+    }
+    vfwnmsac_vv {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b111111;
+    }
+    vfwnmsac_vf {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b111111;
+    }
+    vfcvt_xu_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00000;
+    }
+    vfcvt_x_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00001;
+    }
+    vfcvt_f_xu_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00010;
+    }
+    vfcvt_f_x_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00011;
+    }
+    vfcvt_rtz_xu_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00110;
+    }
+    vfcvt_rtz_x_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b00111;
+    }
+    vfwcvt_xu_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01000;
+    }
+    vfwcvt_x_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01001;
+    }
+    vfwcvt_f_xu_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01010;
+    }
+    vfwcvt_f_x_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01011;
+    }
+    vfwcvt_f_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01100;
+    }
+    vfwcvt_rtz_xu_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01110;
+    }
+    vfwcvt_rtz_x_f_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01111;
+    }
+    vfncvt_xu_f_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10000;
+    }
+    vfncvt_x_f_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10001;
+    }
+    vfncvt_f_xu_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10010;
+    }
+    vfncvt_f_x_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10011;
+    }
+    vfncvt_f_f_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10100;
+    }
+    vfncvt_rod_f_f_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10101;
+    }
+    vfncvt_rtz_xu_f_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10110;
+    }
+    vfncvt_rtz_x_f_w {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b10111;
+    }
+    vfsqrt_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010011;
+        pub const VS1: u32 = 0b00000;
+    }
+    vfrsqrt7_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010011;
+        pub const VS1: u32 = 0b00100;
+    }
+    vfrec7_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010011;
+        pub const VS1: u32 = 0b00101;
+    }
+    vfclass_v {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010011;
+        pub const VS1: u32 = 0b10000;
+    }
+    vfmv_f_s {
+        pub const FUNCT3: u32 = super::OPFVV;
+        pub const FUNCT6: u32 = 0b010000;
+        pub const VS1: u32 = 0;
+    }
+    vfmv_s_f {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b010000;
+        pub const VS2: u32 = 0;
+    }
+    vfmerge_vfm {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b010111;
+    }
+    vfmv_v_f {
+        pub const FUNCT3: u32 = super::OPFVF;
+        pub const FUNCT6: u32 = 0b010111;
+        pub const VS2: u32 = 0;
+    }
+
+    // V (mask manipulation)
+    vmandn_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011000;
+    }
+    vmand_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011001;
+    }
+    vmor_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011010;
+    }
+    vmxor_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011011;
+    }
+    vmorn_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011100;
+    }
+    vmnand_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011101;
+    }
+    vmnor_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011110;
+    }
+    vmxnor_mm {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b011111;
+    }
+    vcpop_m {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010000;
+        pub const VS1: u32 = 0b10000;
+    }
+    vfirst_m {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010000;
+        pub const VS1: u32 = 0b10001;
+    }
+    vmsbf_m {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010100;
+        pub const VS1: u32 = 0b00001;
+    }
+    vmsof_m {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010100;
+        pub const VS1: u32 = 0b00010;
+    }
+    vmsif_m {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010100;
+        pub const VS1: u32 = 0b00011;
+    }
+    viota_m {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010100;
+        pub const VS1: u32 = 0b10000;
+    }
+    vid_v {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010100;
+        pub const VS1: u32 = 0b10001;
+        pub const VS2: u32 = 0;
+    }
+
+    // V (whole register loads, stores and moves), nf is the register count minus one
+    vl1re8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 0;
+    }
+    vl1re16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 0;
+    }
+    vl1re32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 0;
+    }
+    vl1re64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 0;
+    }
+    vl2re8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 1;
+    }
+    vl2re16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 1;
+    }
+    vl2re32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 1;
+    }
+    vl2re64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 1;
+    }
+    vl4re8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 3;
+    }
+    vl4re16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 3;
+    }
+    vl4re32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 3;
+    }
+    vl4re64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 3;
+    }
+    vl8re8_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 7;
+    }
+    vl8re16_v {
+        pub const WIDTH: u32 = 5;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 7;
+    }
+    vl8re32_v {
+        pub const WIDTH: u32 = 6;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 7;
+    }
+    vl8re64_v {
+        pub const WIDTH: u32 = 7;
+        pub const MOP: u32 = 0;
+        pub const LUMOP: u32 = 0b01000;
+        pub const NF: u32 = 7;
+    }
+    vs1r_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0b01000;
+        pub const NF: u32 = 0;
+    }
+    vs2r_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0b01000;
+        pub const NF: u32 = 1;
+    }
+    vs4r_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0b01000;
+        pub const NF: u32 = 3;
+    }
+    vs8r_v {
+        pub const WIDTH: u32 = 0;
+        pub const MOP: u32 = 0;
+        pub const SUMOP: u32 = 0b01000;
+        pub const NF: u32 = 7;
+    }
+    vmv1r_v {
+        pub const FUNCT3: u32 = super::OPIVI;
+        pub const FUNCT6: u32 = 0b100111;
+        pub const SIMM: u32 = 0;
+    }
+    vmv2r_v {
+        pub const FUNCT3: u32 = super::OPIVI;
+        pub const FUNCT6: u32 = 0b100111;
+        pub const SIMM: u32 = 1;
+    }
+    vmv4r_v {
+        pub const FUNCT3: u32 = super::OPIVI;
+        pub const FUNCT6: u32 = 0b100111;
+        pub const SIMM: u32 = 3;
+    }
+    vmv8r_v {
+        pub const FUNCT3: u32 = super::OPIVI;
+        pub const FUNCT6: u32 = 0b100111;
+        pub const SIMM: u32 = 7;
+    }
+
+    // Zvbb (vector basic bit manipulation)
+    vandn_vv {
+        pub const FUNCT3: u32 = super::OPIVV;
+        pub const FUNCT6: u32 = 0b000001;
+    }
+    vandn_vx {
+        pub const FUNCT3: u32 = super::OPIVX;
+        pub const FUNCT6: u32 = 0b000001;
+    }
+    vror_vv {
+        pub const FUNCT3: u32 = super::OPIVV;
+        pub const FUNCT6: u32 = 0b010100;
+    }
+    vror_vx {
+        pub const FUNCT3: u32 = super::OPIVX;
+        pub const FUNCT6: u32 = 0b010100;
+    }
+    vror_vi {
+        pub const FUNCT3: u32 = super::OPIVI;
+        pub const FUNCT5: u32 = 0b01010;
+    }
+    vrol_vv {
+        pub const FUNCT3: u32 = super::OPIVV;
+        pub const FUNCT6: u32 = 0b010101;
+    }
+    vrol_vx {
+        pub const FUNCT3: u32 = super::OPIVX;
+        pub const FUNCT6: u32 = 0b010101;
+    }
+    vwsll_vv {
+        pub const FUNCT3: u32 = super::OPIVV;
+        pub const FUNCT6: u32 = 0b110101;
+    }
+    vwsll_vx {
+        pub const FUNCT3: u32 = super::OPIVX;
+        pub const FUNCT6: u32 = 0b110101;
+    }
+    vwsll_vi {
+        pub const FUNCT3: u32 = super::OPIVI;
+        pub const FUNCT6: u32 = 0b110101;
+    }
+    vbrev8_v {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01000;
+    }
+    vrev8_v {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01001;
+    }
+    vbrev_v {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01010;
+    }
+    vclz_v {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01100;
+    }
+    vctz_v {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01101;
+    }
+    vcpop_v {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01110;
+    }
+
+    // Zvkg (vector GCM/GMAC)
+    vghsh_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101100;
+    }
+    vgmul_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101000;
+        pub const VS1: u32 = 0b10001;
+    }
+
+    // Zvkned (vector AES block cipher)
+    vaesdf_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101000;
+        pub const VS1: u32 = 0b00001;
+    }
+    vaesdf_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101001;
+        pub const VS1: u32 = 0b00001;
+    }
+    vaesdm_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101000;
+        pub const VS1: u32 = 0b00000;
+    }
+    vaesdm_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101001;
+        pub const VS1: u32 = 0b00000;
+    }
+    vaesef_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101000;
+        pub const VS1: u32 = 0b00011;
+    }
+    vaesef_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101001;
+        pub const VS1: u32 = 0b00011;
+    }
+    vaesem_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101000;
+        pub const VS1: u32 = 0b00010;
+    }
+    vaesem_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101001;
+        pub const VS1: u32 = 0b00010;
+    }
+    vaesz_vs {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101001;
+        pub const VS1: u32 = 0b00111;
+    }
+    vaeskf1_vi {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b100010;
+    }
+    vaeskf2_vi {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101010;
+    }
+
+    // Zvknh (vector SHA-2 secure hash)
+    vsha2ms_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101101;
+    }
+    vsha2ch_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101110;
+    }
+    vsha2cl_vv {
+        pub const FUNCT3: u32 = super::OPMVV;
+        pub const FUNCT6: u32 = 0b101111;
+    }
+
+    // P (packed SIMD)
+    add16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0100000;
+    }
+    add8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0100100;
+    }
+    sub16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0100001;
+    }
+    sub8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0100101;
+    }
+    radd16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0000000;
+    }
+    radd8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0000100;
+    }
+    rsub16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0000001;
+    }
+    rsub8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0000101;
+    }
+    kadd16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0001000;
+    }
+    kadd8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0001100;
+    }
+    ksub16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0001001;
+    }
+    ksub8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0001101;
+    }
+    ukadd16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0011000;
+    }
+    ukadd8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0011100;
+    }
+    uksub16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0011001;
+    }
+    uksub8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b0011101;
+    }
+    smul16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b1010000;
+    }
+    smul8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b1010100;
+    }
+    umul16 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b1011000;
+    }
+    umul8 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT7: u32 = 0b1011100;
+    }
+    pkbb16 {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 0b0000111;
+    }
+    pkbt16 {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 0b0001111;
+    }
+    pktb16 {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 0b0010111;
+    }
+    pktt16 {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT7: u32 = 0b0011111;
+    }
+
+    // XTheadBa
+    th_addsl {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT5: u32 = 0;
+    }
+    // XTheadCmo, funct12 is the upper 12 bits
+    th_dcache_call {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x001;
+    }
+    th_dcache_iall {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x002;
+    }
+    th_dcache_ciall {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x003;
+    }
+    th_icache_iall {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x010;
+    }
+    th_icache_ialls {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x011;
+    }
+    th_dcache_cval1 {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x024;
+    }
+    th_dcache_cva {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x025;
+    }
+    th_dcache_iva {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x026;
+    }
+    th_dcache_civa {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x027;
+    }
+    th_dcache_cpa {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x029;
+    }
+    th_dcache_ipa {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x02a;
+    }
+    th_dcache_cipa {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x02b;
+    }
+    th_icache_iva {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x030;
+    }
+    th_icache_ipa {
+        pub const FUNCT3: u32 = 0;
+        pub const FUNCT12: u32 = 0x038;
+    }
+    // XTheadMemIdx
+    th_lrb {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00000;
+    }
+    th_lurb {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00010;
+    }
+    th_lbib {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00001;
+    }
+    th_lbia {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00011;
+    }
+    th_lrh {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00100;
+    }
+    th_lurh {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00110;
+    }
+    th_lhib {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00101;
+    }
+    th_lhia {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b00111;
+    }
+    th_lrw {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01000;
+    }
+    th_lurw {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01010;
+    }
+    th_lwib {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01001;
+    }
+    th_lwia {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01011;
+    }
+    th_lrd {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01100;
+    }
+    th_lurd {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01110;
+    }
+    th_ldib {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01101;
+    }
+    th_ldia {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b01111;
+    }
+    th_lrbu {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10000;
+    }
+    th_lurbu {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10010;
+    }
+    th_lbuib {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10001;
+    }
+    th_lbuia {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10011;
+    }
+    th_lrhu {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10100;
+    }
+    th_lurhu {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10110;
+    }
+    th_lhuib {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10101;
+    }
+    th_lhuia {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b10111;
+    }
+    th_lrwu {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b11000;
+    }
+    th_lurwu {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b11010;
+    }
+    th_lwuib {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b11001;
+    }
+    th_lwuia {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT5: u32 = 0b11011;
+    }
+    th_srb {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00000;
+    }
+    th_surb {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00010;
+    }
+    th_sbib {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00001;
+    }
+    th_sbia {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00011;
+    }
+    th_srh {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00100;
+    }
+    th_surh {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00110;
+    }
+    th_shib {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00101;
+    }
+    th_shia {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b00111;
+    }
+    th_srw {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01000;
+    }
+    th_surw {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01010;
+    }
+    th_swib {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01001;
+    }
+    th_swia {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01011;
+    }
+    th_srd {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01100;
+    }
+    th_surd {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01110;
+    }
+    th_sdib {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01101;
+    }
+    th_sdia {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT5: u32 = 0b01111;
+    }
+
+    // C, quadrant 0
+    c_addi4spn {
+        pub const FUNCT3: u32 = 0;
+    }
+    c_fld {
+        pub const FUNCT3: u32 = 1;
+    }
+    c_lw {
+        pub const FUNCT3: u32 = 2;
+    }
+    c_flw {
+        pub const FUNCT3: u32 = 3;
+    }
+    c_fsd {
+        pub const FUNCT3: u32 = 5;
+    }
+    c_sw {
+        pub const FUNCT3: u32 = 6;
+    }
+    c_fsw {
+        pub const FUNCT3: u32 = 7;
+    }
+
+    // C, quadrant 1
+    c_addi {
+        pub const FUNCT3: u32 = 0;
+    }
+    c_jal {
+        pub const FUNCT3: u32 = 1;
+    }
+    c_li {
+        pub const FUNCT3: u32 = 2;
+    }
+    c_lui {
+        pub const FUNCT3: u32 = 3;
+        // rd = sp is c.addi16sp instead
+        pub const ADDI16SP_RD: u32 = 2;
+    }
+    c_srli {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT2: u32 = 0;
+    }
+    c_srai {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT2: u32 = 1;
+    }
+    c_andi {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT2: u32 = 2;
+    }
+    // the register-register ones are funct6 = 100011 and then funct2
+    c_sub {
+        pub const FUNCT6: u32 = 0b100011;
+        pub const FUNCT2: u32 = 0;
+    }
+    c_xor {
+        pub const FUNCT6: u32 = 0b100011;
+        pub const FUNCT2: u32 = 1;
+    }
+    c_or {
+        pub const FUNCT6: u32 = 0b100011;
+        pub const FUNCT2: u32 = 2;
+    }
+    c_and {
+        pub const FUNCT6: u32 = 0b100011;
+        pub const FUNCT2: u32 = 3;
+    }
+    c_j {
+        pub const FUNCT3: u32 = 5;
+    }
+    c_beqz {
+        pub const FUNCT3: u32 = 6;
+    }
+    c_bnez {
+        pub const FUNCT3: u32 = 7;
+    }
+
+    // C, quadrant 2
+    c_slli {
+        pub const FUNCT3: u32 = 0;
+    }
+    c_fldsp {
+        pub const FUNCT3: u32 = 1;
+    }
+    c_lwsp {
+        pub const FUNCT3: u32 = 2;
+    }
+    c_flwsp {
+        pub const FUNCT3: u32 = 3;
+    }
+    c_fsdsp {
+        pub const FUNCT3: u32 = 5;
+    }
+    c_swsp {
+        pub const FUNCT3: u32 = 6;
+    }
+    c_fswsp {
+        pub const FUNCT3: u32 = 7;
+    }
+    // the CR ones share funct3 = 100 and are told apart by bit 12 and which registers are x0
+    c_mv {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT4: u32 = 0b1000;
+    }
+    c_add {
+        pub const FUNCT4: u32 = 0b1001;
+    }
+
+    // RV64C, these take over the encodings of c.jal and the single precision loads and stores
+    c_addiw {
+        pub const FUNCT3: u32 = 1;
+    }
+    c_ld {
+        pub const FUNCT3: u32 = 3;
+    }
+    c_sd {
+        pub const FUNCT3: u32 = 7;
+    }
+    c_ldsp {
+        pub const FUNCT3: u32 = 3;
+    }
+    c_sdsp {
+        pub const FUNCT3: u32 = 7;
+    }
+    c_subw {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT2: u32 = 0;
+    }
+    c_addw {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT2: u32 = 1;
+    }
+
+    // Zcb, the loads and stores are quadrant 0 funct3 = 100 and bit 6 (funct1) picks c.lh over c.lhu
+    c_lbu {
+        pub const FUNCT3: u32 = 4;
+        pub const FUNCT6: u32 = 0b100000;
+    }
+    c_lhu {
+        pub const FUNCT6: u32 = 0b100001;
+        pub const FUNCT1: u32 = 0;
+    }
+    c_lh {
+        pub const FUNCT6: u32 = 0b100001;
+        pub const FUNCT1: u32 = 1;
+    }
+    c_sb {
+        pub const FUNCT6: u32 = 0b100010;
+    }
+    c_sh {
+        pub const FUNCT6: u32 = 0b100011;
+        pub const FUNCT1: u32 = 0;
+    }
+    c_mul {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT2: u32 = 2;
+    }
+    // the unary ones have no rs2, bits 6:2 are a funct5
+    c_zext_b {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT5: u32 = 0b11000;
+    }
+    c_sext_b {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT5: u32 = 0b11001;
+    }
+    c_zext_h {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT5: u32 = 0b11010;
+    }
+    c_sext_h {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT5: u32 = 0b11011;
+    }
+    c_zext_w {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT5: u32 = 0b11100;
+    }
+    c_not {
+        pub const FUNCT6: u32 = 0b100111;
+        pub const FUNCT5: u32 = 0b11101;
+    }
+
+    // Zcmp, all in the c.fsdsp encodings (quadrant 2 funct3 = 101)
+    cm_push {
+        pub const FUNCT5: u32 = 0b11000;
+    }
+    cm_pop {
+        pub const FUNCT5: u32 = 0b11010;
+    }
+    cm_popretz {
+        pub const FUNCT5: u32 = 0b11100;
+    }
+    cm_popret {
+        pub const FUNCT5: u32 = 0b11110;
+    }
+    cm_mvsa01 {
+        pub const FUNCT6: u32 = 0b101011;
+        pub const FUNCT2: u32 = 1;
+    }
+    cm_mva01s {
+        pub const FUNCT6: u32 = 0b101011;
+        pub const FUNCT2: u32 = 3;
+    }
+
+    // Zcmt, also in the c.fsdsp encodings
+    cm_jt {
+        pub const FUNCT6: u32 = 0b101000;
+    }
+    cm_jalt {
+        pub const FUNCT6: u32 = 0b101000;
+        // the lower indexes are cm.jt
+        pub const MIN_INDEX: u32 = 32;
+    }
+
+    // Zvksed (vector ShangMi SM4)
+    vsm4k_vi {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT6: u32 = 0b100001;
+    }
+    vsm4r_vv {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT6: u32 = 0b101000;
+        pub const VS1: u32 = 0b10000;
+    }
+    vsm4r_vs {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT6: u32 = 0b101001;
+        pub const VS1: u32 = 0b10000;
+    }
+    // Zvksh (vector ShangMi SM3)
+    vsm3me_vv {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT6: u32 = 0b100000;
+    }
+    vsm3c_vi {
+        pub const FUNCT3: u32 = 2;
+        pub const FUNCT6: u32 = 0b101011;
+    }
+
+    // Zvfbfwma / Zvfbfmin (vector bfloat16)
+    vfwmaccbf16_vv {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT6: u32 = 0b111011;
+    }
+    vfwmaccbf16_vf {
+        pub const FUNCT3: u32 = 5;
+        pub const FUNCT6: u32 = 0b111011;
+    }
+    vfncvtbf16_f_f_w {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b11101;
+    }
+    vfwcvtbf16_f_f_v {
+        pub const FUNCT3: u32 = 1;
+        pub const FUNCT6: u32 = 0b010010;
+        pub const VS1: u32 = 0b01101;
+    }
+
+    // utype
+    lui { /* Nothing here */ }
+    auipc { /* Nothing here */ }
+}
+
+pub mod compressed {
+    use super::InstructionSize;
+
+    pub type CompressedSize = u16;
+
+    pub fn is_compressed(inst: InstructionSize) -> bool {
+        const COMPRESSED_MASK: CompressedSize = 0b11;
+        matches!((inst & 0xFFFF) as u16 & COMPRESSED_MASK, 0..=2)
+    }
+
+    // bits hi..=lo of `inst`, compressed immediates are scattered so they get put back together
+    // one slice at a time
+    fn bits(inst: CompressedSize, hi: u32, lo: u32) -> InstructionSize {
+        (inst as InstructionSize >> lo) & ((1 << (hi - lo + 1)) - 1)
+    }
+
+    fn sign_extend(value: InstructionSize, width: u32) -> InstructionSize {
+        let shift = InstructionSize::BITS - width;
+        (((value << shift) as i32) >> shift) as InstructionSize
+    }
+
+    // the registers a Zcmp rlist names, ra and then s0 up to some sN (s10 can't be named without
+    // s11 so 15 is s0-s11), 0-3 are reserved and name nothing
+    pub fn rlist_registers(rlist: InstructionSize) -> &'static [InstructionSize] {
+        const SAVED: [InstructionSize; 13] = [1, 8, 9, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27];
+        match rlist {
+            4..=14 => &SAVED[..rlist as usize - 3],
+            15 => &SAVED,
+            _ => &[],
+        }
+    }
+
+    // how far cm.push/cm.pop move sp before spimm is added, the saved registers rounded up to 16
+    pub fn stack_adj_base(rlist: InstructionSize, xlen_bytes: InstructionSize) -> InstructionSize {
+        (rlist_registers(rlist).len() as InstructionSize * xlen_bytes).next_multiple_of(16)
+    }
+
+    // the 3 bit s register numbers of cm.mvsa01/cm.mva01s, s0-s1 and then s2-s7
+    pub fn sreg(num: InstructionSize) -> InstructionSize {
+        match num {
+            0 | 1 => num + 8,
+            _ => num + 16,
+        }
+    }
+
+    #[test]
+    fn zcmp_registers() {
+        assert_eq!(rlist_registers(4), [1]);
+        assert_eq!(rlist_registers(7), [1, 8, 9, 18]);
+        assert_eq!(rlist_registers(15).len(), 13);
+        assert!(rlist_registers(3).is_empty());
+        assert_eq!(stack_adj_base(7, 4), 16);
+        assert_eq!(stack_adj_base(7, 8), 32);
+        assert_eq!(stack_adj_base(15, 4), 64);
+        assert_eq!(sreg(1), 9);
+        assert_eq!(sreg(2), 18);
+    }
+
+    pub mod crtype {
+        use super::CompressedSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CRType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            // 0 for c.jr/c.jalr
+            pub rs2, _: 6, 2;
+            // rd for c.mv/c.add
+            pub rs1, _: 11, 7;
+            pub funct4, _: 15, 12;
+        }
+
+        impl CRType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+        }
+
+        #[test]
+        fn crtype() {
+            let inst = CRType(0x8602 /* c.jr x12 */);
+            assert_eq!(inst.opcode(), 2);
+            assert_eq!(inst.funct4(), 8);
+            assert_eq!(inst.rs1(), 12);
+            assert_eq!(inst.rs2(), 0);
+        }
+    }
+
+    // stack relative store, `c.swsp`/`c.sdsp`
+    pub mod csstype {
+        use super::{bits, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CSSType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rs2, _: 6, 2;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CSSType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            // uimm[5:2|7:6]
+            pub fn word_offset(&self) -> InstructionSize {
+                bits(self.0, 12, 9) << 2 | bits(self.0, 8, 7) << 6
+            }
+
+            // uimm[5:3|8:6]
+            pub fn double_offset(&self) -> InstructionSize {
+                bits(self.0, 12, 10) << 3 | bits(self.0, 9, 7) << 6
+            }
+        }
+
+        #[test]
+        fn csstype() {
+            let inst = CSSType(0xc606 /* c.swsp ra, 12(sp) */);
+            assert_eq!(inst.opcode(), 2);
+            assert_eq!(inst.funct3(), 6);
+            assert_eq!(inst.rs2(), 1);
+            assert_eq!(inst.word_offset(), 12);
+            let inst = CSSType(0xe406 /* c.sdsp ra, 8(sp) */);
+            assert_eq!(inst.double_offset(), 8);
+        }
+    }
+
+    // wide immediate, only used by `c.addi4spn`
+    pub mod cwitype {
+        use super::{bits, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CIWType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            // x8-x15, use `rd` for the actual register
+            pub rd_prime, _: 4, 2;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CIWType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rd(&self) -> CompressedSize {
+                self.rd_prime() + 8
+            }
+
+            // nzuimm[5:4|9:6|2|3], zero is reserved
+            pub fn imm(&self) -> InstructionSize {
+                bits(self.0, 12, 11) << 4
+                    | bits(self.0, 10, 7) << 6
+                    | bits(self.0, 6, 6) << 2
+                    | bits(self.0, 5, 5) << 3
+            }
+        }
+
+        #[test]
+        fn ciwtype() {
+            let inst = CIWType(0x0808 /* c.addi4spn a0, sp, 16 */);
+            assert_eq!(inst.opcode(), 0);
+            assert_eq!(inst.funct3(), 0);
+            assert_eq!(inst.rd(), 10);
+            assert_eq!(inst.imm(), 16);
+        }
+    }
+
+    // register and a 6 bit immediate, split around rd
+    pub mod citype {
+        use super::{bits, sign_extend, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CIType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rd, _: 11, 7;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CIType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            // imm[5|4:0] sign extended, `c.li`, `c.addi` etc
+            pub fn imm(&self) -> InstructionSize {
+                sign_extend(self.shamt(), 6)
+            }
+
+            // the same bits without sign extension, `c.slli`
+            pub fn shamt(&self) -> InstructionSize {
+                bits(self.0, 12, 12) << 5 | bits(self.0, 6, 2)
+            }
+
+            // nzimm[17|16:12], this is the value loaded into rd (not the 20 bit lui field)
+            pub fn lui_imm(&self) -> InstructionSize {
+                self.imm() << 12
+            }
+
+            // nzimm[9|4|6|8:7|5]
+            pub fn addi16sp_imm(&self) -> InstructionSize {
+                let imm = bits(self.0, 12, 12) << 9
+                    | bits(self.0, 6, 6) << 4
+                    | bits(self.0, 5, 5) << 6
+                    | bits(self.0, 4, 3) << 7
+                    | bits(self.0, 2, 2) << 5;
+                sign_extend(imm, 10)
+            }
+
+            // uimm[5|4:2|7:6], `c.lwsp`/`c.flwsp`
+            pub fn word_offset(&self) -> InstructionSize {
+                bits(self.0, 12, 12) << 5 | bits(self.0, 6, 4) << 2 | bits(self.0, 3, 2) << 6
+            }
+
+            // uimm[5|4:3|8:6], `c.ldsp`/`c.fldsp`
+            pub fn double_offset(&self) -> InstructionSize {
+                bits(self.0, 12, 12) << 5 | bits(self.0, 6, 5) << 3 | bits(self.0, 4, 2) << 6
+            }
+        }
+
+        #[test]
+        fn citype() {
+            use crate::instructions::SignedInstructionSize;
+            let inst = CIType(0x157d /* c.addi a0, -1 */);
+            assert_eq!(inst.opcode(), 1);
+            assert_eq!(inst.funct3(), 0);
+            assert_eq!(inst.rd(), 10);
+            assert_eq!(inst.imm() as SignedInstructionSize, -1);
+            assert_eq!(inst.shamt(), 63);
+            let inst = CIType(0x6505 /* c.lui a0, 1 */);
+            assert_eq!(inst.lui_imm(), 0x1000);
+            let inst = CIType(0x717d /* c.addi16sp sp, -16 */);
+            assert_eq!(inst.rd(), 2);
+            assert_eq!(inst.addi16sp_imm() as SignedInstructionSize, -16);
+            let inst = CIType(0x4532 /* c.lwsp a0, 12(sp) */);
+            assert_eq!(inst.word_offset(), 12);
+            let inst = CIType(0x6522 /* c.ldsp a0, 8(sp) */);
+            assert_eq!(inst.double_offset(), 8);
+        }
+    }
+
+    // `c.j` and `c.jal`
+    pub mod cjtype {
+        use super::{bits, sign_extend, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CJType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CJType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            // offset[11|4|9:8|10|6|7|3:1|5] sign extended
+            pub fn imm(&self) -> InstructionSize {
+                let imm = bits(self.0, 12, 12) << 11
+                    | bits(self.0, 11, 11) << 4
+                    | bits(self.0, 10, 9) << 8
+                    | bits(self.0, 8, 8) << 10
+                    | bits(self.0, 7, 7) << 6
+                    | bits(self.0, 6, 6) << 7
+                    | bits(self.0, 5, 3) << 1
+                    | bits(self.0, 2, 2) << 5;
+                sign_extend(imm, 12)
+            }
+        }
+
+        #[test]
+        fn cjtype() {
+            use crate::instructions::SignedInstructionSize;
+            let inst = CJType(0xa801 /* c.j 16 */);
+            assert_eq!(inst.opcode(), 1);
+            assert_eq!(inst.funct3(), 5);
+            assert_eq!(inst.imm(), 16);
+            let inst = CJType(0xbffd /* c.j -2 */);
+            assert_eq!(inst.imm() as SignedInstructionSize, -2);
+        }
+    }
+
+    // branches on x8-x15 and the shifts/`c.andi` that share their quadrant
+    pub mod cbtype {
+        use super::{bits, sign_extend, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CBType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            // x8-x15, use `rs1` for the actual register
+            pub rs1_prime, _: 9, 7;
+            // only meaningful for the shifts and `c.andi`
+            pub funct2, _: 11, 10;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CBType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rs1(&self) -> CompressedSize {
+                self.rs1_prime() + 8
+            }
+
+            // offset[8|4:3|7:6|2:1|5] sign extended, `c.beqz`/`c.bnez`
+            pub fn offset(&self) -> InstructionSize {
+                let imm = bits(self.0, 12, 12) << 8
+                    | bits(self.0, 11, 10) << 3
+                    | bits(self.0, 6, 5) << 6
+                    | bits(self.0, 4, 3) << 1
+                    | bits(self.0, 2, 2) << 5;
+                sign_extend(imm, 9)
+            }
+
+            // shamt[5|4:0], `c.srli`/`c.srai`
+            pub fn shamt(&self) -> InstructionSize {
+                bits(self.0, 12, 12) << 5 | bits(self.0, 6, 2)
+            }
+
+            // imm[5|4:0] sign extended, `c.andi`
+            pub fn imm(&self) -> InstructionSize {
+                sign_extend(self.shamt(), 6)
+            }
+        }
+
+        #[test]
+        fn cbtype() {
+            use crate::instructions::SignedInstructionSize;
+            let inst = CBType(0xc501 /* c.beqz a0, 8 */);
+            assert_eq!(inst.opcode(), 1);
+            assert_eq!(inst.funct3(), 6);
+            assert_eq!(inst.rs1(), 10);
+            assert_eq!(inst.offset(), 8);
+            let inst = CBType(0xfd7d /* c.bnez a0, -2 */);
+            assert_eq!(inst.offset() as SignedInstructionSize, -2);
+            let inst = CBType(0x8105 /* c.srli a0, 1 */);
+            assert_eq!(inst.funct2(), 0);
+            assert_eq!(inst.shamt(), 1);
+            let inst = CBType(0x997d /* c.andi a0, -1 */);
+            assert_eq!(inst.funct2(), 2);
+            assert_eq!(inst.imm() as SignedInstructionSize, -1);
+        }
+    }
+
+    // loads with both registers in x8-x15
+    pub mod cltype {
+        use super::{bits, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CLType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rd_prime, _: 4, 2;
+            pub rs1_prime, _: 9, 7;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CLType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rd(&self) -> CompressedSize {
+                self.rd_prime() + 8
+            }
+
+            pub fn rs1(&self) -> CompressedSize {
+                self.rs1_prime() + 8
+            }
+
+            // uimm[5:3|2|6], `c.lw`/`c.flw`
+            pub fn word_offset(&self) -> InstructionSize {
+                word_offset(self.0)
+            }
+
+            // uimm[5:3|7:6], `c.ld`/`c.fld`
+            pub fn double_offset(&self) -> InstructionSize {
+                double_offset(self.0)
+            }
+        }
+
+        // cs-type stores put their offset in the same bits
+        pub(super) fn word_offset(inst: CompressedSize) -> InstructionSize {
+            bits(inst, 12, 10) << 3 | bits(inst, 6, 6) << 2 | bits(inst, 5, 5) << 6
+        }
+
+        pub(super) fn double_offset(inst: CompressedSize) -> InstructionSize {
+            bits(inst, 12, 10) << 3 | bits(inst, 6, 5) << 6
+        }
+
+        #[test]
+        fn cltype() {
+            let inst = CLType(0x41c8 /* c.lw a0, 4(a1) */);
+            assert_eq!(inst.opcode(), 0);
+            assert_eq!(inst.funct3(), 2);
+            assert_eq!(inst.rd(), 10);
+            assert_eq!(inst.rs1(), 11);
+            assert_eq!(inst.word_offset(), 4);
+            let inst = CLType(0x6588 /* c.ld a0, 8(a1) */);
+            assert_eq!(inst.double_offset(), 8);
+        }
+    }
+
+    // stores with both registers in x8-x15
+    pub mod cstype {
+        use super::cltype::{double_offset, word_offset};
+        use super::CompressedSize;
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CSType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rs2_prime, _: 4, 2;
+            pub rs1_prime, _: 9, 7;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CSType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rs1(&self) -> CompressedSize {
+                self.rs1_prime() + 8
+            }
+
+            pub fn rs2(&self) -> CompressedSize {
+                self.rs2_prime() + 8
+            }
+
+            // uimm[5:3|2|6], `c.sw`/`c.fsw`
+            pub fn word_offset(&self) -> InstructionSize {
+                word_offset(self.0)
+            }
+
+            // uimm[5:3|7:6], `c.sd`/`c.fsd`
+            pub fn double_offset(&self) -> InstructionSize {
+                double_offset(self.0)
+            }
+        }
+
+        #[test]
+        fn cstype() {
+            let inst = CSType(0xc588 /* c.sw a0, 8(a1) */);
+            assert_eq!(inst.opcode(), 0);
+            assert_eq!(inst.funct3(), 6);
+            assert_eq!(inst.rs1(), 11);
+            assert_eq!(inst.rs2(), 10);
+            assert_eq!(inst.word_offset(), 8);
+        }
+    }
+
+    // register-register arithmetic on x8-x15, `c.sub`, `c.and` etc
+    pub mod catype {
+        use super::CompressedSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CAType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rs2_prime, _: 4, 2;
+            pub funct2, _: 6, 5;
+            // rd is also rs1
+            pub rd_prime, _: 9, 7;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CAType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rd(&self) -> CompressedSize {
+                self.rd_prime() + 8
+            }
+
+            pub fn rs2(&self) -> CompressedSize {
+                self.rs2_prime() + 8
+            }
+        }
+
+        #[test]
+        fn catype() {
+            let inst = CAType(0x8d0d /* c.sub a0, a1 */);
+            assert_eq!(inst.opcode(), 1);
+            assert_eq!(inst.funct6(), 0b100011);
+            assert_eq!(inst.funct2(), 0);
+            assert_eq!(inst.rd(), 10);
+            assert_eq!(inst.rs2(), 11);
+        }
+    }
+
+    // Zcb byte loads, `c.lbu`
+    pub mod clbtype {
+        use super::{bits, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CLBType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rd_prime, _: 4, 2;
+            pub rs1_prime, _: 9, 7;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CLBType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rd(&self) -> CompressedSize {
+                self.rd_prime() + 8
+            }
+
+            pub fn rs1(&self) -> CompressedSize {
+                self.rs1_prime() + 8
+            }
+
+            // uimm[0|1]
+            pub fn uimm(&self) -> InstructionSize {
+                byte_offset(self.0)
+            }
+        }
+
+        // the csb-type stores put theirs in the same bits
+        pub(super) fn byte_offset(inst: CompressedSize) -> InstructionSize {
+            bits(inst, 6, 6) | bits(inst, 5, 5) << 1
+        }
+
+        #[test]
+        fn clbtype() {
+            let inst = CLBType(0x81e8 /* c.lbu a0, 3(a1) */);
+            assert_eq!(inst.opcode(), 0);
+            assert_eq!(inst.funct6(), 0b100000);
+            assert_eq!(inst.rd(), 10);
+            assert_eq!(inst.rs1(), 11);
+            assert_eq!(inst.uimm(), 3);
+        }
+    }
+
+    // Zcb byte stores, `c.sb`
+    pub mod csbtype {
+        use super::clbtype::byte_offset;
+        use super::CompressedSize;
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CSBType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rs2_prime, _: 4, 2;
+            pub rs1_prime, _: 9, 7;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CSBType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rs1(&self) -> CompressedSize {
+                self.rs1_prime() + 8
+            }
+
+            pub fn rs2(&self) -> CompressedSize {
+                self.rs2_prime() + 8
+            }
+
+            // uimm[0|1]
+            pub fn uimm(&self) -> InstructionSize {
+                byte_offset(self.0)
+            }
+        }
+
+        #[test]
+        fn csbtype() {
+            let inst = CSBType(0x89c8 /* c.sb a0, 1(a1) */);
+            assert_eq!(inst.funct6(), 0b100010);
+            assert_eq!(inst.rs1(), 11);
+            assert_eq!(inst.rs2(), 10);
+            assert_eq!(inst.uimm(), 1);
+        }
+    }
+
+    // Zcb halfword loads, bit 6 is a funct1 that picks `c.lh` over `c.lhu`
+    pub mod clhtype {
+        use super::{bits, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CLHType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rd_prime, _: 4, 2;
+            pub funct1, _: 6, 6;
+            pub rs1_prime, _: 9, 7;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CLHType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rd(&self) -> CompressedSize {
+                self.rd_prime() + 8
+            }
+
+            pub fn rs1(&self) -> CompressedSize {
+                self.rs1_prime() + 8
+            }
+
+            // uimm[1]
+            pub fn uimm(&self) -> InstructionSize {
+                half_offset(self.0)
+            }
+        }
+
+        pub(super) fn half_offset(inst: CompressedSize) -> InstructionSize {
+            bits(inst, 5, 5) << 1
+        }
+
+        #[test]
+        fn clhtype() {
+            let inst = CLHType(0x85e8 /* c.lh a0, 2(a1) */);
+            assert_eq!(inst.funct6(), 0b100001);
+            assert_eq!(inst.funct1(), 1);
+            assert_eq!(inst.rd(), 10);
+            assert_eq!(inst.rs1(), 11);
+            assert_eq!(inst.uimm(), 2);
+            let inst = CLHType(0x85a8 /* c.lhu a0, 2(a1) */);
+            assert_eq!(inst.funct1(), 0);
+        }
+    }
+
+    // Zcb halfword stores, `c.sh`
+    pub mod cshtype {
+        use super::clhtype::half_offset;
+        use super::CompressedSize;
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CSHType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub rs2_prime, _: 4, 2;
+            pub funct1, _: 6, 6;
+            pub rs1_prime, _: 9, 7;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CSHType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rs1(&self) -> CompressedSize {
+                self.rs1_prime() + 8
+            }
+
+            pub fn rs2(&self) -> CompressedSize {
+                self.rs2_prime() + 8
+            }
+
+            // uimm[1]
+            pub fn uimm(&self) -> InstructionSize {
+                half_offset(self.0)
+            }
+        }
+
+        #[test]
+        fn cshtype() {
+            let inst = CSHType(0x8da8 /* c.sh a0, 2(a1) */);
+            assert_eq!(inst.funct6(), 0b100011);
+            assert_eq!(inst.funct1(), 0);
+            assert_eq!(inst.rs1(), 11);
+            assert_eq!(inst.rs2(), 10);
+            assert_eq!(inst.uimm(), 2);
+        }
+    }
+
+    // Zcb unary ops on x8-x15, `c.zext.b`, `c.not` etc
+    pub mod cutype {
+        use super::CompressedSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CUType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub funct5, _: 6, 2;
+            // rd is also rs1
+            pub rd_prime, _: 9, 7;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CUType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn rd(&self) -> CompressedSize {
+                self.rd_prime() + 8
+            }
+        }
+
+        #[test]
+        fn cutype() {
+            let inst = CUType(0x9d75 /* c.not a0 */);
+            assert_eq!(inst.opcode(), 1);
+            assert_eq!(inst.funct6(), 0b100111);
+            assert_eq!(inst.funct5(), 0b11101);
+            assert_eq!(inst.rd(), 10);
+        }
+    }
+
+    // Zcmp push/pop, `cm.push {ra, s0-s2}, -32`
+    pub mod cmpptype {
+        use super::{stack_adj_base, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CMPPType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub spimm, _: 3, 2;
+            pub rlist, _: 7, 4;
+            pub funct5, _: 12, 8;
+            pub funct3, _: 15, 13;
+        }
+
+        impl CMPPType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            // how far sp moves, the saved registers rounded up to 16 plus spimm more 16 byte chunks
+            pub fn stack_adj(&self, xlen_bytes: InstructionSize) -> InstructionSize {
+                stack_adj_base(self.rlist() as InstructionSize, xlen_bytes)
+                    + self.spimm() as InstructionSize * 16
+            }
+        }
+
+        #[test]
+        fn cmpptype() {
+            let inst = CMPPType(0xb876 /* cm.push {ra, s0-s2}, -32 */);
+            assert_eq!(inst.opcode(), 2);
+            assert_eq!(inst.funct3(), 5);
+            assert_eq!(inst.funct5(), 0b11000);
+            assert_eq!(inst.rlist(), 7);
+            assert_eq!(inst.spimm(), 1);
+            assert_eq!(inst.stack_adj(4), 32);
+            assert_eq!(inst.stack_adj(8), 48);
+        }
+    }
+
+    // Zcmp moves between a0/a1 and two s registers, `cm.mvsa01 s0, s1`
+    pub mod cmmvtype {
+        use super::{sreg, CompressedSize};
+        use crate::instructions::InstructionSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CMMVType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub r2s_prime, _: 4, 2;
+            pub funct2, _: 6, 5;
+            pub r1s_prime, _: 9, 7;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CMMVType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+
+            pub fn r1s(&self) -> InstructionSize {
+                sreg(self.r1s_prime() as InstructionSize)
+            }
+
+            pub fn r2s(&self) -> InstructionSize {
+                sreg(self.r2s_prime() as InstructionSize)
+            }
+        }
+
+        #[test]
+        fn cmmvtype() {
+            let inst = CMMVType(0xace2 /* cm.mva01s s1, s0 */);
+            assert_eq!(inst.opcode(), 2);
+            assert_eq!(inst.funct6(), 0b101011);
+            assert_eq!(inst.funct2(), 3);
+            assert_eq!(inst.r1s(), 9);
+            assert_eq!(inst.r2s(), 8);
+        }
+    }
+
+    // Zcmt table jumps, `cm.jt`/`cm.jalt`
+    pub mod cmjttype {
+        use super::CompressedSize;
+        use bitfield::bitfield;
+
+        bitfield! {
+            pub struct CMJTType(CompressedSize);
+            impl Debug;
+            pub opcode, _: 1, 0;
+            pub index, _: 9, 2;
+            pub funct6, _: 15, 10;
+        }
+
+        impl CMJTType {
+            pub fn new(inst: CompressedSize) -> Self {
+                Self(inst)
+            }
+        }
+
+        #[test]
+        fn cmjttype() {
+            let inst = CMJTType(0xa3fe /* cm.jalt 255 */);
+            assert_eq!(inst.opcode(), 2);
+            assert_eq!(inst.funct6(), 0b101000);
+            assert_eq!(inst.index(), 255);
+        }
+    }
+}
+
+// how many bytes an instruction takes given its first 16 bit parcel, `None` for the reserved
+// encodings of 192 bits and up whose length the spec doesn't pin down yet
+pub const fn instruction_length(parcel: u16) -> Option<usize> {
+    if parcel & 0b11 != 0b11 {
+        Some(2)
+    } else if parcel & 0b11100 != 0b11100 {
+        Some(4)
+    } else if parcel & 0b111111 == 0b011111 {
+        Some(6)
+    } else if parcel & 0b1111111 == 0b0111111 {
+        Some(8)
+    } else if (parcel >> 12) & 0b111 != 0b111 {
+        // 80 + 16 * nnn bits
+        Some(10 + 2 * ((parcel >> 12) & 0b111) as usize)
+    } else {
+        None
+    }
+}
+
+pub const LOAD_MATCH: InstructionSize = 3;
+pub const FENCE_MATCH: InstructionSize = 15;
+pub const ARITMETIC_IMMEDIATE_MATCH: InstructionSize = 19;
+pub const AUIPC_MATCH: InstructionSize = 23;
+pub const LUI_MATCH: InstructionSize = 55;
+pub const STORE_MATCH: InstructionSize = 35;
+pub const ARITMETIC_REGISTER_MATCH: InstructionSize = 51;
+// OP-IMM-32 and OP-32, the rv64 word sized versions of the two above
+pub const ARITMETIC_IMMEDIATE_WORD_MATCH: InstructionSize = 27;
+pub const ARITMETIC_REGISTER_WORD_MATCH: InstructionSize = 59;
+
+// TODO: maybe this is correct, check it
+pub const FLOATING_POINT_MATCH: InstructionSize = 83;
+// the fmt field of OP-FP and the fused multiply-adds
+pub const SINGLE_PRECISION_FMT: InstructionSize = 0;
+pub const DOUBLE_PRECISION_FMT: InstructionSize = 1;
+pub const HALF_PRECISION_FMT: InstructionSize = 2;
+pub const QUAD_PRECISION_FMT: InstructionSize = 3;
+
+pub const BRANCH_MATCH: InstructionSize = 99;
+pub const CSR_MATCH: InstructionSize = 115;
+pub const JALR_MATCH: InstructionSize = 103;
+pub const JAL_MATCH: InstructionSize = 111;
+pub const ATOMIC_MATCH: InstructionSize = 47;
+// LOAD-FP and STORE-FP, funct3 is the width
+pub const LOAD_FP_MATCH: InstructionSize = 7;
+pub const STORE_FP_MATCH: InstructionSize = 39;
+// the four fused multiply-add opcodes, fmt picks the precision
+pub const FMADD_MATCH: InstructionSize = 67;
+pub const FMSUB_MATCH: InstructionSize = 71;
+pub const FNMSUB_MATCH: InstructionSize = 75;
+pub const FNMADD_MATCH: InstructionSize = 79;
+pub const OP_V_MATCH: InstructionSize = 87;
+// the funct3 of OP-V, which operands an instruction takes (vector-vector, vector-scalar...)
+pub const OPIVV: InstructionSize = 0;
+pub const OPFVV: InstructionSize = 1;
+pub const OPMVV: InstructionSize = 2;
+pub const OPIVI: InstructionSize = 3;
+pub const OPIVX: InstructionSize = 4;
+pub const OPFVF: InstructionSize = 5;
+pub const OPMVX: InstructionSize = 6;
+pub const OPCFG: InstructionSize = 7;
+// vector crypto lives in its own major opcode (OP-VE)
+pub const OP_VE_MATCH: InstructionSize = 119;
+// the P extension shares it, its funct3 values stay clear of the OPMVV ones vector crypto uses
+pub const OP_P_MATCH: InstructionSize = 119;
+// the opcode spaces set aside for vendor extensions
+pub const CUSTOM_0_MATCH: InstructionSize = 11;
+pub const CUSTOM_1_MATCH: InstructionSize = 43;
+pub const CUSTOM_2_MATCH: InstructionSize = 91;
+pub const CUSTOM_3_MATCH: InstructionSize = 123;
+
+#[test]
+fn lengths() {
+    assert_eq!(instruction_length(0x8602 /* c.jr x12 */), Some(2));
+    assert_eq!(instruction_length(0x8613 /* addi */), Some(4));
+    assert_eq!(instruction_length(0x001f), Some(6));
+    assert_eq!(instruction_length(0x003f), Some(8));
+    assert_eq!(instruction_length(0x007f), Some(10));
+    assert_eq!(instruction_length(0x607f), Some(22));
+    assert_eq!(instruction_length(0x707f), None);
+}
+
+// the formats everything gets decoded through are plain shifts and masks instead of `bitfield!` so
+// the accessors are const and always inlined, decoding sits in the hot loop of emulators
+macro_rules! field {
+    ($inst:expr, $hi:literal, $lo:literal) => {
+        ($inst >> $lo) & ((1 << ($hi - $lo + 1)) - 1)
+    };
+}
+
+// immediates straight out of a raw instruction, sign extended, for when that's all that's needed
+// (the format structs below go through these too)
+
+#[inline(always)]
+pub const fn imm_i(inst: InstructionSize) -> SignedInstructionSize {
+    (inst as SignedInstructionSize) >> 20
+}
+
+#[inline(always)]
+pub const fn imm_s(inst: InstructionSize) -> SignedInstructionSize {
+    ((inst as SignedInstructionSize) >> 25 << 5) | field!(inst, 11, 7) as SignedInstructionSize
+}
+
+#[inline(always)]
+pub const fn imm_b(inst: InstructionSize) -> SignedInstructionSize {
+    let imm = field!(inst, 7, 7) << 11 | field!(inst, 11, 8) << 1 | field!(inst, 30, 25) << 5;
+    ((inst as SignedInstructionSize) >> 31 << 12) | imm as SignedInstructionSize
+}
+
+// the value lui loads (and auipc adds), unlike `UType::imm` this is shifted back into place
+#[inline(always)]
+pub const fn imm_u(inst: InstructionSize) -> SignedInstructionSize {
+    (inst & 0xffff_f000) as SignedInstructionSize
+}
+
+#[inline(always)]
+pub const fn imm_j(inst: InstructionSize) -> SignedInstructionSize {
+    let imm = field!(inst, 19, 12) << 12 | field!(inst, 20, 20) << 11 | field!(inst, 30, 21) << 1;
+    ((inst as SignedInstructionSize) >> 31 << 20) | imm as SignedInstructionSize
+}
+
+#[test]
+fn immediates() {
+    // every immediate each format can hold, put into place by hand and pulled back out
+    for imm in -2048..2048 {
+        let bits = imm as InstructionSize;
+        // everything outside the immediate is set so it has to be masked off
+        let i = (bits << 20) | 0x000f_ffff;
+        assert_eq!(imm_i(i), imm);
+        assert_eq!(imm_i(i) as InstructionSize, itype::IType::new(i).imm());
+        let s = (bits >> 5 << 25) | (field!(bits, 4, 0) << 7) | 0x01ff_f07f;
+        assert_eq!(imm_s(s), imm);
+        assert_eq!(imm_s(s) as InstructionSize, stype::SType::new(s).imm());
+    }
+    for imm in (-4096..4096).step_by(2) {
+        let bits = imm as InstructionSize;
+        let b = (field!(bits, 12, 12) << 31)
+            | (field!(bits, 10, 5) << 25)
+            | (field!(bits, 4, 1) << 8)
+            | (field!(bits, 11, 11) << 7)
+            | 0x01ff_f07f;
+        assert_eq!(imm_b(b), imm);
+        assert_eq!(imm_b(b) as InstructionSize, btype::BType::new(b).imm());
+    }
+    for imm in (-(1 << 20)..(1 << 20)).step_by(2) {
+        let bits = imm as InstructionSize;
+        let j = (field!(bits, 20, 20) << 31)
+            | (field!(bits, 10, 1) << 21)
+            | (field!(bits, 11, 11) << 20)
+            | (field!(bits, 19, 12) << 12)
+            | 0xfff;
+        assert_eq!(imm_j(j), imm);
+        assert_eq!(imm_j(j) as InstructionSize, jtype::JType::new(j).imm());
+    }
+    for upper in 0..(1 << 20) {
+        let u = (upper << 12) | 0xfff;
+        assert_eq!(imm_u(u) as InstructionSize, upper << 12);
+        assert_eq!(utype::UType::new(u).imm(), upper);
+    }
+}
+
+pub mod rtype {
+    use super::InstructionSize;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct RType(InstructionSize);
+
+    impl RType {
+        pub const fn new(inst: InstructionSize) -> Self {
+            Self(inst)
+        }
+
+        #[inline(always)]
+        pub const fn opcode(&self) -> InstructionSize {
+            field!(self.0, 6, 0)
+        }
+
+        #[inline(always)]
+        pub const fn rd(&self) -> InstructionSize {
+            field!(self.0, 11, 7)
+        }
+
+        #[inline(always)]
+        pub const fn funct3(&self) -> InstructionSize {
+            field!(self.0, 14, 12)
+        }
+
+        #[inline(always)]
+        pub const fn rs1(&self) -> InstructionSize {
+            field!(self.0, 19, 15)
+        }
+
+        #[inline(always)]
+        pub const fn rs2(&self) -> InstructionSize {
+            field!(self.0, 24, 20)
+        }
+
+        #[inline(always)]
+        pub const fn funct7(&self) -> InstructionSize {
+            field!(self.0, 31, 25)
+        }
+    }
+
+    #[test]
+    fn atomic_check() {
+        let inst = RType(0xCF4A7AF /* amoswap.w x15, x15, (x9) */);
+        assert_eq!(inst.rd(), 15);
+        assert_eq!(inst.rs1(), 9);
+        assert_eq!(inst.rs2(), 15);
+        // usable in const contexts
+        const RD: InstructionSize = RType::new(0xCF4A7AF).rd();
+        assert_eq!(RD, 15);
+    }
+}
+
+// vd, vs1/rs1/imm and vs2 with the vm (mask) bit and funct6 on top, the layout every OP-V inst uses
+pub mod opvtype {
+    use super::InstructionSize;
+    use bitfield::bitfield;
 
     bitfield! {
-        pub struct RType(InstructionSize);
+        pub struct OpVType(InstructionSize);
         impl Debug;
         InstructionSize;
         pub opcode, _: 6, 0;
-        pub rd, _:     11, 7;
+        pub vd, _:     11, 7;
         pub funct3, _: 14, 12;
+        pub vs1, _:    19, 15;
+        pub vs2, _:    24, 20;
+        pub vm, _:     25, 25;
+        pub funct6, _: 31, 26;
+    }
+
+    impl OpVType {
+        pub fn new(inst: InstructionSize) -> Self {
+            Self(inst)
+        }
+    }
+
+    #[test]
+    fn fields() {
+        let inst = OpVType(0xae43a2f7 /* vsm3c.vi v5, v4, 7 */);
+        assert_eq!(inst.opcode(), 0x77);
+        assert_eq!(inst.vd(), 5);
+        assert_eq!(inst.funct3(), 2);
+        assert_eq!(inst.vs1(), 7);
+        assert_eq!(inst.vs2(), 4);
+        assert_eq!(inst.vm(), 1);
+        assert_eq!(inst.funct6(), 0b101011);
+    }
+}
+
+// vector loads and stores, vd is vs3 for stores and rs2 holds lumop/sumop for unit-stride ones
+pub mod vmemtype {
+    use super::InstructionSize;
+    use bitfield::bitfield;
+
+    bitfield! {
+        pub struct VMemType(InstructionSize);
+        impl Debug;
+        InstructionSize;
+        pub opcode, _: 6, 0;
+        pub vd, _:     11, 7;
+        pub width, _:  14, 12;
         pub rs1, _:    19, 15;
         pub rs2, _:    24, 20;
-        pub funct7, _: 31, 25;
+        pub vm, _:     25, 25;
+        pub mop, _:    27, 26;
+        pub mew, _:    28, 28;
+        pub nf, _:     31, 29;
     }
 
-    impl RType {
+    impl VMemType {
         pub fn new(inst: InstructionSize) -> Self {
             Self(inst)
         }
     }
 
     #[test]
-    fn atomic_check() {
-        let inst = RType(0xCF4A7AF /* amoswap.w x15, x15, (x9) */);
-        assert_eq!(inst.rd(), 15);
-        assert_eq!(inst.rs1(), 9);
-        assert_eq!(inst.rs2(), 15);
+    fn fields() {
+        let inst = VMemType(0x42055207 /* vlseg3e16.v v4, (a0) */);
+        assert_eq!(inst.opcode(), 0x07);
+        assert_eq!(inst.vd(), 4);
+        assert_eq!(inst.width(), 5);
+        assert_eq!(inst.rs1(), 10);
+        assert_eq!(inst.rs2(), 0);
+        assert_eq!(inst.vm(), 1);
+        assert_eq!(inst.mop(), 0);
+        assert_eq!(inst.mew(), 0);
+        assert_eq!(inst.nf(), 2);
     }
 }
 
-pub mod itype {
-    use super::{InstructionSize, SignedInstructionSize};
+// the fused multiply-add layout, a third source register on top and fmt where funct7 would be
+pub mod r4type {
+    use super::InstructionSize;
     use bitfield::bitfield;
 
     bitfield! {
-        pub struct IType(InstructionSize);
+        pub struct R4Type(InstructionSize);
         impl Debug;
+        InstructionSize;
         pub opcode, _: 6, 0;
         pub rd, _:     11, 7;
-        pub funct3, _: 14, 12;
+        pub rm, _:     14, 12;
         pub rs1, _:    19, 15;
-        pub uimm, _:   31, 20;
-        SignedInstructionSize;
-        imm_signed, _:   31, 20;
+        pub rs2, _:    24, 20;
+        pub fmt, _:    26, 25;
+        pub rs3, _:    31, 27;
     }
 
-    impl IType {
+    impl R4Type {
         pub fn new(inst: InstructionSize) -> Self {
             Self(inst)
         }
+    }
+
+    #[test]
+    fn fields() {
+        let inst = R4Type(0x68c5f543 /* fmadd.s fa0, fa1, fa2, fa3 */);
+        assert_eq!(inst.opcode(), 0x43);
+        assert_eq!(inst.rd(), 10);
+        assert_eq!(inst.rm(), 7);
+        assert_eq!(inst.rs1(), 11);
+        assert_eq!(inst.rs2(), 12);
+        assert_eq!(inst.fmt(), 0);
+        assert_eq!(inst.rs3(), 13);
+    }
+}
+
+pub mod itype {
+    use super::InstructionSize;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct IType(InstructionSize);
+
+    impl IType {
+        pub const fn new(inst: InstructionSize) -> Self {
+            Self(inst)
+        }
+
+        #[inline(always)]
+        pub const fn opcode(&self) -> InstructionSize {
+            field!(self.0, 6, 0)
+        }
 
-        pub fn imm(&self) -> InstructionSize {
-            self.imm_signed() as InstructionSize
+        #[inline(always)]
+        pub const fn rd(&self) -> InstructionSize {
+            field!(self.0, 11, 7)
+        }
+
+        #[inline(always)]
+        pub const fn funct3(&self) -> InstructionSize {
+            field!(self.0, 14, 12)
+        }
+
+        #[inline(always)]
+        pub const fn rs1(&self) -> InstructionSize {
+            field!(self.0, 19, 15)
+        }
+
+        #[inline(always)]
+        pub const fn uimm(&self) -> InstructionSize {
+            field!(self.0, 31, 20)
+        }
+
+        // sign extended
+        #[inline(always)]
+        pub const fn imm(&self) -> InstructionSize {
+            super::imm_i(self.0) as InstructionSize
         }
     }
 
@@ -561,28 +4503,50 @@ pub mod itype {
 
 pub mod stype {
     use super::{InstructionSize, SignedInstructionSize};
-    use bitfield::bitfield;
 
-    bitfield! {
-        pub struct SType(InstructionSize);
-        impl Debug;
-        pub opcode, _: 6, 0;
-        pub imm1, _:   11, 7;
-        InstructionSize;
-        pub funct3, _: 14, 12;
-        pub rs1, _:    19, 15;
-        pub rs2, _:    24, 20;
-        SignedInstructionSize;
-        pub imm2, _:   31, 25;
-    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SType(InstructionSize);
 
     impl SType {
-        pub fn new(inst: InstructionSize) -> Self {
+        pub const fn new(inst: InstructionSize) -> Self {
             Self(inst)
         }
 
-        pub fn imm(&self) -> InstructionSize {
-            self.imm1() | (self.imm2() << 5) as InstructionSize
+        #[inline(always)]
+        pub const fn opcode(&self) -> InstructionSize {
+            field!(self.0, 6, 0)
+        }
+
+        // imm[4:0]
+        #[inline(always)]
+        pub const fn imm1(&self) -> InstructionSize {
+            field!(self.0, 11, 7)
+        }
+
+        #[inline(always)]
+        pub const fn funct3(&self) -> InstructionSize {
+            field!(self.0, 14, 12)
+        }
+
+        #[inline(always)]
+        pub const fn rs1(&self) -> InstructionSize {
+            field!(self.0, 19, 15)
+        }
+
+        #[inline(always)]
+        pub const fn rs2(&self) -> InstructionSize {
+            field!(self.0, 24, 20)
+        }
+
+        // imm[11:5], sign extended
+        #[inline(always)]
+        pub const fn imm2(&self) -> SignedInstructionSize {
+            (self.0 as SignedInstructionSize) >> 25
+        }
+
+        #[inline(always)]
+        pub const fn imm(&self) -> InstructionSize {
+            super::imm_s(self.0) as InstructionSize
         }
     }
 
@@ -597,25 +4561,30 @@ pub mod stype {
 
 pub mod utype {
     use super::InstructionSize;
-    use bitfield::bitfield;
 
-    bitfield! {
-        pub struct UType(InstructionSize);
-        impl Debug;
-        pub opcode, _: 6, 0;
-        pub rd, _:     11, 7;
-        // SignedInstructionSize;
-        pub imm, _:   31, 12;
-    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct UType(InstructionSize);
 
     impl UType {
-        pub fn new(inst: InstructionSize) -> Self {
+        pub const fn new(inst: InstructionSize) -> Self {
             Self(inst)
         }
 
-        // pub fn imm(&self) -> InstructionSize {
-        //     self.imm1() as InstructionSize
-        // }
+        #[inline(always)]
+        pub const fn opcode(&self) -> InstructionSize {
+            field!(self.0, 6, 0)
+        }
+
+        #[inline(always)]
+        pub const fn rd(&self) -> InstructionSize {
+            field!(self.0, 11, 7)
+        }
+
+        // imm[31:12], not shifted back into place
+        #[inline(always)]
+        pub const fn imm(&self) -> InstructionSize {
+            field!(self.0, 31, 12)
+        }
     }
 
     #[test]
@@ -629,35 +4598,62 @@ pub mod utype {
 // aims to mimic `mm[12|10:5] rs2 rs1 funct3 imm[4:1|11] opcode B-type` in the RISC-V spec
 pub mod btype {
     use super::{InstructionSize, SignedInstructionSize};
-    use bitfield::bitfield;
 
-    bitfield! {
-        pub struct BType(InstructionSize);
-        impl Debug;
-        pub opcode, _: 6, 0;
-        pub imm1, _:   7, 7;
-        pub imm2, _:   11, 8;
-        pub funct3, _: 14, 12;
-        pub rs1, _:    19, 15;
-        pub rs2, _:    24, 20;
-        pub imm3, _:   30, 25;
-        SignedInstructionSize;
-        pub imm4, _:   31, 31;
-    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct BType(InstructionSize);
 
     impl BType {
-        pub fn new(inst: InstructionSize) -> Self {
+        pub const fn new(inst: InstructionSize) -> Self {
             Self(inst)
         }
 
-        pub fn imm(&self) -> InstructionSize {
-            let (imm1, imm2, imm3, imm4) = (
-                self.imm1() << 11,
-                self.imm2() << 1,
-                self.imm3() << 5,
-                self.imm4() << 12,
-            );
-            imm1 | imm2 | imm3 | imm4 as InstructionSize
+        #[inline(always)]
+        pub const fn opcode(&self) -> InstructionSize {
+            field!(self.0, 6, 0)
+        }
+
+        // imm[11]
+        #[inline(always)]
+        pub const fn imm1(&self) -> InstructionSize {
+            field!(self.0, 7, 7)
+        }
+
+        // imm[4:1]
+        #[inline(always)]
+        pub const fn imm2(&self) -> InstructionSize {
+            field!(self.0, 11, 8)
+        }
+
+        #[inline(always)]
+        pub const fn funct3(&self) -> InstructionSize {
+            field!(self.0, 14, 12)
+        }
+
+        #[inline(always)]
+        pub const fn rs1(&self) -> InstructionSize {
+            field!(self.0, 19, 15)
+        }
+
+        #[inline(always)]
+        pub const fn rs2(&self) -> InstructionSize {
+            field!(self.0, 24, 20)
+        }
+
+        // imm[10:5]
+        #[inline(always)]
+        pub const fn imm3(&self) -> InstructionSize {
+            field!(self.0, 30, 25)
+        }
+
+        // imm[12], sign extended so it's either 0 or -1
+        #[inline(always)]
+        pub const fn imm4(&self) -> SignedInstructionSize {
+            (self.0 as SignedInstructionSize) >> 31
+        }
+
+        #[inline(always)]
+        pub const fn imm(&self) -> InstructionSize {
+            super::imm_b(self.0) as InstructionSize
         }
     }
 
@@ -683,55 +4679,36 @@ pub mod btype {
 }
 
 pub mod jtype {
-    use crate::bit_ops;
-
-    use super::{InstructionSize, SignedInstructionSize};
-    use bitfield::bitfield;
+    use super::InstructionSize;
 
-    bitfield! {
-        pub struct JType(InstructionSize);
-        impl Debug;
-        pub opcode, _: 6, 0;
-        pub rd, _: 11, 7;
-    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct JType(InstructionSize);
 
     impl JType {
-        pub fn new(inst: InstructionSize) -> Self {
+        pub const fn new(inst: InstructionSize) -> Self {
             Self(inst)
         }
 
-        fn imm1(&self) -> InstructionSize {
-            let imm = (bit_ops::get_bit(self.0, 31) << 31) as SignedInstructionSize;
-            (imm >> 11) as InstructionSize
-        }
-
-        fn imm2(&self) -> InstructionSize {
-            bit_ops::get_bits(self.0, 8, 12) << 12
-        }
-
-        fn imm3(&self) -> InstructionSize {
-            let imm = self.0 >> 9 /* now get bit 11 */;
-            bit_ops::get_bit(imm, 11) << 11
+        #[inline(always)]
+        pub const fn opcode(&self) -> InstructionSize {
+            field!(self.0, 6, 0)
         }
 
-        fn imm4(&self) -> InstructionSize {
-            let imm = self.0 >> 20 /* now get bits 10:1 */;
-            bit_ops::get_bits(imm, 10, 1) << 1
+        #[inline(always)]
+        pub const fn rd(&self) -> InstructionSize {
+            field!(self.0, 11, 7)
         }
 
-        pub fn imm(&self) -> InstructionSize {
-            let (imm1, imm2, imm3, imm4) = (
-                self.imm1(), // imm[20]
-                self.imm2(), // imm[19:12]
-                self.imm3(), // imm[11]
-                self.imm4(), // imm[10:1]
-            );
-            imm1 | imm2 | imm3 | imm4
+        // imm[20|10:1|11|19:12], sign extended
+        #[inline(always)]
+        pub const fn imm(&self) -> InstructionSize {
+            super::imm_j(self.0) as InstructionSize
         }
     }
 
     #[test]
     fn imm_check() {
+        use super::SignedInstructionSize;
         let inst = JType(0x0100006f /* jal x0 16 */);
         assert_eq!(inst.rd(), 0);
         assert_eq!(inst.imm(), 16);
@@ -0,0 +1,328 @@
+use crate::decoded_inst::InstructionDecoded;
+use crate::instructions::{InstructionSize, SignedInstructionSize};
+
+// a tiny risc-like ir, every instruction lowers to a handful of these so symbolic executors and
+// translators only have to understand this instead of every instruction
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    // x0 always reads as zero
+    Reg(InstructionSize),
+    Imm(SignedInstructionSize),
+    // address of the instruction being lowered
+    Pc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AluOp {
+    Add,
+    Sub,
+    Sll,
+    Slt,
+    Sltu,
+    Xor,
+    Srl,
+    Sra,
+    Or,
+    And,
+    Mul,
+    MulH,
+    MulHSU,
+    MulHU,
+    Div,
+    DivU,
+    Rem,
+    RemU,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Eq,
+    Ne,
+    Lt,
+    Ge,
+    LtU,
+    GeU,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsrOp {
+    Write,
+    Set,
+    Clear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrapKind {
+    ECall,
+    EBreak,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MicroOp {
+    Alu {
+        op: AluOp,
+        dst: InstructionSize,
+        a: Operand,
+        b: Operand,
+    },
+    // width is in bytes
+    Load {
+        dst: InstructionSize,
+        base: Operand,
+        offset: SignedInstructionSize,
+        width: u8,
+        signed: bool,
+    },
+    Store {
+        src: InstructionSize,
+        base: Operand,
+        offset: SignedInstructionSize,
+        width: u8,
+    },
+    // jumps to pc + offset when the condition holds
+    Branch {
+        cond: Condition,
+        a: Operand,
+        b: Operand,
+        offset: SignedInstructionSize,
+    },
+    // the target is computed before `link` gets written so `jalr ra, 0(ra)` works
+    Jump {
+        base: Operand,
+        offset: SignedInstructionSize,
+        link: Option<InstructionSize>,
+    },
+    // reads the old value into dst (when dst != 0) and then applies the op with src
+    Csr {
+        op: CsrOp,
+        dst: InstructionSize,
+        csr: InstructionSize,
+        src: Operand,
+    },
+    Fence,
+    Trap(TrapKind),
+    // mret / sret
+    TrapReturn,
+}
+
+fn alu(op: AluOp, dst: InstructionSize, a: Operand, b: Operand) -> Vec<MicroOp> {
+    vec![MicroOp::Alu { op, dst, a, b }]
+}
+
+fn load(
+    dst: InstructionSize,
+    rs1: InstructionSize,
+    imm: InstructionSize,
+    width: u8,
+    signed: bool,
+) -> Vec<MicroOp> {
+    vec![MicroOp::Load {
+        dst,
+        base: Operand::Reg(rs1),
+        offset: imm as SignedInstructionSize,
+        width,
+        signed,
+    }]
+}
+
+fn store(
+    rs1: InstructionSize,
+    rs2: InstructionSize,
+    imm: InstructionSize,
+    width: u8,
+) -> Vec<MicroOp> {
+    vec![MicroOp::Store {
+        src: rs2,
+        base: Operand::Reg(rs1),
+        offset: imm as SignedInstructionSize,
+        width,
+    }]
+}
+
+fn branch(
+    cond: Condition,
+    rs1: InstructionSize,
+    rs2: InstructionSize,
+    imm: InstructionSize,
+) -> Vec<MicroOp> {
+    vec![MicroOp::Branch {
+        cond,
+        a: Operand::Reg(rs1),
+        b: Operand::Reg(rs2),
+        offset: imm as SignedInstructionSize,
+    }]
+}
+
+fn csr(op: CsrOp, dst: InstructionSize, csr: InstructionSize, src: Operand) -> Vec<MicroOp> {
+    vec![MicroOp::Csr { op, dst, csr, src }]
+}
+
+// lowers an instruction to micro ops, None for instructions the ir doesn't cover (yet)
+pub fn lower(inst: &InstructionDecoded) -> Option<Vec<MicroOp>> {
+    use InstructionDecoded as I;
+    use Operand::{Imm, Pc, Reg};
+
+    let reg_reg = |op, rd: &InstructionSize, rs1: &InstructionSize, rs2: &InstructionSize| {
+        alu(op, *rd, Reg(*rs1), Reg(*rs2))
+    };
+    let reg_imm = |op, rd: &InstructionSize, rs1: &InstructionSize, imm: &InstructionSize| {
+        alu(op, *rd, Reg(*rs1), Imm(*imm as SignedInstructionSize))
+    };
+
+    let ops = match inst {
+        I::Add { rd, rs1, rs2 } => reg_reg(AluOp::Add, rd, rs1, rs2),
+        I::Sub { rd, rs1, rs2 } => reg_reg(AluOp::Sub, rd, rs1, rs2),
+        I::Sll { rd, rs1, rs2 } => reg_reg(AluOp::Sll, rd, rs1, rs2),
+        I::Slt { rd, rs1, rs2 } => reg_reg(AluOp::Slt, rd, rs1, rs2),
+        I::Sltu { rd, rs1, rs2 } => reg_reg(AluOp::Sltu, rd, rs1, rs2),
+        I::Xor { rd, rs1, rs2 } => reg_reg(AluOp::Xor, rd, rs1, rs2),
+        I::Srl { rd, rs1, rs2 } => reg_reg(AluOp::Srl, rd, rs1, rs2),
+        I::Sra { rd, rs1, rs2 } => reg_reg(AluOp::Sra, rd, rs1, rs2),
+        I::Or { rd, rs1, rs2 } => reg_reg(AluOp::Or, rd, rs1, rs2),
+        I::And { rd, rs1, rs2 } => reg_reg(AluOp::And, rd, rs1, rs2),
+        I::Mul { rd, rs1, rs2 } => reg_reg(AluOp::Mul, rd, rs1, rs2),
+        I::Mulh { rd, rs1, rs2 } => reg_reg(AluOp::MulH, rd, rs1, rs2),
+        I::Mulsu { rd, rs1, rs2 } => reg_reg(AluOp::MulHSU, rd, rs1, rs2),
+        I::Mulu { rd, rs1, rs2 } => reg_reg(AluOp::MulHU, rd, rs1, rs2),
+        I::Div { rd, rs1, rs2 } => reg_reg(AluOp::Div, rd, rs1, rs2),
+        I::Divu { rd, rs1, rs2 } => reg_reg(AluOp::DivU, rd, rs1, rs2),
+        I::Rem { rd, rs1, rs2 } => reg_reg(AluOp::Rem, rd, rs1, rs2),
+        I::Remu { rd, rs1, rs2 } => reg_reg(AluOp::RemU, rd, rs1, rs2),
+
+        I::Addi { rd, rs1, imm } => reg_imm(AluOp::Add, rd, rs1, imm),
+        I::Slti { rd, rs1, imm } => reg_imm(AluOp::Slt, rd, rs1, imm),
+        I::Sltiu { rd, rs1, imm } => reg_imm(AluOp::Sltu, rd, rs1, imm),
+        I::Xori { rd, rs1, imm } => reg_imm(AluOp::Xor, rd, rs1, imm),
+        I::Ori { rd, rs1, imm } => reg_imm(AluOp::Or, rd, rs1, imm),
+        I::Andi { rd, rs1, imm } => reg_imm(AluOp::And, rd, rs1, imm),
+        I::Slli { rd, rs1, imm } => reg_imm(AluOp::Sll, rd, rs1, imm),
+        I::Srli { rd, rs1, imm } => reg_imm(AluOp::Srl, rd, rs1, imm),
+        I::Srai { rd, rs1, imm } => reg_imm(AluOp::Sra, rd, rs1, imm),
+        I::Lui { rd, imm } => alu(
+            AluOp::Add,
+            *rd,
+            Reg(0),
+            Imm((*imm << 12) as SignedInstructionSize),
+        ),
+        I::AuiPc { rd, imm } => alu(
+            AluOp::Add,
+            *rd,
+            Pc,
+            Imm((*imm << 12) as SignedInstructionSize),
+        ),
+
+        I::Lb { rd, rs1, imm } => load(*rd, *rs1, *imm, 1, true),
+        I::Lh { rd, rs1, imm } => load(*rd, *rs1, *imm, 2, true),
+        I::Lw { rd, rs1, imm } => load(*rd, *rs1, *imm, 4, true),
+        I::Lbu { rd, rs1, imm } => load(*rd, *rs1, *imm, 1, false),
+        I::Lhu { rd, rs1, imm } => load(*rd, *rs1, *imm, 2, false),
+        I::Lwu { rd, rs1, imm } => load(*rd, *rs1, *imm, 4, false),
+        I::Sb { rs1, rs2, imm } => store(*rs1, *rs2, *imm, 1),
+        I::Sh { rs1, rs2, imm } => store(*rs1, *rs2, *imm, 2),
+        I::Sw { rs1, rs2, imm } => store(*rs1, *rs2, *imm, 4),
+
+        I::Beq { rs1, rs2, imm } => branch(Condition::Eq, *rs1, *rs2, *imm),
+        I::Bne { rs1, rs2, imm } => branch(Condition::Ne, *rs1, *rs2, *imm),
+        I::Blt { rs1, rs2, imm } => branch(Condition::Lt, *rs1, *rs2, *imm),
+        I::Bge { rs1, rs2, imm } => branch(Condition::Ge, *rs1, *rs2, *imm),
+        I::Bltu { rs1, rs2, imm } => branch(Condition::LtU, *rs1, *rs2, *imm),
+        I::Bgeu { rs1, rs2, imm } => branch(Condition::GeU, *rs1, *rs2, *imm),
+        I::Jal { rd, imm } => vec![MicroOp::Jump {
+            base: Pc,
+            offset: *imm as SignedInstructionSize,
+            link: (*rd != 0).then_some(*rd),
+        }],
+        I::Jalr { rd, rs1, imm } => vec![MicroOp::Jump {
+            base: Reg(*rs1),
+            offset: *imm as SignedInstructionSize,
+            link: (*rd != 0).then_some(*rd),
+        }],
+
+        I::CsrRw { rd, rs1, imm } => csr(CsrOp::Write, *rd, *imm, Reg(*rs1)),
+        I::CsrRs { rd, rs1, imm } => csr(CsrOp::Set, *rd, *imm, Reg(*rs1)),
+        I::CsrRc { rd, rs1, imm } => csr(CsrOp::Clear, *rd, *imm, Reg(*rs1)),
+        I::CsrRwi { rd, rs1, imm } => {
+            csr(CsrOp::Write, *rd, *imm, Imm(*rs1 as SignedInstructionSize))
+        }
+        I::CsrRsi { rd, rs1, imm } => {
+            csr(CsrOp::Set, *rd, *imm, Imm(*rs1 as SignedInstructionSize))
+        }
+        I::CsrRci { rd, rs1, imm } => {
+            csr(CsrOp::Clear, *rd, *imm, Imm(*rs1 as SignedInstructionSize))
+        }
+
+        I::Fence { .. }
+        | I::FenceI { .. }
+        | I::SFenceVma { .. }
+        | I::SinvalVma { .. }
+        | I::SFenceWInval
+        | I::SFenceInvalIr
+        | I::HinvalVvma { .. }
+        | I::HinvalGvma { .. } => vec![MicroOp::Fence],
+        I::ECall => vec![MicroOp::Trap(TrapKind::ECall)],
+        I::EBreak => vec![MicroOp::Trap(TrapKind::EBreak)],
+        I::MRet | I::SRet | I::MnRet | I::DRet => vec![MicroOp::TrapReturn],
+
+        _ => return None,
+    };
+
+    Some(ops)
+}
+
+#[test]
+fn lowering() {
+    use crate::decoder::try_decode;
+    let inst = try_decode(0x06468613 /* addi x12 x13 100 */).unwrap();
+    assert_eq!(
+        lower(&inst),
+        Some(vec![MicroOp::Alu {
+            op: AluOp::Add,
+            dst: 12,
+            a: Operand::Reg(13),
+            b: Operand::Imm(100)
+        }])
+    );
+    let inst = try_decode(0x00c12603 /* lw x12, 12(sp) */).unwrap();
+    assert_eq!(
+        lower(&inst),
+        Some(vec![MicroOp::Load {
+            dst: 12,
+            base: Operand::Reg(2),
+            offset: 12,
+            width: 4,
+            signed: true
+        }])
+    );
+    let inst = try_decode(0xfe078ce3 /* beq x15, x0, -8 */).unwrap();
+    assert_eq!(
+        lower(&inst),
+        Some(vec![MicroOp::Branch {
+            cond: Condition::Eq,
+            a: Operand::Reg(15),
+            b: Operand::Reg(0),
+            offset: -8
+        }])
+    );
+    let inst = try_decode(0x00c080e7 /* jalr x1, 12(ra) */).unwrap();
+    assert_eq!(
+        lower(&inst),
+        Some(vec![MicroOp::Jump {
+            base: Operand::Reg(1),
+            offset: 12,
+            link: Some(1)
+        }])
+    );
+    let inst = try_decode(0x00004537 /* lui x10, 4 */).unwrap();
+    assert_eq!(
+        lower(&inst),
+        Some(vec![MicroOp::Alu {
+            op: AluOp::Add,
+            dst: 10,
+            a: Operand::Reg(0),
+            b: Operand::Imm(0x4000)
+        }])
+    );
+    let inst = try_decode(0xd00777d3 /* fcvt.s.w fa5, a4 */).unwrap();
+    assert_eq!(lower(&inst), None);
+}
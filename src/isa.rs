@@ -0,0 +1,140 @@
+use std::io::{self, Write};
+
+use crate::decoder::Decoder;
+use crate::opcode::InstructionInfo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    // a header line and then one line per instruction
+    Csv,
+    // a single array of objects
+    Json,
+}
+
+fn row(info: &InstructionInfo) -> [String; 5] {
+    [
+        info.name.to_string(),
+        format!("{:#010x}", info.mask),
+        format!("{:#010x}", info.match_value),
+        format!("{:?}", info.extension),
+        format!("{:?}", info.format),
+    ]
+}
+
+const COLUMNS: [&str; 5] = ["mnemonic", "mask", "match", "extension", "format"];
+
+// every instruction `decoder` decodes along with how it's encoded, mnemonics and extension/format
+// names never need quoting so neither format escapes anything
+pub fn write_table<W: Write>(
+    out: &mut W,
+    decoder: &Decoder,
+    format: TableFormat,
+) -> io::Result<()> {
+    match format {
+        TableFormat::Csv => {
+            writeln!(out, "{}", COLUMNS.join(","))?;
+            for info in decoder.supported() {
+                writeln!(out, "{}", row(&info).join(","))?;
+            }
+        }
+        TableFormat::Json => {
+            write!(out, "[")?;
+            for (i, info) in decoder.supported().enumerate() {
+                let fields = COLUMNS
+                    .iter()
+                    .zip(row(&info))
+                    .map(|(column, value)| format!("\"{column}\":\"{value}\""))
+                    .collect::<Vec<_>>();
+                let separator = if i == 0 { "" } else { "," };
+                write!(out, "{separator}\n  {{{}}}", fields.join(","))?;
+            }
+            writeln!(out, "\n]")?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn tables() {
+    let mut csv = Vec::new();
+    write_table(&mut csv, &Decoder::new(), TableFormat::Csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("mnemonic,mask,match,extension,format"));
+    assert_eq!(lines.next(), Some("lb,0x0000707f,0x00000003,I,I"));
+    assert_eq!(lines.count(), Decoder::new().supported().count() - 1);
+
+    let mut json = Vec::new();
+    write_table(&mut json, &Decoder::new(), TableFormat::Json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    assert!(json.starts_with(
+        "[\n  {\"mnemonic\":\"lb\",\"mask\":\"0x0000707f\",\"match\":\"0x00000003\",\"extension\":\"I\",\"format\":\"I\"},\n"
+    ));
+    assert!(json.ends_with("}\n]\n"));
+}
+
+// the table has to agree with the decoder, the match value with every operand zeroed out decodes
+// to the instruction it belongs to (or a more specific one)
+#[test]
+fn masks_agree_with_decoder() {
+    use crate::decoder::{try_decode, CompressedDouble, Xlen};
+    use crate::instructions::compressed::is_compressed;
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let zcmp = Decoder {
+        compressed_double: CompressedDouble::Zcmp,
+        ..Decoder::new()
+    };
+    // zeroing every operand of these gives a reserved encoding, an example is decoded instead
+    const EXAMPLES: &[(&str, u32)] = &[
+        ("c.addi4spn", 0x0808 /* c.addi4spn a0, sp, 16 */),
+        ("c.addi16sp", 0x6141 /* c.addi16sp sp, 16 */),
+        ("c.lui", 0x6505 /* c.lui a0, 1 */),
+        ("c.lwsp", 0x4502 /* c.lwsp a0, 0(sp) */),
+        ("c.jr", 0x8082 /* c.jr ra */),
+        ("c.mv", 0x852e /* c.mv a0, a1 */),
+        ("c.addiw", 0x2505 /* c.addiw a0, 1 */),
+        ("c.ldsp", 0x6502 /* c.ldsp a0, 0(sp) */),
+        ("cm.push", 0xb842 /* cm.push {ra}, -16 */),
+        ("cm.pop", 0xba42 /* cm.pop {ra}, 16 */),
+        ("cm.popretz", 0xbc42 /* cm.popretz {ra}, 16 */),
+        ("cm.popret", 0xbe42 /* cm.popret {ra}, 16 */),
+        ("cm.mvsa01", 0xac26 /* cm.mvsa01 s0, s1 */),
+        ("cm.jalt", 0xa082 /* cm.jalt 32 */),
+    ];
+    // what the compressed encodings rv64 and Zcmp reuse mean depends on the decoder
+    let infos = [rv64, Decoder::new(), zcmp]
+        .into_iter()
+        .flat_map(|decoder| {
+            decoder
+                .supported()
+                .map(move |info| (decoder, info))
+                .collect::<Vec<_>>()
+        });
+    for (decoder, info) in infos {
+        assert_eq!(info.match_value & !info.mask, 0, "{}", info.name);
+        let example = EXAMPLES
+            .iter()
+            .find(|(name, _)| *name == info.name)
+            .map_or(info.match_value, |&(_, inst)| inst);
+        assert_eq!(example & info.mask, info.match_value, "{}", info.name);
+        let inst = match is_compressed(example) {
+            true => decoder.decode(example),
+            false => try_decode(example),
+        }
+        .unwrap_or_else(|err| panic!("{}: {err:#}", info.name));
+        let decoded = inst.opcode_id().info();
+        if decoded.id != info.id {
+            // only a narrower encoding can take over the zeroed operands, pack with rs2 = zero is
+            // zext.h
+            assert!(
+                decoded.mask & info.mask == info.mask && decoded.mask != info.mask,
+                "{} decodes as {}",
+                info.name,
+                decoded.name
+            );
+        }
+    }
+}
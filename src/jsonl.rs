@@ -0,0 +1,77 @@
+use std::io::{self, Write};
+
+use crate::stream::Decoded;
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// writes a single json object (and the newline ending it) for one decoded instruction
+pub fn write_instruction<W: Write>(out: &mut W, decoded: &Decoded) -> io::Result<()> {
+    write!(
+        out,
+        "{{\"addr\":{},\"raw\":\"{:#010x}\",\"len\":{},",
+        decoded.addr, decoded.raw, decoded.len
+    )?;
+    match &decoded.inst {
+        Ok(inst) => {
+            write!(
+                out,
+                "\"mnemonic\":\"{}\",\"asm\":\"{}\"",
+                escape(&inst.mnemonic()),
+                escape(&inst.to_string())
+            )?;
+        }
+        Err(err) => write!(out, "\"error\":\"{}\"", escape(&format!("{err:#}")))?,
+    }
+    writeln!(out, "}}")
+}
+
+// writes every instruction as soon as it comes out of the iterator, nothing is collected so the
+// output can be piped somewhere while decoding is still going
+pub fn write_stream<W, I>(out: &mut W, decoded: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Decoded>,
+{
+    for decoded in decoded {
+        write_instruction(out, &decoded)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn lines() {
+    use crate::stream::InstructionStream;
+    let bytes = [
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+        0xff, 0xff, 0xff, 0xff, /* not an instruction */
+    ];
+    let mut out = Vec::new();
+    write_stream(&mut out, InstructionStream::new(&bytes, 0x100).unwrap()).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0],
+        "{\"addr\":256,\"raw\":\"0x06468613\",\"len\":4,\"mnemonic\":\"addi\",\"asm\":\"addi a2, a3, 100\"}"
+    );
+    assert!(lines[1].starts_with("{\"addr\":260,\"raw\":\"0xffffffff\",\"len\":4,\"error\":\""));
+}
+
+#[test]
+fn escaping() {
+    assert_eq!(escape("a\"b\\c\n"), "a\\\"b\\\\c\\n");
+}
@@ -1,7 +1,31 @@
+pub mod cache;
+pub mod cfg;
+pub mod classify;
+pub mod compress;
 pub mod decoded_inst;
 pub mod decoder;
+pub mod diff;
+pub mod effects;
+pub mod elf;
 pub mod error;
+pub mod exception;
+pub mod html;
 pub mod instructions;
+#[cfg(feature = "ir")]
+pub mod ir;
+pub mod isa;
+pub mod jsonl;
+pub mod listing;
+pub mod mutate;
+pub mod opcode;
+pub mod pattern;
+pub mod pcrel;
+pub mod register;
+pub mod rounding;
+pub mod stream;
+pub mod style;
+pub mod vtype;
+pub mod warning;
 
 pub mod bit_ops {
     pub use bit_ops::bitops_u32::*;
@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+
+use crate::decoded_inst::{InstructionDecoded, REG_NAMES};
+use crate::instructions::{InstructionSize, SignedInstructionSize};
+use crate::pcrel::{auipc_target, pcrel_target};
+use crate::stream::Decoded;
+use crate::style::DisplayStyle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListingOptions {
+    // how many instructions after the current one can be looked at to fold a li/la/call/tail
+    // sequence into one line, 0 turns folding off so every instruction gets its own line
+    pub lookahead: usize,
+    // annotate the second half of an auipc pair with the address it ends up at
+    pub annotate_pairs: bool,
+    pub style: DisplayStyle,
+}
+
+impl Default for ListingOptions {
+    fn default() -> Self {
+        Self {
+            lookahead: 1,
+            annotate_pairs: false,
+            style: DisplayStyle::default(),
+        }
+    }
+}
+
+// the pseudo instruction `insts` (starting at `pc`) were expanded from, along with how many
+// instructions it covers
+fn fold(pc: InstructionSize, insts: &[&InstructionDecoded]) -> Option<(String, usize)> {
+    let [first, second, ..] = insts else {
+        return None;
+    };
+    let text = match (first, second) {
+        (
+            InstructionDecoded::Lui { rd, imm: hi },
+            InstructionDecoded::Addi { rd: rd2, rs1, imm },
+        ) if rd == rd2 && rd == rs1 && *rd != 0 => {
+            let value = (hi << 12).wrapping_add(*imm) as SignedInstructionSize;
+            format!("li {}, {value}", REG_NAMES[*rd as usize])
+        }
+        (InstructionDecoded::AuiPc { rd, .. }, InstructionDecoded::Addi { rd: rd2, .. })
+            if rd == rd2 =>
+        {
+            let target = pcrel_target(pc, first, second)?;
+            format!("la {}, {target:#x}", REG_NAMES[*rd as usize])
+        }
+        // call goes through ra and tail through t1 so ra is left alone
+        (InstructionDecoded::AuiPc { rd: 1, .. }, InstructionDecoded::Jalr { rd: 1, .. }) => {
+            format!("call {:#x}", pcrel_target(pc, first, second)?)
+        }
+        (InstructionDecoded::AuiPc { rd: 6, .. }, InstructionDecoded::Jalr { rd: 0, .. }) => {
+            format!("tail {:#x}", pcrel_target(pc, first, second)?)
+        }
+        _ => return None,
+    };
+    Some((text, 2))
+}
+
+// writes a text listing, one line per instruction (or folded pseudo instruction) and `.word` for
+// anything that doesn't decode
+pub fn write_listing<W, I>(out: &mut W, decoded: I, options: ListingOptions) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Decoded>,
+{
+    let mut decoded = decoded.into_iter();
+    let mut window = VecDeque::new();
+    // the last instruction, needed to find the address an auipc pair ends up at
+    let mut prev: Option<Decoded> = None;
+    loop {
+        while window.len() <= options.lookahead {
+            match decoded.next() {
+                Some(item) => window.push_back(item),
+                None => break,
+            }
+        }
+        let Some(current) = window.front() else {
+            return Ok(());
+        };
+
+        let insts = window
+            .iter()
+            .map_while(|item| item.inst.as_ref().ok())
+            .collect::<Vec<_>>();
+        if let Some((text, len)) = fold(current.addr, &insts) {
+            writeln!(
+                out,
+                "{:08x}: {}",
+                current.addr,
+                options.style.restyle(&text)
+            )?;
+            window.drain(..len);
+            prev = None;
+            continue;
+        }
+
+        let current = window.pop_front().unwrap();
+        match &current.inst {
+            Ok(inst) => {
+                let text = inst.display_with(options.style);
+                let pair = match &prev {
+                    Some(Decoded {
+                        addr,
+                        inst: Ok(auipc),
+                        ..
+                    }) if options.annotate_pairs => pcrel_target(*addr, auipc, inst),
+                    _ => None,
+                };
+                match pair.or_else(|| auipc_target(current.addr, inst)) {
+                    Some(target) => writeln!(out, "{:08x}: {text} # {target:#x}", current.addr)?,
+                    None => writeln!(out, "{:08x}: {text}", current.addr)?,
+                }
+            }
+            Err(_) => writeln!(out, "{:08x}: .word {:#x}", current.addr, current.raw)?,
+        }
+        prev = Some(current);
+    }
+}
+
+#[test]
+fn folding() {
+    use crate::stream::InstructionStream;
+    let bytes = [
+        0x37, 0x55, 0x34, 0x12, /* lui a0, 0x12345 */
+        0x13, 0x05, 0x85, 0x67, /* addi a0, a0, 0x678 */
+        0x97, 0x10, 0x00, 0x00, /* auipc ra, 1 */
+        0xe7, 0x80, 0xc0, 0xff, /* jalr ra, -4(ra) */
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+    ];
+    let listing = |options| {
+        let mut out = Vec::new();
+        let stream = InstructionStream::new(&bytes, 0x1000).unwrap();
+        write_listing(&mut out, stream, options).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+
+    assert_eq!(
+        listing(ListingOptions::default()),
+        "00001000: li a0, 305419896\n\
+         00001008: call 0x2004\n\
+         00001010: addi a2, a3, 100\n"
+    );
+    // nothing gets folded without any lookahead
+    let raw = listing(ListingOptions {
+        lookahead: 0,
+        annotate_pairs: true,
+        ..Default::default()
+    });
+    assert_eq!(raw.lines().count(), 5);
+    assert!(raw.contains("00001008: auipc ra, 1 # 0x2008"));
+    assert!(raw.contains("# 0x2004"));
+
+    let numeric = listing(ListingOptions {
+        style: DisplayStyle {
+            registers: crate::style::RegisterNames::Numeric,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert!(numeric.starts_with("00001000: li x10, 305419896\n"));
+}
@@ -0,0 +1,341 @@
+use std::io::{self, BufWriter, Write};
+use std::process::ExitCode;
+
+mod browse;
+
+use riscv_decoder::{
+    decoder::{CompressedDouble, Decoder, HalfPrecision, Xlen},
+    diff::{diff_listings, HunkKind, Line},
+    elf::{write_functions, Elf, Function},
+    isa::{write_table, TableFormat},
+    jsonl,
+    listing::{write_listing, ListingOptions},
+    stream::InstructionStream,
+};
+
+const USAGE: &str =
+    "usage: riscv-decoder [--jsonl | --browse] [--fuse] [--lookahead <n>] [--base <addr>] [--function <name>] [<decoder>] <file>
+       riscv-decoder diff [--base <addr>] [<decoder>] <old> <new>
+       riscv-decoder --isa <csv | json> [<decoder>]
+decoder: [--xlen <32 | 64>] [--no-compressed] [--zfhmin] [--zcmp]
+browse: page through the listing with commands typed on stdin (a line based viewer, not a TUI)";
+
+enum Command {
+    Disassemble(Args),
+    Diff(DiffArgs),
+    // dump the opcode table of the decoder instead of decoding anything
+    Isa(TableFormat, Decoder),
+}
+
+struct Args {
+    jsonl: bool,
+    // browse the listing with commands read from stdin instead of printing all of it
+    browse: bool,
+    // also resolve the instruction that completes an auipc pair
+    fuse: bool,
+    // how far ahead li/la/call sequences are looked for, 0 prints every instruction as is
+    lookahead: usize,
+    base: u32,
+    // ELF files have their XLEN in the header, it's only taken from here for raw images
+    decoder: Decoder,
+    // only this function out of an ELF file
+    function: Option<String>,
+    path: String,
+}
+
+// both raw images are assumed to be loaded at the same address, ELF files have their own
+struct DiffArgs {
+    base: u32,
+    decoder: Decoder,
+    old: String,
+    new: String,
+}
+
+fn parse_number(text: &str) -> Option<u32> {
+    match text.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+fn parse_xlen(text: &str) -> Option<Xlen> {
+    match text {
+        "32" => Some(Xlen::Rv32),
+        "64" => Some(Xlen::Rv64),
+        _ => None,
+    }
+}
+
+// the switches every command takes to set up the decoder, `Some(false)` if `arg` isn't one of them
+// and `None` if it is but its value is missing or wrong
+fn parse_decoder_arg(
+    arg: &str,
+    args: &mut impl Iterator<Item = String>,
+    decoder: &mut Decoder,
+) -> Option<bool> {
+    match arg {
+        "--xlen" => decoder.xlen = parse_xlen(&args.next()?)?,
+        "--no-compressed" => decoder.compressed = false,
+        "--zfhmin" => decoder.half_precision = HalfPrecision::Zfhmin,
+        "--zcmp" => decoder.compressed_double = CompressedDouble::Zcmp,
+        _ => return Some(false),
+    }
+    Some(true)
+}
+
+fn parse_diff_args(mut args: impl Iterator<Item = String>) -> Option<DiffArgs> {
+    let (mut base, mut decoder, mut paths) = (0, Decoder::new(), Vec::new());
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base" => base = parse_number(&args.next()?)?,
+            _ if parse_decoder_arg(&arg, &mut args, &mut decoder)? => {}
+            _ => paths.push(arg),
+        }
+    }
+    let [old, new] = <[String; 2]>::try_from(paths).ok()?;
+    Some(DiffArgs {
+        base,
+        decoder,
+        old,
+        new,
+    })
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Option<Command> {
+    let mut args = args.peekable();
+    if args.peek().is_some_and(|arg| arg == "diff") {
+        return parse_diff_args(args.skip(1)).map(Command::Diff);
+    }
+
+    let (mut jsonl, mut browse, mut fuse, mut base, mut path) = (false, false, false, 0, None);
+    let (mut decoder, mut function, mut isa) = (Decoder::new(), None, None);
+    let mut lookahead = ListingOptions::default().lookahead;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--jsonl" => jsonl = true,
+            "--browse" => browse = true,
+            "--fuse" => fuse = true,
+            "--lookahead" => lookahead = args.next()?.parse().ok()?,
+            "--base" => base = parse_number(&args.next()?)?,
+            "--function" => function = Some(args.next()?),
+            "--isa" => {
+                isa = Some(match args.next()?.as_str() {
+                    "csv" => TableFormat::Csv,
+                    "json" => TableFormat::Json,
+                    _ => return None,
+                })
+            }
+            _ if parse_decoder_arg(&arg, &mut args, &mut decoder)? => {}
+            _ if path.is_none() => path = Some(arg),
+            _ => return None,
+        }
+    }
+    // the table doesn't need a file
+    if let Some(format) = isa {
+        return path.is_none().then_some(Command::Isa(format, decoder));
+    }
+    Some(Command::Disassemble(Args {
+        jsonl,
+        browse,
+        fuse,
+        lookahead,
+        base,
+        decoder,
+        function,
+        path: path?,
+    }))
+}
+
+fn stream(bytes: &[u8], base: u32, decoder: Decoder) -> io::Result<InstructionStream<'_>> {
+    InstructionStream::new(bytes, base)
+        .map(|stream| stream.with_decoder(decoder))
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let bytes = std::fs::read(&args.path)?;
+    if Elf::is_elf(&bytes) {
+        return run_elf(&args, &bytes);
+    }
+    if args.function.is_some() {
+        return Err(invalid("--function needs an ELF file with a symbol table"));
+    }
+    let stream = stream(&bytes, args.base, args.decoder)?;
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    if args.browse {
+        browse::run(stream.collect(), Vec::new(), io::stdin().lock(), &mut out)?;
+    } else if args.jsonl {
+        jsonl::write_stream(&mut out, stream)?;
+    } else {
+        write_listing(&mut out, stream, listing_options(&args))?;
+    }
+
+    out.flush()
+}
+
+fn invalid(err: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+}
+
+fn listing_options(args: &Args) -> ListingOptions {
+    ListingOptions {
+        lookahead: args.lookahead,
+        annotate_pairs: args.fuse,
+        ..Default::default()
+    }
+}
+
+// the decoder switches apply to ELF files too, except for `--xlen` since the class of the file says
+// what XLEN it is
+fn with_decoder<'a>(
+    elf: &Elf<'a>,
+    functions: Vec<Function<'a>>,
+    decoder: Decoder,
+) -> Vec<Function<'a>> {
+    let decoder = Decoder {
+        xlen: elf.xlen,
+        ..decoder
+    };
+    functions
+        .into_iter()
+        .map(|function| Function {
+            decoder,
+            ..function
+        })
+        .collect()
+}
+
+// ELF files are grouped by function, `--base` is ignored since every section has its own address
+fn run_elf(args: &Args, bytes: &[u8]) -> io::Result<()> {
+    let elf = Elf::parse(bytes).map_err(|err| invalid(format!("{err:#}")))?;
+    let functions = match &args.function {
+        Some(name) => vec![elf
+            .function(name)
+            .ok_or_else(|| invalid(format!("no function called {name}")))?],
+        None => elf.functions(),
+    };
+    let functions = with_decoder(&elf, functions, args.decoder);
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    if args.browse || args.jsonl {
+        let stream = functions.iter().flat_map(|function| function.stream());
+        if args.browse {
+            let symbols = functions
+                .iter()
+                .flat_map(|function| {
+                    let labels = function.labels.iter().copied();
+                    std::iter::once((function.addr, function.name)).chain(labels)
+                })
+                .collect();
+            browse::run(stream.collect(), symbols, io::stdin().lock(), &mut out)?;
+        } else {
+            jsonl::write_stream(&mut out, stream)?;
+        }
+    } else {
+        write_functions(&mut out, functions, listing_options(args))?;
+    }
+
+    out.flush()
+}
+
+// the listing of a raw image, or of every function in an ELF file (like `run` does)
+fn diff_lines(path: &str, args: &DiffArgs) -> io::Result<Vec<Line>> {
+    let bytes = std::fs::read(path)?;
+    if !Elf::is_elf(&bytes) {
+        return Ok(stream(&bytes, args.base, args.decoder)?
+            .map(Line::from)
+            .collect());
+    }
+    let elf = Elf::parse(&bytes).map_err(|err| invalid(format!("{path}: {err:#}")))?;
+    Ok(with_decoder(&elf, elf.functions(), args.decoder)
+        .iter()
+        .flat_map(|function| function.stream())
+        .map(Line::from)
+        .collect())
+}
+
+// prints every hunk with the old lines prefixed by `-` and the new ones by `+`
+fn run_diff(args: DiffArgs) -> io::Result<()> {
+    let (old, new) = (diff_lines(&args.old, &args)?, diff_lines(&args.new, &args)?);
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    for hunk in diff_listings(&old, &new) {
+        // where the hunk starts in each image, for insertions/removals that's the line after it
+        let addr = |lines: &[Line], index: usize| match lines.get(index) {
+            Some(line) => format!("{:08x}", line.addr),
+            None => "end".to_string(),
+        };
+        let kind = match hunk.kind() {
+            HunkKind::Inserted => "inserted",
+            HunkKind::Removed => "removed",
+            HunkKind::Changed => "changed",
+        };
+        writeln!(
+            out,
+            "@@ {} -> {} {kind} @@",
+            addr(&old, hunk.old.start),
+            addr(&new, hunk.new.start)
+        )?;
+        for line in &old[hunk.old] {
+            writeln!(out, "- {:08x}: {}", line.addr, line.text)?;
+        }
+        for line in &new[hunk.new] {
+            writeln!(out, "+ {:08x}: {}", line.addr, line.text)?;
+        }
+    }
+
+    out.flush()
+}
+
+fn main() -> ExitCode {
+    let Some(command) = parse_args(std::env::args().skip(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let result = match command {
+        Command::Disassemble(args) => run(args),
+        Command::Diff(args) => run_diff(args),
+        Command::Isa(format, decoder) => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            write_table(&mut out, &decoder, format).and_then(|()| out.flush())
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // the other end of a pipe going away (e.g. `| head`) isn't worth complaining about
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("riscv-decoder: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[test]
+fn isa_table_for_the_decoder() {
+    let table = |args: &[&str]| {
+        let Some(Command::Isa(format, decoder)) =
+            parse_args(args.iter().map(|arg| arg.to_string()))
+        else {
+            panic!("not an --isa command");
+        };
+        let mut out = Vec::new();
+        write_table(&mut out, &decoder, format).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        out.lines()
+            .map(|line| line.split(',').next().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    let rv32 = table(&["--isa", "csv"]);
+    // --isa doesn't have to come first
+    let rv64 = table(&["--xlen", "64", "--isa", "csv"]);
+    for name in ["ld", "addiw", "c.ldsp"] {
+        assert!(!rv32.iter().any(|row| row == name), "{name}");
+        assert!(rv64.iter().any(|row| row == name), "{name}");
+    }
+    let no_c = table(&["--isa", "csv", "--no-compressed"]);
+    assert!(!no_c.iter().any(|row| row.starts_with("c.")));
+    assert!(parse_args(["--isa", "csv", "file"].iter().map(|arg| arg.to_string())).is_none());
+}
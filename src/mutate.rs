@@ -0,0 +1,125 @@
+use std::panic;
+
+use crate::bit_ops::get_bits;
+use crate::decoded_inst::InstructionDecoded;
+use crate::decoder::try_decode;
+use crate::effects::RegisterFile;
+use crate::instructions::{compressed::is_compressed, InstructionSize};
+
+// every encoding that's `flips` or fewer bits away from `inst` (not counting `inst` itself)
+pub fn bit_flips(inst: InstructionSize, flips: usize) -> Vec<InstructionSize> {
+    let mut mutants = vec![inst];
+    let mut frontier = vec![(inst, 0)];
+    for _ in 0..flips {
+        let mut next = Vec::new();
+        for (mutant, lowest) in frontier {
+            // only flip bits above the last one flipped so every combination comes out once
+            for bit in lowest..32 {
+                let flipped = mutant ^ (1 << bit);
+                mutants.push(flipped);
+                next.push((flipped, bit + 1));
+            }
+        }
+        frontier = next;
+    }
+    mutants.remove(0);
+    mutants
+}
+
+// encodings that only differ from `inst` in the fields the decoder uses to pick the instruction,
+// every funct7, funct3 and rs2 value (rs2 doubles as a selector for unary fp and vector insts)
+pub fn near_misses(inst: InstructionSize) -> Vec<InstructionSize> {
+    let replace = |lo: u32, len: u32, value: u32| {
+        let mask = ((1 << len) - 1) << lo;
+        (inst & !mask) | (value << lo)
+    };
+    (0..1 << 7)
+        .map(|funct7| replace(25, 7, funct7))
+        .chain((0..1 << 3).map(|funct3| replace(12, 3, funct3)))
+        .chain((0..1 << 5).map(|rs2| replace(20, 5, rs2)))
+        .filter(|mutant| *mutant != inst)
+        .collect()
+}
+
+// checks the registers of a decoded instruction actually come from the fields they're encoded in,
+// a decoded rd always comes from bits 11:7 and sources from rs1/rs2/rs3
+fn fields_match(raw: InstructionSize, inst: &InstructionDecoded) -> bool {
+    if is_compressed(raw) {
+        return true;
+    }
+    let effects = inst.effects();
+    let rd = get_bits(raw, 5, 7);
+    let sources = [
+        get_bits(raw, 5, 15),
+        get_bits(raw, 5, 20),
+        get_bits(raw, 5, 27),
+    ];
+    effects.writes.iter().all(|reg| reg.num == rd)
+        && effects.reads.iter().all(|reg| {
+            sources.contains(&reg.num)
+                // the v0 mask and the old value of vd for accumulating vector insts
+                || (reg.file == RegisterFile::Vector && (reg.num == 0 || reg.num == rd))
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MutantFailure {
+    Panicked(InstructionSize),
+    // decoded to something whose registers don't line up with the encoding
+    Misattributed(InstructionSize),
+}
+
+// decodes every mutant and makes sure each one either errors or decodes to something sane, the
+// decoder should never panic no matter what it's given
+pub fn check_mutants(mutants: &[InstructionSize]) -> Vec<MutantFailure> {
+    mutants
+        .iter()
+        .filter_map(|&mutant| {
+            let decoded = panic::catch_unwind(|| {
+                // printing is part of decoding as far as users are concerned
+                try_decode(mutant).inspect(|inst| {
+                    let _ = inst.to_string();
+                })
+            });
+            match decoded {
+                Err(_) => Some(MutantFailure::Panicked(mutant)),
+                Ok(Ok(inst)) if !fields_match(mutant, &inst) => {
+                    Some(MutantFailure::Misattributed(mutant))
+                }
+                Ok(_) => None,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn flips() {
+    assert_eq!(bit_flips(0, 1).len(), 32);
+    assert_eq!(bit_flips(0, 2).len(), 32 + 32 * 31 / 2);
+    assert!(bit_flips(0x13, 1).contains(&0x12));
+    assert!(!bit_flips(0x13, 2).contains(&0x13));
+    assert_eq!(near_misses(0x06468613).len(), 127 + 7 + 31);
+}
+
+#[test]
+fn mutants() {
+    let seeds = [
+        0x06468613u32, /* addi x12 x13 100 */
+        0x00c12603,    /* lw x12, 12(sp) */
+        0x00112f23,    /* sw ra, 30(sp) */
+        0x00004537,    /* lui x10, 4 */
+        0xfe20dae3,    /* bge x1 x2 -12 */
+        0xfb9ff0ef,    /* jal ra, -72 */
+        0xf14025f3,    /* csrrs x11, mhartid, x0 */
+        0x0CF4A7AF,    /* amoswap.w x15, x15, (x9) */
+        0x00c5f553,    /* fadd.s f10 f11 f12 */
+        0x02c58533,    /* mul x10 x11 x12 */
+        0xae43a2f7,    /* vsm3c.vi v5, v4, 7 */
+        0xee209057,    /* vfwmaccbf16.vv */
+    ];
+    for seed in seeds {
+        let mut mutants = bit_flips(seed, 2);
+        mutants.extend(near_misses(seed));
+        assert_eq!(check_mutants(&mutants), vec![], "mutants of {seed:#010x}");
+    }
+}
@@ -0,0 +1,1066 @@
+use std::fmt::Display;
+
+use crate::decoded_inst::InstructionDecoded;
+
+// a small integer per instruction that never changes between releases, for ffi and for indexing
+// handler tables. ids are dense (0..OpcodeId::COUNT) and new instructions only ever get added on
+// the end so existing ids stay the same
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OpcodeId(u16);
+
+// the extension an instruction comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Extension {
+    I,
+    M,
+    A,
+    F,
+    D,
+    Q,
+    Zfh,
+    // the part of Zfh that only moves and converts halves, for cores without half precision math
+    Zfhmin,
+    Zfa,
+    Zba,
+    Zbb,
+    Zbc,
+    Zbs,
+    Zbkb,
+    Zbkx,
+    // packed SIMD
+    P,
+    Zknd,
+    Zkne,
+    Zknh,
+    Zksed,
+    Zksh,
+    V,
+    C,
+    // the extra 16 bit encodings, byte/halfword memory ops, extends, not and mul
+    Zcb,
+    // compressed push/pop and register moves for embedded code, in place of Zcd
+    Zcmp,
+    // table jumps through the jvt csr
+    Zcmt,
+    Zicsr,
+    Zifencei,
+    Zihintpause,
+    Zihintntl,
+    Zicbom,
+    Zicboz,
+    Zicbop,
+    Zawrs,
+    Zicfiss,
+    Zicfilp,
+    Zvksed,
+    Zvksh,
+    Zvbb,
+    Zvkg,
+    Zvkned,
+    Zvknh,
+    Zvfbfmin,
+    Zvfbfwma,
+    // the privileged spec (sret, mret, sfence.vma...)
+    Privileged,
+    // T-Head vendor extensions
+    XTheadBa,
+    XTheadCmo,
+    XTheadMemIdx,
+    // whatever a `Decoder::register_custom` hook decodes
+    Custom,
+    // fine grained address translation cache invalidation
+    Svinval,
+    // resumable non-maskable interrupts
+    Smrnmi,
+    // debug mode
+    Sdext,
+}
+
+// the encoding layout, named the way the spec names them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    R,
+    R4,
+    I,
+    S,
+    B,
+    U,
+    J,
+    OpV,
+    CR,
+    CI,
+    CIW,
+    CL,
+    CS,
+    CJ,
+    CB,
+    CA,
+    CSS,
+    CLB,
+    CSB,
+    CLH,
+    CSH,
+    CU,
+    CMPP,
+    CMMV,
+    CMJT,
+}
+
+// what kind of thing each operand is, in the order they're printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum OperandKind {
+    Rd,
+    Rs1,
+    Rs2,
+    Fd,
+    Fs1,
+    Fs2,
+    Fs3,
+    Vd,
+    Vs1,
+    Vs2,
+    // the optional `v0.t` mask operand
+    Vm,
+    Imm,
+    Uimm,
+    Csr,
+    // fence ordering sets
+    Pred,
+    Succ,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstructionInfo {
+    pub id: OpcodeId,
+    pub name: &'static str,
+    pub extension: Extension,
+    pub format: Format,
+    // an instruction is this one when `inst & mask == match_value`, compressed ones only use the
+    // low 16 bits
+    pub mask: u32,
+    pub match_value: u32,
+    pub operands: &'static [OperandKind],
+}
+
+macro_rules! opcodes {
+    ($($variant:ident => $name:literal, $extension:ident, $format:ident, $mask:literal, $match:literal, [$($operand:ident),*];)*) => {
+        #[repr(u16)]
+        enum Opcode {
+            $($variant,)*
+        }
+
+        const NAMES: &[&str] = &[$($name,)*];
+
+        const INFO: &[(Extension, Format, u32, u32, &[OperandKind])] = &[
+            $((Extension::$extension, Format::$format, $mask, $match, &[$(OperandKind::$operand),*]),)*
+        ];
+
+        impl InstructionDecoded {
+            pub fn opcode_id(&self) -> OpcodeId {
+                match self {
+                    $(InstructionDecoded::$variant { .. } => OpcodeId(Opcode::$variant as u16),)*
+                }
+            }
+        }
+    };
+}
+
+// APPEND ONLY, the position in this list is the id. each entry is the variant, its mnemonic, the
+// extension it's from, its format, its mask and match value and its operands
+opcodes! {
+    Lb => "lb", I, I, 0x0000707f, 0x00000003, [Rd, Imm, Rs1];
+    Lh => "lh", I, I, 0x0000707f, 0x00001003, [Rd, Imm, Rs1];
+    Lw => "lw", I, I, 0x0000707f, 0x00002003, [Rd, Imm, Rs1];
+    Lbu => "lbu", I, I, 0x0000707f, 0x00004003, [Rd, Imm, Rs1];
+    Lhu => "lhu", I, I, 0x0000707f, 0x00005003, [Rd, Imm, Rs1];
+    Lwu => "lwu", I, I, 0x0000707f, 0x00006003, [Rd, Imm, Rs1];
+    Addi => "addi", I, I, 0x0000707f, 0x00000013, [Rd, Rs1, Imm];
+    Slli => "slli", I, I, 0xfe00707f, 0x00001013, [Rd, Rs1, Imm];
+    Slti => "slti", I, I, 0x0000707f, 0x00002013, [Rd, Rs1, Imm];
+    Sltiu => "sltiu", I, I, 0x0000707f, 0x00003013, [Rd, Rs1, Imm];
+    Xori => "xori", I, I, 0x0000707f, 0x00004013, [Rd, Rs1, Imm];
+    Srli => "srli", I, I, 0xfe00707f, 0x00005013, [Rd, Rs1, Imm];
+    Srai => "srai", I, I, 0xfe00707f, 0x40005013, [Rd, Rs1, Imm];
+    Ori => "ori", I, I, 0x0000707f, 0x00006013, [Rd, Rs1, Imm];
+    Andi => "andi", I, I, 0x0000707f, 0x00007013, [Rd, Rs1, Imm];
+    AuiPc => "auipc", I, U, 0x0000007f, 0x00000017, [Rd, Imm];
+    Sb => "sb", I, S, 0x0000707f, 0x00000023, [Rs2, Imm, Rs1];
+    Sh => "sh", I, S, 0x0000707f, 0x00001023, [Rs2, Imm, Rs1];
+    Sw => "sw", I, S, 0x0000707f, 0x00002023, [Rs2, Imm, Rs1];
+    Add => "add", I, R, 0xfe00707f, 0x00000033, [Rd, Rs1, Rs2];
+    Sub => "sub", I, R, 0xfe00707f, 0x40000033, [Rd, Rs1, Rs2];
+    Sll => "sll", I, R, 0xfe00707f, 0x00001033, [Rd, Rs1, Rs2];
+    Slt => "slt", I, R, 0xfe00707f, 0x00002033, [Rd, Rs1, Rs2];
+    Sltu => "sltu", I, R, 0xfe00707f, 0x00003033, [Rd, Rs1, Rs2];
+    Xor => "xor", I, R, 0xfe00707f, 0x00004033, [Rd, Rs1, Rs2];
+    Srl => "srl", I, R, 0xfe00707f, 0x00005033, [Rd, Rs1, Rs2];
+    Sra => "sra", I, R, 0xfe00707f, 0x40005033, [Rd, Rs1, Rs2];
+    Or => "or", I, R, 0xfe00707f, 0x00006033, [Rd, Rs1, Rs2];
+    And => "and", I, R, 0xfe00707f, 0x00007033, [Rd, Rs1, Rs2];
+    Lui => "lui", I, U, 0x0000007f, 0x00000037, [Rd, Imm];
+    Beq => "beq", I, B, 0x0000707f, 0x00000063, [Rs1, Rs2, Imm];
+    Bne => "bne", I, B, 0x0000707f, 0x00001063, [Rs1, Rs2, Imm];
+    Blt => "blt", I, B, 0x0000707f, 0x00004063, [Rs1, Rs2, Imm];
+    Bge => "bge", I, B, 0x0000707f, 0x00005063, [Rs1, Rs2, Imm];
+    Bltu => "bltu", I, B, 0x0000707f, 0x00006063, [Rs1, Rs2, Imm];
+    Bgeu => "bgeu", I, B, 0x0000707f, 0x00007063, [Rs1, Rs2, Imm];
+    Jalr => "jalr", I, I, 0x0000707f, 0x00000067, [Rd, Imm, Rs1];
+    Jal => "jal", I, J, 0x0000007f, 0x0000006f, [Rd, Imm];
+    ECall => "ecall", I, I, 0xffffffff, 0x00000073, [];
+    EBreak => "ebreak", I, I, 0xffffffff, 0x00100073, [];
+    SRet => "sret", Privileged, R, 0xffffffff, 0x10200073, [];
+    MRet => "mret", Privileged, R, 0xffffffff, 0x30200073, [];
+    SFenceVma => "sfence.vma", Privileged, R, 0xfe007fff, 0x12000073, [Rs1, Rs2];
+    CsrRw => "csrrw", Zicsr, I, 0x0000707f, 0x00001073, [Rd, Csr, Rs1];
+    CsrRs => "csrrs", Zicsr, I, 0x0000707f, 0x00002073, [Rd, Csr, Rs1];
+    CsrRc => "csrrc", Zicsr, I, 0x0000707f, 0x00003073, [Rd, Csr, Rs1];
+    CsrRwi => "csrrwi", Zicsr, I, 0x0000707f, 0x00005073, [Rd, Csr, Uimm];
+    CsrRsi => "csrrsi", Zicsr, I, 0x0000707f, 0x00006073, [Rd, Csr, Uimm];
+    CsrRci => "csrrci", Zicsr, I, 0x0000707f, 0x00007073, [Rd, Csr, Uimm];
+    Fence => "fence", I, I, 0x0000707f, 0x0000000f, [Pred, Succ];
+    FenceI => "fence.i", Zifencei, I, 0x0000707f, 0x0000100f, [];
+    Flw => "flw", F, I, 0x0000707f, 0x00002007, [Fd, Imm, Rs1];
+    Fsw => "fsw", F, S, 0x0000707f, 0x00002027, [Fs2, Imm, Rs1];
+    FmaddS => "fmadd.s", F, R4, 0x0600007f, 0x00000043, [Fd, Fs1, Fs2, Fs3];
+    FmsubS => "fmsub.s", F, R4, 0x0600007f, 0x00000047, [Fd, Fs1, Fs2, Fs3];
+    FnmaddS => "fnmadd.s", F, R4, 0x0600007f, 0x0000004f, [Fd, Fs1, Fs2, Fs3];
+    FnmsubS => "fnmsub.s", F, R4, 0x0600007f, 0x0000004b, [Fd, Fs1, Fs2, Fs3];
+    FaddS => "fadd.s", F, R, 0xfe00007f, 0x00000053, [Fd, Fs1, Fs2];
+    FsubS => "fsub.s", F, R, 0xfe00007f, 0x08000053, [Fd, Fs1, Fs2];
+    FmulS => "fmul.s", F, R, 0xfe00007f, 0x10000053, [Fd, Fs1, Fs2];
+    FdivS => "fdiv.s", F, R, 0xfe00007f, 0x18000053, [Fd, Fs1, Fs2];
+    FsqrtS => "fsqrt.s", F, R, 0xfff0007f, 0x58000053, [Fd, Fs1];
+    FsgnjS => "fsgnj.s", F, R, 0xfe00707f, 0x20000053, [Fd, Fs1, Fs2];
+    FsgnjnS => "fsgnjn.s", F, R, 0xfe00707f, 0x20001053, [Fd, Fs1, Fs2];
+    FsgnjxS => "fsgnjx.s", F, R, 0xfe00707f, 0x20002053, [Fd, Fs1, Fs2];
+    FminS => "fmin.s", F, R, 0xfe00707f, 0x28000053, [Fd, Fs1, Fs2];
+    FmaxS => "fmax.s", F, R, 0xfe00707f, 0x28001053, [Fd, Fs1, Fs2];
+    FcvtSW => "fcvt.s.w", F, R, 0xfff0007f, 0xd0000053, [Fd, Rs1];
+    FcvtSWU => "fcvt.s.wu", F, R, 0xfff0007f, 0xd0100053, [Fd, Rs1];
+    FcvtWS => "fcvt.w.s", F, R, 0xfff0007f, 0xc0000053, [Rd, Fs1];
+    FcvtWUS => "fcvt.wu.s", F, R, 0xfff0007f, 0xc0100053, [Rd, Fs1];
+    FmvXW => "fmv.x.w", F, R, 0xfff0707f, 0xe0000053, [Rd, Fs1];
+    FmvWX => "fmv.w.x", F, R, 0xfff0707f, 0xf0000053, [Fd, Rs1];
+    FeqS => "feq.s", F, R, 0xfe00707f, 0xa0002053, [Rd, Fs1, Fs2];
+    FltS => "flt.s", F, R, 0xfe00707f, 0xa0001053, [Rd, Fs1, Fs2];
+    FleS => "fle.s", F, R, 0xfe00707f, 0xa0000053, [Rd, Fs1, Fs2];
+    FClassS => "fclass.s", F, R, 0xfff0707f, 0xe0001053, [Rd, Fs1];
+    Mul => "mul", M, R, 0xfe00707f, 0x02000033, [Rd, Rs1, Rs2];
+    Mulh => "mulh", M, R, 0xfe00707f, 0x02001033, [Rd, Rs1, Rs2];
+    Mulsu => "mulhsu", M, R, 0xfe00707f, 0x02002033, [Rd, Rs1, Rs2];
+    Mulu => "mulhu", M, R, 0xfe00707f, 0x02003033, [Rd, Rs1, Rs2];
+    Div => "div", M, R, 0xfe00707f, 0x02004033, [Rd, Rs1, Rs2];
+    Divu => "divu", M, R, 0xfe00707f, 0x02005033, [Rd, Rs1, Rs2];
+    Rem => "rem", M, R, 0xfe00707f, 0x02006033, [Rd, Rs1, Rs2];
+    Remu => "remu", M, R, 0xfe00707f, 0x02007033, [Rd, Rs1, Rs2];
+    LrW => "lr.w", A, R, 0xf9f0707f, 0x1000202f, [Rd, Rs1];
+    ScW => "sc.w", A, R, 0xf800707f, 0x1800202f, [Rd, Rs2, Rs1];
+    AmoswapW => "amoswap.w", A, R, 0xf800707f, 0x0800202f, [Rd, Rs2, Rs1];
+    AmoaddW => "amoadd.w", A, R, 0xf800707f, 0x0000202f, [Rd, Rs2, Rs1];
+    AmoandW => "amoand.w", A, R, 0xf800707f, 0x6000202f, [Rd, Rs2, Rs1];
+    AmoorW => "amoor.w", A, R, 0xf800707f, 0x4000202f, [Rd, Rs2, Rs1];
+    AmoxorW => "amoxor.w", A, R, 0xf800707f, 0x2000202f, [Rd, Rs2, Rs1];
+    AmomaxW => "amomax.w", A, R, 0xf800707f, 0xa000202f, [Rd, Rs2, Rs1];
+    AmominW => "amomin.w", A, R, 0xf800707f, 0x8000202f, [Rd, Rs2, Rs1];
+    Vsm4kVi => "vsm4k.vi", Zvksed, OpV, 0xfe00707f, 0x86002077, [Vd, Vs2, Uimm];
+    Vsm4rVv => "vsm4r.vv", Zvksed, OpV, 0xfe0ff07f, 0xa2082077, [Vd, Vs2];
+    Vsm4rVs => "vsm4r.vs", Zvksed, OpV, 0xfe0ff07f, 0xa6082077, [Vd, Vs2];
+    Vsm3meVv => "vsm3me.vv", Zvksh, OpV, 0xfe00707f, 0x82002077, [Vd, Vs2, Vs1];
+    Vsm3cVi => "vsm3c.vi", Zvksh, OpV, 0xfe00707f, 0xae002077, [Vd, Vs2, Uimm];
+    Vfwmaccbf16Vv => "vfwmaccbf16.vv", Zvfbfwma, OpV, 0xfc00707f, 0xec001057, [Vd, Vs1, Vs2, Vm];
+    Vfwmaccbf16Vf => "vfwmaccbf16.vf", Zvfbfwma, OpV, 0xfc00707f, 0xec005057, [Vd, Fs1, Vs2, Vm];
+    Vfncvtbf16FFW => "vfncvtbf16.f.f.w", Zvfbfmin, OpV, 0xfc0ff07f, 0x480e9057, [Vd, Vs2, Vm];
+    Vfwcvtbf16FFV => "vfwcvtbf16.f.f.v", Zvfbfmin, OpV, 0xfc0ff07f, 0x48069057, [Vd, Vs2, Vm];
+    CAddi4Spn => "c.addi4spn", C, CIW, 0x0000e003, 0x00000000, [Rd, Uimm];
+    CNop => "c.nop", C, CI, 0x0000ffff, 0x00000001, [];
+    CSlli => "c.slli", C, CI, 0x0000e003, 0x00000002, [Rd, Uimm];
+    Ld => "ld", I, I, 0x0000707f, 0x00003003, [Rd, Imm, Rs1];
+    Sd => "sd", I, S, 0x0000707f, 0x00003023, [Rs2, Imm, Rs1];
+    Addiw => "addiw", I, I, 0x0000707f, 0x0000001b, [Rd, Rs1, Imm];
+    Slliw => "slliw", I, I, 0xfe00707f, 0x0000101b, [Rd, Rs1, Imm];
+    Srliw => "srliw", I, I, 0xfe00707f, 0x0000501b, [Rd, Rs1, Imm];
+    Sraiw => "sraiw", I, I, 0xfe00707f, 0x4000501b, [Rd, Rs1, Imm];
+    Addw => "addw", I, R, 0xfe00707f, 0x0000003b, [Rd, Rs1, Rs2];
+    Subw => "subw", I, R, 0xfe00707f, 0x4000003b, [Rd, Rs1, Rs2];
+    Sllw => "sllw", I, R, 0xfe00707f, 0x0000103b, [Rd, Rs1, Rs2];
+    Srlw => "srlw", I, R, 0xfe00707f, 0x0000503b, [Rd, Rs1, Rs2];
+    Sraw => "sraw", I, R, 0xfe00707f, 0x4000503b, [Rd, Rs1, Rs2];
+    Mulw => "mulw", M, R, 0xfe00707f, 0x0200003b, [Rd, Rs1, Rs2];
+    Divw => "divw", M, R, 0xfe00707f, 0x0200403b, [Rd, Rs1, Rs2];
+    Divuw => "divuw", M, R, 0xfe00707f, 0x0200503b, [Rd, Rs1, Rs2];
+    Remw => "remw", M, R, 0xfe00707f, 0x0200603b, [Rd, Rs1, Rs2];
+    Remuw => "remuw", M, R, 0xfe00707f, 0x0200703b, [Rd, Rs1, Rs2];
+    LrD => "lr.d", A, R, 0xf9f0707f, 0x1000302f, [Rd, Rs1];
+    ScD => "sc.d", A, R, 0xf800707f, 0x1800302f, [Rd, Rs2, Rs1];
+    AmoswapD => "amoswap.d", A, R, 0xf800707f, 0x0800302f, [Rd, Rs2, Rs1];
+    AmoaddD => "amoadd.d", A, R, 0xf800707f, 0x0000302f, [Rd, Rs2, Rs1];
+    AmoxorD => "amoxor.d", A, R, 0xf800707f, 0x2000302f, [Rd, Rs2, Rs1];
+    AmoandD => "amoand.d", A, R, 0xf800707f, 0x6000302f, [Rd, Rs2, Rs1];
+    AmoorD => "amoor.d", A, R, 0xf800707f, 0x4000302f, [Rd, Rs2, Rs1];
+    AmominD => "amomin.d", A, R, 0xf800707f, 0x8000302f, [Rd, Rs2, Rs1];
+    AmomaxD => "amomax.d", A, R, 0xf800707f, 0xa000302f, [Rd, Rs2, Rs1];
+    AmominuD => "amominu.d", A, R, 0xf800707f, 0xc000302f, [Rd, Rs2, Rs1];
+    AmomaxuD => "amomaxu.d", A, R, 0xf800707f, 0xe000302f, [Rd, Rs2, Rs1];
+    AmominuW => "amominu.w", A, R, 0xf800707f, 0xc000202f, [Rd, Rs2, Rs1];
+    AmomaxuW => "amomaxu.w", A, R, 0xf800707f, 0xe000202f, [Rd, Rs2, Rs1];
+    FmvXD => "fmv.x.d", D, R, 0xfff0707f, 0xe2000053, [Rd, Fs1];
+    FmvDX => "fmv.d.x", D, R, 0xfff0707f, 0xf2000053, [Fd, Rs1];
+    FcvtLS => "fcvt.l.s", F, R, 0xfff0007f, 0xc0200053, [Rd, Fs1];
+    FcvtLUS => "fcvt.lu.s", F, R, 0xfff0007f, 0xc0300053, [Rd, Fs1];
+    FcvtSL => "fcvt.s.l", F, R, 0xfff0007f, 0xd0200053, [Fd, Rs1];
+    FcvtSLU => "fcvt.s.lu", F, R, 0xfff0007f, 0xd0300053, [Fd, Rs1];
+    FcvtLD => "fcvt.l.d", D, R, 0xfff0007f, 0xc2200053, [Rd, Fs1];
+    FcvtLUD => "fcvt.lu.d", D, R, 0xfff0007f, 0xc2300053, [Rd, Fs1];
+    FcvtDL => "fcvt.d.l", D, R, 0xfff0007f, 0xd2200053, [Fd, Rs1];
+    FcvtDLU => "fcvt.d.lu", D, R, 0xfff0007f, 0xd2300053, [Fd, Rs1];
+    Fld => "fld", D, I, 0x0000707f, 0x00003007, [Fd, Imm, Rs1];
+    Fsd => "fsd", D, S, 0x0000707f, 0x00003027, [Fs2, Imm, Rs1];
+    FmaddD => "fmadd.d", D, R4, 0x0600007f, 0x02000043, [Fd, Fs1, Fs2, Fs3];
+    FmsubD => "fmsub.d", D, R4, 0x0600007f, 0x02000047, [Fd, Fs1, Fs2, Fs3];
+    FnmsubD => "fnmsub.d", D, R4, 0x0600007f, 0x0200004b, [Fd, Fs1, Fs2, Fs3];
+    FnmaddD => "fnmadd.d", D, R4, 0x0600007f, 0x0200004f, [Fd, Fs1, Fs2, Fs3];
+    FaddQ => "fadd.q", Q, R, 0xfe00007f, 0x06000053, [Fd, Fs1, Fs2];
+    FsubQ => "fsub.q", Q, R, 0xfe00007f, 0x0e000053, [Fd, Fs1, Fs2];
+    FmulQ => "fmul.q", Q, R, 0xfe00007f, 0x16000053, [Fd, Fs1, Fs2];
+    FdivQ => "fdiv.q", Q, R, 0xfe00007f, 0x1e000053, [Fd, Fs1, Fs2];
+    FsqrtQ => "fsqrt.q", Q, R, 0xfff0007f, 0x5e000053, [Fd, Fs1];
+    FsgnjQ => "fsgnj.q", Q, R, 0xfe00707f, 0x26000053, [Fd, Fs1, Fs2];
+    FsgnjnQ => "fsgnjn.q", Q, R, 0xfe00707f, 0x26001053, [Fd, Fs1, Fs2];
+    FsgnjxQ => "fsgnjx.q", Q, R, 0xfe00707f, 0x26002053, [Fd, Fs1, Fs2];
+    FminQ => "fmin.q", Q, R, 0xfe00707f, 0x2e000053, [Fd, Fs1, Fs2];
+    FmaxQ => "fmax.q", Q, R, 0xfe00707f, 0x2e001053, [Fd, Fs1, Fs2];
+    FcvtSQ => "fcvt.s.q", Q, R, 0xfff0007f, 0x40300053, [Fd, Fs1];
+    FcvtQS => "fcvt.q.s", Q, R, 0xfff0007f, 0x46000053, [Fd, Fs1];
+    FcvtDQ => "fcvt.d.q", Q, R, 0xfff0007f, 0x42300053, [Fd, Fs1];
+    FcvtQD => "fcvt.q.d", Q, R, 0xfff0007f, 0x46100053, [Fd, Fs1];
+    FeqQ => "feq.q", Q, R, 0xfe00707f, 0xa6002053, [Rd, Fs1, Fs2];
+    FltQ => "flt.q", Q, R, 0xfe00707f, 0xa6001053, [Rd, Fs1, Fs2];
+    FleQ => "fle.q", Q, R, 0xfe00707f, 0xa6000053, [Rd, Fs1, Fs2];
+    FClassQ => "fclass.q", Q, R, 0xfff0707f, 0xe6001053, [Rd, Fs1];
+    FcvtWQ => "fcvt.w.q", Q, R, 0xfff0007f, 0xc6000053, [Rd, Fs1];
+    FcvtWUQ => "fcvt.wu.q", Q, R, 0xfff0007f, 0xc6100053, [Rd, Fs1];
+    FcvtLQ => "fcvt.l.q", Q, R, 0xfff0007f, 0xc6200053, [Rd, Fs1];
+    FcvtLUQ => "fcvt.lu.q", Q, R, 0xfff0007f, 0xc6300053, [Rd, Fs1];
+    FcvtQW => "fcvt.q.w", Q, R, 0xfff0007f, 0xd6000053, [Fd, Rs1];
+    FcvtQWU => "fcvt.q.wu", Q, R, 0xfff0007f, 0xd6100053, [Fd, Rs1];
+    FcvtQL => "fcvt.q.l", Q, R, 0xfff0007f, 0xd6200053, [Fd, Rs1];
+    FcvtQLU => "fcvt.q.lu", Q, R, 0xfff0007f, 0xd6300053, [Fd, Rs1];
+    FmaddQ => "fmadd.q", Q, R4, 0x0600007f, 0x06000043, [Fd, Fs1, Fs2, Fs3];
+    FmsubQ => "fmsub.q", Q, R4, 0x0600007f, 0x06000047, [Fd, Fs1, Fs2, Fs3];
+    FnmsubQ => "fnmsub.q", Q, R4, 0x0600007f, 0x0600004b, [Fd, Fs1, Fs2, Fs3];
+    FnmaddQ => "fnmadd.q", Q, R4, 0x0600007f, 0x0600004f, [Fd, Fs1, Fs2, Fs3];
+    Flq => "flq", Q, I, 0x0000707f, 0x00004007, [Fd, Imm, Rs1];
+    Fsq => "fsq", Q, S, 0x0000707f, 0x00004027, [Fs2, Imm, Rs1];
+    FaddH => "fadd.h", Zfh, R, 0xfe00007f, 0x04000053, [Fd, Fs1, Fs2];
+    FsubH => "fsub.h", Zfh, R, 0xfe00007f, 0x0c000053, [Fd, Fs1, Fs2];
+    FmulH => "fmul.h", Zfh, R, 0xfe00007f, 0x14000053, [Fd, Fs1, Fs2];
+    FdivH => "fdiv.h", Zfh, R, 0xfe00007f, 0x1c000053, [Fd, Fs1, Fs2];
+    FsqrtH => "fsqrt.h", Zfh, R, 0xfff0007f, 0x5c000053, [Fd, Fs1];
+    FsgnjH => "fsgnj.h", Zfh, R, 0xfe00707f, 0x24000053, [Fd, Fs1, Fs2];
+    FsgnjnH => "fsgnjn.h", Zfh, R, 0xfe00707f, 0x24001053, [Fd, Fs1, Fs2];
+    FsgnjxH => "fsgnjx.h", Zfh, R, 0xfe00707f, 0x24002053, [Fd, Fs1, Fs2];
+    FminH => "fmin.h", Zfh, R, 0xfe00707f, 0x2c000053, [Fd, Fs1, Fs2];
+    FmaxH => "fmax.h", Zfh, R, 0xfe00707f, 0x2c001053, [Fd, Fs1, Fs2];
+    FcvtSH => "fcvt.s.h", Zfhmin, R, 0xfff0007f, 0x40200053, [Fd, Fs1];
+    FcvtHS => "fcvt.h.s", Zfhmin, R, 0xfff0007f, 0x44000053, [Fd, Fs1];
+    FcvtDH => "fcvt.d.h", Zfhmin, R, 0xfff0007f, 0x42200053, [Fd, Fs1];
+    FcvtHD => "fcvt.h.d", Zfhmin, R, 0xfff0007f, 0x44100053, [Fd, Fs1];
+    FeqH => "feq.h", Zfh, R, 0xfe00707f, 0xa4002053, [Rd, Fs1, Fs2];
+    FltH => "flt.h", Zfh, R, 0xfe00707f, 0xa4001053, [Rd, Fs1, Fs2];
+    FleH => "fle.h", Zfh, R, 0xfe00707f, 0xa4000053, [Rd, Fs1, Fs2];
+    FClassH => "fclass.h", Zfh, R, 0xfff0707f, 0xe4001053, [Rd, Fs1];
+    FcvtWH => "fcvt.w.h", Zfh, R, 0xfff0007f, 0xc4000053, [Rd, Fs1];
+    FcvtWUH => "fcvt.wu.h", Zfh, R, 0xfff0007f, 0xc4100053, [Rd, Fs1];
+    FcvtLH => "fcvt.l.h", Zfh, R, 0xfff0007f, 0xc4200053, [Rd, Fs1];
+    FcvtLUH => "fcvt.lu.h", Zfh, R, 0xfff0007f, 0xc4300053, [Rd, Fs1];
+    FcvtHW => "fcvt.h.w", Zfh, R, 0xfff0007f, 0xd4000053, [Fd, Rs1];
+    FcvtHWU => "fcvt.h.wu", Zfh, R, 0xfff0007f, 0xd4100053, [Fd, Rs1];
+    FcvtHL => "fcvt.h.l", Zfh, R, 0xfff0007f, 0xd4200053, [Fd, Rs1];
+    FcvtHLU => "fcvt.h.lu", Zfh, R, 0xfff0007f, 0xd4300053, [Fd, Rs1];
+    FmvXH => "fmv.x.h", Zfhmin, R, 0xfff0707f, 0xe4000053, [Rd, Fs1];
+    FmvHX => "fmv.h.x", Zfhmin, R, 0xfff0707f, 0xf4000053, [Fd, Rs1];
+    FmaddH => "fmadd.h", Zfh, R4, 0x0600007f, 0x04000043, [Fd, Fs1, Fs2, Fs3];
+    FmsubH => "fmsub.h", Zfh, R4, 0x0600007f, 0x04000047, [Fd, Fs1, Fs2, Fs3];
+    FnmsubH => "fnmsub.h", Zfh, R4, 0x0600007f, 0x0400004b, [Fd, Fs1, Fs2, Fs3];
+    FnmaddH => "fnmadd.h", Zfh, R4, 0x0600007f, 0x0400004f, [Fd, Fs1, Fs2, Fs3];
+    Flh => "flh", Zfhmin, I, 0x0000707f, 0x00001007, [Fd, Imm, Rs1];
+    Fsh => "fsh", Zfhmin, S, 0x0000707f, 0x00001027, [Fs2, Imm, Rs1];
+    FliS => "fli.s", Zfa, R, 0xfff0707f, 0xf0100053, [Fd, Imm];
+    FliD => "fli.d", Zfa, R, 0xfff0707f, 0xf2100053, [Fd, Imm];
+    FminmS => "fminm.s", Zfa, R, 0xfe00707f, 0x28002053, [Fd, Fs1, Fs2];
+    FmaxmS => "fmaxm.s", Zfa, R, 0xfe00707f, 0x28003053, [Fd, Fs1, Fs2];
+    FminmD => "fminm.d", Zfa, R, 0xfe00707f, 0x2a002053, [Fd, Fs1, Fs2];
+    FmaxmD => "fmaxm.d", Zfa, R, 0xfe00707f, 0x2a003053, [Fd, Fs1, Fs2];
+    FroundS => "fround.s", Zfa, R, 0xfff0007f, 0x40400053, [Fd, Fs1];
+    FroundnxS => "froundnx.s", Zfa, R, 0xfff0007f, 0x40500053, [Fd, Fs1];
+    FroundD => "fround.d", Zfa, R, 0xfff0007f, 0x42400053, [Fd, Fs1];
+    FroundnxD => "froundnx.d", Zfa, R, 0xfff0007f, 0x42500053, [Fd, Fs1];
+    FcvtmodWD => "fcvtmod.w.d", Zfa, R, 0xfff0707f, 0xc2801053, [Rd, Fs1];
+    Sh1add => "sh1add", Zba, R, 0xfe00707f, 0x20002033, [Rd, Rs1, Rs2];
+    Sh2add => "sh2add", Zba, R, 0xfe00707f, 0x20004033, [Rd, Rs1, Rs2];
+    Sh3add => "sh3add", Zba, R, 0xfe00707f, 0x20006033, [Rd, Rs1, Rs2];
+    AddUw => "add.uw", Zba, R, 0xfe00707f, 0x0800003b, [Rd, Rs1, Rs2];
+    Sh1addUw => "sh1add.uw", Zba, R, 0xfe00707f, 0x2000203b, [Rd, Rs1, Rs2];
+    Sh2addUw => "sh2add.uw", Zba, R, 0xfe00707f, 0x2000403b, [Rd, Rs1, Rs2];
+    Sh3addUw => "sh3add.uw", Zba, R, 0xfe00707f, 0x2000603b, [Rd, Rs1, Rs2];
+    SlliUw => "slli.uw", Zba, I, 0xfc00707f, 0x0800101b, [Rd, Rs1, Imm];
+    Andn => "andn", Zbb, R, 0xfe00707f, 0x40007033, [Rd, Rs1, Rs2];
+    Orn => "orn", Zbb, R, 0xfe00707f, 0x40006033, [Rd, Rs1, Rs2];
+    Xnor => "xnor", Zbb, R, 0xfe00707f, 0x40004033, [Rd, Rs1, Rs2];
+    Min => "min", Zbb, R, 0xfe00707f, 0x0a004033, [Rd, Rs1, Rs2];
+    Minu => "minu", Zbb, R, 0xfe00707f, 0x0a005033, [Rd, Rs1, Rs2];
+    Max => "max", Zbb, R, 0xfe00707f, 0x0a006033, [Rd, Rs1, Rs2];
+    Maxu => "maxu", Zbb, R, 0xfe00707f, 0x0a007033, [Rd, Rs1, Rs2];
+    Rol => "rol", Zbb, R, 0xfe00707f, 0x60001033, [Rd, Rs1, Rs2];
+    Ror => "ror", Zbb, R, 0xfe00707f, 0x60005033, [Rd, Rs1, Rs2];
+    Clz => "clz", Zbb, I, 0xfff0707f, 0x60001013, [Rd, Rs1];
+    Ctz => "ctz", Zbb, I, 0xfff0707f, 0x60101013, [Rd, Rs1];
+    Cpop => "cpop", Zbb, I, 0xfff0707f, 0x60201013, [Rd, Rs1];
+    SextB => "sext.b", Zbb, I, 0xfff0707f, 0x60401013, [Rd, Rs1];
+    SextH => "sext.h", Zbb, I, 0xfff0707f, 0x60501013, [Rd, Rs1];
+    OrcB => "orc.b", Zbb, I, 0xfff0707f, 0x28705013, [Rd, Rs1];
+    Rori => "rori", Zbb, I, 0xfc00707f, 0x60005013, [Rd, Rs1, Imm];
+    Rolw => "rolw", Zbb, R, 0xfe00707f, 0x6000103b, [Rd, Rs1, Rs2];
+    Rorw => "rorw", Zbb, R, 0xfe00707f, 0x6000503b, [Rd, Rs1, Rs2];
+    Roriw => "roriw", Zbb, I, 0xfe00707f, 0x6000501b, [Rd, Rs1, Imm];
+    Clzw => "clzw", Zbb, I, 0xfff0707f, 0x6000101b, [Rd, Rs1];
+    Ctzw => "ctzw", Zbb, I, 0xfff0707f, 0x6010101b, [Rd, Rs1];
+    Cpopw => "cpopw", Zbb, I, 0xfff0707f, 0x6020101b, [Rd, Rs1];
+    // rev8 and zext.h are encoded differently on rv32, these are the rv64 encodings
+    Rev8 => "rev8", Zbb, I, 0xfff0707f, 0x6b805013, [Rd, Rs1];
+    ZextH => "zext.h", Zbb, R, 0xfff0707f, 0x0800403b, [Rd, Rs1];
+    Clmul => "clmul", Zbc, R, 0xfe00707f, 0x0a001033, [Rd, Rs1, Rs2];
+    Clmulh => "clmulh", Zbc, R, 0xfe00707f, 0x0a003033, [Rd, Rs1, Rs2];
+    Clmulr => "clmulr", Zbc, R, 0xfe00707f, 0x0a002033, [Rd, Rs1, Rs2];
+    Bclr => "bclr", Zbs, R, 0xfe00707f, 0x48001033, [Rd, Rs1, Rs2];
+    Bext => "bext", Zbs, R, 0xfe00707f, 0x48005033, [Rd, Rs1, Rs2];
+    Binv => "binv", Zbs, R, 0xfe00707f, 0x68001033, [Rd, Rs1, Rs2];
+    Bset => "bset", Zbs, R, 0xfe00707f, 0x28001033, [Rd, Rs1, Rs2];
+    Bclri => "bclri", Zbs, I, 0xfc00707f, 0x48001013, [Rd, Rs1, Imm];
+    Bexti => "bexti", Zbs, I, 0xfc00707f, 0x48005013, [Rd, Rs1, Imm];
+    Binvi => "binvi", Zbs, I, 0xfc00707f, 0x68001013, [Rd, Rs1, Imm];
+    Bseti => "bseti", Zbs, I, 0xfc00707f, 0x28001013, [Rd, Rs1, Imm];
+    Pack => "pack", Zbkb, R, 0xfe00707f, 0x08004033, [Rd, Rs1, Rs2];
+    Packh => "packh", Zbkb, R, 0xfe00707f, 0x08007033, [Rd, Rs1, Rs2];
+    Packw => "packw", Zbkb, R, 0xfe00707f, 0x0800403b, [Rd, Rs1, Rs2];
+    Brev8 => "brev8", Zbkb, I, 0xfff0707f, 0x68705013, [Rd, Rs1];
+    Zip => "zip", Zbkb, I, 0xfff0707f, 0x08f01013, [Rd, Rs1];
+    Unzip => "unzip", Zbkb, I, 0xfff0707f, 0x08f05013, [Rd, Rs1];
+    Xperm4 => "xperm4", Zbkx, R, 0xfe00707f, 0x28002033, [Rd, Rs1, Rs2];
+    Xperm8 => "xperm8", Zbkx, R, 0xfe00707f, 0x28004033, [Rd, Rs1, Rs2];
+    Aes32esi => "aes32esi", Zkne, R, 0x3e00707f, 0x22000033, [Rd, Rs1, Rs2, Uimm];
+    Aes32esmi => "aes32esmi", Zkne, R, 0x3e00707f, 0x26000033, [Rd, Rs1, Rs2, Uimm];
+    Aes32dsi => "aes32dsi", Zknd, R, 0x3e00707f, 0x2a000033, [Rd, Rs1, Rs2, Uimm];
+    Aes32dsmi => "aes32dsmi", Zknd, R, 0x3e00707f, 0x2e000033, [Rd, Rs1, Rs2, Uimm];
+    Aes64es => "aes64es", Zkne, R, 0xfe00707f, 0x32000033, [Rd, Rs1, Rs2];
+    Aes64esm => "aes64esm", Zkne, R, 0xfe00707f, 0x36000033, [Rd, Rs1, Rs2];
+    Aes64ds => "aes64ds", Zknd, R, 0xfe00707f, 0x3a000033, [Rd, Rs1, Rs2];
+    Aes64dsm => "aes64dsm", Zknd, R, 0xfe00707f, 0x3e000033, [Rd, Rs1, Rs2];
+    // the key schedule instructions are in both Zknd and Zkne
+    Aes64ks2 => "aes64ks2", Zkne, R, 0xfe00707f, 0x7e000033, [Rd, Rs1, Rs2];
+    Aes64im => "aes64im", Zknd, I, 0xfff0707f, 0x30001013, [Rd, Rs1];
+    Aes64ks1i => "aes64ks1i", Zkne, I, 0xff00707f, 0x31001013, [Rd, Rs1, Uimm];
+    Sha256sum0 => "sha256sum0", Zknh, I, 0xfff0707f, 0x10001013, [Rd, Rs1];
+    Sha256sum1 => "sha256sum1", Zknh, I, 0xfff0707f, 0x10101013, [Rd, Rs1];
+    Sha256sig0 => "sha256sig0", Zknh, I, 0xfff0707f, 0x10201013, [Rd, Rs1];
+    Sha256sig1 => "sha256sig1", Zknh, I, 0xfff0707f, 0x10301013, [Rd, Rs1];
+    Sha512sum0 => "sha512sum0", Zknh, I, 0xfff0707f, 0x10401013, [Rd, Rs1];
+    Sha512sum1 => "sha512sum1", Zknh, I, 0xfff0707f, 0x10501013, [Rd, Rs1];
+    Sha512sig0 => "sha512sig0", Zknh, I, 0xfff0707f, 0x10601013, [Rd, Rs1];
+    Sha512sig1 => "sha512sig1", Zknh, I, 0xfff0707f, 0x10701013, [Rd, Rs1];
+    Sha512sum0r => "sha512sum0r", Zknh, R, 0xfe00707f, 0x50000033, [Rd, Rs1, Rs2];
+    Sha512sum1r => "sha512sum1r", Zknh, R, 0xfe00707f, 0x52000033, [Rd, Rs1, Rs2];
+    Sha512sig0l => "sha512sig0l", Zknh, R, 0xfe00707f, 0x54000033, [Rd, Rs1, Rs2];
+    Sha512sig1l => "sha512sig1l", Zknh, R, 0xfe00707f, 0x56000033, [Rd, Rs1, Rs2];
+    Sha512sig0h => "sha512sig0h", Zknh, R, 0xfe00707f, 0x5c000033, [Rd, Rs1, Rs2];
+    Sha512sig1h => "sha512sig1h", Zknh, R, 0xfe00707f, 0x5e000033, [Rd, Rs1, Rs2];
+    Sm4ed => "sm4ed", Zksed, R, 0x3e00707f, 0x30000033, [Rd, Rs1, Rs2, Uimm];
+    Sm4ks => "sm4ks", Zksed, R, 0x3e00707f, 0x34000033, [Rd, Rs1, Rs2, Uimm];
+    Sm3p0 => "sm3p0", Zksh, I, 0xfff0707f, 0x10801013, [Rd, Rs1];
+    Sm3p1 => "sm3p1", Zksh, I, 0xfff0707f, 0x10901013, [Rd, Rs1];
+    Vsetvli => "vsetvli", V, OpV, 0x8000707f, 0x00007057, [Rd, Rs1, Imm];
+    Vsetivli => "vsetivli", V, OpV, 0xc000707f, 0xc0007057, [Rd, Uimm, Imm];
+    Vsetvl => "vsetvl", V, OpV, 0xfe00707f, 0x80007057, [Rd, Rs1, Rs2];
+    Vle8V => "vle8.v", V, OpV, 0x1df0707f, 0x00000007, [Vd, Rs1, Vm];
+    Vle16V => "vle16.v", V, OpV, 0x1df0707f, 0x00005007, [Vd, Rs1, Vm];
+    Vle32V => "vle32.v", V, OpV, 0x1df0707f, 0x00006007, [Vd, Rs1, Vm];
+    Vle64V => "vle64.v", V, OpV, 0x1df0707f, 0x00007007, [Vd, Rs1, Vm];
+    Vse8V => "vse8.v", V, OpV, 0x1df0707f, 0x00000027, [Vd, Rs1, Vm];
+    Vse16V => "vse16.v", V, OpV, 0x1df0707f, 0x00005027, [Vd, Rs1, Vm];
+    Vse32V => "vse32.v", V, OpV, 0x1df0707f, 0x00006027, [Vd, Rs1, Vm];
+    Vse64V => "vse64.v", V, OpV, 0x1df0707f, 0x00007027, [Vd, Rs1, Vm];
+    Vlse8V => "vlse8.v", V, OpV, 0x1c00707f, 0x08000007, [Vd, Rs1, Rs2, Vm];
+    Vlse16V => "vlse16.v", V, OpV, 0x1c00707f, 0x08005007, [Vd, Rs1, Rs2, Vm];
+    Vlse32V => "vlse32.v", V, OpV, 0x1c00707f, 0x08006007, [Vd, Rs1, Rs2, Vm];
+    Vlse64V => "vlse64.v", V, OpV, 0x1c00707f, 0x08007007, [Vd, Rs1, Rs2, Vm];
+    Vsse8V => "vsse8.v", V, OpV, 0x1c00707f, 0x08000027, [Vd, Rs1, Rs2, Vm];
+    Vsse16V => "vsse16.v", V, OpV, 0x1c00707f, 0x08005027, [Vd, Rs1, Rs2, Vm];
+    Vsse32V => "vsse32.v", V, OpV, 0x1c00707f, 0x08006027, [Vd, Rs1, Rs2, Vm];
+    Vsse64V => "vsse64.v", V, OpV, 0x1c00707f, 0x08007027, [Vd, Rs1, Rs2, Vm];
+    Vluxei8V => "vluxei8.v", V, OpV, 0x1c00707f, 0x04000007, [Vd, Rs1, Vs2, Vm];
+    Vluxei16V => "vluxei16.v", V, OpV, 0x1c00707f, 0x04005007, [Vd, Rs1, Vs2, Vm];
+    Vluxei32V => "vluxei32.v", V, OpV, 0x1c00707f, 0x04006007, [Vd, Rs1, Vs2, Vm];
+    Vluxei64V => "vluxei64.v", V, OpV, 0x1c00707f, 0x04007007, [Vd, Rs1, Vs2, Vm];
+    Vloxei8V => "vloxei8.v", V, OpV, 0x1c00707f, 0x0c000007, [Vd, Rs1, Vs2, Vm];
+    Vloxei16V => "vloxei16.v", V, OpV, 0x1c00707f, 0x0c005007, [Vd, Rs1, Vs2, Vm];
+    Vloxei32V => "vloxei32.v", V, OpV, 0x1c00707f, 0x0c006007, [Vd, Rs1, Vs2, Vm];
+    Vloxei64V => "vloxei64.v", V, OpV, 0x1c00707f, 0x0c007007, [Vd, Rs1, Vs2, Vm];
+    Vsuxei8V => "vsuxei8.v", V, OpV, 0x1c00707f, 0x04000027, [Vd, Rs1, Vs2, Vm];
+    Vsuxei16V => "vsuxei16.v", V, OpV, 0x1c00707f, 0x04005027, [Vd, Rs1, Vs2, Vm];
+    Vsuxei32V => "vsuxei32.v", V, OpV, 0x1c00707f, 0x04006027, [Vd, Rs1, Vs2, Vm];
+    Vsuxei64V => "vsuxei64.v", V, OpV, 0x1c00707f, 0x04007027, [Vd, Rs1, Vs2, Vm];
+    Vsoxei8V => "vsoxei8.v", V, OpV, 0x1c00707f, 0x0c000027, [Vd, Rs1, Vs2, Vm];
+    Vsoxei16V => "vsoxei16.v", V, OpV, 0x1c00707f, 0x0c005027, [Vd, Rs1, Vs2, Vm];
+    Vsoxei32V => "vsoxei32.v", V, OpV, 0x1c00707f, 0x0c006027, [Vd, Rs1, Vs2, Vm];
+    Vsoxei64V => "vsoxei64.v", V, OpV, 0x1c00707f, 0x0c007027, [Vd, Rs1, Vs2, Vm];
+    VredsumVs => "vredsum.vs", V, OpV, 0xfc00707f, 0x00002057, [Vd, Vs2, Vs1, Vm];
+    VredandVs => "vredand.vs", V, OpV, 0xfc00707f, 0x04002057, [Vd, Vs2, Vs1, Vm];
+    VredorVs => "vredor.vs", V, OpV, 0xfc00707f, 0x08002057, [Vd, Vs2, Vs1, Vm];
+    VredxorVs => "vredxor.vs", V, OpV, 0xfc00707f, 0x0c002057, [Vd, Vs2, Vs1, Vm];
+    VredminuVs => "vredminu.vs", V, OpV, 0xfc00707f, 0x10002057, [Vd, Vs2, Vs1, Vm];
+    VredminVs => "vredmin.vs", V, OpV, 0xfc00707f, 0x14002057, [Vd, Vs2, Vs1, Vm];
+    VredmaxuVs => "vredmaxu.vs", V, OpV, 0xfc00707f, 0x18002057, [Vd, Vs2, Vs1, Vm];
+    VredmaxVs => "vredmax.vs", V, OpV, 0xfc00707f, 0x1c002057, [Vd, Vs2, Vs1, Vm];
+    VaadduVv => "vaaddu.vv", V, OpV, 0xfc00707f, 0x20002057, [Vd, Vs2, Vs1, Vm];
+    VaadduVx => "vaaddu.vx", V, OpV, 0xfc00707f, 0x20006057, [Vd, Vs2, Rs1, Vm];
+    VaaddVv => "vaadd.vv", V, OpV, 0xfc00707f, 0x24002057, [Vd, Vs2, Vs1, Vm];
+    VaaddVx => "vaadd.vx", V, OpV, 0xfc00707f, 0x24006057, [Vd, Vs2, Rs1, Vm];
+    VasubuVv => "vasubu.vv", V, OpV, 0xfc00707f, 0x28002057, [Vd, Vs2, Vs1, Vm];
+    VasubuVx => "vasubu.vx", V, OpV, 0xfc00707f, 0x28006057, [Vd, Vs2, Rs1, Vm];
+    VasubVv => "vasub.vv", V, OpV, 0xfc00707f, 0x2c002057, [Vd, Vs2, Vs1, Vm];
+    VasubVx => "vasub.vx", V, OpV, 0xfc00707f, 0x2c006057, [Vd, Vs2, Rs1, Vm];
+    Vslide1upVx => "vslide1up.vx", V, OpV, 0xfc00707f, 0x38006057, [Vd, Vs2, Rs1, Vm];
+    Vslide1downVx => "vslide1down.vx", V, OpV, 0xfc00707f, 0x3c006057, [Vd, Vs2, Rs1, Vm];
+    VdivuVv => "vdivu.vv", V, OpV, 0xfc00707f, 0x80002057, [Vd, Vs2, Vs1, Vm];
+    VdivuVx => "vdivu.vx", V, OpV, 0xfc00707f, 0x80006057, [Vd, Vs2, Rs1, Vm];
+    VdivVv => "vdiv.vv", V, OpV, 0xfc00707f, 0x84002057, [Vd, Vs2, Vs1, Vm];
+    VdivVx => "vdiv.vx", V, OpV, 0xfc00707f, 0x84006057, [Vd, Vs2, Rs1, Vm];
+    VremuVv => "vremu.vv", V, OpV, 0xfc00707f, 0x88002057, [Vd, Vs2, Vs1, Vm];
+    VremuVx => "vremu.vx", V, OpV, 0xfc00707f, 0x88006057, [Vd, Vs2, Rs1, Vm];
+    VremVv => "vrem.vv", V, OpV, 0xfc00707f, 0x8c002057, [Vd, Vs2, Vs1, Vm];
+    VremVx => "vrem.vx", V, OpV, 0xfc00707f, 0x8c006057, [Vd, Vs2, Rs1, Vm];
+    VmulhuVv => "vmulhu.vv", V, OpV, 0xfc00707f, 0x90002057, [Vd, Vs2, Vs1, Vm];
+    VmulhuVx => "vmulhu.vx", V, OpV, 0xfc00707f, 0x90006057, [Vd, Vs2, Rs1, Vm];
+    VmulVv => "vmul.vv", V, OpV, 0xfc00707f, 0x94002057, [Vd, Vs2, Vs1, Vm];
+    VmulVx => "vmul.vx", V, OpV, 0xfc00707f, 0x94006057, [Vd, Vs2, Rs1, Vm];
+    VmulhsuVv => "vmulhsu.vv", V, OpV, 0xfc00707f, 0x98002057, [Vd, Vs2, Vs1, Vm];
+    VmulhsuVx => "vmulhsu.vx", V, OpV, 0xfc00707f, 0x98006057, [Vd, Vs2, Rs1, Vm];
+    VmulhVv => "vmulh.vv", V, OpV, 0xfc00707f, 0x9c002057, [Vd, Vs2, Vs1, Vm];
+    VmulhVx => "vmulh.vx", V, OpV, 0xfc00707f, 0x9c006057, [Vd, Vs2, Rs1, Vm];
+    VmaddVv => "vmadd.vv", V, OpV, 0xfc00707f, 0xa4002057, [Vd, Vs1, Vs2, Vm];
+    VmaddVx => "vmadd.vx", V, OpV, 0xfc00707f, 0xa4006057, [Vd, Rs1, Vs2, Vm];
+    VnmsubVv => "vnmsub.vv", V, OpV, 0xfc00707f, 0xac002057, [Vd, Vs1, Vs2, Vm];
+    VnmsubVx => "vnmsub.vx", V, OpV, 0xfc00707f, 0xac006057, [Vd, Rs1, Vs2, Vm];
+    VmaccVv => "vmacc.vv", V, OpV, 0xfc00707f, 0xb4002057, [Vd, Vs1, Vs2, Vm];
+    VmaccVx => "vmacc.vx", V, OpV, 0xfc00707f, 0xb4006057, [Vd, Rs1, Vs2, Vm];
+    VnmsacVv => "vnmsac.vv", V, OpV, 0xfc00707f, 0xbc002057, [Vd, Vs1, Vs2, Vm];
+    VnmsacVx => "vnmsac.vx", V, OpV, 0xfc00707f, 0xbc006057, [Vd, Rs1, Vs2, Vm];
+    VwadduVv => "vwaddu.vv", V, OpV, 0xfc00707f, 0xc0002057, [Vd, Vs2, Vs1, Vm];
+    VwadduVx => "vwaddu.vx", V, OpV, 0xfc00707f, 0xc0006057, [Vd, Vs2, Rs1, Vm];
+    VwaddVv => "vwadd.vv", V, OpV, 0xfc00707f, 0xc4002057, [Vd, Vs2, Vs1, Vm];
+    VwaddVx => "vwadd.vx", V, OpV, 0xfc00707f, 0xc4006057, [Vd, Vs2, Rs1, Vm];
+    VwsubuVv => "vwsubu.vv", V, OpV, 0xfc00707f, 0xc8002057, [Vd, Vs2, Vs1, Vm];
+    VwsubuVx => "vwsubu.vx", V, OpV, 0xfc00707f, 0xc8006057, [Vd, Vs2, Rs1, Vm];
+    VwsubVv => "vwsub.vv", V, OpV, 0xfc00707f, 0xcc002057, [Vd, Vs2, Vs1, Vm];
+    VwsubVx => "vwsub.vx", V, OpV, 0xfc00707f, 0xcc006057, [Vd, Vs2, Rs1, Vm];
+    VwadduWv => "vwaddu.wv", V, OpV, 0xfc00707f, 0xd0002057, [Vd, Vs2, Vs1, Vm];
+    VwadduWx => "vwaddu.wx", V, OpV, 0xfc00707f, 0xd0006057, [Vd, Vs2, Rs1, Vm];
+    VwaddWv => "vwadd.wv", V, OpV, 0xfc00707f, 0xd4002057, [Vd, Vs2, Vs1, Vm];
+    VwaddWx => "vwadd.wx", V, OpV, 0xfc00707f, 0xd4006057, [Vd, Vs2, Rs1, Vm];
+    VwsubuWv => "vwsubu.wv", V, OpV, 0xfc00707f, 0xd8002057, [Vd, Vs2, Vs1, Vm];
+    VwsubuWx => "vwsubu.wx", V, OpV, 0xfc00707f, 0xd8006057, [Vd, Vs2, Rs1, Vm];
+    VwsubWv => "vwsub.wv", V, OpV, 0xfc00707f, 0xdc002057, [Vd, Vs2, Vs1, Vm];
+    VwsubWx => "vwsub.wx", V, OpV, 0xfc00707f, 0xdc006057, [Vd, Vs2, Rs1, Vm];
+    VwmuluVv => "vwmulu.vv", V, OpV, 0xfc00707f, 0xe0002057, [Vd, Vs2, Vs1, Vm];
+    VwmuluVx => "vwmulu.vx", V, OpV, 0xfc00707f, 0xe0006057, [Vd, Vs2, Rs1, Vm];
+    VwmulsuVv => "vwmulsu.vv", V, OpV, 0xfc00707f, 0xe8002057, [Vd, Vs2, Vs1, Vm];
+    VwmulsuVx => "vwmulsu.vx", V, OpV, 0xfc00707f, 0xe8006057, [Vd, Vs2, Rs1, Vm];
+    VwmulVv => "vwmul.vv", V, OpV, 0xfc00707f, 0xec002057, [Vd, Vs2, Vs1, Vm];
+    VwmulVx => "vwmul.vx", V, OpV, 0xfc00707f, 0xec006057, [Vd, Vs2, Rs1, Vm];
+    VwmaccuVv => "vwmaccu.vv", V, OpV, 0xfc00707f, 0xf0002057, [Vd, Vs1, Vs2, Vm];
+    VwmaccuVx => "vwmaccu.vx", V, OpV, 0xfc00707f, 0xf0006057, [Vd, Rs1, Vs2, Vm];
+    VwmaccVv => "vwmacc.vv", V, OpV, 0xfc00707f, 0xf4002057, [Vd, Vs1, Vs2, Vm];
+    VwmaccVx => "vwmacc.vx", V, OpV, 0xfc00707f, 0xf4006057, [Vd, Rs1, Vs2, Vm];
+    VwmaccusVx => "vwmaccus.vx", V, OpV, 0xfc00707f, 0xf8006057, [Vd, Rs1, Vs2, Vm];
+    VwmaccsuVv => "vwmaccsu.vv", V, OpV, 0xfc00707f, 0xfc002057, [Vd, Vs1, Vs2, Vm];
+    VwmaccsuVx => "vwmaccsu.vx", V, OpV, 0xfc00707f, 0xfc006057, [Vd, Rs1, Vs2, Vm];
+    VmvXS => "vmv.x.s", V, OpV, 0xfe0ff07f, 0x42002057, [Rd, Vs2];
+    VmvSX => "vmv.s.x", V, OpV, 0xfff0707f, 0x42006057, [Vd, Rs1];
+    VzextVf8 => "vzext.vf8", V, OpV, 0xfc0ff07f, 0x48012057, [Vd, Vs2, Vm];
+    VsextVf8 => "vsext.vf8", V, OpV, 0xfc0ff07f, 0x4801a057, [Vd, Vs2, Vm];
+    VzextVf4 => "vzext.vf4", V, OpV, 0xfc0ff07f, 0x48022057, [Vd, Vs2, Vm];
+    VsextVf4 => "vsext.vf4", V, OpV, 0xfc0ff07f, 0x4802a057, [Vd, Vs2, Vm];
+    VzextVf2 => "vzext.vf2", V, OpV, 0xfc0ff07f, 0x48032057, [Vd, Vs2, Vm];
+    VsextVf2 => "vsext.vf2", V, OpV, 0xfc0ff07f, 0x4803a057, [Vd, Vs2, Vm];
+    VcompressVm => "vcompress.vm", V, OpV, 0xfe00707f, 0x5e002057, [Vd, Vs2, Vs1];
+    VfaddVv => "vfadd.vv", V, OpV, 0xfc00707f, 0x00001057, [Vd, Vs2, Vs1, Vm];
+    VfaddVf => "vfadd.vf", V, OpV, 0xfc00707f, 0x00005057, [Vd, Vs2, Rs1, Vm];
+    VfredusumVs => "vfredusum.vs", V, OpV, 0xfc00707f, 0x04001057, [Vd, Vs2, Vs1, Vm];
+    VfsubVv => "vfsub.vv", V, OpV, 0xfc00707f, 0x08001057, [Vd, Vs2, Vs1, Vm];
+    VfsubVf => "vfsub.vf", V, OpV, 0xfc00707f, 0x08005057, [Vd, Vs2, Rs1, Vm];
+    VfredosumVs => "vfredosum.vs", V, OpV, 0xfc00707f, 0x0c001057, [Vd, Vs2, Vs1, Vm];
+    VfminVv => "vfmin.vv", V, OpV, 0xfc00707f, 0x10001057, [Vd, Vs2, Vs1, Vm];
+    VfminVf => "vfmin.vf", V, OpV, 0xfc00707f, 0x10005057, [Vd, Vs2, Rs1, Vm];
+    VfredminVs => "vfredmin.vs", V, OpV, 0xfc00707f, 0x14001057, [Vd, Vs2, Vs1, Vm];
+    VfmaxVv => "vfmax.vv", V, OpV, 0xfc00707f, 0x18001057, [Vd, Vs2, Vs1, Vm];
+    VfmaxVf => "vfmax.vf", V, OpV, 0xfc00707f, 0x18005057, [Vd, Vs2, Rs1, Vm];
+    VfredmaxVs => "vfredmax.vs", V, OpV, 0xfc00707f, 0x1c001057, [Vd, Vs2, Vs1, Vm];
+    VfsgnjVv => "vfsgnj.vv", V, OpV, 0xfc00707f, 0x20001057, [Vd, Vs2, Vs1, Vm];
+    VfsgnjVf => "vfsgnj.vf", V, OpV, 0xfc00707f, 0x20005057, [Vd, Vs2, Rs1, Vm];
+    VfsgnjnVv => "vfsgnjn.vv", V, OpV, 0xfc00707f, 0x24001057, [Vd, Vs2, Vs1, Vm];
+    VfsgnjnVf => "vfsgnjn.vf", V, OpV, 0xfc00707f, 0x24005057, [Vd, Vs2, Rs1, Vm];
+    VfsgnjxVv => "vfsgnjx.vv", V, OpV, 0xfc00707f, 0x28001057, [Vd, Vs2, Vs1, Vm];
+    VfsgnjxVf => "vfsgnjx.vf", V, OpV, 0xfc00707f, 0x28005057, [Vd, Vs2, Rs1, Vm];
+    Vfslide1upVf => "vfslide1up.vf", V, OpV, 0xfc00707f, 0x38005057, [Vd, Vs2, Rs1, Vm];
+    Vfslide1downVf => "vfslide1down.vf", V, OpV, 0xfc00707f, 0x3c005057, [Vd, Vs2, Rs1, Vm];
+    VmfeqVv => "vmfeq.vv", V, OpV, 0xfc00707f, 0x60001057, [Vd, Vs2, Vs1, Vm];
+    VmfeqVf => "vmfeq.vf", V, OpV, 0xfc00707f, 0x60005057, [Vd, Vs2, Rs1, Vm];
+    VmfleVv => "vmfle.vv", V, OpV, 0xfc00707f, 0x64001057, [Vd, Vs2, Vs1, Vm];
+    VmfleVf => "vmfle.vf", V, OpV, 0xfc00707f, 0x64005057, [Vd, Vs2, Rs1, Vm];
+    VmfltVv => "vmflt.vv", V, OpV, 0xfc00707f, 0x6c001057, [Vd, Vs2, Vs1, Vm];
+    VmfltVf => "vmflt.vf", V, OpV, 0xfc00707f, 0x6c005057, [Vd, Vs2, Rs1, Vm];
+    VmfneVv => "vmfne.vv", V, OpV, 0xfc00707f, 0x70001057, [Vd, Vs2, Vs1, Vm];
+    VmfneVf => "vmfne.vf", V, OpV, 0xfc00707f, 0x70005057, [Vd, Vs2, Rs1, Vm];
+    VmfgtVf => "vmfgt.vf", V, OpV, 0xfc00707f, 0x74005057, [Vd, Vs2, Rs1, Vm];
+    VmfgeVf => "vmfge.vf", V, OpV, 0xfc00707f, 0x7c005057, [Vd, Vs2, Rs1, Vm];
+    VfdivVv => "vfdiv.vv", V, OpV, 0xfc00707f, 0x80001057, [Vd, Vs2, Vs1, Vm];
+    VfdivVf => "vfdiv.vf", V, OpV, 0xfc00707f, 0x80005057, [Vd, Vs2, Rs1, Vm];
+    VfrdivVf => "vfrdiv.vf", V, OpV, 0xfc00707f, 0x84005057, [Vd, Vs2, Rs1, Vm];
+    VfmulVv => "vfmul.vv", V, OpV, 0xfc00707f, 0x90001057, [Vd, Vs2, Vs1, Vm];
+    VfmulVf => "vfmul.vf", V, OpV, 0xfc00707f, 0x90005057, [Vd, Vs2, Rs1, Vm];
+    VfrsubVf => "vfrsub.vf", V, OpV, 0xfc00707f, 0x9c005057, [Vd, Vs2, Rs1, Vm];
+    VfmaddVv => "vfmadd.vv", V, OpV, 0xfc00707f, 0xa0001057, [Vd, Vs1, Vs2, Vm];
+    VfmaddVf => "vfmadd.vf", V, OpV, 0xfc00707f, 0xa0005057, [Vd, Rs1, Vs2, Vm];
+    VfnmaddVv => "vfnmadd.vv", V, OpV, 0xfc00707f, 0xa4001057, [Vd, Vs1, Vs2, Vm];
+    VfnmaddVf => "vfnmadd.vf", V, OpV, 0xfc00707f, 0xa4005057, [Vd, Rs1, Vs2, Vm];
+    VfmsubVv => "vfmsub.vv", V, OpV, 0xfc00707f, 0xa8001057, [Vd, Vs1, Vs2, Vm];
+    VfmsubVf => "vfmsub.vf", V, OpV, 0xfc00707f, 0xa8005057, [Vd, Rs1, Vs2, Vm];
+    VfnmsubVv => "vfnmsub.vv", V, OpV, 0xfc00707f, 0xac001057, [Vd, Vs1, Vs2, Vm];
+    VfnmsubVf => "vfnmsub.vf", V, OpV, 0xfc00707f, 0xac005057, [Vd, Rs1, Vs2, Vm];
+    VfmaccVv => "vfmacc.vv", V, OpV, 0xfc00707f, 0xb0001057, [Vd, Vs1, Vs2, Vm];
+    VfmaccVf => "vfmacc.vf", V, OpV, 0xfc00707f, 0xb0005057, [Vd, Rs1, Vs2, Vm];
+    VfnmaccVv => "vfnmacc.vv", V, OpV, 0xfc00707f, 0xb4001057, [Vd, Vs1, Vs2, Vm];
+    VfnmaccVf => "vfnmacc.vf", V, OpV, 0xfc00707f, 0xb4005057, [Vd, Rs1, Vs2, Vm];
+    VfmsacVv => "vfmsac.vv", V, OpV, 0xfc00707f, 0xb8001057, [Vd, Vs1, Vs2, Vm];
+    VfmsacVf => "vfmsac.vf", V, OpV, 0xfc00707f, 0xb8005057, [Vd, Rs1, Vs2, Vm];
+    VfnmsacVv => "vfnmsac.vv", V, OpV, 0xfc00707f, 0xbc001057, [Vd, Vs1, Vs2, Vm];
+    VfnmsacVf => "vfnmsac.vf", V, OpV, 0xfc00707f, 0xbc005057, [Vd, Rs1, Vs2, Vm];
+    VfwaddVv => "vfwadd.vv", V, OpV, 0xfc00707f, 0xc0001057, [Vd, Vs2, Vs1, Vm];
+    VfwaddVf => "vfwadd.vf", V, OpV, 0xfc00707f, 0xc0005057, [Vd, Vs2, Rs1, Vm];
+    VfwredusumVs => "vfwredusum.vs", V, OpV, 0xfc00707f, 0xc4001057, [Vd, Vs2, Vs1, Vm];
+    VfwsubVv => "vfwsub.vv", V, OpV, 0xfc00707f, 0xc8001057, [Vd, Vs2, Vs1, Vm];
+    VfwsubVf => "vfwsub.vf", V, OpV, 0xfc00707f, 0xc8005057, [Vd, Vs2, Rs1, Vm];
+    VfwredosumVs => "vfwredosum.vs", V, OpV, 0xfc00707f, 0xcc001057, [Vd, Vs2, Vs1, Vm];
+    VfwaddWv => "vfwadd.wv", V, OpV, 0xfc00707f, 0xd0001057, [Vd, Vs2, Vs1, Vm];
+    VfwaddWf => "vfwadd.wf", V, OpV, 0xfc00707f, 0xd0005057, [Vd, Vs2, Rs1, Vm];
+    VfwsubWv => "vfwsub.wv", V, OpV, 0xfc00707f, 0xd8001057, [Vd, Vs2, Vs1, Vm];
+    VfwsubWf => "vfwsub.wf", V, OpV, 0xfc00707f, 0xd8005057, [Vd, Vs2, Rs1, Vm];
+    VfwmulVv => "vfwmul.vv", V, OpV, 0xfc00707f, 0xe0001057, [Vd, Vs2, Vs1, Vm];
+    VfwmulVf => "vfwmul.vf", V, OpV, 0xfc00707f, 0xe0005057, [Vd, Vs2, Rs1, Vm];
+    VfwmaccVv => "vfwmacc.vv", V, OpV, 0xfc00707f, 0xf0001057, [Vd, Vs1, Vs2, Vm];
+    VfwmaccVf => "vfwmacc.vf", V, OpV, 0xfc00707f, 0xf0005057, [Vd, Rs1, Vs2, Vm];
+    VfwnmaccVv => "vfwnmacc.vv", V, OpV, 0xfc00707f, 0xf4001057, [Vd, Vs1, Vs2, Vm];
+    VfwnmaccVf => "vfwnmacc.vf", V, OpV, 0xfc00707f, 0xf4005057, [Vd, Rs1, Vs2, Vm];
+    VfwmsacVv => "vfwmsac.vv", V, OpV, 0xfc00707f, 0xf8001057, [Vd, Vs1, Vs2, Vm];
+    VfwmsacVf => "vfwmsac.vf", V, OpV, 0xfc00707f, 0xf8005057, [Vd, Rs1, Vs2, Vm];
+    VfwnmsacVv => "vfwnmsac.vv", V, OpV, 0xfc00707f, 0xfc001057, [Vd, Vs1, Vs2, Vm];
+    VfwnmsacVf => "vfwnmsac.vf", V, OpV, 0xfc00707f, 0xfc005057, [Vd, Rs1, Vs2, Vm];
+    VfcvtXuFV => "vfcvt.xu.f.v", V, OpV, 0xfc0ff07f, 0x48001057, [Vd, Vs2, Vm];
+    VfcvtXFV => "vfcvt.x.f.v", V, OpV, 0xfc0ff07f, 0x48009057, [Vd, Vs2, Vm];
+    VfcvtFXuV => "vfcvt.f.xu.v", V, OpV, 0xfc0ff07f, 0x48011057, [Vd, Vs2, Vm];
+    VfcvtFXV => "vfcvt.f.x.v", V, OpV, 0xfc0ff07f, 0x48019057, [Vd, Vs2, Vm];
+    VfcvtRtzXuFV => "vfcvt.rtz.xu.f.v", V, OpV, 0xfc0ff07f, 0x48031057, [Vd, Vs2, Vm];
+    VfcvtRtzXFV => "vfcvt.rtz.x.f.v", V, OpV, 0xfc0ff07f, 0x48039057, [Vd, Vs2, Vm];
+    VfwcvtXuFV => "vfwcvt.xu.f.v", V, OpV, 0xfc0ff07f, 0x48041057, [Vd, Vs2, Vm];
+    VfwcvtXFV => "vfwcvt.x.f.v", V, OpV, 0xfc0ff07f, 0x48049057, [Vd, Vs2, Vm];
+    VfwcvtFXuV => "vfwcvt.f.xu.v", V, OpV, 0xfc0ff07f, 0x48051057, [Vd, Vs2, Vm];
+    VfwcvtFXV => "vfwcvt.f.x.v", V, OpV, 0xfc0ff07f, 0x48059057, [Vd, Vs2, Vm];
+    VfwcvtFFV => "vfwcvt.f.f.v", V, OpV, 0xfc0ff07f, 0x48061057, [Vd, Vs2, Vm];
+    VfwcvtRtzXuFV => "vfwcvt.rtz.xu.f.v", V, OpV, 0xfc0ff07f, 0x48071057, [Vd, Vs2, Vm];
+    VfwcvtRtzXFV => "vfwcvt.rtz.x.f.v", V, OpV, 0xfc0ff07f, 0x48079057, [Vd, Vs2, Vm];
+    VfncvtXuFW => "vfncvt.xu.f.w", V, OpV, 0xfc0ff07f, 0x48081057, [Vd, Vs2, Vm];
+    VfncvtXFW => "vfncvt.x.f.w", V, OpV, 0xfc0ff07f, 0x48089057, [Vd, Vs2, Vm];
+    VfncvtFXuW => "vfncvt.f.xu.w", V, OpV, 0xfc0ff07f, 0x48091057, [Vd, Vs2, Vm];
+    VfncvtFXW => "vfncvt.f.x.w", V, OpV, 0xfc0ff07f, 0x48099057, [Vd, Vs2, Vm];
+    VfncvtFFW => "vfncvt.f.f.w", V, OpV, 0xfc0ff07f, 0x480a1057, [Vd, Vs2, Vm];
+    VfncvtRodFFW => "vfncvt.rod.f.f.w", V, OpV, 0xfc0ff07f, 0x480a9057, [Vd, Vs2, Vm];
+    VfncvtRtzXuFW => "vfncvt.rtz.xu.f.w", V, OpV, 0xfc0ff07f, 0x480b1057, [Vd, Vs2, Vm];
+    VfncvtRtzXFW => "vfncvt.rtz.x.f.w", V, OpV, 0xfc0ff07f, 0x480b9057, [Vd, Vs2, Vm];
+    VfsqrtV => "vfsqrt.v", V, OpV, 0xfc0ff07f, 0x4c001057, [Vd, Vs2, Vm];
+    Vfrsqrt7V => "vfrsqrt7.v", V, OpV, 0xfc0ff07f, 0x4c021057, [Vd, Vs2, Vm];
+    Vfrec7V => "vfrec7.v", V, OpV, 0xfc0ff07f, 0x4c029057, [Vd, Vs2, Vm];
+    VfclassV => "vfclass.v", V, OpV, 0xfc0ff07f, 0x4c081057, [Vd, Vs2, Vm];
+    VfmvFS => "vfmv.f.s", V, OpV, 0xfe0ff07f, 0x42001057, [Fd, Vs2];
+    VfmvSF => "vfmv.s.f", V, OpV, 0xfff0707f, 0x42005057, [Vd, Fs1];
+    // vfmerge is always masked by v0, the unmasked encoding is vfmv.v.f
+    VfmergeVfm => "vfmerge.vfm", V, OpV, 0xfe00707f, 0x5c005057, [Vd, Vs2, Fs1];
+    VfmvVF => "vfmv.v.f", V, OpV, 0xfff0707f, 0x5e005057, [Vd, Fs1];
+    VmandnMm => "vmandn.mm", V, OpV, 0xfe00707f, 0x62002057, [Vd, Vs2, Vs1];
+    VmandMm => "vmand.mm", V, OpV, 0xfe00707f, 0x66002057, [Vd, Vs2, Vs1];
+    VmorMm => "vmor.mm", V, OpV, 0xfe00707f, 0x6a002057, [Vd, Vs2, Vs1];
+    VmxorMm => "vmxor.mm", V, OpV, 0xfe00707f, 0x6e002057, [Vd, Vs2, Vs1];
+    VmornMm => "vmorn.mm", V, OpV, 0xfe00707f, 0x72002057, [Vd, Vs2, Vs1];
+    VmnandMm => "vmnand.mm", V, OpV, 0xfe00707f, 0x76002057, [Vd, Vs2, Vs1];
+    VmnorMm => "vmnor.mm", V, OpV, 0xfe00707f, 0x7a002057, [Vd, Vs2, Vs1];
+    VmxnorMm => "vmxnor.mm", V, OpV, 0xfe00707f, 0x7e002057, [Vd, Vs2, Vs1];
+    VcpopM => "vcpop.m", V, OpV, 0xfc0ff07f, 0x40082057, [Rd, Vs2, Vm];
+    VfirstM => "vfirst.m", V, OpV, 0xfc0ff07f, 0x4008a057, [Rd, Vs2, Vm];
+    VmsbfM => "vmsbf.m", V, OpV, 0xfc0ff07f, 0x5000a057, [Vd, Vs2, Vm];
+    VmsofM => "vmsof.m", V, OpV, 0xfc0ff07f, 0x50012057, [Vd, Vs2, Vm];
+    VmsifM => "vmsif.m", V, OpV, 0xfc0ff07f, 0x5001a057, [Vd, Vs2, Vm];
+    ViotaM => "viota.m", V, OpV, 0xfc0ff07f, 0x50082057, [Vd, Vs2, Vm];
+    VidV => "vid.v", V, OpV, 0xfdfff07f, 0x5008a057, [Vd, Vm];
+    Vl1re8V => "vl1re8.v", V, OpV, 0xfff0707f, 0x02800007, [Vd, Rs1];
+    Vl1re16V => "vl1re16.v", V, OpV, 0xfff0707f, 0x02805007, [Vd, Rs1];
+    Vl1re32V => "vl1re32.v", V, OpV, 0xfff0707f, 0x02806007, [Vd, Rs1];
+    Vl1re64V => "vl1re64.v", V, OpV, 0xfff0707f, 0x02807007, [Vd, Rs1];
+    Vl2re8V => "vl2re8.v", V, OpV, 0xfff0707f, 0x22800007, [Vd, Rs1];
+    Vl2re16V => "vl2re16.v", V, OpV, 0xfff0707f, 0x22805007, [Vd, Rs1];
+    Vl2re32V => "vl2re32.v", V, OpV, 0xfff0707f, 0x22806007, [Vd, Rs1];
+    Vl2re64V => "vl2re64.v", V, OpV, 0xfff0707f, 0x22807007, [Vd, Rs1];
+    Vl4re8V => "vl4re8.v", V, OpV, 0xfff0707f, 0x62800007, [Vd, Rs1];
+    Vl4re16V => "vl4re16.v", V, OpV, 0xfff0707f, 0x62805007, [Vd, Rs1];
+    Vl4re32V => "vl4re32.v", V, OpV, 0xfff0707f, 0x62806007, [Vd, Rs1];
+    Vl4re64V => "vl4re64.v", V, OpV, 0xfff0707f, 0x62807007, [Vd, Rs1];
+    Vl8re8V => "vl8re8.v", V, OpV, 0xfff0707f, 0xe2800007, [Vd, Rs1];
+    Vl8re16V => "vl8re16.v", V, OpV, 0xfff0707f, 0xe2805007, [Vd, Rs1];
+    Vl8re32V => "vl8re32.v", V, OpV, 0xfff0707f, 0xe2806007, [Vd, Rs1];
+    Vl8re64V => "vl8re64.v", V, OpV, 0xfff0707f, 0xe2807007, [Vd, Rs1];
+    Vs1rV => "vs1r.v", V, OpV, 0xfff0707f, 0x02800027, [Vd, Rs1];
+    Vs2rV => "vs2r.v", V, OpV, 0xfff0707f, 0x22800027, [Vd, Rs1];
+    Vs4rV => "vs4r.v", V, OpV, 0xfff0707f, 0x62800027, [Vd, Rs1];
+    Vs8rV => "vs8r.v", V, OpV, 0xfff0707f, 0xe2800027, [Vd, Rs1];
+    Vmv1rV => "vmv1r.v", V, OpV, 0xfe0ff07f, 0x9e003057, [Vd, Vs2];
+    Vmv2rV => "vmv2r.v", V, OpV, 0xfe0ff07f, 0x9e00b057, [Vd, Vs2];
+    Vmv4rV => "vmv4r.v", V, OpV, 0xfe0ff07f, 0x9e01b057, [Vd, Vs2];
+    Vmv8rV => "vmv8r.v", V, OpV, 0xfe0ff07f, 0x9e03b057, [Vd, Vs2];
+    VandnVv => "vandn.vv", Zvbb, OpV, 0xfc00707f, 0x04000057, [Vd, Vs2, Vs1, Vm];
+    VandnVx => "vandn.vx", Zvbb, OpV, 0xfc00707f, 0x04004057, [Vd, Vs2, Rs1, Vm];
+    VrorVv => "vror.vv", Zvbb, OpV, 0xfc00707f, 0x50000057, [Vd, Vs2, Vs1, Vm];
+    VrorVx => "vror.vx", Zvbb, OpV, 0xfc00707f, 0x50004057, [Vd, Vs2, Rs1, Vm];
+    VrorVi => "vror.vi", Zvbb, OpV, 0xf800707f, 0x50003057, [Vd, Vs2, Uimm, Vm];
+    VrolVv => "vrol.vv", Zvbb, OpV, 0xfc00707f, 0x54000057, [Vd, Vs2, Vs1, Vm];
+    VrolVx => "vrol.vx", Zvbb, OpV, 0xfc00707f, 0x54004057, [Vd, Vs2, Rs1, Vm];
+    VwsllVv => "vwsll.vv", Zvbb, OpV, 0xfc00707f, 0xd4000057, [Vd, Vs2, Vs1, Vm];
+    VwsllVx => "vwsll.vx", Zvbb, OpV, 0xfc00707f, 0xd4004057, [Vd, Vs2, Rs1, Vm];
+    VwsllVi => "vwsll.vi", Zvbb, OpV, 0xfc00707f, 0xd4003057, [Vd, Vs2, Uimm, Vm];
+    Vbrev8V => "vbrev8.v", Zvbb, OpV, 0xfc0ff07f, 0x48042057, [Vd, Vs2, Vm];
+    Vrev8V => "vrev8.v", Zvbb, OpV, 0xfc0ff07f, 0x4804a057, [Vd, Vs2, Vm];
+    VbrevV => "vbrev.v", Zvbb, OpV, 0xfc0ff07f, 0x48052057, [Vd, Vs2, Vm];
+    VclzV => "vclz.v", Zvbb, OpV, 0xfc0ff07f, 0x48062057, [Vd, Vs2, Vm];
+    VctzV => "vctz.v", Zvbb, OpV, 0xfc0ff07f, 0x4806a057, [Vd, Vs2, Vm];
+    VcpopV => "vcpop.v", Zvbb, OpV, 0xfc0ff07f, 0x48072057, [Vd, Vs2, Vm];
+    VghshVv => "vghsh.vv", Zvkg, OpV, 0xfe00707f, 0xb2002077, [Vd, Vs2, Vs1];
+    VgmulVv => "vgmul.vv", Zvkg, OpV, 0xfe0ff07f, 0xa208a077, [Vd, Vs2];
+    VaesdfVv => "vaesdf.vv", Zvkned, OpV, 0xfe0ff07f, 0xa200a077, [Vd, Vs2];
+    VaesdfVs => "vaesdf.vs", Zvkned, OpV, 0xfe0ff07f, 0xa600a077, [Vd, Vs2];
+    VaesdmVv => "vaesdm.vv", Zvkned, OpV, 0xfe0ff07f, 0xa2002077, [Vd, Vs2];
+    VaesdmVs => "vaesdm.vs", Zvkned, OpV, 0xfe0ff07f, 0xa6002077, [Vd, Vs2];
+    VaesefVv => "vaesef.vv", Zvkned, OpV, 0xfe0ff07f, 0xa201a077, [Vd, Vs2];
+    VaesefVs => "vaesef.vs", Zvkned, OpV, 0xfe0ff07f, 0xa601a077, [Vd, Vs2];
+    VaesemVv => "vaesem.vv", Zvkned, OpV, 0xfe0ff07f, 0xa2012077, [Vd, Vs2];
+    VaesemVs => "vaesem.vs", Zvkned, OpV, 0xfe0ff07f, 0xa6012077, [Vd, Vs2];
+    VaeszVs => "vaesz.vs", Zvkned, OpV, 0xfe0ff07f, 0xa603a077, [Vd, Vs2];
+    Vaeskf1Vi => "vaeskf1.vi", Zvkned, OpV, 0xfe00707f, 0x8a002077, [Vd, Vs2, Uimm];
+    Vaeskf2Vi => "vaeskf2.vi", Zvkned, OpV, 0xfe00707f, 0xaa002077, [Vd, Vs2, Uimm];
+    Vsha2msVv => "vsha2ms.vv", Zvknh, OpV, 0xfe00707f, 0xb6002077, [Vd, Vs2, Vs1];
+    Vsha2chVv => "vsha2ch.vv", Zvknh, OpV, 0xfe00707f, 0xba002077, [Vd, Vs2, Vs1];
+    Vsha2clVv => "vsha2cl.vv", Zvknh, OpV, 0xfe00707f, 0xbe002077, [Vd, Vs2, Vs1];
+    SinvalVma => "sinval.vma", Svinval, R, 0xfe007fff, 0x16000073, [Rs1, Rs2];
+    SFenceWInval => "sfence.w.inval", Svinval, R, 0xffffffff, 0x18000073, [];
+    SFenceInvalIr => "sfence.inval.ir", Svinval, R, 0xffffffff, 0x18100073, [];
+    HinvalVvma => "hinval.vvma", Svinval, R, 0xfe007fff, 0x26000073, [Rs1, Rs2];
+    HinvalGvma => "hinval.gvma", Svinval, R, 0xfe007fff, 0x66000073, [Rs1, Rs2];
+    Pause => "pause", Zihintpause, I, 0xffffffff, 0x0100000f, [];
+    NtlP1 => "ntl.p1", Zihintntl, R, 0xffffffff, 0x00200033, [];
+    NtlPall => "ntl.pall", Zihintntl, R, 0xffffffff, 0x00300033, [];
+    NtlS1 => "ntl.s1", Zihintntl, R, 0xffffffff, 0x00400033, [];
+    NtlAll => "ntl.all", Zihintntl, R, 0xffffffff, 0x00500033, [];
+    CboInval => "cbo.inval", Zicbom, I, 0xfff07fff, 0x0000200f, [Rs1];
+    CboClean => "cbo.clean", Zicbom, I, 0xfff07fff, 0x0010200f, [Rs1];
+    CboFlush => "cbo.flush", Zicbom, I, 0xfff07fff, 0x0020200f, [Rs1];
+    CboZero => "cbo.zero", Zicboz, I, 0xfff07fff, 0x0040200f, [Rs1];
+    PrefetchI => "prefetch.i", Zicbop, I, 0x01f07fff, 0x00006013, [Imm, Rs1];
+    PrefetchR => "prefetch.r", Zicbop, I, 0x01f07fff, 0x00106013, [Imm, Rs1];
+    PrefetchW => "prefetch.w", Zicbop, I, 0x01f07fff, 0x00306013, [Imm, Rs1];
+    WrsNto => "wrs.nto", Zawrs, I, 0xffffffff, 0x00d00073, [];
+    WrsSto => "wrs.sto", Zawrs, I, 0xffffffff, 0x01d00073, [];
+    Add16 => "add16", P, R, 0xfe00707f, 0x40000077, [Rd, Rs1, Rs2];
+    Add8 => "add8", P, R, 0xfe00707f, 0x48000077, [Rd, Rs1, Rs2];
+    Sub16 => "sub16", P, R, 0xfe00707f, 0x42000077, [Rd, Rs1, Rs2];
+    Sub8 => "sub8", P, R, 0xfe00707f, 0x4a000077, [Rd, Rs1, Rs2];
+    Radd16 => "radd16", P, R, 0xfe00707f, 0x00000077, [Rd, Rs1, Rs2];
+    Radd8 => "radd8", P, R, 0xfe00707f, 0x08000077, [Rd, Rs1, Rs2];
+    Rsub16 => "rsub16", P, R, 0xfe00707f, 0x02000077, [Rd, Rs1, Rs2];
+    Rsub8 => "rsub8", P, R, 0xfe00707f, 0x0a000077, [Rd, Rs1, Rs2];
+    Kadd16 => "kadd16", P, R, 0xfe00707f, 0x10000077, [Rd, Rs1, Rs2];
+    Kadd8 => "kadd8", P, R, 0xfe00707f, 0x18000077, [Rd, Rs1, Rs2];
+    Ksub16 => "ksub16", P, R, 0xfe00707f, 0x12000077, [Rd, Rs1, Rs2];
+    Ksub8 => "ksub8", P, R, 0xfe00707f, 0x1a000077, [Rd, Rs1, Rs2];
+    Ukadd16 => "ukadd16", P, R, 0xfe00707f, 0x30000077, [Rd, Rs1, Rs2];
+    Ukadd8 => "ukadd8", P, R, 0xfe00707f, 0x38000077, [Rd, Rs1, Rs2];
+    Uksub16 => "uksub16", P, R, 0xfe00707f, 0x32000077, [Rd, Rs1, Rs2];
+    Uksub8 => "uksub8", P, R, 0xfe00707f, 0x3a000077, [Rd, Rs1, Rs2];
+    Smul16 => "smul16", P, R, 0xfe00707f, 0xa0000077, [Rd, Rs1, Rs2];
+    Smul8 => "smul8", P, R, 0xfe00707f, 0xa8000077, [Rd, Rs1, Rs2];
+    Umul16 => "umul16", P, R, 0xfe00707f, 0xb0000077, [Rd, Rs1, Rs2];
+    Umul8 => "umul8", P, R, 0xfe00707f, 0xb8000077, [Rd, Rs1, Rs2];
+    Pkbb16 => "pkbb16", P, R, 0xfe00707f, 0x0e001077, [Rd, Rs1, Rs2];
+    Pkbt16 => "pkbt16", P, R, 0xfe00707f, 0x1e001077, [Rd, Rs1, Rs2];
+    Pktb16 => "pktb16", P, R, 0xfe00707f, 0x2e001077, [Rd, Rs1, Rs2];
+    Pktt16 => "pktt16", P, R, 0xfe00707f, 0x3e001077, [Rd, Rs1, Rs2];
+    ThAddsl => "th.addsl", XTheadBa, R, 0xf800707f, 0x0000100b, [Rd, Rs1, Rs2, Imm];
+    ThDcacheCall => "th.dcache.call", XTheadCmo, I, 0xffffffff, 0x0010000b, [];
+    ThDcacheIall => "th.dcache.iall", XTheadCmo, I, 0xffffffff, 0x0020000b, [];
+    ThDcacheCiall => "th.dcache.ciall", XTheadCmo, I, 0xffffffff, 0x0030000b, [];
+    ThIcacheIall => "th.icache.iall", XTheadCmo, I, 0xffffffff, 0x0100000b, [];
+    ThIcacheIalls => "th.icache.ialls", XTheadCmo, I, 0xffffffff, 0x0110000b, [];
+    ThDcacheCval1 => "th.dcache.cval1", XTheadCmo, I, 0xfff07fff, 0x0240000b, [Rs1];
+    ThDcacheCva => "th.dcache.cva", XTheadCmo, I, 0xfff07fff, 0x0250000b, [Rs1];
+    ThDcacheIva => "th.dcache.iva", XTheadCmo, I, 0xfff07fff, 0x0260000b, [Rs1];
+    ThDcacheCiva => "th.dcache.civa", XTheadCmo, I, 0xfff07fff, 0x0270000b, [Rs1];
+    ThDcacheCpa => "th.dcache.cpa", XTheadCmo, I, 0xfff07fff, 0x0290000b, [Rs1];
+    ThDcacheIpa => "th.dcache.ipa", XTheadCmo, I, 0xfff07fff, 0x02a0000b, [Rs1];
+    ThDcacheCipa => "th.dcache.cipa", XTheadCmo, I, 0xfff07fff, 0x02b0000b, [Rs1];
+    ThIcacheIva => "th.icache.iva", XTheadCmo, I, 0xfff07fff, 0x0300000b, [Rs1];
+    ThIcacheIpa => "th.icache.ipa", XTheadCmo, I, 0xfff07fff, 0x0380000b, [Rs1];
+    ThLrb => "th.lrb", XTheadMemIdx, R, 0xf800707f, 0x0000400b, [Rd, Rs1, Rs2, Imm];
+    ThLurb => "th.lurb", XTheadMemIdx, R, 0xf800707f, 0x1000400b, [Rd, Rs1, Rs2, Imm];
+    ThLbib => "th.lbib", XTheadMemIdx, R, 0xf800707f, 0x0800400b, [Rd, Rs1, Imm, Imm];
+    ThLbia => "th.lbia", XTheadMemIdx, R, 0xf800707f, 0x1800400b, [Rd, Rs1, Imm, Imm];
+    ThLrh => "th.lrh", XTheadMemIdx, R, 0xf800707f, 0x2000400b, [Rd, Rs1, Rs2, Imm];
+    ThLurh => "th.lurh", XTheadMemIdx, R, 0xf800707f, 0x3000400b, [Rd, Rs1, Rs2, Imm];
+    ThLhib => "th.lhib", XTheadMemIdx, R, 0xf800707f, 0x2800400b, [Rd, Rs1, Imm, Imm];
+    ThLhia => "th.lhia", XTheadMemIdx, R, 0xf800707f, 0x3800400b, [Rd, Rs1, Imm, Imm];
+    ThLrw => "th.lrw", XTheadMemIdx, R, 0xf800707f, 0x4000400b, [Rd, Rs1, Rs2, Imm];
+    ThLurw => "th.lurw", XTheadMemIdx, R, 0xf800707f, 0x5000400b, [Rd, Rs1, Rs2, Imm];
+    ThLwib => "th.lwib", XTheadMemIdx, R, 0xf800707f, 0x4800400b, [Rd, Rs1, Imm, Imm];
+    ThLwia => "th.lwia", XTheadMemIdx, R, 0xf800707f, 0x5800400b, [Rd, Rs1, Imm, Imm];
+    ThLrd => "th.lrd", XTheadMemIdx, R, 0xf800707f, 0x6000400b, [Rd, Rs1, Rs2, Imm];
+    ThLurd => "th.lurd", XTheadMemIdx, R, 0xf800707f, 0x7000400b, [Rd, Rs1, Rs2, Imm];
+    ThLdib => "th.ldib", XTheadMemIdx, R, 0xf800707f, 0x6800400b, [Rd, Rs1, Imm, Imm];
+    ThLdia => "th.ldia", XTheadMemIdx, R, 0xf800707f, 0x7800400b, [Rd, Rs1, Imm, Imm];
+    ThLrbu => "th.lrbu", XTheadMemIdx, R, 0xf800707f, 0x8000400b, [Rd, Rs1, Rs2, Imm];
+    ThLurbu => "th.lurbu", XTheadMemIdx, R, 0xf800707f, 0x9000400b, [Rd, Rs1, Rs2, Imm];
+    ThLbuib => "th.lbuib", XTheadMemIdx, R, 0xf800707f, 0x8800400b, [Rd, Rs1, Imm, Imm];
+    ThLbuia => "th.lbuia", XTheadMemIdx, R, 0xf800707f, 0x9800400b, [Rd, Rs1, Imm, Imm];
+    ThLrhu => "th.lrhu", XTheadMemIdx, R, 0xf800707f, 0xa000400b, [Rd, Rs1, Rs2, Imm];
+    ThLurhu => "th.lurhu", XTheadMemIdx, R, 0xf800707f, 0xb000400b, [Rd, Rs1, Rs2, Imm];
+    ThLhuib => "th.lhuib", XTheadMemIdx, R, 0xf800707f, 0xa800400b, [Rd, Rs1, Imm, Imm];
+    ThLhuia => "th.lhuia", XTheadMemIdx, R, 0xf800707f, 0xb800400b, [Rd, Rs1, Imm, Imm];
+    ThLrwu => "th.lrwu", XTheadMemIdx, R, 0xf800707f, 0xc000400b, [Rd, Rs1, Rs2, Imm];
+    ThLurwu => "th.lurwu", XTheadMemIdx, R, 0xf800707f, 0xd000400b, [Rd, Rs1, Rs2, Imm];
+    ThLwuib => "th.lwuib", XTheadMemIdx, R, 0xf800707f, 0xc800400b, [Rd, Rs1, Imm, Imm];
+    ThLwuia => "th.lwuia", XTheadMemIdx, R, 0xf800707f, 0xd800400b, [Rd, Rs1, Imm, Imm];
+    ThSrb => "th.srb", XTheadMemIdx, R, 0xf800707f, 0x0000500b, [Rd, Rs1, Rs2, Imm];
+    ThSurb => "th.surb", XTheadMemIdx, R, 0xf800707f, 0x1000500b, [Rd, Rs1, Rs2, Imm];
+    ThSbib => "th.sbib", XTheadMemIdx, R, 0xf800707f, 0x0800500b, [Rd, Rs1, Imm, Imm];
+    ThSbia => "th.sbia", XTheadMemIdx, R, 0xf800707f, 0x1800500b, [Rd, Rs1, Imm, Imm];
+    ThSrh => "th.srh", XTheadMemIdx, R, 0xf800707f, 0x2000500b, [Rd, Rs1, Rs2, Imm];
+    ThSurh => "th.surh", XTheadMemIdx, R, 0xf800707f, 0x3000500b, [Rd, Rs1, Rs2, Imm];
+    ThShib => "th.shib", XTheadMemIdx, R, 0xf800707f, 0x2800500b, [Rd, Rs1, Imm, Imm];
+    ThShia => "th.shia", XTheadMemIdx, R, 0xf800707f, 0x3800500b, [Rd, Rs1, Imm, Imm];
+    ThSrw => "th.srw", XTheadMemIdx, R, 0xf800707f, 0x4000500b, [Rd, Rs1, Rs2, Imm];
+    ThSurw => "th.surw", XTheadMemIdx, R, 0xf800707f, 0x5000500b, [Rd, Rs1, Rs2, Imm];
+    ThSwib => "th.swib", XTheadMemIdx, R, 0xf800707f, 0x4800500b, [Rd, Rs1, Imm, Imm];
+    ThSwia => "th.swia", XTheadMemIdx, R, 0xf800707f, 0x5800500b, [Rd, Rs1, Imm, Imm];
+    ThSrd => "th.srd", XTheadMemIdx, R, 0xf800707f, 0x6000500b, [Rd, Rs1, Rs2, Imm];
+    ThSurd => "th.surd", XTheadMemIdx, R, 0xf800707f, 0x7000500b, [Rd, Rs1, Rs2, Imm];
+    ThSdib => "th.sdib", XTheadMemIdx, R, 0xf800707f, 0x6800500b, [Rd, Rs1, Imm, Imm];
+    ThSdia => "th.sdia", XTheadMemIdx, R, 0xf800707f, 0x7800500b, [Rd, Rs1, Imm, Imm];
+    // any of custom-0..custom-3, they only differ in bits 5 and 6
+    Custom => "custom", Custom, R, 0x0000001f, 0x0000000b, [Rd, Rs1, Rs2];
+    MnRet => "mnret", Smrnmi, R, 0xffffffff, 0x70200073, [];
+    DRet => "dret", Sdext, R, 0xffffffff, 0x7b200073, [];
+    // rs2 of sspush and rs1 of sspopchk are either x1 or x5, bit 2 is the only one left free
+    Sspush => "sspush", Zicfiss, R, 0xfebfffff, 0xce104073, [Rs2];
+    Sspopchk => "sspopchk", Zicfiss, I, 0xfffdffff, 0xcdc0c073, [Rs1];
+    Ssrdp => "ssrdp", Zicfiss, I, 0xfffff07f, 0xcdc04073, [Rd];
+    SsamoswapW => "ssamoswap.w", Zicfiss, R, 0xf800707f, 0x4800202f, [Rd, Rs2, Rs1];
+    SsamoswapD => "ssamoswap.d", Zicfiss, R, 0xf800707f, 0x4800302f, [Rd, Rs2, Rs1];
+    Lpad => "lpad", Zicfilp, U, 0x00000fff, 0x00000017, [Imm];
+    CLw => "c.lw", C, CL, 0x0000e003, 0x00004000, [Rd, Imm, Rs1];
+    CSw => "c.sw", C, CS, 0x0000e003, 0x0000c000, [Rs2, Imm, Rs1];
+    CFlw => "c.flw", C, CL, 0x0000e003, 0x00006000, [Fd, Imm, Rs1];
+    CFsw => "c.fsw", C, CS, 0x0000e003, 0x0000e000, [Fs2, Imm, Rs1];
+    CFld => "c.fld", C, CL, 0x0000e003, 0x00002000, [Fd, Imm, Rs1];
+    CFsd => "c.fsd", C, CS, 0x0000e003, 0x0000a000, [Fs2, Imm, Rs1];
+    CAddi => "c.addi", C, CI, 0x0000e003, 0x00000001, [Rd, Imm];
+    CJal => "c.jal", C, CJ, 0x0000e003, 0x00002001, [Imm];
+    CLi => "c.li", C, CI, 0x0000e003, 0x00004001, [Rd, Imm];
+    CAddi16Sp => "c.addi16sp", C, CI, 0x0000ef83, 0x00006101, [Imm];
+    CLui => "c.lui", C, CI, 0x0000e003, 0x00006001, [Rd, Imm];
+    CSrli => "c.srli", C, CB, 0x0000ec03, 0x00008001, [Rd, Uimm];
+    CSrai => "c.srai", C, CB, 0x0000ec03, 0x00008401, [Rd, Uimm];
+    CAndi => "c.andi", C, CB, 0x0000ec03, 0x00008801, [Rd, Imm];
+    CSub => "c.sub", C, CA, 0x0000fc63, 0x00008c01, [Rd, Rs2];
+    CXor => "c.xor", C, CA, 0x0000fc63, 0x00008c21, [Rd, Rs2];
+    COr => "c.or", C, CA, 0x0000fc63, 0x00008c41, [Rd, Rs2];
+    CAnd => "c.and", C, CA, 0x0000fc63, 0x00008c61, [Rd, Rs2];
+    CJ => "c.j", C, CJ, 0x0000e003, 0x0000a001, [Imm];
+    CBeqz => "c.beqz", C, CB, 0x0000e003, 0x0000c001, [Rs1, Imm];
+    CBnez => "c.bnez", C, CB, 0x0000e003, 0x0000e001, [Rs1, Imm];
+    CLwsp => "c.lwsp", C, CI, 0x0000e003, 0x00004002, [Rd, Uimm];
+    CSwsp => "c.swsp", C, CSS, 0x0000e003, 0x0000c002, [Rs2, Uimm];
+    CFlwsp => "c.flwsp", C, CI, 0x0000e003, 0x00006002, [Fd, Uimm];
+    CFswsp => "c.fswsp", C, CSS, 0x0000e003, 0x0000e002, [Fs2, Uimm];
+    CFldsp => "c.fldsp", C, CI, 0x0000e003, 0x00002002, [Fd, Uimm];
+    CFsdsp => "c.fsdsp", C, CSS, 0x0000e003, 0x0000a002, [Fs2, Uimm];
+    CJr => "c.jr", C, CR, 0x0000f07f, 0x00008002, [Rs1];
+    CMv => "c.mv", C, CR, 0x0000f003, 0x00008002, [Rd, Rs2];
+    CEbreak => "c.ebreak", C, CR, 0x0000ffff, 0x00009002, [];
+    CJalr => "c.jalr", C, CR, 0x0000f07f, 0x00009002, [Rs1];
+    CAdd => "c.add", C, CR, 0x0000f003, 0x00009002, [Rd, Rs2];
+    CAddiw => "c.addiw", C, CI, 0x0000e003, 0x00002001, [Rd, Imm];
+    CLd => "c.ld", C, CL, 0x0000e003, 0x00006000, [Rd, Imm, Rs1];
+    CSd => "c.sd", C, CS, 0x0000e003, 0x0000e000, [Rs2, Imm, Rs1];
+    CLdsp => "c.ldsp", C, CI, 0x0000e003, 0x00006002, [Rd, Uimm];
+    CSdsp => "c.sdsp", C, CSS, 0x0000e003, 0x0000e002, [Rs2, Uimm];
+    CSubw => "c.subw", C, CA, 0x0000fc63, 0x00009c01, [Rd, Rs2];
+    CAddw => "c.addw", C, CA, 0x0000fc63, 0x00009c21, [Rd, Rs2];
+    CLbu => "c.lbu", Zcb, CLB, 0x0000fc03, 0x00008000, [Rd, Uimm, Rs1];
+    CLhu => "c.lhu", Zcb, CLH, 0x0000fc43, 0x00008400, [Rd, Uimm, Rs1];
+    CLh => "c.lh", Zcb, CLH, 0x0000fc43, 0x00008440, [Rd, Uimm, Rs1];
+    CSb => "c.sb", Zcb, CSB, 0x0000fc03, 0x00008800, [Rs2, Uimm, Rs1];
+    CSh => "c.sh", Zcb, CSH, 0x0000fc43, 0x00008c00, [Rs2, Uimm, Rs1];
+    CZextB => "c.zext.b", Zcb, CU, 0x0000fc7f, 0x00009c61, [Rd];
+    CSextB => "c.sext.b", Zcb, CU, 0x0000fc7f, 0x00009c65, [Rd];
+    CZextH => "c.zext.h", Zcb, CU, 0x0000fc7f, 0x00009c69, [Rd];
+    CSextH => "c.sext.h", Zcb, CU, 0x0000fc7f, 0x00009c6d, [Rd];
+    CZextW => "c.zext.w", Zcb, CU, 0x0000fc7f, 0x00009c71, [Rd];
+    CNot => "c.not", Zcb, CU, 0x0000fc7f, 0x00009c75, [Rd];
+    CMul => "c.mul", Zcb, CA, 0x0000fc63, 0x00009c41, [Rd, Rs2];
+    CmPush => "cm.push", Zcmp, CMPP, 0x0000ff03, 0x0000b802, [Imm];
+    CmPop => "cm.pop", Zcmp, CMPP, 0x0000ff03, 0x0000ba02, [Imm];
+    CmPopretz => "cm.popretz", Zcmp, CMPP, 0x0000ff03, 0x0000bc02, [Imm];
+    CmPopret => "cm.popret", Zcmp, CMPP, 0x0000ff03, 0x0000be02, [Imm];
+    CmMvsa01 => "cm.mvsa01", Zcmp, CMMV, 0x0000fc63, 0x0000ac22, [Rd, Rs2];
+    CmMva01s => "cm.mva01s", Zcmp, CMMV, 0x0000fc63, 0x0000ac62, [Rs1, Rs2];
+    CmJt => "cm.jt", Zcmt, CMJT, 0x0000ff83, 0x0000a002, [Uimm];
+    CmJalt => "cm.jalt", Zcmt, CMJT, 0x0000fc03, 0x0000a002, [Uimm];
+    Vle8ffV => "vle8ff.v", V, OpV, 0x1df0707f, 0x01000007, [Vd, Rs1, Vm];
+    Vle16ffV => "vle16ff.v", V, OpV, 0x1df0707f, 0x01005007, [Vd, Rs1, Vm];
+    Vle32ffV => "vle32ff.v", V, OpV, 0x1df0707f, 0x01006007, [Vd, Rs1, Vm];
+    Vle64ffV => "vle64ff.v", V, OpV, 0x1df0707f, 0x01007007, [Vd, Rs1, Vm];
+    FaddD => "fadd.d", D, R, 0xfe00007f, 0x02000053, [Fd, Fs1, Fs2];
+    FsubD => "fsub.d", D, R, 0xfe00007f, 0x0a000053, [Fd, Fs1, Fs2];
+    FmulD => "fmul.d", D, R, 0xfe00007f, 0x12000053, [Fd, Fs1, Fs2];
+    FdivD => "fdiv.d", D, R, 0xfe00007f, 0x1a000053, [Fd, Fs1, Fs2];
+    FsqrtD => "fsqrt.d", D, R, 0xfff0007f, 0x5a000053, [Fd, Fs1];
+    FsgnjD => "fsgnj.d", D, R, 0xfe00707f, 0x22000053, [Fd, Fs1, Fs2];
+    FsgnjnD => "fsgnjn.d", D, R, 0xfe00707f, 0x22001053, [Fd, Fs1, Fs2];
+    FsgnjxD => "fsgnjx.d", D, R, 0xfe00707f, 0x22002053, [Fd, Fs1, Fs2];
+    FminD => "fmin.d", D, R, 0xfe00707f, 0x2a000053, [Fd, Fs1, Fs2];
+    FmaxD => "fmax.d", D, R, 0xfe00707f, 0x2a001053, [Fd, Fs1, Fs2];
+    FcvtSD => "fcvt.s.d", D, R, 0xfff0007f, 0x40100053, [Fd, Fs1];
+    FcvtDS => "fcvt.d.s", D, R, 0xfff0007f, 0x42000053, [Fd, Fs1];
+    FeqD => "feq.d", D, R, 0xfe00707f, 0xa2002053, [Rd, Fs1, Fs2];
+    FltD => "flt.d", D, R, 0xfe00707f, 0xa2001053, [Rd, Fs1, Fs2];
+    FleD => "fle.d", D, R, 0xfe00707f, 0xa2000053, [Rd, Fs1, Fs2];
+    FClassD => "fclass.d", D, R, 0xfff0707f, 0xe2001053, [Rd, Fs1];
+    FcvtWD => "fcvt.w.d", D, R, 0xfff0007f, 0xc2000053, [Rd, Fs1];
+    FcvtWUD => "fcvt.wu.d", D, R, 0xfff0007f, 0xc2100053, [Rd, Fs1];
+    FcvtDW => "fcvt.d.w", D, R, 0xfff0007f, 0xd2000053, [Fd, Rs1];
+    FcvtDWU => "fcvt.d.wu", D, R, 0xfff0007f, 0xd2100053, [Fd, Rs1];
+}
+
+impl OpcodeId {
+    pub const COUNT: usize = NAMES.len();
+
+    pub const fn new(id: u16) -> Option<Self> {
+        if (id as usize) < Self::COUNT {
+            Some(Self(id))
+        } else {
+            None
+        }
+    }
+
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    // the ISA mnemonic, without any aq/rl or pseudo instruction changes made when printing
+    pub const fn name(self) -> &'static str {
+        NAMES[self.0 as usize]
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMES
+            .iter()
+            .position(|n| *n == name)
+            .map(|id| Self(id as u16))
+    }
+
+    pub const fn info(self) -> InstructionInfo {
+        let (extension, format, mask, match_value, operands) = INFO[self.0 as usize];
+        InstructionInfo {
+            id: self,
+            name: self.name(),
+            extension,
+            format,
+            mask,
+            match_value,
+            operands,
+        }
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..Self::COUNT as u16).map(Self)
+    }
+}
+
+impl From<&InstructionDecoded> for OpcodeId {
+    fn from(inst: &InstructionDecoded) -> Self {
+        inst.opcode_id()
+    }
+}
+
+impl From<OpcodeId> for u16 {
+    fn from(id: OpcodeId) -> Self {
+        id.0
+    }
+}
+
+impl Display for OpcodeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[test]
+fn ids() {
+    use crate::decoder::try_decode;
+    let id = try_decode(0x06468613 /* addi x12 x13 100 */)
+        .unwrap()
+        .opcode_id();
+    assert_eq!(id.name(), "addi");
+    assert_eq!(OpcodeId::from_name("addi"), Some(id));
+    assert_eq!(OpcodeId::new(id.get()), Some(id));
+    assert_eq!(OpcodeId::new(OpcodeId::COUNT as u16), None);
+
+    // these can't move, they're part of the public api
+    assert_eq!(OpcodeId::from_name("lb").map(OpcodeId::get), Some(0));
+    assert_eq!(InstructionDecoded::ECall.opcode_id().get(), 38);
+
+    // names are unique
+    for id in OpcodeId::all() {
+        assert_eq!(OpcodeId::from_name(id.name()), Some(id));
+    }
+}
+
+#[test]
+fn info() {
+    let info = OpcodeId::from_name("sw").unwrap().info();
+    assert_eq!(info.extension, Extension::I);
+    assert_eq!(info.format, Format::S);
+    assert_eq!(
+        info.operands,
+        &[OperandKind::Rs2, OperandKind::Imm, OperandKind::Rs1]
+    );
+    let info = OpcodeId::from_name("vsm3c.vi").unwrap().info();
+    assert_eq!(info.extension, Extension::Zvksh);
+    assert_eq!(info.format, Format::OpV);
+}
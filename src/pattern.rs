@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+
+use crate::decoded_inst::InstructionDecoded;
+use crate::instructions::InstructionSize;
+
+// patterns are written the way instructions are printed, instructions are separated by `;`:
+//
+//     lui %r, %hi ; addi %r, %r, %lo
+//
+// `%name` captures whatever is in that place (and has to be the same everywhere the name is used),
+// `*` matches anything, everything else has to match exactly. punctuation is ignored so
+// `lw %r, %off(%base)` and `lw %r %off %base` are the same pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Atom {
+    Literal(String),
+    Capture(String),
+    Any,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    insts: Vec<Vec<Atom>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub addr: InstructionSize,
+    // index of the first matched instruction and how many were matched
+    pub index: usize,
+    pub len: usize,
+    pub captures: HashMap<String, String>,
+}
+
+fn words(text: &str) -> Vec<&str> {
+    text.split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '%' | '*')))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+impl Pattern {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut insts = Vec::new();
+        for inst in text.split(';') {
+            let atoms = words(inst)
+                .into_iter()
+                .map(|word| match word {
+                    "*" => Ok(Atom::Any),
+                    _ => match word.strip_prefix('%') {
+                        Some("") => bail!("capture without a name in pattern `{text}`"),
+                        Some(name) => Ok(Atom::Capture(name.to_string())),
+                        None => Ok(Atom::Literal(word.to_string())),
+                    },
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if atoms.is_empty() {
+                bail!("empty instruction in pattern `{text}`");
+            }
+            insts.push(atoms);
+        }
+        Ok(Self { insts })
+    }
+
+    pub fn len(&self) -> usize {
+        self.insts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.insts.is_empty()
+    }
+
+    // tries to match the pattern against the instructions starting at `index`
+    pub fn match_at(
+        &self,
+        insts: &[(InstructionSize, InstructionDecoded)],
+        index: usize,
+    ) -> Option<Match> {
+        let window = insts.get(index..index + self.insts.len())?;
+
+        let mut captures = HashMap::new();
+        for (atoms, (_, inst)) in self.insts.iter().zip(window) {
+            let text = inst.to_string();
+            let words = words(&text);
+            if words.len() != atoms.len() {
+                return None;
+            }
+            for (atom, word) in atoms.iter().zip(words) {
+                match atom {
+                    Atom::Any => {}
+                    Atom::Literal(literal) if literal == word => {}
+                    Atom::Literal(_) => return None,
+                    Atom::Capture(name) => match captures.get(name) {
+                        Some(captured) if captured != word => return None,
+                        Some(_) => {}
+                        None => {
+                            captures.insert(name.clone(), word.to_string());
+                        }
+                    },
+                }
+            }
+        }
+
+        Some(Match {
+            addr: insts[index].0,
+            index,
+            len: self.insts.len(),
+            captures,
+        })
+    }
+
+    // every place the pattern matches, matches are allowed to overlap
+    pub fn find_all(&self, insts: &[(InstructionSize, InstructionDecoded)]) -> Vec<Match> {
+        (0..insts.len())
+            .filter_map(|index| self.match_at(insts, index))
+            .collect()
+    }
+}
+
+#[test]
+fn parse() {
+    let pattern = Pattern::parse("lui %r, %hi ; addi %r, %r, *").unwrap();
+    assert_eq!(pattern.len(), 2);
+    assert_eq!(
+        pattern.insts[1],
+        vec![
+            Atom::Literal("addi".to_string()),
+            Atom::Capture("r".to_string()),
+            Atom::Capture("r".to_string()),
+            Atom::Any,
+        ]
+    );
+    assert!(Pattern::parse("lui %r ;; addi").is_err());
+    assert!(Pattern::parse("lui %, 1").is_err());
+}
+
+#[test]
+fn find() {
+    use crate::decoder::try_decode;
+    let insts = [
+        (0x0, 0x00004537 /* lui x10, 4 */),
+        (0x4, 0x06450513 /* addi x10, x10, 100 */),
+        (0x8, 0x00004537 /* lui x10, 4 */),
+        (0xc, 0x06468613 /* addi x12 x13 100 */),
+    ]
+    .into_iter()
+    .map(|(addr, inst)| (addr, try_decode(inst).unwrap()))
+    .collect::<Vec<_>>();
+
+    let pattern = Pattern::parse("lui %r, %hi ; addi %r, %r, %lo").unwrap();
+    let matches = pattern.find_all(&insts);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].addr, 0x0);
+    assert_eq!(matches[0].len, 2);
+    assert_eq!(matches[0].captures["r"], "a0");
+    assert_eq!(matches[0].captures["hi"], "0x4");
+    assert_eq!(matches[0].captures["lo"], "100");
+
+    let pattern = Pattern::parse("addi * * 100").unwrap();
+    assert_eq!(pattern.find_all(&insts).len(), 2);
+}
@@ -0,0 +1,171 @@
+use crate::decoded_inst::InstructionDecoded;
+use crate::instructions::{InstructionSize, SignedInstructionSize};
+
+// what the low half of a pc relative pair is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PcRelKind {
+    // la: auipc rd, %pcrel_hi; addi rd, rd, %pcrel_lo
+    Addi,
+    // load from a pc relative address: auipc rd, %pcrel_hi; lw rd, %pcrel_lo(rd)
+    Lw,
+    // far call / tail: auipc rd, %pcrel_hi; jalr rd, %pcrel_lo(rd)
+    Jalr,
+}
+
+// splits a pc relative offset into the 20 bit auipc immediate and the 12 bit signed low part,
+// since the low part gets sign extended the high part is rounded up whenever bit 11 is set
+pub const fn split_offset(
+    offset: SignedInstructionSize,
+) -> (InstructionSize, SignedInstructionSize) {
+    let hi = ((offset.wrapping_add(0x800) >> 12) as InstructionSize) & 0xFFFFF;
+    let lo = offset.wrapping_sub((hi << 12) as SignedInstructionSize);
+    (hi, lo)
+}
+
+// builds the auipc pair that makes `rd` (or the load/jump) reach `target` when placed at `pc`
+pub fn pcrel_sequence(
+    pc: InstructionSize,
+    target: InstructionSize,
+    rd: InstructionSize,
+    kind: PcRelKind,
+) -> [InstructionDecoded; 2] {
+    let (hi, lo) = split_offset(target.wrapping_sub(pc) as SignedInstructionSize);
+    let imm = lo as InstructionSize;
+    [
+        InstructionDecoded::AuiPc { rd, imm: hi },
+        match kind {
+            PcRelKind::Addi => InstructionDecoded::Addi { rd, rs1: rd, imm },
+            PcRelKind::Lw => InstructionDecoded::Lw { rd, rs1: rd, imm },
+            PcRelKind::Jalr => InstructionDecoded::Jalr { rd, rs1: rd, imm },
+        },
+    ]
+}
+
+// the address auipc leaves in its destination register when it's run at `pc`
+pub fn auipc_target(pc: InstructionSize, inst: &InstructionDecoded) -> Option<InstructionSize> {
+    match inst {
+        InstructionDecoded::AuiPc { imm, .. } => Some(pc.wrapping_add(imm << 12)),
+        _ => None,
+    }
+}
+
+// the final address of an auipc pair (auipc at `pc` followed by `next`), `None` when `next` doesn't
+// use the register auipc wrote as its base
+pub fn pcrel_target(
+    pc: InstructionSize,
+    auipc: &InstructionDecoded,
+    next: &InstructionDecoded,
+) -> Option<InstructionSize> {
+    let InstructionDecoded::AuiPc { rd, .. } = auipc else {
+        return None;
+    };
+    let (rs1, imm) = match next {
+        InstructionDecoded::Addi { rs1, imm, .. }
+        | InstructionDecoded::Lb { rs1, imm, .. }
+        | InstructionDecoded::Lh { rs1, imm, .. }
+        | InstructionDecoded::Lw { rs1, imm, .. }
+        | InstructionDecoded::Lbu { rs1, imm, .. }
+        | InstructionDecoded::Lhu { rs1, imm, .. }
+        | InstructionDecoded::Lwu { rs1, imm, .. }
+        | InstructionDecoded::Ld { rs1, imm, .. }
+        | InstructionDecoded::Sb { rs1, imm, .. }
+        | InstructionDecoded::Sh { rs1, imm, .. }
+        | InstructionDecoded::Sw { rs1, imm, .. }
+        | InstructionDecoded::Sd { rs1, imm, .. }
+        | InstructionDecoded::Jalr { rs1, imm, .. } => (rs1, imm),
+        _ => return None,
+    };
+    if rs1 != rd || *rd == 0 {
+        return None;
+    }
+    Some(auipc_target(pc, auipc)?.wrapping_add(*imm))
+}
+
+#[test]
+fn split() {
+    assert_eq!(split_offset(0), (0, 0));
+    assert_eq!(split_offset(-4), (0, -4));
+    assert_eq!(split_offset(0x7FF), (0, 0x7FF));
+    // bit 11 set means the low part is negative so the high part gets rounded up
+    assert_eq!(split_offset(0x800), (1, -0x800));
+    assert_eq!(split_offset(0x12345678), (0x12345, 0x678));
+    assert_eq!(split_offset(0x12345FFF), (0x12346, -1));
+    assert_eq!(split_offset(-0x12345678), (0xEDCBB, -0x678));
+    assert_eq!(split_offset(0x7FFFF800), (0x80000, -0x800));
+
+    for offset in [
+        SignedInstructionSize::MIN,
+        SignedInstructionSize::MAX,
+        -0x801,
+        -0x800,
+        0x7FF,
+        0x800,
+        0xFFF,
+        0x1000,
+    ] {
+        let (hi, lo) = split_offset(offset);
+        assert!((-0x800..0x800).contains(&lo));
+        assert_eq!(
+            (hi << 12).wrapping_add(lo as InstructionSize),
+            offset as InstructionSize
+        );
+    }
+}
+
+#[test]
+fn sequence() {
+    let [auipc, addi] = pcrel_sequence(0x1000, 0x2804, 10, PcRelKind::Addi);
+    assert_eq!(auipc, InstructionDecoded::AuiPc { rd: 10, imm: 2 });
+    assert_eq!(
+        addi,
+        InstructionDecoded::Addi {
+            rd: 10,
+            rs1: 10,
+            imm: -0x7FC_i32 as InstructionSize
+        }
+    );
+    let [auipc, jalr] = pcrel_sequence(0x2000, 0x1000, 1, PcRelKind::Jalr);
+    assert_eq!(
+        auipc,
+        InstructionDecoded::AuiPc {
+            rd: 1,
+            imm: 0xFFFFF
+        }
+    );
+    assert_eq!(
+        jalr,
+        InstructionDecoded::Jalr {
+            rd: 1,
+            rs1: 1,
+            imm: 0
+        }
+    );
+    let [_, lw] = pcrel_sequence(0x0, 0x10, 5, PcRelKind::Lw);
+    assert_eq!(
+        lw,
+        InstructionDecoded::Lw {
+            rd: 5,
+            rs1: 5,
+            imm: 0x10
+        }
+    );
+}
+
+#[test]
+fn targets() {
+    let [auipc, addi] = pcrel_sequence(0x1000, 0x2804, 10, PcRelKind::Addi);
+    assert_eq!(auipc_target(0x1000, &auipc), Some(0x3000));
+    assert_eq!(pcrel_target(0x1000, &auipc, &addi), Some(0x2804));
+    assert_eq!(auipc_target(0x1000, &addi), None);
+
+    let [auipc, jalr] = pcrel_sequence(0x2000, 0x1000, 1, PcRelKind::Jalr);
+    assert_eq!(pcrel_target(0x2000, &auipc, &jalr), Some(0x1000));
+
+    // the second instruction has to use what auipc wrote
+    let other = InstructionDecoded::Addi {
+        rd: 10,
+        rs1: 11,
+        imm: 4,
+    };
+    assert_eq!(pcrel_target(0x2000, &auipc, &other), None);
+}
@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use crate::decoded_inst::REG_NAMES;
+use crate::instructions::InstructionSize;
+
+// what the standard calling convention uses a register for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AbiRole {
+    Zero,
+    ReturnAddress,
+    StackPointer,
+    GlobalPointer,
+    ThreadPointer,
+    // t0-t6, caller saved
+    Temporary,
+    // s0-s11 (s0 doubles as the frame pointer), callee saved
+    Saved,
+    // a0-a7, a0 and a1 also hold return values
+    Argument,
+}
+
+// an integer register, x0 to x31
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Register(u8);
+
+impl Register {
+    pub const fn new(num: InstructionSize) -> Option<Self> {
+        if num < 32 {
+            Some(Self(num as u8))
+        } else {
+            None
+        }
+    }
+
+    // accepts `x10`, `a0` and `fp`
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name == "fp" {
+            return Some(Self(8));
+        }
+        if let Some(num) = name.strip_prefix('x').and_then(|num| num.parse().ok()) {
+            return Self::new(num);
+        }
+        REG_NAMES
+            .iter()
+            .position(|n| *n == name)
+            .map(|num| Self(num as u8))
+    }
+
+    pub const fn num(&self) -> InstructionSize {
+        self.0 as InstructionSize
+    }
+
+    // the abi name, what `Display` prints
+    pub const fn name(&self) -> &'static str {
+        REG_NAMES[self.0 as usize]
+    }
+
+    // s0 is also called fp since it's used as the frame pointer
+    pub const fn alias(&self) -> Option<&'static str> {
+        match self.0 {
+            8 => Some("fp"),
+            _ => None,
+        }
+    }
+
+    pub const fn role(&self) -> AbiRole {
+        match self.0 {
+            0 => AbiRole::Zero,
+            1 => AbiRole::ReturnAddress,
+            2 => AbiRole::StackPointer,
+            3 => AbiRole::GlobalPointer,
+            4 => AbiRole::ThreadPointer,
+            5..=7 | 28..=31 => AbiRole::Temporary,
+            8 | 9 | 18..=27 => AbiRole::Saved,
+            _ => AbiRole::Argument,
+        }
+    }
+
+    // has to be preserved across calls by the callee, sp counts since it has to be restored too
+    pub const fn is_callee_saved(&self) -> bool {
+        matches!(self.role(), AbiRole::Saved | AbiRole::StackPointer)
+    }
+
+    // can be clobbered by any call
+    pub const fn is_caller_saved(&self) -> bool {
+        matches!(
+            self.role(),
+            AbiRole::ReturnAddress | AbiRole::Temporary | AbiRole::Argument
+        )
+    }
+
+    pub const fn is_argument(&self) -> bool {
+        matches!(self.role(), AbiRole::Argument)
+    }
+
+    pub const fn is_temporary(&self) -> bool {
+        matches!(self.role(), AbiRole::Temporary)
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[test]
+fn metadata() {
+    let a0 = Register::from_name("a0").unwrap();
+    assert_eq!(a0.num(), 10);
+    assert!(a0.is_argument() && a0.is_caller_saved() && !a0.is_callee_saved());
+
+    let fp = Register::from_name("fp").unwrap();
+    assert_eq!(fp, Register::new(8).unwrap());
+    assert_eq!(fp.to_string(), "s0");
+    assert_eq!(fp.alias(), Some("fp"));
+    assert!(fp.is_callee_saved());
+
+    assert_eq!(Register::from_name("x31").unwrap().name(), "t6");
+    assert!(Register::from_name("t6").unwrap().is_temporary());
+    assert_eq!(Register::from_name("s11").unwrap().role(), AbiRole::Saved);
+    assert_eq!(
+        Register::from_name("sp").unwrap().role(),
+        AbiRole::StackPointer
+    );
+    assert!(Register::from_name("sp").unwrap().is_callee_saved());
+    assert_eq!(Register::new(32), None);
+    assert_eq!(Register::from_name("x32"), None);
+
+    // every register is exactly one of the three (or special)
+    for num in 0..32 {
+        let reg = Register::new(num).unwrap();
+        assert!(!(reg.is_callee_saved() && reg.is_caller_saved()));
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use crate::instructions::InstructionSize;
+
+// the rm field of floating point instructions, dyn uses whatever frm holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RoundingMode {
+    // round to nearest, ties to even
+    Rne,
+    // round towards zero
+    Rtz,
+    // round down (towards -inf)
+    Rdn,
+    // round up (towards +inf)
+    Rup,
+    // round to nearest, ties to max magnitude
+    Rmm,
+    Dyn,
+}
+
+impl RoundingMode {
+    // 0b101 and 0b110 are reserved
+    pub const fn from_bits(rm: InstructionSize) -> Option<Self> {
+        match rm & 0b111 {
+            0b000 => Some(RoundingMode::Rne),
+            0b001 => Some(RoundingMode::Rtz),
+            0b010 => Some(RoundingMode::Rdn),
+            0b011 => Some(RoundingMode::Rup),
+            0b100 => Some(RoundingMode::Rmm),
+            0b111 => Some(RoundingMode::Dyn),
+            _ => None,
+        }
+    }
+
+    pub const fn bits(&self) -> InstructionSize {
+        match self {
+            RoundingMode::Rne => 0b000,
+            RoundingMode::Rtz => 0b001,
+            RoundingMode::Rdn => 0b010,
+            RoundingMode::Rup => 0b011,
+            RoundingMode::Rmm => 0b100,
+            RoundingMode::Dyn => 0b111,
+        }
+    }
+
+    // the name assemblers take as the last operand, `rtz`
+    pub const fn name(&self) -> &'static str {
+        match self {
+            RoundingMode::Rne => "rne",
+            RoundingMode::Rtz => "rtz",
+            RoundingMode::Rdn => "rdn",
+            RoundingMode::Rup => "rup",
+            RoundingMode::Rmm => "rmm",
+            RoundingMode::Dyn => "dyn",
+        }
+    }
+}
+
+impl Display for RoundingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[test]
+fn rounding_modes() {
+    for bits in 0..8 {
+        match RoundingMode::from_bits(bits) {
+            Some(rm) => assert_eq!(rm.bits(), bits),
+            None => assert!(bits == 0b101 || bits == 0b110),
+        }
+    }
+    assert_eq!(RoundingMode::from_bits(1).unwrap().to_string(), "rtz");
+}
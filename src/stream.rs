@@ -0,0 +1,279 @@
+use anyhow::Result;
+
+use crate::decoded_inst::InstructionDecoded;
+use crate::decoder::Decoder;
+use crate::error::DecodeError;
+use crate::instructions::{compressed::is_compressed, instruction_length, InstructionSize};
+
+pub struct Decoded {
+    pub addr: InstructionSize,
+    // the first 32 bits for instructions longer than that
+    pub raw: InstructionSize,
+    // how many bytes were consumed, the next item starts at `addr + len`
+    pub len: usize,
+    pub inst: Result<InstructionDecoded>,
+}
+
+// decodes a little endian buffer one instruction at a time, nothing is decoded until it's asked for
+// so this works for buffers of any size
+pub struct InstructionStream<'a> {
+    bytes: &'a [u8],
+    addr: InstructionSize,
+    offset: usize,
+    decoder: Decoder,
+}
+
+impl<'a> InstructionStream<'a> {
+    // `base` is the address of the first byte in `bytes`
+    pub fn new(bytes: &'a [u8], base: InstructionSize) -> Result<Self, DecodeError> {
+        Self::starting_at(bytes, base, base)
+    }
+
+    // starts decoding at `start` instead of at the beginning of the buffer, this has to be on a
+    // parcel boundary or everything after it would be garbage (and inside the buffer, the very end
+    // is fine and gives an empty stream)
+    pub fn starting_at(
+        bytes: &'a [u8],
+        base: InstructionSize,
+        start: InstructionSize,
+    ) -> Result<Self, DecodeError> {
+        if !start.is_multiple_of(2) {
+            return Err(DecodeError::MisalignedStart(start));
+        }
+        let offset = start.wrapping_sub(base) as usize;
+        if start < base || offset > bytes.len() {
+            return Err(DecodeError::StartOutOfRange(start));
+        }
+        Ok(Self {
+            bytes,
+            addr: start,
+            offset,
+            decoder: Decoder::new(),
+        })
+    }
+
+    // decode with `decoder` instead of a default (rv32) one
+    pub fn with_decoder(self, decoder: Decoder) -> Self {
+        Self { decoder, ..self }
+    }
+}
+
+impl Iterator for InstructionStream<'_> {
+    type Item = Decoded;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.bytes[self.offset..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        let addr = self.addr;
+        if rest.len() < 2 {
+            self.offset = self.bytes.len();
+            return Some(Decoded {
+                addr,
+                raw: rest[0] as InstructionSize,
+                len: 1,
+                inst: Err(DecodeError::UnknownInstruction.into()),
+            });
+        }
+
+        let low = u16::from_le_bytes([rest[0], rest[1]]);
+        // reserved lengths can't be skipped properly, they're treated like an unknown 32 bit
+        // instruction (0xffffffff from erased flash is one of them)
+        let size = instruction_length(low).unwrap_or(4);
+        if rest.len() < size {
+            // the rest of the instruction got cut off, nothing more can be decoded after this
+            self.offset = self.bytes.len();
+            return Some(Decoded {
+                addr,
+                raw: low as InstructionSize,
+                len: rest.len(),
+                inst: Err(DecodeError::UnknownInstruction.into()),
+            });
+        }
+        let raw = match size {
+            2 => low as InstructionSize,
+            _ => u32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]),
+        };
+        let inst = match instruction_length(low) {
+            Some(2 | 4) => self.decoder.decode(raw),
+            Some(len) => Err(DecodeError::UnsupportedLength(len).into()),
+            None => Err(DecodeError::UnknownInstruction.into()),
+        };
+
+        self.offset += size;
+        self.addr = self.addr.wrapping_add(size as InstructionSize);
+        Some(Decoded {
+            addr,
+            raw,
+            len: size,
+            inst,
+        })
+    }
+}
+
+// the raw instruction at the start of `bytes`, `None` if it's cut off
+fn raw_at(bytes: &[u8]) -> Option<InstructionSize> {
+    let low = u16::from_le_bytes([*bytes.first()?, *bytes.get(1)?]) as InstructionSize;
+    if is_compressed(low) {
+        return Some(low);
+    }
+    let high = u16::from_le_bytes([*bytes.get(2)?, *bytes.get(3)?]) as InstructionSize;
+    Some(low | high << 16)
+}
+
+// every address in `bytes` where an instruction matching `pred` starts. unlike `InstructionStream`
+// this doesn't follow the instruction stream, every halfword is tried as a start (so 4 byte
+// instructions are found at both 2 and 4 byte alignment), which is what gadget and audit tools want
+pub fn find_instructions<F>(
+    decoder: &Decoder,
+    bytes: &[u8],
+    base: InstructionSize,
+    mut pred: F,
+) -> Vec<InstructionSize>
+where
+    F: FnMut(&InstructionDecoded) -> bool,
+{
+    (0..bytes.len())
+        .step_by(2)
+        .filter(|offset| {
+            raw_at(&bytes[*offset..])
+                .and_then(|raw| decoder.decode(raw).ok())
+                .is_some_and(|inst| pred(&inst))
+        })
+        .map(|offset| base.wrapping_add(offset as InstructionSize))
+        .collect()
+}
+
+#[test]
+fn stream() {
+    let bytes = [
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+        0x02, 0x86, /* c.jr x12 */
+        0x6f, 0x00, 0x00, 0x01, /* jal x0 16 */
+        0x13, /* cut off */
+    ];
+    let decoded = InstructionStream::new(&bytes, 0x1000)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(decoded.len(), 4);
+    assert_eq!(decoded[0].addr, 0x1000);
+    assert_eq!(decoded[0].raw, 0x06468613);
+    assert_eq!(decoded[0].len, 4);
+    assert!(decoded[0].inst.is_ok());
+    assert_eq!(decoded[1].addr, 0x1004);
+    assert_eq!(decoded[1].raw, 0x8602);
+    assert_eq!(decoded[1].len, 2);
+    assert_eq!(decoded[2].addr, 0x1006);
+    assert_eq!(
+        decoded[2].inst.as_ref().unwrap(),
+        &InstructionDecoded::Jal { rd: 0, imm: 16 }
+    );
+    assert!(decoded[3].inst.is_err());
+    assert_eq!(decoded[3].len, 1);
+}
+
+#[test]
+fn long_instructions() {
+    let bytes = [
+        0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, /* 48 bit */
+        0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, /* 64 bit */
+        0x13, 0x86, 0x46, 0x06, /* addi x12 x13 100 */
+        0x3f, 0x00, 0x00, 0x00, /* cut off 64 bit */
+    ];
+    let decoded = InstructionStream::new(&bytes, 0x1000)
+        .unwrap()
+        .collect::<Vec<_>>();
+    let lens = decoded.iter().map(|item| item.len).collect::<Vec<_>>();
+    assert_eq!(lens, vec![6, 8, 4, 4]);
+    let error = |item: &Decoded| item.inst.as_ref().unwrap_err().downcast_ref().cloned();
+    assert_eq!(error(&decoded[0]), Some(DecodeError::UnsupportedLength(6)));
+    assert_eq!(error(&decoded[1]), Some(DecodeError::UnsupportedLength(8)));
+    // still in sync after the long ones
+    assert_eq!(decoded[2].addr, 0x100e);
+    assert!(decoded[2].inst.is_ok());
+    assert_eq!(error(&decoded[3]), Some(DecodeError::UnknownInstruction));
+}
+
+#[test]
+fn misaligned_start() {
+    let bytes = [0x13, 0x86, 0x46, 0x06, 0x02, 0x86];
+    assert_eq!(
+        InstructionStream::new(&bytes, 0x1001).err(),
+        Some(DecodeError::MisalignedStart(0x1001))
+    );
+    assert_eq!(
+        InstructionStream::starting_at(&bytes, 0x1000, 0x1003).err(),
+        Some(DecodeError::MisalignedStart(0x1003))
+    );
+    assert_eq!(
+        InstructionStream::starting_at(&bytes, 0x1000, 0xffe).err(),
+        Some(DecodeError::StartOutOfRange(0xffe))
+    );
+    assert_eq!(
+        InstructionStream::starting_at(&bytes, 0x1000, 0x1008).err(),
+        Some(DecodeError::StartOutOfRange(0x1008))
+    );
+    assert_eq!(
+        InstructionStream::starting_at(&bytes, 0x1000, 0x1006)
+            .unwrap()
+            .count(),
+        0
+    );
+    let decoded = InstructionStream::starting_at(&bytes, 0x1000, 0x1004)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(decoded.len(), 1);
+    assert_eq!(decoded[0].addr, 0x1004);
+    assert_eq!(decoded[0].raw, 0x8602);
+}
+
+#[test]
+fn find() {
+    let bytes = [
+        0x73, 0x00, 0x00, 0x00, /* ecall */
+        0x13, 0x86, /* first half of addi x12 x13 100 */
+        0x73, 0x00, 0x00, 0x00, /* ecall, only 2 byte aligned */
+        0x73, 0x00, /* cut off */
+    ];
+    let decoder = Decoder::new();
+    let found = find_instructions(&decoder, &bytes, 0x1000, |inst| {
+        *inst == InstructionDecoded::ECall
+    });
+    assert_eq!(found, vec![0x1000, 0x1006]);
+    assert!(find_instructions(&decoder, &bytes, 0x1000, |_| false).is_empty());
+}
+
+#[test]
+fn decoder() {
+    use crate::decoder::Xlen;
+    let bytes = [
+        0x02, 0x65, /* c.flwsp fa0, 0(sp) on rv32, c.ldsp a0, 0(sp) on rv64 */
+        0x03, 0x35, 0x85, 0x00, /* ld a0, 8(a0) */
+    ];
+    let text = |stream: InstructionStream| {
+        stream
+            .map(|item| item.inst.map(|inst| inst.to_string()).ok())
+            .collect::<Vec<_>>()
+    };
+    let rv32 = InstructionStream::new(&bytes, 0x1000).unwrap();
+    assert_eq!(
+        text(rv32),
+        vec![Some("c.flwsp a0, 0(sp)".to_string()), None]
+    );
+    let rv64 = Decoder {
+        xlen: Xlen::Rv64,
+        ..Decoder::new()
+    };
+    let stream = InstructionStream::new(&bytes, 0x1000)
+        .unwrap()
+        .with_decoder(rv64);
+    assert_eq!(
+        text(stream),
+        vec![
+            Some("c.ldsp a0, 0(sp)".to_string()),
+            Some("ld a0, 8(a0)".to_string())
+        ]
+    );
+}
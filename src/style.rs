@@ -0,0 +1,629 @@
+use std::fmt::Display;
+
+use crate::decoded_inst::{rm_suffix, InstructionDecoded, Latest, REG_NAMES};
+use crate::instructions::{ntl_all, ntl_p1, ntl_pall, ntl_s1, pause, InstructionSize};
+use crate::rounding::RoundingMode;
+
+// every change to what gets printed bumps the version, pin one with `DisplayStyle::version` to
+// keep output (and golden tests built on it) exactly the same
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum StyleVersion {
+    // 0.1.3, atomics print their fields in order with aq/rl as numbers
+    V1,
+    // atomics in assembler syntax, `amoswap.w.aq a0, a2, (a1)`
+    V2,
+    // fp instructions print their rounding mode when it isn't dyn, `fcvt.w.s a0, a0, rtz`, and
+    // `pause` and the `ntl.*` hints are printed by name
+    V3,
+}
+
+// how the 20 bit immediate of lui/auipc gets printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UpperImmediate {
+    // the immediate as it's encoded, `lui a0, 0x12345` (what `Display` does)
+    #[default]
+    Raw,
+    // the value that actually ends up in the register, `lui a0, 0x12345000`. auipc keeps the raw
+    // immediate and gets the offset it adds to pc as a comment
+    Shifted,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RegisterNames {
+    // `a0`, `sp`
+    #[default]
+    Abi,
+    // `x10`, `x2`
+    Numeric,
+}
+
+// radix of immediates that are printed in decimal by default, the lui immediate is always hex
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Radix {
+    #[default]
+    Decimal,
+    Hex,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AmoSyntax {
+    // `amoswap.w.aq a0, a2, (a1)`
+    #[default]
+    Assembler,
+    // `amoswap.w a0, a1, a2, 0, 1`, rs1 before rs2 and rl before aq
+    Fields,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayStyle {
+    pub upper_immediate: UpperImmediate,
+    pub registers: RegisterNames,
+    pub radix: Radix,
+    pub amo: AmoSyntax,
+    // print single instruction pseudos like `nop`, `li`, `mv`, `j` and `ret` where they apply
+    // (folding sequences like `lui`+`addi` is done by the listing)
+    pub pseudo: bool,
+    // print the rounding mode of fp instructions when it isn't dyn, without it the instruction
+    // reads as if it used frm
+    pub rounding_mode: bool,
+    // print hints by name, `pause` rather than the `fence 1, 0` it's encoded as and `ntl.p1` rather
+    // than `add zero, zero, sp`
+    pub hints: bool,
+}
+
+impl DisplayStyle {
+    pub const LATEST: StyleVersion = StyleVersion::V3;
+    // what `Default` and `Display` use, it only moves when it's bumped here on purpose and never
+    // just because a new version was added
+    pub const DEFAULT_VERSION: StyleVersion = StyleVersion::V3;
+
+    // the defaults as they were in `version`
+    pub const fn version(version: StyleVersion) -> Self {
+        let amo = match version {
+            StyleVersion::V1 => AmoSyntax::Fields,
+            StyleVersion::V2 | StyleVersion::V3 => AmoSyntax::Assembler,
+        };
+        Self {
+            upper_immediate: UpperImmediate::Raw,
+            registers: RegisterNames::Abi,
+            radix: Radix::Decimal,
+            amo,
+            pseudo: false,
+            rounding_mode: matches!(version, StyleVersion::V3),
+            hints: matches!(version, StyleVersion::V3),
+        }
+    }
+
+    // register names and immediates of already formatted text, the mnemonic and anything after a
+    // `#` comment are left alone
+    pub(crate) fn restyle(&self, text: &str) -> String {
+        if self.registers == RegisterNames::Abi && self.radix == Radix::Decimal {
+            return text.to_string();
+        }
+        let Some((mnemonic, rest)) = text.split_once(' ') else {
+            return text.to_string();
+        };
+        let (operands, comment) = match rest.find(" #") {
+            Some(index) => rest.split_at(index),
+            None => (rest, ""),
+        };
+
+        let mut out = format!("{mnemonic} ");
+        let mut token = String::new();
+        for c in operands.chars() {
+            if matches!(c, ',' | ' ' | '(' | ')') {
+                out += &self.token(&token);
+                token.clear();
+                out.push(c);
+            } else {
+                token.push(c);
+            }
+        }
+        out += &self.token(&token);
+        out += comment;
+        out
+    }
+
+    fn token(&self, token: &str) -> String {
+        if self.registers == RegisterNames::Numeric {
+            if let Some(num) = REG_NAMES.iter().position(|name| *name == token) {
+                return format!("x{num}");
+            }
+        }
+        if self.radix == Radix::Hex {
+            if let Ok(value) = token.parse::<i64>() {
+                let sign = if value < 0 { "-" } else { "" };
+                return format!("{sign}{:#x}", value.unsigned_abs());
+            }
+        }
+        token.to_string()
+    }
+}
+
+// what `Display` does
+impl Default for DisplayStyle {
+    fn default() -> Self {
+        Self::version(Self::DEFAULT_VERSION)
+    }
+}
+
+pub struct DisplayWith<'a> {
+    inst: &'a InstructionDecoded,
+    style: DisplayStyle,
+}
+
+impl InstructionDecoded {
+    // same as `Display` but with control over how things get printed
+    pub fn display_with(&self, style: DisplayStyle) -> DisplayWith<'_> {
+        DisplayWith { inst: self, style }
+    }
+}
+
+fn pseudo(inst: &InstructionDecoded) -> Option<String> {
+    let reg = |num: &u32| REG_NAMES[*num as usize];
+    let text = match inst {
+        InstructionDecoded::Addi {
+            rd: 0,
+            rs1: 0,
+            imm: 0,
+        } => "nop".to_string(),
+        InstructionDecoded::Addi { rd, rs1: 0, imm } => format!("li {}, {}", reg(rd), *imm as i32),
+        InstructionDecoded::Addi { rd, rs1, imm: 0 } => format!("mv {}, {}", reg(rd), reg(rs1)),
+        InstructionDecoded::Xori { rd, rs1, imm } if *imm as i32 == -1 => {
+            format!("not {}, {}", reg(rd), reg(rs1))
+        }
+        InstructionDecoded::Sub { rd, rs1: 0, rs2 } => format!("neg {}, {}", reg(rd), reg(rs2)),
+        InstructionDecoded::Sltiu { rd, rs1, imm: 1 } => {
+            format!("seqz {}, {}", reg(rd), reg(rs1))
+        }
+        InstructionDecoded::Sltu { rd, rs1: 0, rs2 } => format!("snez {}, {}", reg(rd), reg(rs2)),
+        InstructionDecoded::Beq { rs1, rs2: 0, imm } => {
+            format!("beqz {}, {}", reg(rs1), *imm as i32)
+        }
+        InstructionDecoded::Bne { rs1, rs2: 0, imm } => {
+            format!("bnez {}, {}", reg(rs1), *imm as i32)
+        }
+        InstructionDecoded::Jal { rd: 0, imm } => format!("j {}", *imm as i32),
+        InstructionDecoded::Jal { rd: 1, imm } => format!("jal {}", *imm as i32),
+        InstructionDecoded::Jal { rd, imm } => format!("jal {}, {}", reg(rd), *imm as i32),
+        InstructionDecoded::Jalr {
+            rd: 0,
+            rs1: 1,
+            imm: 0,
+        } => "ret".to_string(),
+        InstructionDecoded::Jalr { rd: 0, rs1, imm: 0 } => format!("jr {}", reg(rs1)),
+        _ => return None,
+    };
+    Some(text)
+}
+
+// the 0.1.3 syntax for atomics
+fn amo_fields(inst: &InstructionDecoded) -> Option<String> {
+    let (rd, rs1, rs2, rl, aq) = match inst {
+        InstructionDecoded::LrW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::ScW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoswapW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoaddW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoandW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoorW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoxorW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmomaxW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmominW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmominuW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmomaxuW {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::LrD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::ScD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoswapD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoaddD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoxorD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoandD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmoorD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmominD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmomaxD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmominuD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        }
+        | InstructionDecoded::AmomaxuD {
+            rd,
+            rs1,
+            rs2,
+            rl,
+            aq,
+        } => (rd, rs1, rs2, rl, aq),
+        _ => return None,
+    };
+    // drop the `.aq`/`.rl` suffix, only `name.w` is left
+    let mnemonic = inst.mnemonic();
+    let name = mnemonic
+        .splitn(3, '.')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join(".");
+    Some(format!(
+        "{name} {}, {}, {}, {}, {}",
+        REG_NAMES[*rd as usize],
+        REG_NAMES[*rs1 as usize],
+        REG_NAMES[*rs2 as usize],
+        *rl as i32,
+        *aq as i32
+    ))
+}
+
+// the `add zero, zero, rs2` a Zihintntl hint is encoded as
+fn ntl_add(rs2: InstructionSize) -> String {
+    Latest(&InstructionDecoded::Add { rd: 0, rs1: 0, rs2 }).to_string()
+}
+
+// the `fence w, 0` pause is encoded as
+fn pause_fence() -> String {
+    let fence = InstructionDecoded::Fence {
+        pred: pause::IMM >> 4,
+        succ: pause::IMM & 0xf,
+    };
+    Latest(&fence).to_string()
+}
+
+// the rm field of the fp instructions that have one
+fn rounding_mode(inst: &InstructionDecoded) -> Option<RoundingMode> {
+    match inst {
+        InstructionDecoded::FmaddS { rm, .. }
+        | InstructionDecoded::FmsubS { rm, .. }
+        | InstructionDecoded::FnmaddS { rm, .. }
+        | InstructionDecoded::FnmsubS { rm, .. }
+        | InstructionDecoded::FaddS { rm, .. }
+        | InstructionDecoded::FsubS { rm, .. }
+        | InstructionDecoded::FmulS { rm, .. }
+        | InstructionDecoded::FdivS { rm, .. }
+        | InstructionDecoded::FsqrtS { rm, .. }
+        | InstructionDecoded::FcvtSW { rm, .. }
+        | InstructionDecoded::FcvtSWU { rm, .. }
+        | InstructionDecoded::FcvtWS { rm, .. }
+        | InstructionDecoded::FcvtWUS { rm, .. }
+        | InstructionDecoded::FcvtLS { rm, .. }
+        | InstructionDecoded::FcvtLUS { rm, .. }
+        | InstructionDecoded::FcvtSL { rm, .. }
+        | InstructionDecoded::FcvtSLU { rm, .. }
+        | InstructionDecoded::FcvtLD { rm, .. }
+        | InstructionDecoded::FcvtLUD { rm, .. }
+        | InstructionDecoded::FcvtDL { rm, .. }
+        | InstructionDecoded::FcvtDLU { rm, .. }
+        | InstructionDecoded::FmaddD { rm, .. }
+        | InstructionDecoded::FmsubD { rm, .. }
+        | InstructionDecoded::FnmsubD { rm, .. }
+        | InstructionDecoded::FnmaddD { rm, .. }
+        | InstructionDecoded::FaddD { rm, .. }
+        | InstructionDecoded::FsubD { rm, .. }
+        | InstructionDecoded::FmulD { rm, .. }
+        | InstructionDecoded::FdivD { rm, .. }
+        | InstructionDecoded::FsqrtD { rm, .. }
+        | InstructionDecoded::FcvtSD { rm, .. }
+        | InstructionDecoded::FcvtDS { rm, .. }
+        | InstructionDecoded::FcvtWD { rm, .. }
+        | InstructionDecoded::FcvtWUD { rm, .. }
+        | InstructionDecoded::FcvtDW { rm, .. }
+        | InstructionDecoded::FcvtDWU { rm, .. }
+        | InstructionDecoded::FaddQ { rm, .. }
+        | InstructionDecoded::FsubQ { rm, .. }
+        | InstructionDecoded::FmulQ { rm, .. }
+        | InstructionDecoded::FdivQ { rm, .. }
+        | InstructionDecoded::FsqrtQ { rm, .. }
+        | InstructionDecoded::FcvtSQ { rm, .. }
+        | InstructionDecoded::FcvtQS { rm, .. }
+        | InstructionDecoded::FcvtDQ { rm, .. }
+        | InstructionDecoded::FcvtQD { rm, .. }
+        | InstructionDecoded::FcvtWQ { rm, .. }
+        | InstructionDecoded::FcvtWUQ { rm, .. }
+        | InstructionDecoded::FcvtLQ { rm, .. }
+        | InstructionDecoded::FcvtLUQ { rm, .. }
+        | InstructionDecoded::FcvtQW { rm, .. }
+        | InstructionDecoded::FcvtQWU { rm, .. }
+        | InstructionDecoded::FcvtQL { rm, .. }
+        | InstructionDecoded::FcvtQLU { rm, .. }
+        | InstructionDecoded::FmaddQ { rm, .. }
+        | InstructionDecoded::FmsubQ { rm, .. }
+        | InstructionDecoded::FnmsubQ { rm, .. }
+        | InstructionDecoded::FnmaddQ { rm, .. }
+        | InstructionDecoded::FaddH { rm, .. }
+        | InstructionDecoded::FsubH { rm, .. }
+        | InstructionDecoded::FmulH { rm, .. }
+        | InstructionDecoded::FdivH { rm, .. }
+        | InstructionDecoded::FsqrtH { rm, .. }
+        | InstructionDecoded::FcvtSH { rm, .. }
+        | InstructionDecoded::FcvtHS { rm, .. }
+        | InstructionDecoded::FcvtDH { rm, .. }
+        | InstructionDecoded::FcvtHD { rm, .. }
+        | InstructionDecoded::FcvtWH { rm, .. }
+        | InstructionDecoded::FcvtWUH { rm, .. }
+        | InstructionDecoded::FcvtLH { rm, .. }
+        | InstructionDecoded::FcvtLUH { rm, .. }
+        | InstructionDecoded::FcvtHW { rm, .. }
+        | InstructionDecoded::FcvtHWU { rm, .. }
+        | InstructionDecoded::FcvtHL { rm, .. }
+        | InstructionDecoded::FcvtHLU { rm, .. }
+        | InstructionDecoded::FmaddH { rm, .. }
+        | InstructionDecoded::FmsubH { rm, .. }
+        | InstructionDecoded::FnmsubH { rm, .. }
+        | InstructionDecoded::FnmaddH { rm, .. }
+        | InstructionDecoded::FroundS { rm, .. }
+        | InstructionDecoded::FroundnxS { rm, .. }
+        | InstructionDecoded::FroundD { rm, .. }
+        | InstructionDecoded::FroundnxD { rm, .. } => Some(*rm),
+        _ => None,
+    }
+}
+
+impl Display for DisplayWith<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let style = &self.style;
+        let text = match (self.inst, style.upper_immediate) {
+            (inst, _) if style.pseudo && pseudo(inst).is_some() => pseudo(inst).unwrap(),
+            (inst, _) if style.amo == AmoSyntax::Fields && amo_fields(inst).is_some() => {
+                amo_fields(inst).unwrap()
+            }
+            (InstructionDecoded::Pause, _) if !style.hints => pause_fence(),
+            (InstructionDecoded::NtlP1, _) if !style.hints => ntl_add(ntl_p1::RS2),
+            (InstructionDecoded::NtlPall, _) if !style.hints => ntl_add(ntl_pall::RS2),
+            (InstructionDecoded::NtlS1, _) if !style.hints => ntl_add(ntl_s1::RS2),
+            (InstructionDecoded::NtlAll, _) if !style.hints => ntl_add(ntl_all::RS2),
+            (InstructionDecoded::Lui { rd, imm }, UpperImmediate::Shifted) => {
+                format!("lui {}, {:#X}", REG_NAMES[*rd as usize], *imm << 12)
+            }
+            (InstructionDecoded::AuiPc { imm, .. }, UpperImmediate::Shifted) => {
+                let offset = (*imm << 12) as i32;
+                let sign = if offset < 0 { '-' } else { '+' };
+                format!(
+                    "{} # pc {sign} {:#x}",
+                    Latest(self.inst),
+                    offset.unsigned_abs()
+                )
+            }
+            (inst, _) => Latest(inst).to_string(),
+        };
+        let text = match rounding_mode(self.inst) {
+            Some(rm) if !style.rounding_mode => match text.strip_suffix(rm_suffix(rm)) {
+                Some(text) => text.to_string(),
+                None => text,
+            },
+            _ => text,
+        };
+        write!(f, "{}", style.restyle(&text))
+    }
+}
+
+#[test]
+fn shifted_upper_immediate() {
+    let shifted = DisplayStyle {
+        upper_immediate: UpperImmediate::Shifted,
+        ..Default::default()
+    };
+    let lui = InstructionDecoded::Lui {
+        rd: 10,
+        imm: 0x12345,
+    };
+    assert_eq!(lui.to_string(), "lui a0, 0x12345");
+    assert_eq!(
+        lui.display_with(DisplayStyle::default()).to_string(),
+        "lui a0, 0x12345"
+    );
+    assert_eq!(lui.display_with(shifted).to_string(), "lui a0, 0x12345000");
+
+    let auipc = InstructionDecoded::AuiPc { rd: 10, imm: 2 };
+    assert_eq!(
+        auipc.display_with(shifted).to_string(),
+        "auipc a0, 2 # pc + 0x2000"
+    );
+    let auipc = InstructionDecoded::AuiPc {
+        rd: 10,
+        imm: 0xfffff,
+    };
+    assert_eq!(
+        auipc.display_with(shifted).to_string(),
+        "auipc a0, 1048575 # pc - 0x1000"
+    );
+    let add = InstructionDecoded::Add {
+        rd: 10,
+        rs1: 11,
+        rs2: 12,
+    };
+    assert_eq!(add.display_with(shifted).to_string(), add.to_string());
+}
+
+#[test]
+fn versions() {
+    use crate::decoder::try_decode;
+    let amoswap = try_decode(0x0cf4a7af /* amoswap.w.aq a5, a5, (s1) */).unwrap();
+    let v1 = DisplayStyle::version(StyleVersion::V1);
+    assert_eq!(
+        amoswap.display_with(v1).to_string(),
+        "amoswap.w a5, s1, a5, 0, 1"
+    );
+    assert_eq!(
+        amoswap.display_with(DisplayStyle::default()).to_string(),
+        amoswap.to_string()
+    );
+    // everything else is the same between the two
+    let addi = try_decode(0x06468613 /* addi x12 x13 100 */).unwrap();
+    assert_eq!(addi.display_with(v1).to_string(), addi.to_string());
+
+    // the rounding mode only shows up from v3 on
+    let fcvt = try_decode(0xc0051553 /* fcvt.w.s a0, fa0, rtz */).unwrap();
+    let v2 = DisplayStyle::version(StyleVersion::V2);
+    assert_eq!(fcvt.display_with(v1).to_string(), "fcvt.w.s a0, a0");
+    assert_eq!(fcvt.display_with(v2).to_string(), "fcvt.w.s a0, a0");
+    assert_eq!(
+        fcvt.display_with(DisplayStyle::default()).to_string(),
+        "fcvt.w.s a0, a0, rtz"
+    );
+    let fadd = try_decode(0x00c5f553 /* fadd.s fa0, fa1, fa2 (dyn) */).unwrap();
+    assert_eq!(fadd.display_with(v2).to_string(), fadd.to_string());
+
+    // and so do hints, before that they print as the instruction they're encoded as
+    let pause = try_decode(0x0100000f /* pause */).unwrap();
+    let fence = try_decode(0x0100008f /* fence with the pause imm but rd = ra */).unwrap();
+    assert_eq!(pause.display_with(v2).to_string(), fence.to_string());
+    assert_eq!(fence.to_string(), "fence 1, 0");
+    assert_eq!(
+        pause.display_with(DisplayStyle::default()).to_string(),
+        "pause"
+    );
+    let ntl = try_decode(0x00500033 /* ntl.all */).unwrap();
+    assert_eq!(ntl.display_with(v1).to_string(), "add zero, zero, t0");
+    assert_eq!(
+        ntl.display_with(DisplayStyle::default()).to_string(),
+        "ntl.all"
+    );
+
+    // `Display` sticks to the pinned version rather than following `LATEST`
+    let pinned = DisplayStyle::version(DisplayStyle::DEFAULT_VERSION);
+    assert_eq!(DisplayStyle::default(), pinned);
+    for inst in [&amoswap, &fcvt, &pause, &ntl] {
+        assert_eq!(inst.to_string(), inst.display_with(pinned).to_string());
+    }
+}
+
+#[test]
+fn styles() {
+    use crate::decoder::try_decode;
+    let style = DisplayStyle {
+        registers: RegisterNames::Numeric,
+        radix: Radix::Hex,
+        pseudo: true,
+        ..Default::default()
+    };
+    let text = |inst| try_decode(inst).unwrap().display_with(style).to_string();
+    assert_eq!(
+        text(0x06468613 /* addi x12 x13 100 */),
+        "addi x12, x13, 0x64"
+    );
+    assert_eq!(text(0x00000013 /* nop */), "nop");
+    assert_eq!(text(0xfff00513 /* addi a0, zero, -1 */), "li x10, -0x1");
+    assert_eq!(text(0x00112f23 /* sw ra, 30(sp) */), "sw x1, 0x1e(x2)");
+    assert_eq!(text(0x00008067 /* jalr zero, 0(ra) */), "ret");
+    assert_eq!(text(0x0100006f /* jal x0 16 */), "j 0x10");
+    assert_eq!(text(0xfe078ce3 /* beq x15, x0, -8 */), "beqz x15, -0x8");
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use crate::bit_ops::{get_bits, is_set};
+use crate::instructions::InstructionSize;
+
+// register group multiplier, the fractional ones use part of a single register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lmul {
+    Mf8,
+    Mf4,
+    Mf2,
+    M1,
+    M2,
+    M4,
+    M8,
+}
+
+impl Lmul {
+    // vlmul 0b100 is reserved
+    pub const fn from_bits(vlmul: InstructionSize) -> Option<Self> {
+        match vlmul & 0b111 {
+            0b000 => Some(Lmul::M1),
+            0b001 => Some(Lmul::M2),
+            0b010 => Some(Lmul::M4),
+            0b011 => Some(Lmul::M8),
+            0b101 => Some(Lmul::Mf8),
+            0b110 => Some(Lmul::Mf4),
+            0b111 => Some(Lmul::Mf2),
+            _ => None,
+        }
+    }
+
+    // the multiplier as numerator/denominator, (1, 4) for mf4
+    pub const fn ratio(&self) -> (u32, u32) {
+        match self {
+            Lmul::Mf8 => (1, 8),
+            Lmul::Mf4 => (1, 4),
+            Lmul::Mf2 => (1, 2),
+            Lmul::M1 => (1, 1),
+            Lmul::M2 => (2, 1),
+            Lmul::M4 => (4, 1),
+            Lmul::M8 => (8, 1),
+        }
+    }
+}
+
+impl Display for Lmul {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.ratio() {
+            (1, denominator) if denominator > 1 => write!(f, "mf{denominator}"),
+            (numerator, _) => write!(f, "m{numerator}"),
+        }
+    }
+}
+
+// the vtype csr, or the immediate of vsetvli/vsetivli which uses the same layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vtype {
+    // element width in bits
+    pub sew: u32,
+    pub lmul: Lmul,
+    // tail/mask agnostic
+    pub ta: bool,
+    pub ma: bool,
+    // set by the hart when a vset{i}vl{i} asked for something it doesn't support
+    pub vill: bool,
+}
+
+impl Vtype {
+    // `None` when the sew or lmul encoding is reserved
+    pub fn new(value: InstructionSize) -> Option<Self> {
+        let vsew = get_bits(value, 3, 3);
+        if vsew > 0b011 {
+            return None;
+        }
+        Some(Self {
+            sew: 8 << vsew,
+            lmul: Lmul::from_bits(get_bits(value, 3, 0))?,
+            ta: is_set(value, 6),
+            ma: is_set(value, 7),
+            // vill is the top bit of the csr, vset*vli immediates never have it set
+            vill: is_set(value, 31),
+        })
+    }
+}
+
+// the assembler syntax, `e32, m1, ta, mu`
+impl Display for Vtype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "e{}, {}, {}, {}",
+            self.sew,
+            self.lmul,
+            if self.ta { "ta" } else { "tu" },
+            if self.ma { "ma" } else { "mu" }
+        )
+    }
+}
+
+#[test]
+fn decode() {
+    let vtype = Vtype::new(0b1101_0000).unwrap();
+    assert_eq!(vtype.sew, 32);
+    assert_eq!(vtype.lmul, Lmul::M1);
+    assert!(vtype.ta && vtype.ma && !vtype.vill);
+    assert_eq!(vtype.to_string(), "e32, m1, ta, ma");
+
+    let vtype = Vtype::new(0b0001_1110).unwrap();
+    assert_eq!(vtype.to_string(), "e64, mf4, tu, mu");
+    assert_eq!(vtype.lmul.ratio(), (1, 4));
+
+    assert!(Vtype::new(0x8000_0000).unwrap().vill);
+    // reserved lmul and sew
+    assert_eq!(Vtype::new(0b100), None);
+    assert_eq!(Vtype::new(0b100_000), None);
+}
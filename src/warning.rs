@@ -0,0 +1,137 @@
+use anyhow::Result;
+
+use crate::bit_ops::get_bits;
+use crate::decoded_inst::{InstructionDecoded, SEED_CSR};
+use crate::decoder::try_decode;
+use crate::instructions::{InstructionSize, FENCE_MATCH, FLOATING_POINT_MATCH};
+
+// things that decoded fine but that a stricter tool might want to know about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeWarning {
+    // the encoding is in the HINT space (mostly writes to x0), it runs as a nop on most harts
+    Hint,
+    // rm 5 and 6 are reserved, executing this raises an illegal instruction exception
+    ReservedRoundingMode(InstructionSize),
+    // fence with fm/rs1/rd bits the base ISA doesn't define, they're ignored for now but might not
+    // be in the future
+    NonStandardFence,
+    // `seed` can only be read by instructions that also write it, csrrs/csrrc with x0 raise an
+    // illegal instruction exception instead of returning entropy
+    ReadOnlySeed,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DecodeOutcome {
+    pub inst: InstructionDecoded,
+    pub warnings: Vec<DecodeWarning>,
+}
+
+const OPCODE_MASK: InstructionSize = crate::bit_ops::create_mask(7);
+
+fn is_hint(inst: &InstructionDecoded) -> bool {
+    match inst {
+        // addi x0, x0, 0 is the canonical nop, not a hint
+        InstructionDecoded::Addi { rd: 0, rs1, imm } => *rs1 != 0 || *imm != 0,
+        InstructionDecoded::Lui { rd, .. }
+        | InstructionDecoded::AuiPc { rd, .. }
+        | InstructionDecoded::Slti { rd, .. }
+        | InstructionDecoded::Sltiu { rd, .. }
+        | InstructionDecoded::Xori { rd, .. }
+        | InstructionDecoded::Ori { rd, .. }
+        | InstructionDecoded::Andi { rd, .. }
+        | InstructionDecoded::Slli { rd, .. }
+        | InstructionDecoded::Srli { rd, .. }
+        | InstructionDecoded::Srai { rd, .. }
+        | InstructionDecoded::Add { rd, .. }
+        | InstructionDecoded::Sub { rd, .. }
+        | InstructionDecoded::Sll { rd, .. }
+        | InstructionDecoded::Slt { rd, .. }
+        | InstructionDecoded::Sltu { rd, .. }
+        | InstructionDecoded::Xor { rd, .. }
+        | InstructionDecoded::Srl { rd, .. }
+        | InstructionDecoded::Sra { rd, .. }
+        | InstructionDecoded::Or { rd, .. }
+        | InstructionDecoded::And { rd, .. } => *rd == 0,
+        // a fence that doesn't order anything
+        InstructionDecoded::Fence { pred, succ } => *pred == 0 || *succ == 0,
+        _ => false,
+    }
+}
+
+// everything worth warning about in an already decoded instruction
+pub fn warnings(raw: InstructionSize, inst: &InstructionDecoded) -> Vec<DecodeWarning> {
+    let mut warnings = Vec::new();
+    if is_hint(inst) {
+        warnings.push(DecodeWarning::Hint);
+    }
+    match raw & OPCODE_MASK {
+        FLOATING_POINT_MATCH => {
+            let rm = get_bits(raw, 3, 12);
+            if rm == 5 || rm == 6 {
+                warnings.push(DecodeWarning::ReservedRoundingMode(rm));
+            }
+        }
+        FENCE_MATCH if matches!(inst, InstructionDecoded::Fence { .. }) => {
+            let fm = get_bits(raw, 4, 28);
+            let pred_succ = get_bits(raw, 8, 20);
+            // fm 0b1000 is fence.tso which only makes sense as `fence.tso rw, rw`
+            let fm_ok = fm == 0 || (fm == 0b1000 && pred_succ == 0b0011_0011);
+            if !fm_ok || get_bits(raw, 5, 15) != 0 || get_bits(raw, 5, 7) != 0 {
+                warnings.push(DecodeWarning::NonStandardFence);
+            }
+        }
+        _ => {}
+    }
+    if inst
+        .effects()
+        .csr
+        .is_some_and(|csr| csr.csr == SEED_CSR && !csr.write)
+    {
+        warnings.push(DecodeWarning::ReadOnlySeed);
+    }
+    warnings
+}
+
+// like `try_decode` but also says what's questionable about the instruction
+pub fn try_decode_outcome(inst: InstructionSize) -> Result<DecodeOutcome> {
+    let decoded = try_decode(inst)?;
+    Ok(DecodeOutcome {
+        warnings: warnings(inst, &decoded),
+        inst: decoded,
+    })
+}
+
+#[test]
+fn hints() {
+    let outcome = try_decode_outcome(0x00000013 /* nop */).unwrap();
+    assert!(outcome.warnings.is_empty());
+    let outcome = try_decode_outcome(0x00100013 /* addi x0 x0 1 */).unwrap();
+    assert_eq!(outcome.warnings, vec![DecodeWarning::Hint]);
+    let outcome = try_decode_outcome(0x00c58033 /* add x0 x11 x12 */).unwrap();
+    assert_eq!(outcome.warnings, vec![DecodeWarning::Hint]);
+    let outcome = try_decode_outcome(0x06468613 /* addi x12 x13 100 */).unwrap();
+    assert!(outcome.warnings.is_empty());
+}
+
+#[test]
+fn fences() {
+    let outcome = try_decode_outcome(0x0ff0000f /* fence iorw, iorw */).unwrap();
+    assert!(outcome.warnings.is_empty());
+    let outcome = try_decode_outcome(0x8330000f /* fence.tso */).unwrap();
+    assert!(outcome.warnings.is_empty());
+    let outcome = try_decode_outcome(0x0ff5000f /* fence with rs1 = x10 */).unwrap();
+    assert_eq!(outcome.warnings, vec![DecodeWarning::NonStandardFence]);
+    let outcome = try_decode_outcome(0x4ff0000f /* fm = 0b0100 */).unwrap();
+    assert_eq!(outcome.warnings, vec![DecodeWarning::NonStandardFence]);
+}
+
+#[test]
+fn seed() {
+    let outcome = try_decode_outcome(0x01501573 /* csrrw a0, seed, zero */).unwrap();
+    assert!(outcome.warnings.is_empty());
+    assert!(outcome.inst.is_entropy_source());
+    let outcome = try_decode_outcome(0x01502573 /* csrrs a0, seed, zero */).unwrap();
+    assert_eq!(outcome.warnings, vec![DecodeWarning::ReadOnlySeed]);
+    assert!(!outcome.inst.is_entropy_source());
+    assert_eq!(outcome.inst.to_string(), "csrrs a0, seed, zero");
+}